@@ -108,6 +108,14 @@ impl Comment {
     pub fn is_jsdoc(&self, source_text: &str) -> bool {
         self.is_leading() && self.is_block() && self.span.source_text(source_text).starts_with('*')
     }
+
+    /// Legal comments start with `//!` or `/*!`, or contain `@license` or `@preserve`.
+    ///
+    /// <https://esbuild.github.io/api/#legal-comments>
+    pub fn is_legal(&self, source_text: &str) -> bool {
+        let text = self.span.source_text(source_text);
+        text.starts_with('!') || text.contains("@license") || text.contains("@preserve")
+    }
 }
 
 /// Sorted set of unique trivia comments, in ascending order by starting position.
@@ -120,6 +120,22 @@ impl<'a> Codegen<'a> {
         }
     }
 
+    /// Print all legal comments at the top of the file in minify mode.
+    ///
+    /// Removing whitespace can leave a legal comment attached to the wrong node, so they are
+    /// hoisted instead of being printed in place.
+    pub(crate) fn print_legal_comments(&mut self) {
+        if !self.options.minify {
+            return;
+        }
+        let Some(source_text) = self.source_text else { return };
+        let trivias = self.trivias.clone();
+        for comment in trivias.comments().filter(|comment| comment.is_legal(source_text)) {
+            self.print_comment(comment, source_text);
+            self.print_hard_newline();
+        }
+    }
+
     fn is_annotation_comment(comment: &Comment, source_text: &str) -> bool {
         let comment_content = comment.span.source_text(source_text);
         ANNOTATION_MATCHER.find_iter(comment_content).count() != 0
@@ -5,7 +5,7 @@ use cow_utils::CowUtils;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 use oxc_syntax::{
-    identifier::{LS, PS},
+    identifier::{is_identifier_name, LS, PS},
     operator::UnaryOperator,
     precedence::{GetPrecedence, Precedence},
 };
@@ -36,6 +36,7 @@ impl<'a> Gen for Program<'a> {
         if let Some(hashbang) = &self.hashbang {
             hashbang.print(p, ctx);
         }
+        p.print_legal_comments();
         for directive in &self.directives {
             directive.print(p, ctx);
         }
@@ -72,6 +73,10 @@ impl<'a> Gen for Directive<'a> {
 impl<'a> Gen for Statement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_leading_comments(self.span().start);
+        // Only expression statements can continue the previous statement
+        if !matches!(self, Self::ExpressionStatement(_)) {
+            p.omitted_semicolon = false;
+        }
         match self {
             Self::BlockStatement(stmt) => stmt.print(p, ctx),
             Self::BreakStatement(stmt) => stmt.print(p, ctx),
@@ -143,10 +148,77 @@ impl<'a> Gen for Statement<'a> {
     }
 }
 
+/// Whether `expr` starts with a token which continues the previous line when printed at
+/// the start of a statement, so the previous statement needs a `;` in [`crate::Semicolons::Asi`] mode.
+///
+/// This includes expressions which are wrapped in parentheses at the start of a statement,
+/// and may include some which are not.
+fn starts_with_asi_hazard(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::ParenthesizedExpression(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::JSXElement(_)
+        | Expression::JSXFragment(_)
+        | Expression::TSTypeAssertion(_) => true,
+        Expression::UnaryExpression(expr) => {
+            matches!(expr.operator, UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation)
+        }
+        Expression::UpdateExpression(expr) => {
+            expr.prefix || simple_assignment_target_starts_with_asi_hazard(&expr.argument)
+        }
+        Expression::AssignmentExpression(expr) => match &expr.left {
+            AssignmentTarget::ArrayAssignmentTarget(_)
+            | AssignmentTarget::ObjectAssignmentTarget(_) => true,
+            target => target
+                .as_simple_assignment_target()
+                .is_some_and(simple_assignment_target_starts_with_asi_hazard),
+        },
+        Expression::CallExpression(expr) => starts_with_asi_hazard(&expr.callee),
+        Expression::TaggedTemplateExpression(expr) => starts_with_asi_hazard(&expr.tag),
+        Expression::BinaryExpression(expr) => starts_with_asi_hazard(&expr.left),
+        Expression::LogicalExpression(expr) => starts_with_asi_hazard(&expr.left),
+        Expression::ConditionalExpression(expr) => starts_with_asi_hazard(&expr.test),
+        Expression::SequenceExpression(expr) => {
+            expr.expressions.first().is_some_and(starts_with_asi_hazard)
+        }
+        Expression::ChainExpression(expr) => match &expr.expression {
+            ChainElement::CallExpression(call) => starts_with_asi_hazard(&call.callee),
+            element => element
+                .as_member_expression()
+                .is_some_and(|member| starts_with_asi_hazard(member.object())),
+        },
+        Expression::TSAsExpression(expr) => starts_with_asi_hazard(&expr.expression),
+        Expression::TSSatisfiesExpression(expr) => starts_with_asi_hazard(&expr.expression),
+        Expression::TSNonNullExpression(expr) => starts_with_asi_hazard(&expr.expression),
+        Expression::TSInstantiationExpression(expr) => starts_with_asi_hazard(&expr.expression),
+        expr => expr
+            .as_member_expression()
+            .is_some_and(|member| starts_with_asi_hazard(member.object())),
+    }
+}
+
+fn simple_assignment_target_starts_with_asi_hazard(target: &SimpleAssignmentTarget<'_>) -> bool {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(_) => false,
+        target => target
+            .as_member_expression()
+            .map_or(true, |member| starts_with_asi_hazard(member.object())),
+    }
+}
+
 impl<'a> Gen for ExpressionStatement<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span.start);
         p.print_indent();
+        if std::mem::take(&mut p.omitted_semicolon) && starts_with_asi_hazard(&self.expression) {
+            p.print_semicolon();
+        }
         p.start_of_stmt = p.code_len();
         p.print_expression(&self.expression);
         if self.expression.is_specific_id("let") {
@@ -1560,10 +1632,14 @@ impl<'a> Gen for ObjectProperty<'a> {
             }
         }
 
+        // `{ a: a }` is only printed as `{ a }` when minifying, or if it was written as shorthand
         let mut shorthand = false;
         if let PropertyKey::StaticIdentifier(key) = &self.key {
             if let Expression::Identifier(ident) = self.value.without_parentheses() {
-                if key.name == p.get_identifier_reference_name(ident) && key.name != "__proto__" {
+                if (self.shorthand || p.options.minify)
+                    && key.name == p.get_identifier_reference_name(ident)
+                    && key.name != "__proto__"
+                {
                     shorthand = true;
                 }
             }
@@ -1589,6 +1665,13 @@ impl<'a> Gen for ObjectProperty<'a> {
 impl<'a> Gen for PropertyKey<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
+            // Keys created by transforms may not be valid identifiers, e.g. `a-b`
+            Self::StaticIdentifier(ident) if !is_identifier_name(&ident.name) => {
+                p.add_source_mapping(ident.span.start);
+                p.wrap_quote(|p, quote| {
+                    print_unquoted_str(&ident.name, quote, p);
+                });
+            }
             Self::StaticIdentifier(ident) => ident.print(p, ctx),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
             match_expression!(Self) => {
@@ -2072,7 +2155,7 @@ impl<'a> GenExpr for NewExpression<'a> {
             p.print_str("new ");
             self.callee.print_expr(p, Precedence::New, Context::FORBID_CALL);
             p.print_char(b'(');
-            let has_comment = p.has_comment(self.span.end - 1)
+            let has_comment = (self.span.end > 0 && p.has_comment(self.span.end - 1))
                 || self.arguments.iter().any(|item| p.has_comment(item.span().start));
             if has_comment {
                 p.indent();
@@ -2231,17 +2314,17 @@ impl<'a> Gen for ClassElement<'a> {
             Self::PropertyDefinition(elem) => {
                 p.print_leading_comments(elem.span.start);
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_element();
             }
             Self::AccessorProperty(elem) => {
                 p.print_leading_comments(elem.span.start);
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_element();
             }
             Self::TSIndexSignature(elem) => {
                 p.print_leading_comments(elem.span.start);
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_element();
             }
         }
     }
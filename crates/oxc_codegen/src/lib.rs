@@ -47,6 +47,26 @@ pub struct CodegenOptions {
     ///
     /// Default is `false`.
     pub minify: bool,
+
+    /// When to print semicolons after statements.
+    ///
+    /// Not used when [`CodegenOptions::minify`] is `true`, which only prints semicolons between
+    /// statements on the same line.
+    ///
+    /// Default is [`Semicolons::Always`].
+    pub semicolons: Semicolons,
+}
+
+/// When to print semicolons after statements, see [`CodegenOptions::semicolons`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Semicolons {
+    /// Print a semicolon after every statement which can end with one.
+    #[default]
+    Always,
+    /// Rely on automatic semicolon insertion, printing semicolons only where a statement
+    /// starting on the next line would otherwise continue the previous one, e.g.
+    /// `;(function () {})()` or `;[a, b] = [b, a]`.
+    Asi,
 }
 
 #[derive(Default, Clone, Copy)]
@@ -104,6 +124,9 @@ pub struct Codegen<'a> {
     /// For avoiding `;` if the previous statement ends with `}`.
     needs_semicolon: bool,
 
+    /// Whether the `;` of the previous statement was omitted in [`Semicolons::Asi`] mode.
+    omitted_semicolon: bool,
+
     prev_op: Option<Operator>,
 
     start_of_stmt: usize,
@@ -152,6 +175,7 @@ impl<'a> Codegen<'a> {
             mangler: None,
             code: vec![],
             needs_semicolon: false,
+            omitted_semicolon: false,
             need_space_before_dot: 0,
             print_next_indent_as_space: false,
             binary_expr_stack: Vec::with_capacity(5),
@@ -347,6 +371,20 @@ impl<'a> Codegen<'a> {
 
     #[inline]
     fn print_semicolon_after_statement(&mut self) {
+        if self.options.minify {
+            self.needs_semicolon = true;
+        } else if self.options.semicolons == Semicolons::Asi {
+            self.print_hard_newline();
+            self.omitted_semicolon = true;
+        } else {
+            self.print_str(";\n");
+        }
+    }
+
+    /// Class elements always end with a semicolon, as computed keys and generator methods
+    /// would continue a property definition without one.
+    #[inline]
+    fn print_semicolon_after_class_element(&mut self) {
         if self.options.minify {
             self.needs_semicolon = true;
         } else {
@@ -398,6 +436,7 @@ impl<'a> Codegen<'a> {
         }
         self.add_source_mapping(span.end);
         self.print_char(b'}');
+        self.omitted_semicolon = false;
     }
 
     fn print_block_start(&mut self, position: u32) {
@@ -412,6 +451,7 @@ impl<'a> Codegen<'a> {
         self.print_indent();
         self.add_source_mapping(position);
         self.print_char(b'}');
+        self.omitted_semicolon = false;
     }
 
     fn print_body(&mut self, stmt: &Statement<'_>, need_space: bool, ctx: Context) {
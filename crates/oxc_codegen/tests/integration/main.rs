@@ -1,7 +1,9 @@
 #![allow(clippy::missing_panics_doc)]
 pub mod esbuild;
 pub mod jsdoc;
+pub mod property_keys;
 pub mod pure_comments;
+pub mod semicolons;
 pub mod tester;
 pub mod ts;
 pub mod unit;
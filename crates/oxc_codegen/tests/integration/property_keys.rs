@@ -0,0 +1,44 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, ObjectPropertyKind, PropertyKey, Statement};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::snapshot;
+
+#[test]
+fn property_keys() {
+    let cases = vec![
+        "x = { default: 1, 'a-b': 2, 1: 3 };",
+        "x = { if: 1, class: 2, new: 3, typeof: 4 };",
+        "x = { 'a b': 1, '0x': 2, '': 3, 2.5: 4 };",
+        "x = { default() {}, 'a-b'() {}, get 1() {} };",
+    ];
+
+    snapshot("property_keys", &cases);
+}
+
+/// Keys created by transforms are not checked to be valid identifiers.
+#[test]
+fn non_identifier_static_keys() {
+    let source_text = "x = { a: 1, b: 2, c: 3 };";
+    let allocator = Allocator::default();
+    let mut ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+
+    let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first_mut() else {
+        unreachable!()
+    };
+    let Expression::AssignmentExpression(assign) = &mut stmt.expression else { unreachable!() };
+    let Expression::ObjectExpression(obj) = &mut assign.right else { unreachable!() };
+    for (prop, name) in obj.properties.iter_mut().zip(["a-b", "default", "1"]) {
+        let ObjectPropertyKind::ObjectProperty(prop) = prop else { unreachable!() };
+        let PropertyKey::StaticIdentifier(key) = &mut prop.key else { unreachable!() };
+        key.name = name.into();
+    }
+
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(result, "x = {\n\t'a-b': 1,\n\tdefault: 2,\n\t'1': 3\n};\n");
+}
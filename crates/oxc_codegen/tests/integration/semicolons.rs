@@ -0,0 +1,44 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions, Semicolons};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn test_asi(source_text: &str, expected: &str) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { semicolons: Semicolons::Asi, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(result, expected, "\nfor source: {source_text:?}");
+}
+
+#[test]
+fn omitted() {
+    test_asi("a();\nb();", "a()\nb()\n");
+    test_asi("let a = 1;\nconst b = a + 1;", "let a = 1\nconst b = a + 1\n");
+    test_asi("import a from 'a';\nexport { a };", "import a from \"a\"\nexport { a }\n");
+    test_asi("function f() {\n\treturn 1;\n}", "function f() {\n\treturn 1\n}\n");
+}
+
+#[test]
+fn guard() {
+    test_asi("a();\n(b || c)();", "a()\n;(b || c)()\n");
+    test_asi("a();\n[1, 2].forEach(f);", "a()\n;[1, 2].forEach(f)\n");
+    test_asi("a();\n`b`.length;", "a()\n;`b`.length\n");
+    test_asi("a();\n+b;", "a()\n;+b\n");
+    test_asi("let a = b;\n[a, b] = [b, a];", "let a = b\n;[a, b] = [b, a]\n");
+    test_asi("a();\n(function() {})();", "a()\n;(function() {})()\n");
+    test_asi("a();\n({}).toString();", "a()\n;({}).toString()\n");
+}
+
+#[test]
+fn no_guard_after_block() {
+    test_asi("if (a) {\n\tb();\n}\n[1].forEach(f);", "if (a) {\n\tb()\n}\n[1].forEach(f)\n");
+    test_asi("[1].forEach(f);", "[1].forEach(f)\n");
+}
+
+#[test]
+fn class_elements() {
+    test_asi("class A {\n\ta = 1;\n\t[b] = 2;\n}", "class A {\n\ta = 1;\n\t[b] = 2;\n}\n");
+}
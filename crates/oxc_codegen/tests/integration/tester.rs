@@ -36,3 +36,19 @@ pub fn test_minify(source_text: &str, expected: &str) {
         .source_text;
     assert_eq!(result, expected, "\nfor minify source: {source_text}");
 }
+
+pub fn test_minify_with_legal_comments(source_text: &str, expected: &str) {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+        .enable_comment(
+            source_text,
+            ret.trivias,
+            CommentOptions { preserve_annotate_comments: true },
+        )
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(result, expected, "\nfor minify source: {source_text}");
+}
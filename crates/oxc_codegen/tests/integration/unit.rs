@@ -1,4 +1,4 @@
-use crate::tester::{test, test_minify, test_without_source};
+use crate::tester::{test, test_minify, test_minify_with_legal_comments, test_without_source};
 
 #[test]
 fn module_decl() {
@@ -23,6 +23,19 @@ fn expr() {
     test_minify("1000000000000000128.0.toFixed(0)", "0xde0b6b3a7640080.toFixed(0);");
 }
 
+#[test]
+fn legal_comments_in_minify() {
+    test_minify_with_legal_comments(
+        "foo();\n/*! license */\nfunction bar() {}\nbaz();",
+        "/*! license */\nfoo();function bar(){}baz();",
+    );
+    test_minify_with_legal_comments(
+        "let a = 1;\n/** @license MIT */\nlet b = 2;\n// @preserve\nlet c = 3;",
+        "/** @license MIT */\n// @preserve\nlet a=1;let b=2;let c=3;",
+    );
+    test_minify_with_legal_comments("/* not legal */ foo();", "foo();");
+}
+
 #[test]
 fn access_property() {
     test(
@@ -51,7 +64,8 @@ fn for_stmt() {
 fn shorthand() {
     test("let _ = { x }", "let _ = { x };\n");
     test("let { x } = y", "let { x } = y;\n");
-    test("({ x: (x) })", "({ x });\n");
+    test("({ x: (x) })", "({ x: x });\n");
+    test_minify("({ x: (x) })", "({x});");
     test("({ x } = y)", "({x} = y);\n");
 }
 
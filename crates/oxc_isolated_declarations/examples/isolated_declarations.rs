@@ -36,7 +36,7 @@ fn main() {
         &allocator,
         &source_text,
         &ret.trivias,
-        IsolatedDeclarationsOptions { strip_internal: true },
+        IsolatedDeclarationsOptions { strip_internal: true, jsdoc_types: false },
     )
     .build(&ret.program);
     let printed = CodeGenerator::new()
@@ -0,0 +1,222 @@
+//! Types from JSDoc comments, for emitting declarations of JavaScript files.
+//!
+//! ```js
+//! /**
+//!  * @param {string} a
+//!  * @param {number} [b]
+//!  * @returns {boolean}
+//!  */
+//! export function foo(a, b) {}
+//!
+//! /** @type {string[]} */
+//! export const bar = [];
+//! ```
+//!
+//! Only function and variable declarations at the top level are supported.
+//! Annotations which already exist in the source take precedence over JSDoc types.
+
+use oxc_allocator::{Box, CloneIn};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, SPAN};
+use rustc_hash::FxHashMap;
+
+use crate::IsolatedDeclarations;
+
+/// A JSDoc tag with a type, e.g. `@param {string} name`.
+#[derive(Debug)]
+enum JSDocTag {
+    Param { name: String, type_text: String, optional: bool },
+    Returns { type_text: String },
+    Type { type_text: String },
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Clone `program`, adding type annotations from JSDoc comments of its declarations.
+    pub(crate) fn add_jsdoc_types(&self, program: &Program<'a>) -> Box<'a, Program<'a>> {
+        let mut program = self.ast.alloc(program.clone_in(self.ast.allocator));
+        for stmt in program.body.iter_mut() {
+            let Some(comment) = self.jsdoc_comments.get(&stmt.span().start) else { continue };
+            let tags = parse_tags(comment);
+            match stmt {
+                Statement::FunctionDeclaration(func) => self.add_function_types(func, &tags),
+                Statement::VariableDeclaration(decl) => self.add_variable_types(decl, &tags),
+                Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                    Some(Declaration::FunctionDeclaration(func)) => {
+                        self.add_function_types(func, &tags);
+                    }
+                    Some(Declaration::VariableDeclaration(decl)) => {
+                        self.add_variable_types(decl, &tags);
+                    }
+                    _ => {}
+                },
+                Statement::ExportDefaultDeclaration(decl) => {
+                    if let ExportDefaultDeclarationKind::FunctionDeclaration(func) =
+                        &mut decl.declaration
+                    {
+                        self.add_function_types(func, &tags);
+                    }
+                }
+                _ => {}
+            }
+        }
+        program
+    }
+
+    fn add_function_types(&self, func: &mut Function<'a>, tags: &[JSDocTag]) {
+        self.add_parameter_types(&mut func.params, tags);
+        if func.return_type.is_none() {
+            func.return_type = self.jsdoc_return_type(tags);
+        }
+    }
+
+    fn add_variable_types(&self, decl: &mut VariableDeclaration<'a>, tags: &[JSDocTag]) {
+        // The comment is ambiguous if there are multiple declarators
+        let [declarator] = decl.declarations.as_mut_slice() else { return };
+        match &mut declarator.init {
+            Some(Expression::FunctionExpression(func)) => self.add_function_types(func, tags),
+            Some(Expression::ArrowFunctionExpression(arrow)) => {
+                self.add_parameter_types(&mut arrow.params, tags);
+                if arrow.return_type.is_none() {
+                    arrow.return_type = self.jsdoc_return_type(tags);
+                }
+            }
+            _ => {}
+        }
+        if declarator.id.type_annotation.is_none() {
+            declarator.id.type_annotation = tags.iter().find_map(|tag| match tag {
+                JSDocTag::Type { type_text } => self.parse_jsdoc_type(type_text),
+                _ => None,
+            });
+        }
+    }
+
+    /// Add types from `@param` tags to parameters without a type annotation.
+    fn add_parameter_types(&self, params: &mut FormalParameters<'a>, tags: &[JSDocTag]) {
+        for param in params.items.iter_mut() {
+            let pattern = &mut param.pattern;
+            if pattern.type_annotation.is_some() {
+                continue;
+            }
+            let BindingPatternKind::BindingIdentifier(ident) = &pattern.kind else { continue };
+            let Some((type_text, optional)) = tags.iter().find_map(|tag| match tag {
+                JSDocTag::Param { name, type_text, optional } if name == ident.name.as_str() => {
+                    Some((type_text, *optional))
+                }
+                _ => None,
+            }) else {
+                continue;
+            };
+            pattern.type_annotation = self.parse_jsdoc_type(type_text);
+            pattern.optional |= optional && pattern.type_annotation.is_some();
+        }
+    }
+
+    fn jsdoc_return_type(&self, tags: &[JSDocTag]) -> Option<Box<'a, TSTypeAnnotation<'a>>> {
+        tags.iter().find_map(|tag| match tag {
+            JSDocTag::Returns { type_text } => self.parse_jsdoc_type(type_text),
+            _ => None,
+        })
+    }
+
+    /// Parse a JSDoc type as a TypeScript type.
+    ///
+    /// JSDoc-only syntax is converted: `*` to `any`, `?` to `unknown`, `?T` to `T | null`,
+    /// and `!T` to `T`. Types which TypeScript cannot parse are ignored.
+    fn parse_jsdoc_type(&self, type_text: &str) -> Option<Box<'a, TSTypeAnnotation<'a>>> {
+        let type_text = match type_text {
+            "*" => "any".to_string(),
+            "?" => "unknown".to_string(),
+            _ => {
+                if let Some(nullable) = type_text.strip_prefix('?') {
+                    format!("({nullable}) | null")
+                } else {
+                    type_text.strip_prefix('!').unwrap_or(type_text).to_string()
+                }
+            }
+        };
+        let source_text = self.ast.str(&format!("type T = {type_text};"));
+        let ret = Parser::new(self.ast.allocator, source_text, SourceType::d_ts()).parse();
+        if !ret.errors.is_empty() {
+            return None;
+        }
+        let mut body = ret.program.body;
+        let Some(Statement::TSTypeAliasDeclaration(decl)) = body.pop() else { return None };
+        Some(self.ast.alloc_ts_type_annotation(SPAN, decl.unbox().type_annotation))
+    }
+}
+
+/// Parse the `@param`, `@returns` and `@type` tags of a JSDoc comment.
+fn parse_tags(comment: &str) -> Vec<JSDocTag> {
+    let mut tags = vec![];
+    for tag in comment.split('@').skip(1) {
+        let name_end = tag.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(tag.len());
+        let (name, rest) = tag.split_at(name_end);
+        let Some((type_text, rest)) = parse_type_expression(rest) else { continue };
+        match name {
+            "param" | "arg" | "argument" => {
+                let rest = rest.trim_start();
+                // `[name]` or `[name=default]`
+                let (name, mut optional) = if let Some(rest) = rest.strip_prefix('[') {
+                    let end = rest.find([']', '=']).unwrap_or(rest.len());
+                    (rest[..end].trim(), true)
+                } else {
+                    let end = rest
+                        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                        .unwrap_or(rest.len());
+                    (&rest[..end], false)
+                };
+                // `{string=}`
+                let type_text = if let Some(type_text) = type_text.strip_suffix('=') {
+                    optional = true;
+                    type_text
+                } else {
+                    type_text
+                };
+                if !name.is_empty() {
+                    tags.push(JSDocTag::Param {
+                        name: name.to_string(),
+                        type_text: type_text.to_string(),
+                        optional,
+                    });
+                }
+            }
+            "returns" | "return" => {
+                tags.push(JSDocTag::Returns { type_text: type_text.to_string() });
+            }
+            "type" => tags.push(JSDocTag::Type { type_text: type_text.to_string() }),
+            _ => {}
+        }
+    }
+    tags
+}
+
+/// Parse `{type}` at the start of `text`, after whitespace.
+/// Returns the trimmed type, and the rest of `text`.
+fn parse_type_expression(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim_start().strip_prefix('{')?;
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some((text[..index].trim(), &text[index + 1..])),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Collect JSDoc comments by the start of the node they are attached to.
+/// If a node has multiple JSDoc comments, the last one is used, as TypeScript does.
+pub(crate) fn collect_jsdoc_comments(
+    source_text: &str,
+    trivias: &oxc_ast::Trivias,
+) -> FxHashMap<u32, String> {
+    trivias
+        .comments()
+        .filter(|comment| comment.is_jsdoc(source_text))
+        .map(|comment| (comment.attached_to, comment.span.source_text(source_text).to_string()))
+        .collect()
+}
@@ -12,6 +12,7 @@ mod r#enum;
 mod formal_parameter_binding_pattern;
 mod function;
 mod inferrer;
+mod jsdoc;
 mod literal;
 mod module;
 mod return_type;
@@ -37,6 +38,10 @@ pub struct IsolatedDeclarationsOptions {
     /// This is an internal compiler option; use at your own risk, because the compiler does not check that the result is valid.
     /// <https://www.typescriptlang.org/tsconfig/#stripInternal>
     pub strip_internal: bool,
+
+    /// Use types from JSDoc comments (`@param`, `@returns` and `@type`) for functions and
+    /// variables without type annotations, to emit declarations of JavaScript files.
+    pub jsdoc_types: bool,
 }
 
 pub struct IsolatedDeclarationsReturn<'a> {
@@ -56,6 +61,10 @@ pub struct IsolatedDeclarations<'a> {
 
     /// Start position of `@internal` jsdoc annotations.
     internal_annotations: FxHashSet<u32>,
+
+    /// JSDoc comments by start position of the node they are attached to.
+    /// Only collected if [`IsolatedDeclarationsOptions::jsdoc_types`] is enabled.
+    jsdoc_comments: FxHashMap<u32, String>,
 }
 
 impl<'a> IsolatedDeclarations<'a> {
@@ -69,11 +78,16 @@ impl<'a> IsolatedDeclarations<'a> {
         let is_internal_set = strip_internal
             .then(|| Self::build_internal_annotations(source_text, trivias))
             .unwrap_or_default();
+        let jsdoc_comments = options
+            .jsdoc_types
+            .then(|| jsdoc::collect_jsdoc_comments(source_text, trivias))
+            .unwrap_or_default();
 
         Self {
             ast: AstBuilder::new(allocator),
             strip_internal,
             internal_annotations: is_internal_set,
+            jsdoc_comments,
             scope: ScopeTree::new(allocator),
             errors: RefCell::new(vec![]),
         }
@@ -85,6 +99,13 @@ impl<'a> IsolatedDeclarations<'a> {
     pub fn build(mut self, program: &Program<'a>) -> IsolatedDeclarationsReturn<'a> {
         let source_type = SourceType::d_ts();
         let directives = self.ast.vec();
+        let jsdoc_program;
+        let program = if self.jsdoc_comments.is_empty() {
+            program
+        } else {
+            jsdoc_program = self.add_jsdoc_types(program);
+            &jsdoc_program
+        };
         let stmts = self.transform_program(program);
         let program = self.ast.program(SPAN, source_type, None, directives, stmts);
         IsolatedDeclarationsReturn { program, errors: self.take_errors() }
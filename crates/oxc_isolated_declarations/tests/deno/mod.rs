@@ -17,7 +17,7 @@ mod tests {
             &allocator,
             source,
             &ret.trivias,
-            IsolatedDeclarationsOptions { strip_internal: true },
+            IsolatedDeclarationsOptions { strip_internal: true, jsdoc_types: false },
         )
         .build(&ret.program);
         let actual = CodeGenerator::new().build(&ret.program).source_text;
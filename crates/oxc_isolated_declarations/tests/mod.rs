@@ -17,7 +17,7 @@ fn transform(path: &Path, source_text: &str) -> String {
         &allocator,
         source_text,
         &parser_ret.trivias,
-        IsolatedDeclarationsOptions { strip_internal: true },
+        IsolatedDeclarationsOptions { strip_internal: true, jsdoc_types: false },
     )
     .build(&parser_ret.program);
     let code = CodeGenerator::new()
@@ -1,14 +1,21 @@
 use itertools::Itertools;
-use oxc_ast::ast::Program;
+use oxc_ast::{
+    ast::{BindingIdentifier, Program},
+    Visit,
+};
 use oxc_index::{index_vec, Idx, IndexVec};
 use oxc_semantic::{ReferenceId, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
-use oxc_span::CompactStr;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashSet;
 
 type Slot = usize;
 
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct MangleOptions {
     pub debug: bool,
+
+    /// Keep the names of functions, e.g. for `Function.prototype.name`.
+    pub keep_fnames: bool,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -80,6 +87,18 @@ impl Mangler {
         Some(self.symbol_table.get_name(symbol_id))
     }
 
+    /// Get the original and mangled names of all renamed bindings in `program`, in source order.
+    ///
+    /// `program` must be the program this mangler was built with.
+    ///
+    /// There is one entry per binding identifier, so bindings with the same original name in
+    /// different scopes have separate entries, told apart by their spans.
+    pub fn get_renamed_bindings(&self, program: &Program<'_>) -> Vec<RenamedBinding> {
+        let mut collector = RenamedBindingsCollector { mangler: self, bindings: vec![] };
+        collector.visit_program(program);
+        collector.bindings
+    }
+
     #[must_use]
     pub fn build<'a>(mut self, program: &'a Program<'a>) -> Mangler {
         let semantic = SemanticBuilder::new("").build(program).semantic;
@@ -97,6 +116,9 @@ impl Mangler {
         // Keep track of the maximum slot number for each scope
         let mut max_slot_for_scope = vec![0; scope_tree.len()];
 
+        // Names of symbols which are not mangled, and must not be reused for mangled names
+        let mut kept_names: FxHashSet<CompactStr> = FxHashSet::default();
+
         // Walk the scope tree and compute the slot number for each scope
         for scope_id in scope_tree.descendants_from_root() {
             let bindings = scope_tree.get_bindings(scope_id);
@@ -111,6 +133,10 @@ impl Mangler {
             if !bindings.is_empty() {
                 // `bindings` are stored in order, traverse and increment slot
                 for symbol_id in bindings.values().copied() {
+                    if self.is_kept(&symbol_table, symbol_id) {
+                        kept_names.insert(CompactStr::from(symbol_table.get_name(symbol_id)));
+                        continue;
+                    }
                     slots[symbol_id] = slot;
                     slot += 1;
                 }
@@ -124,7 +150,7 @@ impl Mangler {
         }
 
         let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, &scope_tree, total_number_of_slots, &slots);
+            self.tally_slot_frequencies(&symbol_table, &scope_tree, total_number_of_slots, &slots);
 
         let root_unresolved_references = scope_tree.root_unresolved_references();
         let root_bindings = scope_tree.get_bindings(scope_tree.root_scope_id());
@@ -143,6 +169,7 @@ impl Mangler {
                     && !is_special_name(n)
                     && !root_unresolved_references.contains_key(n)
                     && !root_bindings.contains_key(n)
+                    && !kept_names.contains(n)
                 {
                     break name;
                 }
@@ -201,6 +228,7 @@ impl Mangler {
     }
 
     fn tally_slot_frequencies(
+        &self,
         symbol_table: &SymbolTable,
         scope_tree: &ScopeTree,
         total_number_of_slots: usize,
@@ -212,7 +240,9 @@ impl Mangler {
             if symbol_table.get_scope_id(symbol_id) == root_scope_id {
                 continue;
             }
-            if is_special_name(symbol_table.get_name(symbol_id)) {
+            if is_special_name(symbol_table.get_name(symbol_id))
+                || self.is_kept(symbol_table, symbol_id)
+            {
                 continue;
             }
             let index = *slot;
@@ -224,6 +254,42 @@ impl Mangler {
         frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
         frequencies
     }
+
+    /// Whether the symbol keeps its original name.
+    fn is_kept(&self, symbol_table: &SymbolTable, symbol_id: SymbolId) -> bool {
+        self.options.keep_fnames && symbol_table.get_flags(symbol_id).is_function()
+    }
+}
+
+/// Collects original and mangled names of binding identifiers.
+/// A binding renamed by the mangler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenamedBinding {
+    /// Name in the source
+    pub original: CompactStr,
+    /// Name in the mangled output
+    pub mangled: CompactStr,
+    /// Span of the binding identifier in the source
+    pub span: Span,
+}
+
+struct RenamedBindingsCollector<'m> {
+    mangler: &'m Mangler,
+    bindings: Vec<RenamedBinding>,
+}
+
+impl<'a, 'm> Visit<'a> for RenamedBindingsCollector<'m> {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        let Some(symbol_id) = ident.symbol_id.get() else { return };
+        let mangled = self.mangler.get_symbol_name(symbol_id);
+        if mangled != ident.name.as_str() {
+            self.bindings.push(RenamedBinding {
+                original: CompactStr::from(ident.name.as_str()),
+                mangled: CompactStr::from(mangled),
+                span: ident.span,
+            });
+        }
+    }
 }
 
 fn is_special_name(name: &str) -> bool {
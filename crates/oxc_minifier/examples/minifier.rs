@@ -3,7 +3,7 @@ use std::path::Path;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use pico_args::Arguments;
@@ -45,7 +45,10 @@ fn minify(
 ) -> String {
     let ret = Parser::new(allocator, source_text, source_type).parse();
     let mut program = ret.program;
-    let options = MinifierOptions { mangle, compress: CompressOptions::default() };
+    let options = MinifierOptions {
+        mangle: mangle.then(MangleOptions::default),
+        compress: Some(CompressOptions::default()),
+    };
     let ret = Minifier::new(options).build(allocator, &mut program);
     CodeGenerator::new().with_mangler(ret.mangler).build(&program).source_text
 }
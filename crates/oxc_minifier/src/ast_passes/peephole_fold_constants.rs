@@ -323,15 +323,13 @@ impl<'a> PeepholeFoldConstants {
             BinaryOperator::Instanceof => self.try_fold_instanceof(e.span, &e.left, &e.right, ctx),
             BinaryOperator::Addition => self.try_fold_addition(e.span, &e.left, &e.right, ctx),
             BinaryOperator::Subtraction
+            | BinaryOperator::Multiplication
             | BinaryOperator::Division
             | BinaryOperator::Remainder
             | BinaryOperator::Exponential => {
-                self.try_fold_arithmetic_op(e.span, &e.left, &e.right, ctx)
+                self.try_fold_arithmetic_op(e.span, e.operator, &e.left, &e.right, ctx)
             }
-            BinaryOperator::Multiplication
-            | BinaryOperator::BitwiseAnd
-            | BinaryOperator::BitwiseOR
-            | BinaryOperator::BitwiseXOR => {
+            BinaryOperator::BitwiseAnd | BinaryOperator::BitwiseOR | BinaryOperator::BitwiseXOR => {
                 // TODO:
                 // self.try_fold_arithmetic_op(e.span, &e.left, &e.right, ctx)
                 // if (result != subtree) {
@@ -391,14 +389,56 @@ impl<'a> PeepholeFoldConstants {
         }
     }
 
+    /// `2 * 3` -> `6`, `1 - 2` -> `-1`
+    ///
+    /// Results which are not finite, are too large to be exact, or are longer than the original
+    /// operands (e.g. `1 / 3`) are not folded.
     fn try_fold_arithmetic_op<'b>(
         &self,
-        _span: Span,
-        _left: &'b Expression<'a>,
-        _right: &'b Expression<'a>,
-        _ctx: &mut TraverseCtx<'a>,
+        span: Span,
+        op: BinaryOperator,
+        left: &'b Expression<'a>,
+        right: &'b Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Option<Expression<'a>> {
-        None
+        // 2^53, beyond which integers are not exact
+        const MAX_FOLD_NUMBER: f64 = 9_007_199_254_740_992.0;
+
+        let Some(NumberValue::Number(left_val)) = ctx.get_side_free_number_value(left) else {
+            return None;
+        };
+        let Some(NumberValue::Number(right_val)) = ctx.get_side_free_number_value(right) else {
+            return None;
+        };
+        if !left_val.is_finite() || !right_val.is_finite() {
+            return None;
+        }
+
+        let result = match op {
+            BinaryOperator::Subtraction => left_val - right_val,
+            BinaryOperator::Multiplication => left_val * right_val,
+            BinaryOperator::Division => left_val / right_val,
+            BinaryOperator::Remainder => left_val % right_val,
+            BinaryOperator::Exponential => left_val.powf(right_val),
+            _ => unreachable!("Unknown arithmetic operator {:?}", op),
+        };
+        if !result.is_finite() || result.abs() > MAX_FOLD_NUMBER {
+            return None;
+        }
+        // Length of both operands plus 1 byte for the operator
+        if result.to_string().len() > left_val.to_string().len() + right_val.to_string().len() + 1 {
+            return None;
+        }
+
+        let value = result.abs();
+        let number_base =
+            if is_exact_int64(value) { NumberBase::Decimal } else { NumberBase::Float };
+        let number = ctx.ast.expression_numeric_literal(span, value, "", number_base);
+        if result.is_sign_negative() {
+            Some(ctx.ast.expression_unary(span, UnaryOperator::UnaryNegation, number))
+        } else {
+            Some(number)
+        }
     }
 
     fn try_fold_instanceof<'b>(
@@ -1424,6 +1464,33 @@ mod test {
         test_same("void x()");
     }
 
+    #[test]
+    fn test_fold_arithmetic() {
+        test("x = 10 - 20", "x = -10");
+        test("x = 2 * 3", "x = 6");
+        test("x = 6 / 3", "x = 2");
+        test("x = 7 % 3", "x = 1");
+        test("x = -7 % 3", "x = -1");
+        test("x = 2 ** 10", "x = 1024");
+        test("x = 1.5 * 2", "x = 3");
+        test("x = 0.5 * 0.5", "x = .25");
+        test("x = '6' / '2'", "x = 3");
+        test("x = 0 * -1", "x = -0");
+
+        // Result is longer than the original
+        test_same("x = 1 / 3");
+        test_same("x = 2 ** 60");
+        // Result is not finite
+        test_same("x = 1 / 0");
+        test_same("x = 0 / 0");
+        test_same("x = 10 ** 400");
+
+        // Operands with side effects or unknown values
+        test_same("x = y * 2");
+        test_same("x = foo() - 1");
+        test_same("x = 2 * foo()");
+    }
+
     #[test]
     fn test_fold_bit_shift() {
         test("x = 1 << 0", "x=1");
@@ -1,6 +1,7 @@
 use oxc_ast::ast::*;
 use oxc_span::{GetSpan, SPAN};
 use oxc_syntax::{
+    identifier::is_identifier_name,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
 };
@@ -76,6 +77,10 @@ impl<'a> Traverse<'a> for PeepholeSubstituteAlternateSyntax {
         self.in_define_export = false;
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.compress_property_key(prop, ctx);
+    }
+
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if !self.compress_undefined(expr, ctx) {
             self.compress_boolean(expr, ctx);
@@ -247,6 +252,19 @@ impl<'a> PeepholeSubstituteAlternateSyntax {
         }
     }
 
+    /// `{ "foo": 1 }` -> `{ foo: 1 }`
+    fn compress_property_key(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.keep_quoted_props || prop.computed {
+            return;
+        }
+        if let PropertyKey::StringLiteral(lit) = &prop.key {
+            if is_identifier_name(&lit.value) {
+                prop.key = ctx.ast.property_key_identifier_name(lit.span, lit.value.clone());
+                self.changed = true;
+            }
+        }
+    }
+
     fn compress_variable_declarator(
         &mut self,
         decl: &mut VariableDeclarator<'a>,
@@ -279,6 +297,13 @@ mod test {
         test(source_text, source_text);
     }
 
+    fn test_keep_quoted_props(source_text: &str) {
+        let allocator = Allocator::default();
+        let options = CompressOptions { keep_quoted_props: true, ..CompressOptions::default() };
+        let mut pass = super::PeepholeSubstituteAlternateSyntax::new(options);
+        tester::test(&allocator, source_text, source_text, &mut pass);
+    }
+
     #[test]
     fn fold_return_result() {
         test("function f(){return !1;}", "function f(){return !1}");
@@ -302,4 +327,12 @@ mod test {
         // shadowd
         test_same("(function(undefined) { let x = typeof undefined; })()");
     }
+
+    #[test]
+    fn unquote_property_keys() {
+        test("x = { 'foo': 1, 'bar'() {} }", "x = { foo: 1, bar() {} }");
+        test_same("x = { 'foo-bar': 1, '1': 2, '': 3 }");
+        test_same("x = { ['foo']: 1 }");
+        test_keep_quoted_props("x = { 'foo': 1, 'bar'() {} }");
+    }
 }
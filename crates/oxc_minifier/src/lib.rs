@@ -17,17 +17,20 @@ use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 use oxc_mangler::Mangler;
 
+pub use oxc_mangler::MangleOptions;
+
 pub use crate::{ast_passes::CompressorPass, compressor::Compressor, options::CompressOptions};
 
 #[derive(Debug, Clone, Copy)]
 pub struct MinifierOptions {
-    pub mangle: bool,
-    pub compress: CompressOptions,
+    pub mangle: Option<MangleOptions>,
+    /// `None` skips compression, leaving the syntax untouched.
+    pub compress: Option<CompressOptions>,
 }
 
 impl Default for MinifierOptions {
     fn default() -> Self {
-        Self { mangle: true, compress: CompressOptions::default() }
+        Self { mangle: Some(MangleOptions::default()), compress: Some(CompressOptions::default()) }
     }
 }
 
@@ -45,8 +48,11 @@ impl Minifier {
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
-        Compressor::new(allocator, self.options.compress).build(program);
-        let mangler = self.options.mangle.then(|| Mangler::default().build(program));
+        if let Some(options) = self.options.compress {
+            Compressor::new(allocator, options).build(program);
+        }
+        let mangler =
+            self.options.mangle.map(|options| Mangler::new().with_options(options).build(program));
         MinifierReturn { mangler }
     }
 }
@@ -36,6 +36,12 @@ pub struct CompressOptions {
     ///
     /// Default `true`
     pub typeofs: bool,
+
+    /// Keep quotes on object property keys, e.g. `{ "foo": 1 }`.
+    /// Otherwise quotes are removed from keys which are valid identifiers.
+    ///
+    /// Default `false`
+    pub keep_quoted_props: bool,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -56,6 +62,7 @@ impl CompressOptions {
             join_vars: true,
             loops: true,
             typeofs: true,
+            keep_quoted_props: false,
         }
     }
 
@@ -69,6 +76,7 @@ impl CompressOptions {
             join_vars: false,
             loops: false,
             typeofs: false,
+            keep_quoted_props: true,
         }
     }
 
@@ -57,3 +57,16 @@ fn tagged_template() {
     test("foo(true && o.f)", "foo(o.f)");
     test("foo(true ? o.f : false)", "foo(o.f)");
 }
+
+#[test]
+fn fold_constants() {
+    test("x = 1 + 2", "x = 3");
+    test("x = 'a' + 'b'", "x = 'ab'");
+    test("x = 2 * 3 - 1", "x = 5");
+    test("function f() { return 1; foo(); }", "function f() { return 1; }");
+    test("if (true) { foo() } else { bar() }", "foo()");
+
+    // Operands with side effects are kept
+    test_same("x = 1 + foo()");
+    test_same("x = foo() * 2");
+}
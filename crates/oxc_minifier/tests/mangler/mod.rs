@@ -1,17 +1,22 @@
 use std::fmt::Write;
 
 use oxc_allocator::Allocator;
+use oxc_ast::{ast::*, Visit};
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::Mangler;
+use oxc_mangler::{MangleOptions, Mangler};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc_span::{CompactStr, SourceType};
 
 fn mangle(source_text: &str) -> String {
+    mangle_with_options(source_text, MangleOptions::default())
+}
+
+fn mangle_with_options(source_text: &str, options: MangleOptions) -> String {
     let allocator = Allocator::default();
     let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let mangler = Mangler::new().build(&program);
+    let mangler = Mangler::new().with_options(options).build(&program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&program).source_text
 }
 
@@ -35,3 +40,78 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn keep_fnames() {
+    let source_text =
+        "function foo() { var fact = function factorial(n) { return n ? n * factorial(n - 1) : 1 }; return fact; }";
+
+    // Recursive references are renamed together with the function name
+    let mangled = mangle(source_text);
+    assert_eq!(
+        mangled,
+        "function foo() {\n\tvar b = function c(d) {\n\t\treturn d ? d * c(d - 1) : 1;\n\t};\n\treturn b;\n}\n"
+    );
+
+    let options = MangleOptions { keep_fnames: true, ..MangleOptions::default() };
+    let mangled = mangle_with_options(source_text, options);
+    assert_eq!(
+        mangled,
+        "function foo() {\n\tvar a = function factorial(b) {\n\t\treturn b ? b * factorial(b - 1) : 1;\n\t};\n\treturn a;\n}\n"
+    );
+}
+
+#[test]
+fn renamed_bindings() {
+    let source_text = "
+export function foo(x, y) {
+  var x;
+  function inner(x) { let y = x; return y; }
+  { let x = y; inner(x); }
+  return inner(x) + y;
+}
+";
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let mangler = Mangler::new().build(&program);
+    let renamed = mangler.get_renamed_bindings(&program);
+    let output = CodeGenerator::new().with_mangler(Some(mangler)).build(&program).source_text;
+
+    // Shadowed and redeclared bindings have an entry each, located by span
+    let originals = renamed.iter().map(|binding| binding.original.as_str()).collect::<Vec<_>>();
+    assert_eq!(originals, ["x", "y", "x", "inner", "x", "y", "x"]);
+    assert!(renamed
+        .iter()
+        .all(|binding| binding.span.source_text(source_text) == binding.original));
+    // `var x` redeclares parameter `x`, so they get the same mangled name
+    assert_eq!(renamed[0].mangled, renamed[2].mangled);
+
+    // Entries match the binding identifiers of the output which differ from the source
+    let mut source_names = BindingNames::default();
+    source_names.visit_program(&program);
+    let mut output_names = BindingNames::default();
+    output_names.visit_program(&Parser::new(&allocator, &output, source_type).parse().program);
+    let expected = source_names
+        .0
+        .iter()
+        .zip(&output_names.0)
+        .filter(|(original, mangled)| original != mangled)
+        .map(|(original, mangled)| (original.as_str(), mangled.as_str()))
+        .collect::<Vec<_>>();
+    let actual = renamed
+        .iter()
+        .map(|binding| (binding.original.as_str(), binding.mangled.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+/// Names of binding identifiers, in source order.
+#[derive(Default)]
+struct BindingNames(Vec<CompactStr>);
+
+impl<'a> Visit<'a> for BindingNames {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.0.push(CompactStr::from(ident.name.as_str()));
+    }
+}
@@ -164,12 +164,18 @@ impl<'a> ParserImpl<'a> {
             self.eat(Kind::Await);
         }
 
+        let kind = if is_await {
+            VariableDeclarationKind::AwaitUsing
+        } else {
+            VariableDeclarationKind::Using
+        };
+
         // BindingList[?In, ?Yield, ?Await, ~Pattern]
         let mut declarations: oxc_allocator::Vec<'_, VariableDeclarator<'_>> = self.ast.vec();
         loop {
             let declaration = self.parse_variable_declarator(
                 VariableDeclarationContext::new(VariableDeclarationParent::Statement),
-                VariableDeclarationKind::Var,
+                kind,
             )?;
 
             match declaration.id.kind {
@@ -194,11 +200,6 @@ impl<'a> ParserImpl<'a> {
             }
         }
 
-        let kind = if is_await {
-            VariableDeclarationKind::AwaitUsing
-        } else {
-            VariableDeclarationKind::Using
-        };
         Ok(self.ast.variable_declaration(self.end_span(span), kind, declarations, false))
     }
 }
@@ -2004,7 +2004,12 @@ impl<'a> SemanticBuilder<'a> {
                 }
                 self.current_reference_flags -= ReferenceFlags::Write;
             }
-            AstKind::AssignmentExpression(_) | AstKind::ExportNamedDeclaration(_)
+            AstKind::ExportNamedDeclaration(_) => {
+                // `export { a }` has no binding identifier to remove the flag
+                self.current_symbol_flags -= SymbolFlags::Export;
+                self.current_reference_flags = ReferenceFlags::empty();
+            }
+            AstKind::AssignmentExpression(_)
             | AstKind::TSTypeQuery(_)
             // Clear the reference flags that are set in AstKind::PropertySignature
             | AstKind::PropertyKey(_) => {
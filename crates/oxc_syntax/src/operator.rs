@@ -67,6 +67,36 @@ impl AssignmentOperator {
         )
     }
 
+    /// Get the [`BinaryOperator`] applied by a compound assignment, e.g. `+` for `+=`.
+    pub fn to_binary_operator(self) -> Option<BinaryOperator> {
+        let op = match self {
+            Self::Addition => BinaryOperator::Addition,
+            Self::Subtraction => BinaryOperator::Subtraction,
+            Self::Multiplication => BinaryOperator::Multiplication,
+            Self::Division => BinaryOperator::Division,
+            Self::Remainder => BinaryOperator::Remainder,
+            Self::ShiftLeft => BinaryOperator::ShiftLeft,
+            Self::ShiftRight => BinaryOperator::ShiftRight,
+            Self::ShiftRightZeroFill => BinaryOperator::ShiftRightZeroFill,
+            Self::BitwiseOR => BinaryOperator::BitwiseOR,
+            Self::BitwiseXOR => BinaryOperator::BitwiseXOR,
+            Self::BitwiseAnd => BinaryOperator::BitwiseAnd,
+            Self::Exponential => BinaryOperator::Exponential,
+            _ => return None,
+        };
+        Some(op)
+    }
+
+    /// Get the [`LogicalOperator`] applied by a logical assignment, e.g. `||` for `||=`.
+    pub fn to_logical_operator(self) -> Option<LogicalOperator> {
+        match self {
+            Self::LogicalAnd => Some(LogicalOperator::And),
+            Self::LogicalOr => Some(LogicalOperator::Or),
+            Self::LogicalNullish => Some(LogicalOperator::Coalesce),
+            _ => None,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Assign => "=",
@@ -0,0 +1,103 @@
+//! Utility for referencing Babel helpers.
+//!
+//! Helpers are accessed as properties of the `babelHelpers` global, equivalent to Babel's
+//! `externalHelpers` mode:
+//!
+//! ```rs
+//! // `babelHelpers.classPrivateFieldGet2(_x, this)`
+//! let expr = helper_loader::call(Helper::ClassPrivateFieldGet2, arguments, ctx);
+//! ```
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::reference::ReferenceFlags;
+use oxc_traverse::TraverseCtx;
+
+/// Babel helpers which transforms can request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::enum_variant_names)]
+pub enum Helper {
+    ApplyDecs2311,
+    AssertClassBrand,
+    CheckInRHS,
+    ClassPrivateFieldGet2,
+    ClassPrivateFieldInitSpec,
+    ClassPrivateFieldSet2,
+    ClassPrivateGetter,
+    ClassPrivateMethodInitSpec,
+    ClassPrivateSetter,
+    Construct,
+    CreateForOfIteratorHelper,
+    DefineProperty,
+    ObjectDestructuringEmpty,
+    ObjectSpread2,
+    ObjectWithoutProperties,
+    ReadOnlyError,
+    RegeneratorRuntime,
+    SlicedToArray,
+    TaggedTemplateLiteral,
+    Tdz,
+    ToArray,
+    ToConsumableArray,
+    ToPropertyKey,
+    ToSetter,
+    Typeof,
+    UsingCtx,
+    WriteOnlyError,
+}
+
+impl Helper {
+    /// Name of the helper, as exported by `@babel/helpers`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ApplyDecs2311 => "applyDecs2311",
+            Self::AssertClassBrand => "assertClassBrand",
+            Self::CheckInRHS => "checkInRHS",
+            Self::ClassPrivateFieldGet2 => "classPrivateFieldGet2",
+            Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
+            Self::ClassPrivateFieldSet2 => "classPrivateFieldSet2",
+            Self::ClassPrivateGetter => "classPrivateGetter",
+            Self::ClassPrivateMethodInitSpec => "classPrivateMethodInitSpec",
+            Self::ClassPrivateSetter => "classPrivateSetter",
+            Self::Construct => "construct",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::DefineProperty => "defineProperty",
+            Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
+            Self::ObjectSpread2 => "objectSpread2",
+            Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ReadOnlyError => "readOnlyError",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::SlicedToArray => "slicedToArray",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::Tdz => "tdz",
+            Self::ToArray => "toArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::ToPropertyKey => "toPropertyKey",
+            Self::ToSetter => "toSetter",
+            Self::Typeof => "typeof",
+            Self::UsingCtx => "usingCtx",
+            Self::WriteOnlyError => "writeOnlyError",
+        }
+    }
+}
+
+/// Create `babelHelpers.<helper>`.
+pub fn load<'a>(helper: Helper, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "babelHelpers");
+    let ident =
+        ctx.create_reference_id(SPAN, Atom::from("babelHelpers"), symbol_id, ReferenceFlags::Read);
+    let object = ctx.ast.expression_from_identifier_reference(ident);
+    let property = ctx.ast.identifier_name(SPAN, Atom::from(helper.name()));
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+/// Create `babelHelpers.<helper>(...arguments)`.
+pub fn call<'a>(
+    helper: Helper,
+    arguments: Vec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = load(helper, ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
@@ -160,9 +160,9 @@ impl<'a, 'ctx> ModuleImports<'a, 'ctx> {
 
 /// Store for `import` / `require` statements to be added at top of program.
 ///
-/// TODO(improve-on-babel): Insertion order does not matter. We only have to use `IndexMap`
-/// to produce output that's the same as Babel's.
-/// Substitute `FxHashMap` once we don't need to match Babel's output exactly.
+/// Uses an `IndexMap` so `import`s / `require`s are inserted in the order they were added,
+/// which matches Babel's output, and keeps output deterministic for reproducible builds.
+/// Do not substitute a hash map, as its iteration order is not stable.
 pub struct ModuleImportsStore<'a> {
     imports: RefCell<IndexMap<Atom<'a>, Vec<NamedImport<'a>>>>,
 }
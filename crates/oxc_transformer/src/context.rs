@@ -13,7 +13,7 @@ use crate::{
         module_imports::ModuleImportsStore, top_level_statements::TopLevelStatementsStore,
         var_declarations::VarDeclarationsStore,
     },
-    TransformOptions,
+    CompilerAssumptions, TransformOptions,
 };
 
 pub struct TransformCtx<'a> {
@@ -24,13 +24,16 @@ pub struct TransformCtx<'a> {
     /// <https://babeljs.io/docs/options#filename>
     pub filename: String,
 
-    /// Source path in the form of `<CWD>/path/to/file/input.js`
+    /// Source path, relative to the CWD if the file is inside it, e.g. `path/to/file/input.js`
     pub source_path: PathBuf,
 
     pub source_type: SourceType,
 
     pub source_text: &'a str,
 
+    /// <https://babeljs.io/docs/assumptions>
+    pub assumptions: CompilerAssumptions,
+
     // Helpers
     /// Manage import statement globally
     pub module_imports: ModuleImportsStore<'a>,
@@ -52,9 +55,11 @@ impl<'a> TransformCtx<'a> {
             .file_stem() // omit file extension
             .map_or_else(|| String::from("unknown"), |name| name.to_string_lossy().to_string());
 
-        let source_path = source_path
-            .strip_prefix(&options.cwd)
-            .map_or_else(|_| source_path.to_path_buf(), |p| Path::new("<CWD>").join(p));
+        let source_path = if options.cwd.as_os_str().is_empty() {
+            source_path.to_path_buf()
+        } else {
+            source_path.strip_prefix(&options.cwd).unwrap_or(source_path).to_path_buf()
+        };
 
         Self {
             errors: RefCell::new(vec![]),
@@ -62,6 +67,7 @@ impl<'a> TransformCtx<'a> {
             source_path,
             source_type,
             source_text,
+            assumptions: options.assumptions,
             trivias,
             module_imports: ModuleImportsStore::new(),
             var_declarations: VarDeclarationsStore::new(),
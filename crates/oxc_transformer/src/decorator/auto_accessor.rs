@@ -0,0 +1,295 @@
+//! Lower auto-accessors to a private field with a getter and setter.
+//!
+//! ```js
+//! class A { accessor x = 1; }
+//! ```
+//! ->
+//! ```js
+//! class A {
+//!   #A = 1;
+//!   get x() { return this.#A; }
+//!   set x(v) { this.#A = v; }
+//! }
+//! ```
+//!
+//! Private names are generated as Babel does: `#A` to `#Z`, `#a` to `#z`, then `#AA` and so on,
+//! skipping names already declared in the class.
+//!
+//! Decorated auto-accessors are left as is, as decorators on auto-accessors are not supported yet.
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, Vec as ArenaVec};
+use oxc_ast::{ast::*, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::{
+    node::NodeId,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::TraverseCtx;
+
+use super::Decorators;
+use crate::helpers::bindings::BoundIdentifier;
+
+impl<'a, 'ctx> Decorators<'a, 'ctx> {
+    pub(super) fn transform_auto_accessors(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !class.body.body.iter().any(is_auto_accessor) {
+            return;
+        }
+
+        let mut private_names = PrivateNameGenerator::new(class);
+        let class_scope_id = class.scope_id.get().unwrap();
+
+        let elements = ctx.ast.move_vec(&mut class.body.body);
+        let mut body = ctx.ast.vec_with_capacity(elements.len() + 2);
+        for element in elements {
+            let ClassElement::AccessorProperty(prop) = element else {
+                body.push(element);
+                continue;
+            };
+            if !is_auto_accessor_property(&prop) || !prop.decorators.is_empty() {
+                body.push(ClassElement::AccessorProperty(prop));
+                continue;
+            }
+            if prop.computed {
+                self.ctx.error(
+                    OxcDiagnostic::error(
+                        "Auto-accessors with computed keys are not supported yet.",
+                    )
+                    .with_label(prop.key.span()),
+                );
+                body.push(ClassElement::AccessorProperty(prop));
+                continue;
+            }
+
+            let name = private_names.generate(ctx);
+            Self::transform_auto_accessor(prop.unbox(), &name, class_scope_id, &mut body, ctx);
+        }
+        class.body.body = body;
+    }
+
+    /// `accessor x = 1` -> `#A = 1; get x() { return this.#A; } set x(v) { this.#A = v; }`
+    fn transform_auto_accessor(
+        prop: AccessorProperty<'a>,
+        name: &Atom<'a>,
+        class_scope_id: ScopeId,
+        body: &mut ArenaVec<'a, ClassElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `#A = 1;`
+        body.push(ctx.ast.class_element_property_definition(
+            PropertyDefinitionType::PropertyDefinition,
+            prop.span,
+            ctx.ast.vec(),
+            ctx.ast.property_key_private_identifier(SPAN, name.clone()),
+            prop.value,
+            false,
+            prop.r#static,
+            false,
+            false,
+            false,
+            prop.definite,
+            false,
+            prop.type_annotation,
+            None,
+        ));
+
+        // `get x() { return this.#A; }`
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::GetAccessor | ScopeFlags::StrictMode,
+        );
+        let field = create_private_field(name, ctx);
+        let statements = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(field)));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        body.push(create_accessor_method(
+            &prop.key,
+            MethodDefinitionKind::Get,
+            prop.r#static,
+            prop.accessibility,
+            params,
+            statements,
+            scope_id,
+            ctx,
+        ));
+
+        // `set x(v) { this.#A = v; }`
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::SetAccessor | ScopeFlags::StrictMode,
+        );
+        let value_name = Atom::from("v");
+        let symbol_id = ctx.symbols_mut().create_symbol(
+            SPAN,
+            value_name.to_compact_str(),
+            SymbolFlags::FunctionScopedVariable,
+            scope_id,
+            NodeId::DUMMY,
+        );
+        ctx.scopes_mut().add_binding(scope_id, value_name.to_compact_str(), symbol_id);
+        let value_binding = BoundIdentifier { name: value_name, symbol_id };
+
+        let Expression::PrivateFieldExpression(field) = create_private_field(name, ctx) else {
+            unreachable!()
+        };
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::PrivateFieldExpression(field),
+            ctx.ast.expression_from_identifier_reference(value_binding.create_read_reference(ctx)),
+        );
+        let statements = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                    value_binding.create_binding_identifier(),
+                ),
+                NONE,
+                false,
+            ),
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        body.push(create_accessor_method(
+            &prop.key,
+            MethodDefinitionKind::Set,
+            prop.r#static,
+            prop.accessibility,
+            params,
+            statements,
+            scope_id,
+            ctx,
+        ));
+    }
+}
+
+fn is_auto_accessor(element: &ClassElement<'_>) -> bool {
+    matches!(element, ClassElement::AccessorProperty(prop) if is_auto_accessor_property(prop))
+}
+
+fn is_auto_accessor_property(prop: &AccessorProperty<'_>) -> bool {
+    // `abstract accessor x` is removed by TypeScript transform
+    prop.r#type == AccessorPropertyType::AccessorProperty
+}
+
+/// `this.#A`
+fn create_private_field<'a>(name: &Atom<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    Expression::from(ctx.ast.member_expression_private_field_expression(
+        SPAN,
+        ctx.ast.expression_this(SPAN),
+        ctx.ast.private_identifier(SPAN, name.clone()),
+        false,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_accessor_method<'a>(
+    key: &PropertyKey<'a>,
+    kind: MethodDefinitionKind,
+    r#static: bool,
+    accessibility: Option<TSAccessibility>,
+    params: ArenaBox<'a, FormalParameters<'a>>,
+    statements: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+    let function = ctx.ast.alloc_function(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+    );
+    function.scope_id.set(Some(scope_id));
+    ctx.ast.class_element_method_definition(
+        MethodDefinitionType::MethodDefinition,
+        SPAN,
+        ctx.ast.vec(),
+        key.clone_in(ctx.ast.allocator),
+        function,
+        kind,
+        false,
+        r#static,
+        false,
+        false,
+        accessibility,
+    )
+}
+
+/// Generator for private names which are not declared in a class.
+struct PrivateNameGenerator {
+    declared: Vec<String>,
+    current: Vec<u8>,
+}
+
+impl PrivateNameGenerator {
+    fn new(class: &Class<'_>) -> Self {
+        let declared = class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| match element {
+                ClassElement::MethodDefinition(method) => method.key.private_name(),
+                ClassElement::PropertyDefinition(prop) => prop.key.private_name(),
+                ClassElement::AccessorProperty(prop) => prop.key.private_name(),
+                _ => None,
+            })
+            .map(|name| name.to_string())
+            .collect();
+        Self { declared, current: vec![] }
+    }
+
+    fn generate<'a>(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            increment_id(&mut self.current);
+            // Only contains ASCII letters
+            let name = std::str::from_utf8(&self.current).unwrap();
+            if !self.declared.iter().any(|declared| declared == name) {
+                return ctx.ast.atom(name);
+            }
+        }
+    }
+}
+
+/// `A` -> `B`, `Z` -> `a`, `z` -> `AA`.
+fn increment_id(id: &mut Vec<u8>) {
+    for byte in id.iter_mut().rev() {
+        match *byte {
+            b'Z' => {
+                *byte = b'a';
+                return;
+            }
+            b'z' => *byte = b'A',
+            c => {
+                *byte = c + 1;
+                return;
+            }
+        }
+    }
+    id.insert(0, b'A');
+}
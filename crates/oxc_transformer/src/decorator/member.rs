@@ -0,0 +1,404 @@
+//! TC39 decorators on class members.
+//!
+//! ```js
+//! class A {
+//!   @dec a = 1;
+//!   @dec2 m() {}
+//!   b = 2;
+//! }
+//! ```
+//! ->
+//! ```js
+//! let _init_a, _init_extra_a, _initProto;
+//! class A {
+//!   static {
+//!     [_init_a, _init_extra_a, _initProto] = babelHelpers.applyDecs2311(this, [], [
+//!       [dec2, 2, "m"],
+//!       [dec, 0, "a"],
+//!     ]).e;
+//!   }
+//!   a = (_initProto(this), _init_a(this, 1));
+//!   m() {}
+//!   b = (_init_extra_a(this), 2);
+//! }
+//! ```
+//!
+//! Evaluation order follows the decorators proposal:
+//!
+//! 1. Decorator expressions are evaluated in source order, after class decorators.
+//!    Expressions other than identifiers are stored in variables declared before the class,
+//!    as `applyDecs2311` receives decorators in a different order.
+//! 2. Decorators are applied when the class is defined, to methods and accessors before fields,
+//!    and to static members before instance members.
+//! 3. When an instance is constructed, initializers added by method decorators (`_initProto`)
+//!    run before the first field is initialized. Each decorated field is initialized by the
+//!    initializers returned by its decorators (`_init_a`), and initializers added by its
+//!    decorators (`_init_extra_a`) run before the next field is initialized, or at the start
+//!    of the constructor after the last field.
+//!
+//! Static fields work the same way, with `_initStatic` running before any static field is
+//! initialized, and `_init_extra_a` in a static block after the field.
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::SPAN;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::TraverseCtx;
+
+use super::{create_assignment_target, create_let_declarator, ClassDecorators, Decorators};
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::{bindings::BoundIdentifier, constructor::insert_inits_into_constructor},
+};
+
+/// Kinds of class members, as numbered by `applyDecs2311`.
+const FIELD: u8 = 0;
+const METHOD: u8 = 2;
+const GETTER: u8 = 3;
+const SETTER: u8 = 4;
+/// Added to the kind of static members.
+const STATIC: u8 = 8;
+
+struct DecoratedMember<'a> {
+    /// Index of the member in the class body
+    index: usize,
+    kind: u8,
+    r#static: bool,
+    name: String,
+    decorators: Vec<Expression<'a>>,
+    /// `_init_a` and `_init_extra_a` of fields
+    init: Option<(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+}
+
+impl DecoratedMember<'_> {
+    fn is_field(&self) -> bool {
+        self.kind == FIELD
+    }
+}
+
+impl<'a, 'ctx> Decorators<'a, 'ctx> {
+    /// Apply decorators of members of `class`, which must pass [`supports_member_decorators`].
+    ///
+    /// Variables used by the output are added to `declarators`, to be declared before the class.
+    /// If the class is decorated too, its decorators are applied by the same `applyDecs2311` call.
+    pub(super) fn transform_member_decorators(
+        &self,
+        class: &mut Class<'a>,
+        class_decorators: Option<&ClassDecorators<'a>>,
+        declarators: &mut ArenaVec<'a, VariableDeclarator<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Evaluate decorators in source order
+        let mut members = vec![];
+        for (index, element) in class.body.body.iter_mut().enumerate() {
+            let (decorators, kind, r#static, name) = match element {
+                ClassElement::MethodDefinition(method) => {
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Get => GETTER,
+                        MethodDefinitionKind::Set => SETTER,
+                        _ => METHOD,
+                    };
+                    let name = method.key.static_name().unwrap_or_default().into_owned();
+                    (ctx.ast.move_vec(&mut method.decorators), kind, method.r#static, name)
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    let name = prop.key.static_name().unwrap_or_default().into_owned();
+                    (ctx.ast.move_vec(&mut prop.decorators), FIELD, prop.r#static, name)
+                }
+                _ => continue,
+            };
+            if decorators.is_empty() {
+                continue;
+            }
+            let decorators = decorators
+                .into_iter()
+                .map(|decorator| memoize_decorator(decorator.expression, declarators, ctx))
+                .collect();
+            members.push(DecoratedMember { index, kind, r#static, name, decorators, init: None });
+        }
+
+        // Methods and accessors are decorated before fields, and static members before
+        // instance members
+        members.sort_by_key(|member| (member.is_field(), !member.r#static));
+
+        let mut new_binding = |name: &str, ctx: &mut TraverseCtx<'a>| {
+            let binding = BoundIdentifier::new_uid_in_current_scope(
+                name,
+                SymbolFlags::BlockScopedVariable,
+                ctx,
+            );
+            declarators.push(create_let_declarator(&binding, None, ctx));
+            binding
+        };
+        for member in members.iter_mut().filter(|member| member.is_field()) {
+            let (init_name, extra_name) = if is_identifier_name(&member.name) {
+                (format!("init_{}", member.name), format!("init_extra_{}", member.name))
+            } else {
+                ("init".to_string(), "init_extra".to_string())
+            };
+            member.init = Some((new_binding(&init_name, ctx), new_binding(&extra_name, ctx)));
+        }
+        let init_proto = members
+            .iter()
+            .any(|member| !member.is_field() && !member.r#static)
+            .then(|| new_binding("initProto", ctx));
+        let init_static = members
+            .iter()
+            .any(|member| !member.is_field() && member.r#static)
+            .then(|| new_binding("initStatic", ctx));
+
+        // `[_init_a, _init_extra_a, _initProto, _initStatic]`
+        let mut targets = ctx.ast.vec();
+        for (init, extra) in members.iter().filter_map(|member| member.init.as_ref()) {
+            targets.push(Some(create_array_target_element(init, ctx)));
+            targets.push(Some(create_array_target_element(extra, ctx)));
+        }
+        for binding in init_proto.iter().chain(&init_static) {
+            targets.push(Some(create_array_target_element(binding, ctx)));
+        }
+        let targets = create_array_target(targets, ctx);
+
+        // `[[dec2, 2, "m"], [dec, 0, "a"]]`
+        let mut field_inits = vec![None; class.body.body.len()];
+        let mut member_decs = ctx.ast.vec_with_capacity(members.len());
+        for member in members {
+            let mut decorators = member.decorators;
+            let decorators = if decorators.len() == 1 {
+                decorators.pop().unwrap()
+            } else {
+                let elements =
+                    ctx.ast.vec_from_iter(decorators.into_iter().map(ArrayExpressionElement::from));
+                ctx.ast.expression_array(SPAN, elements, None)
+            };
+            let kind = member.kind + if member.r#static { STATIC } else { 0 };
+            let elements = ctx.ast.vec_from_iter([
+                ArrayExpressionElement::from(decorators),
+                ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    f64::from(kind),
+                    kind.to_string(),
+                    NumberBase::Decimal,
+                )),
+                ArrayExpressionElement::from(
+                    ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&member.name)),
+                ),
+            ]);
+            member_decs
+                .push(ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, elements, None)));
+            field_inits[member.index] = member.init;
+        }
+
+        // `babelHelpers.applyDecs2311(this, _classDecs, [...])`
+        let class_decs = match class_decorators {
+            Some(class_decorators) => ctx.ast.expression_from_identifier_reference(
+                class_decorators.decs.create_read_reference(ctx),
+            ),
+            None => ctx.ast.expression_array(SPAN, ctx.ast.vec(), None),
+        };
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(class_decs),
+            Argument::from(ctx.ast.expression_array(SPAN, member_decs, None)),
+        ]);
+        let call = helper_loader::call(Helper::ApplyDecs2311, arguments, ctx);
+
+        let assignment = if let Some(class_decorators) = class_decorators {
+            // `({ e: [...], c: [_A, _initClass] } = babelHelpers.applyDecs2311(...))`
+            let class_targets = create_array_target(
+                ctx.ast.vec_from_iter([
+                    Some(create_array_target_element(&class_decorators.new_class, ctx)),
+                    Some(create_array_target_element(&class_decorators.init_class, ctx)),
+                ]),
+                ctx,
+            );
+            let properties = ctx.ast.vec_from_iter([
+                create_target_property("e", targets, ctx),
+                create_target_property("c", class_targets, ctx),
+            ]);
+            let target = AssignmentTarget::ObjectAssignmentTarget(
+                ctx.ast.alloc_object_assignment_target(SPAN, properties, None),
+            );
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+        } else {
+            // `[...] = babelHelpers.applyDecs2311(...).e`
+            let property = ctx.ast.identifier_name(SPAN, "e");
+            let value =
+                Expression::from(ctx.ast.member_expression_static(SPAN, call, property, false));
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, targets, value)
+        };
+
+        let mut statements = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+        if let Some(init_static) = &init_static {
+            // `_initStatic(this);`
+            statements
+                .push(ctx.ast.statement_expression(SPAN, create_init_call(init_static, None, ctx)));
+        }
+
+        let class_scope_id = class.scope_id.get().unwrap();
+        let elements = ctx.ast.move_vec(&mut class.body.body);
+        let mut body = ctx.ast.vec_with_capacity(elements.len() + 1);
+        body.push(create_static_block(statements, class_scope_id, ctx));
+
+        // Initializers which run before the next instance field is initialized
+        let mut pending_inits = vec![];
+        if let Some(init_proto) = &init_proto {
+            pending_inits.push(create_init_call(init_proto, None, ctx));
+        }
+        for (element, init) in elements.into_iter().zip(field_inits) {
+            let ClassElement::PropertyDefinition(mut prop) = element else {
+                body.push(element);
+                continue;
+            };
+
+            // `a = 1` -> `a = _init_a(this, 1)`
+            if let Some((init, _)) = &init {
+                let value = prop.value.take();
+                prop.value = Some(create_init_call(init, value, ctx));
+            }
+
+            if prop.r#static {
+                body.push(ClassElement::PropertyDefinition(prop));
+                // `static { _init_extra_a(this); }`
+                if let Some((_, extra)) = &init {
+                    let call = create_init_call(extra, None, ctx);
+                    let statements = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+                    body.push(create_static_block(statements, class_scope_id, ctx));
+                }
+                continue;
+            }
+
+            // `b = 2` -> `b = (_init_extra_a(this), 2)`
+            if !pending_inits.is_empty() {
+                let value = prop.value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                let mut expressions = ctx.ast.vec_from_iter(pending_inits.drain(..));
+                expressions.push(value);
+                prop.value = Some(ctx.ast.expression_sequence(SPAN, expressions));
+            }
+            if let Some((_, extra)) = &init {
+                pending_inits.push(create_init_call(extra, None, ctx));
+            }
+            body.push(ClassElement::PropertyDefinition(prop));
+        }
+        class.body.body = body;
+
+        if !pending_inits.is_empty() {
+            if let Err(span) = insert_inits_into_constructor(class, pending_inits, ctx) {
+                self.ctx.error(OxcDiagnostic::error(
+                    "Decorators transform does not support derived class constructors without a `super()` call.",
+                ).with_label(span));
+            }
+        }
+    }
+}
+
+/// Whether decorators of all members of `class` can be transformed by
+/// [`Decorators::transform_member_decorators`].
+///
+/// Decorators are not supported on auto-accessors, parameters, and members with private or
+/// computed keys.
+pub(super) fn supports_member_decorators(class: &Class<'_>) -> bool {
+    class.body.body.iter().all(|element| match element {
+        ClassElement::MethodDefinition(method) => {
+            method.value.params.items.iter().all(|param| param.decorators.is_empty())
+                && (method.decorators.is_empty()
+                    || (method.kind != MethodDefinitionKind::Constructor
+                        && is_supported_key(&method.key, method.computed)))
+        }
+        ClassElement::PropertyDefinition(prop) => {
+            prop.decorators.is_empty()
+                || (prop.r#type == PropertyDefinitionType::PropertyDefinition
+                    && !prop.declare
+                    && is_supported_key(&prop.key, prop.computed))
+        }
+        ClassElement::AccessorProperty(prop) => prop.decorators.is_empty(),
+        ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => true,
+    })
+}
+
+fn is_supported_key(key: &PropertyKey<'_>, computed: bool) -> bool {
+    !key.is_private_identifier()
+        && (!computed || matches!(key, PropertyKey::StringLiteral(_)))
+        && key.static_name().is_some()
+}
+
+/// Store a decorator expression in a variable, unless it is an identifier.
+///
+/// `@(a.b()) m() {}` -> `let _dec = a.b();`
+fn memoize_decorator<'a>(
+    expr: Expression<'a>,
+    declarators: &mut ArenaVec<'a, VariableDeclarator<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    if matches!(expr, Expression::Identifier(_)) {
+        return expr;
+    }
+    let binding =
+        BoundIdentifier::new_uid_in_current_scope("dec", SymbolFlags::BlockScopedVariable, ctx);
+    declarators.push(create_let_declarator(&binding, Some(expr), ctx));
+    ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+}
+
+/// `_init_a(this)` or `_init_a(this, value)`
+fn create_init_call<'a>(
+    binding: &BoundIdentifier<'a>,
+    value: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN)));
+    if let Some(value) = value {
+        arguments.push(Argument::from(value));
+    }
+    ctx.ast.expression_call(
+        SPAN,
+        ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+        NONE,
+        arguments,
+        false,
+    )
+}
+
+fn create_array_target_element<'a>(
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    AssignmentTargetMaybeDefault::from(create_assignment_target(binding, ctx))
+}
+
+fn create_array_target<'a>(
+    elements: ArenaVec<'a, Option<AssignmentTargetMaybeDefault<'a>>>,
+    ctx: &TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    AssignmentTarget::ArrayAssignmentTarget(
+        ctx.ast.alloc_array_assignment_target(SPAN, elements, None, None),
+    )
+}
+
+/// `name: target` in an object assignment target
+fn create_target_property<'a>(
+    name: &'static str,
+    target: AssignmentTarget<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> AssignmentTargetProperty<'a> {
+    ctx.ast.assignment_target_property_assignment_target_property_property(
+        SPAN,
+        ctx.ast.property_key_identifier_name(SPAN, name),
+        AssignmentTargetMaybeDefault::from(target),
+    )
+}
+
+fn create_static_block<'a>(
+    statements: ArenaVec<'a, Statement<'a>>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let scope_id = ctx
+        .create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock | ScopeFlags::StrictMode);
+    let block = ctx.ast.alloc_static_block(SPAN, statements);
+    block.scope_id.set(Some(scope_id));
+    ClassElement::StaticBlock(block)
+}
@@ -0,0 +1,572 @@
+//! Decorators
+//!
+//! This plugin transforms decorators on classes and their members, with either the TC39
+//! decorators semantics (default), or the legacy semantics used by TypeScript's
+//! `experimentalDecorators` (`experimental_decorators`).
+//!
+//! The two differ significantly. Legacy decorators are plain functions called with the class,
+//! and their return value replaces the class. TC39 decorators receive a `context` object as their
+//! second argument, and are applied by `babelHelpers.applyDecs2311`, which also runs the
+//! initializers registered through `context.addInitializer`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class A {}
+//! ```
+//!
+//! Output (legacy):
+//! ```js
+//! var _class;
+//! let A = dec(_class = class A {}) || _class;
+//! ```
+//!
+//! Output (TC39):
+//! ```js
+//! let _classDecs = [dec], _A, _initClass;
+//! class A {}
+//! [_A, _initClass] = babelHelpers.applyDecs2311(A, _classDecs, []).c;
+//! A = _A;
+//! _initClass();
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://babel.dev/docs/babel-plugin-proposal-decorators),
+//! with `version: "legacy"` and `version: "2023-11"`.
+//!
+//! Decorated class declarations are rewritten when entering the statements which contain them,
+//! so the rest of the transformer visits the output.
+//!
+//! TC39 decorators on members are applied in a static block at the start of the class,
+//! see [`member`] for the order in which they run.
+//!
+//! Auto-accessors (`accessor x = 1`) are lowered to a private field with a getter and setter
+//! when entering the class, so the class properties transform can lower the private field.
+//!
+//! ## Missing features
+//!
+//! * Legacy decorators on class members, and decorators on parameters.
+//! * TC39 decorators on auto-accessors, and on members with private or computed keys.
+//! * TC39 decorators on class expressions and anonymous default exported classes.
+//! * Decorators like `@a.b` are not called with `a` as `this`.
+//! * TC39 decorators are applied after the class is defined, so static fields and blocks
+//!   see the undecorated class.
+//!
+//! Decorators on class members which are not supported are reported as errors,
+//! and removed from the output.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-proposal-decorators>
+//! * Decorators TC39 proposal: <https://github.com/tc39/proposal-decorators>
+//! * TypeScript experimental decorators: <https://www.typescriptlang.org/docs/handbook/decorators.html>
+
+mod auto_accessor;
+mod member;
+mod options;
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{ast::*, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Span, SPAN};
+use oxc_syntax::{node::NodeId, symbol::SymbolFlags};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub use options::DecoratorOptions;
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+    TransformCtx,
+};
+use member::supports_member_decorators;
+
+/// Bindings used to apply TC39 class decorators.
+struct ClassDecorators<'a> {
+    /// `_classDecs`
+    decs: BoundIdentifier<'a>,
+    /// `_A`
+    new_class: BoundIdentifier<'a>,
+    /// `_initClass`
+    init_class: BoundIdentifier<'a>,
+}
+
+pub struct Decorators<'a, 'ctx> {
+    options: Option<DecoratorOptions>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Decorators<'a, 'ctx> {
+    pub fn new(options: Option<DecoratorOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Decorators<'a, 'ctx> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(options) = self.options else { return };
+        // Legacy decorators on members are not supported
+        let is_decorated = |stmt: &Statement<'a>| {
+            get_class_declaration(stmt).is_some_and(|class| {
+                !class.decorators.is_empty()
+                    || (!options.experimental_decorators && has_member_decorators(class))
+            })
+        };
+        if !stmts.iter().any(is_decorated) {
+            return;
+        }
+
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in ctx.ast.move_vec(stmts) {
+            if !is_decorated(&stmt) {
+                new_stmts.push(stmt);
+            } else if options.experimental_decorators {
+                self.transform_statement_legacy(stmt, &mut new_stmts, ctx);
+            } else {
+                self.transform_statement(stmt, &mut new_stmts, ctx);
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(options) = self.options else { return };
+        let Expression::ClassExpression(class) = expr else { return };
+        if class.decorators.is_empty() {
+            return;
+        }
+
+        if !options.experimental_decorators {
+            self.ctx.error(
+                OxcDiagnostic::error("Decorators on class expressions are not supported yet.")
+                    .with_label(class.decorators[0].span),
+            );
+            return;
+        }
+
+        let Expression::ClassExpression(class) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        *expr = self.transform_class_legacy(class, ctx);
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_none() {
+            return;
+        }
+        if let Some(span) = find_member_decorator(class) {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Decorators on class members and parameters are not supported yet.",
+                )
+                .with_label(span),
+            );
+            // Remove decorators, so the output is valid JS
+            remove_member_decorators(class);
+        }
+        self.transform_auto_accessors(class, ctx);
+    }
+}
+
+impl<'a, 'ctx> Decorators<'a, 'ctx> {
+    /// Transform a decorated class declaration with legacy semantics.
+    ///
+    /// * `@dec class A {}` -> `let A = dec(_class = class A {}) || _class;`
+    /// * `export @dec class A {}` -> `export let A = dec(_class = class A {}) || _class;`
+    /// * `export default @dec class A {}`
+    ///   -> `let A = dec(_class = class A {}) || _class; export { A as default };`
+    /// * `export default @dec class {}` -> `export default dec(_class = class {}) || _class;`
+    fn transform_statement_legacy(
+        &self,
+        stmt: Statement<'a>,
+        new_stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                let decl = self.transform_class_declaration_legacy(class, ctx);
+                new_stmts.push(Statement::VariableDeclaration(decl));
+            }
+            Statement::ExportNamedDeclaration(mut export) => {
+                let Some(Declaration::ClassDeclaration(class)) = export.declaration.take() else {
+                    unreachable!()
+                };
+                let decl = self.transform_class_declaration_legacy(class, ctx);
+                export.declaration = Some(Declaration::VariableDeclaration(decl));
+                new_stmts.push(Statement::ExportNamedDeclaration(export));
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let export = export.unbox();
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = export.declaration
+                else {
+                    unreachable!()
+                };
+
+                let Some(id) = &class.id else {
+                    let expr = self.transform_class_legacy(class, ctx);
+                    new_stmts.push(ctx.ast.statement_module_declaration(
+                        ctx.ast.module_declaration_export_default_declaration(
+                            export.span,
+                            ExportDefaultDeclarationKind::from(expr),
+                            export.exported,
+                        ),
+                    ));
+                    return;
+                };
+
+                let binding = BoundIdentifier {
+                    name: id.name.clone(),
+                    symbol_id: id.symbol_id.get().unwrap(),
+                };
+                let decl = self.transform_class_declaration_legacy(class, ctx);
+                new_stmts.push(Statement::VariableDeclaration(decl));
+
+                let local =
+                    ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+                let specifier = ctx.ast.export_specifier(
+                    SPAN,
+                    local,
+                    export.exported,
+                    ImportOrExportKind::Value,
+                );
+                new_stmts.push(ctx.ast.statement_module_declaration(
+                    ctx.ast.module_declaration_export_named_declaration(
+                        export.span,
+                        None,
+                        ctx.ast.vec1(specifier),
+                        None,
+                        ImportOrExportKind::Value,
+                        NONE,
+                    ),
+                ));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// `@dec class A {}` -> `let A = dec(_class = class A {}) || _class;`
+    fn transform_class_declaration_legacy(
+        &self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let span = class.span;
+        let id = class.id.take().unwrap();
+
+        // The outer binding becomes a `let`, and the class expression keeps its name,
+        // which is bound in the class scope.
+        let symbol_id = id.symbol_id.get().unwrap();
+        *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::BlockScopedVariable;
+        let class_scope_id = class.scope_id.get().unwrap();
+        let inner_symbol_id = ctx.symbols_mut().create_symbol(
+            id.span,
+            id.name.to_compact_str(),
+            SymbolFlags::Class,
+            class_scope_id,
+            NodeId::DUMMY,
+        );
+        ctx.scopes_mut().add_binding(class_scope_id, id.name.to_compact_str(), inner_symbol_id);
+        class.id =
+            Some(BindingIdentifier::new_with_symbol_id(id.span, id.name.clone(), inner_symbol_id));
+
+        let init = self.transform_class_legacy(class, ctx);
+        let kind = VariableDeclarationKind::Let;
+        let id = ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_from_binding_identifier(id),
+            NONE,
+            false,
+        );
+        let declarator = ctx.ast.variable_declarator(span, kind, id, Some(init), false);
+        ctx.ast.alloc_variable_declaration(span, kind, ctx.ast.vec1(declarator), false)
+    }
+
+    /// `@a @b class A {}` -> `a(_class = b(_class = class A {}) || _class) || _class`
+    fn transform_class_legacy(
+        &self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let decorators = ctx.ast.move_vec(&mut class.decorators);
+        class.r#type = ClassType::ClassExpression;
+
+        let binding = BoundIdentifier::new_uid_in_current_scope(
+            "class",
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+
+        // Decorators are applied from last to first
+        let mut expr = Expression::ClassExpression(class);
+        for decorator in decorators.into_iter().rev() {
+            // Value of the assignment is used, so it's a read and a write
+            let target =
+                AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                    binding.create_read_write_reference(ctx),
+                ));
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, expr);
+            let call = ctx.ast.expression_call(
+                decorator.span,
+                decorator.expression,
+                NONE,
+                ctx.ast.vec1(Argument::from(assignment)),
+                false,
+            );
+            expr = ctx.ast.expression_logical(
+                SPAN,
+                call,
+                LogicalOperator::Or,
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+            );
+        }
+        expr
+    }
+
+    /// Transform a class declaration with TC39 semantics, which has decorators on the class
+    /// or its members.
+    ///
+    /// ```js
+    /// @dec class A {}
+    /// ```
+    /// ->
+    /// ```js
+    /// let _classDecs = [dec], _A, _initClass;
+    /// class A {}
+    /// [_A, _initClass] = babelHelpers.applyDecs2311(A, _classDecs, []).c;
+    /// A = _A;
+    /// _initClass();
+    /// ```
+    ///
+    /// See [`member`] for decorators on members.
+    fn transform_statement(
+        &self,
+        mut stmt: Statement<'a>,
+        new_stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let class = match &mut stmt {
+            Statement::ClassDeclaration(class) => class,
+            Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                Some(Declaration::ClassDeclaration(class)) => class,
+                _ => unreachable!(),
+            },
+            Statement::ExportDefaultDeclaration(export) => match &mut export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        let decorate_members = has_member_decorators(class) && supports_member_decorators(class);
+        if class.decorators.is_empty() {
+            if decorate_members {
+                let mut declarators = ctx.ast.vec();
+                self.transform_member_decorators(class, None, &mut declarators, ctx);
+                new_stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Let,
+                    declarators,
+                    false,
+                )));
+            }
+            new_stmts.push(stmt);
+            return;
+        }
+
+        let Some(id) = &class.id else {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Decorators on anonymous default exported classes are not supported yet.",
+                )
+                .with_label(class.decorators[0].span),
+            );
+            new_stmts.push(stmt);
+            return;
+        };
+
+        let class_binding =
+            BoundIdentifier { name: id.name.clone(), symbol_id: id.symbol_id.get().unwrap() };
+        let decorators = ctx.ast.move_vec(&mut class.decorators);
+
+        let decs_binding = BoundIdentifier::new_uid_in_current_scope(
+            "classDecs",
+            SymbolFlags::BlockScopedVariable,
+            ctx,
+        );
+        let new_class_binding = BoundIdentifier::new_uid_in_current_scope(
+            &class_binding.name,
+            SymbolFlags::BlockScopedVariable,
+            ctx,
+        );
+        let init_binding = BoundIdentifier::new_uid_in_current_scope(
+            "initClass",
+            SymbolFlags::BlockScopedVariable,
+            ctx,
+        );
+
+        // `let _classDecs = [dec], _A, _initClass;`
+        let elements = ctx.ast.vec_from_iter(
+            decorators
+                .into_iter()
+                .map(|decorator| ArrayExpressionElement::from(decorator.expression)),
+        );
+        let decs = ctx.ast.expression_array(SPAN, elements, None);
+        let mut declarators = ctx.ast.vec1(create_let_declarator(&decs_binding, Some(decs), ctx));
+        if decorate_members {
+            // Class decorators are applied with member decorators, in a static block
+            let class_decorators = ClassDecorators {
+                decs: decs_binding.clone(),
+                new_class: new_class_binding.clone(),
+                init_class: init_binding.clone(),
+            };
+            self.transform_member_decorators(class, Some(&class_decorators), &mut declarators, ctx);
+        }
+        declarators.push(create_let_declarator(&new_class_binding, None, ctx));
+        declarators.push(create_let_declarator(&init_binding, None, ctx));
+        new_stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Let,
+            declarators,
+            false,
+        )));
+
+        new_stmts.push(stmt);
+
+        if !decorate_members {
+            // `[_A, _initClass] = babelHelpers.applyDecs2311(A, _classDecs, []).c;`
+            let arguments =
+                ctx.ast.vec_from_iter([
+                    Argument::from(ctx.ast.expression_from_identifier_reference(
+                        class_binding.create_read_reference(ctx),
+                    )),
+                    Argument::from(ctx.ast.expression_from_identifier_reference(
+                        decs_binding.create_read_reference(ctx),
+                    )),
+                    Argument::from(ctx.ast.expression_array(SPAN, ctx.ast.vec(), None)),
+                ]);
+            let call = helper_loader::call(Helper::ApplyDecs2311, arguments, ctx);
+            let property = ctx.ast.identifier_name(SPAN, "c");
+            let value =
+                Expression::from(ctx.ast.member_expression_static(SPAN, call, property, false));
+            let elements = ctx.ast.vec_from_iter([
+                Some(AssignmentTargetMaybeDefault::from(create_assignment_target(
+                    &new_class_binding,
+                    ctx,
+                ))),
+                Some(AssignmentTargetMaybeDefault::from(create_assignment_target(
+                    &init_binding,
+                    ctx,
+                ))),
+            ]);
+            let target = AssignmentTarget::ArrayAssignmentTarget(
+                ctx.ast.alloc_array_assignment_target(SPAN, elements, None, None),
+            );
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            new_stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+
+        // `A = _A;`
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            create_assignment_target(&class_binding, ctx),
+            ctx.ast
+                .expression_from_identifier_reference(new_class_binding.create_read_reference(ctx)),
+        );
+        new_stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+
+        // `_initClass();`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(init_binding.create_read_reference(ctx)),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        new_stmts.push(ctx.ast.statement_expression(SPAN, call));
+    }
+}
+
+/// Get class of a class declaration statement, including exported classes.
+fn get_class_declaration<'b, 'a>(stmt: &'b Statement<'a>) -> Option<&'b Class<'a>> {
+    match stmt {
+        Statement::ClassDeclaration(class) => Some(&**class),
+        Statement::ExportNamedDeclaration(export) => match &export.declaration {
+            Some(Declaration::ClassDeclaration(class)) => Some(&**class),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(&**class),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn has_member_decorators(class: &Class<'_>) -> bool {
+    find_member_decorator(class).is_some()
+}
+
+/// Get span of first decorator on a class member or a method parameter.
+fn find_member_decorator(class: &Class<'_>) -> Option<Span> {
+    class.body.body.iter().find_map(|element| {
+        let decorator = match element {
+            ClassElement::MethodDefinition(method) => method.decorators.first().or_else(|| {
+                method.value.params.items.iter().find_map(|param| param.decorators.first())
+            }),
+            ClassElement::PropertyDefinition(prop) => prop.decorators.first(),
+            ClassElement::AccessorProperty(prop) => prop.decorators.first(),
+            _ => None,
+        };
+        decorator.map(|decorator| decorator.span)
+    })
+}
+
+/// Remove decorators from class members and method parameters.
+fn remove_member_decorators(class: &mut Class<'_>) {
+    for element in class.body.body.iter_mut() {
+        match element {
+            ClassElement::MethodDefinition(method) => {
+                method.decorators.clear();
+                for param in method.value.params.items.iter_mut() {
+                    param.decorators.clear();
+                }
+            }
+            ClassElement::PropertyDefinition(prop) => prop.decorators.clear(),
+            ClassElement::AccessorProperty(prop) => prop.decorators.clear(),
+            _ => {}
+        }
+    }
+}
+
+fn create_assignment_target<'a>(
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    AssignmentTarget::from(
+        ctx.ast.simple_assignment_target_from_identifier_reference(
+            binding.create_write_reference(ctx),
+        ),
+    )
+}
+
+fn create_let_declarator<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    let id = ctx.ast.binding_pattern(
+        ctx.ast.binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier()),
+        NONE,
+        false,
+    );
+    ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Let, id, init, false)
+}
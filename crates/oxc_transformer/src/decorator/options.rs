@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DecoratorOptions {
+    /// Use the legacy decorators semantics, as TypeScript's `experimentalDecorators` option
+    /// and Babel's `version: "legacy"`, instead of the TC39 decorators proposal (`2023-11`).
+    pub experimental_decorators: bool,
+}
@@ -21,19 +21,19 @@ fn features() -> &'static FxHashMap<String, Versions> {
 
         map.into_iter()
             .map(|(feature, mut versions)| {
-                (feature, {
-                    let version = versions.get("safari");
-                    if version.is_some_and(|v| v == "tp") {
-                        versions.remove("safari");
-                    }
-
-                    Versions(
-                        versions
-                            .into_iter()
-                            .map(|(k, v)| (k, v.parse::<Version>().unwrap()))
-                            .collect::<FxHashMap<String, Version>>(),
-                    )
-                })
+                let version = versions.get("safari");
+                if version.is_some_and(|v| v == "tp") {
+                    versions.remove("safari");
+                }
+
+                let mut versions = Versions(
+                    versions
+                        .into_iter()
+                        .map(|(k, v)| (k, v.parse::<Version>().unwrap()))
+                        .collect::<FxHashMap<String, Version>>(),
+                );
+                insert_es_version(&feature, &mut versions);
+                (feature, versions)
             })
             .collect()
     })
@@ -43,13 +43,69 @@ fn features() -> &'static FxHashMap<String, Versions> {
 fn bugfix_features() -> &'static FxHashMap<String, Versions> {
     static BUGFIX_FEATURES: OnceLock<FxHashMap<String, Versions>> = OnceLock::new();
     BUGFIX_FEATURES.get_or_init(|| {
-        let map: FxHashMap<String, Versions> =
+        let mut map: FxHashMap<String, Versions> =
             serde_json::from_str(include_str!("./@babel/compat_data/data/plugin_bugfixes.json"))
                 .expect("failed to parse json");
+        for (feature, versions) in &mut map {
+            insert_es_version(feature, versions);
+        }
         features().clone().into_iter().chain(map).collect()
     })
 }
 
+/// Add the ECMAScript version which introduced the feature as the `es` engine,
+/// so an ECMAScript version can be used as a target, e.g. `{ "es": "2015" }`.
+fn insert_es_version(feature: &str, versions: &mut Versions) {
+    let name = feature
+        .strip_prefix("transform-")
+        .or_else(|| feature.strip_prefix("proposal-"))
+        .or_else(|| feature.strip_prefix("esbuild-"))
+        .unwrap_or(feature);
+    let major = match name {
+        "member-expression-literals" | "property-literals" | "reserved-words" => 5,
+        "arrow-functions"
+        | "block-scoped-functions"
+        | "block-scoping"
+        | "classes"
+        | "computed-properties"
+        | "destructuring"
+        | "duplicate-keys"
+        | "for-of"
+        | "function-name"
+        | "literals"
+        | "new-target"
+        | "object-super"
+        | "parameters"
+        | "regenerator"
+        | "shorthand-properties"
+        | "spread"
+        | "sticky-regex"
+        | "template-literals"
+        | "typeof-symbol"
+        | "unicode-escapes"
+        | "unicode-regex" => 2015,
+        "exponentiation-operator" => 2016,
+        "async-to-generator" => 2017,
+        "async-generator-functions"
+        | "dotall-regex"
+        | "named-capturing-groups-regex"
+        | "object-rest-spread"
+        | "regexp-lookbehind-assertions"
+        | "unicode-property-regex" => 2018,
+        "json-strings" | "optional-catch-binding" => 2019,
+        "export-namespace-from" | "nullish-coalescing-operator" | "optional-chaining" => 2020,
+        "logical-assignment-operators" | "numeric-separator" => 2021,
+        "class-properties"
+        | "class-static-block"
+        | "private-methods"
+        | "private-property-in-object"
+        | "regexp-match-indices" => 2022,
+        "unicode-sets-regex" => 2024,
+        _ => return,
+    };
+    versions.insert("es".to_string(), Version { major, minor: 0, patch: 0 });
+}
+
 pub fn can_enable_plugin(name: &str, targets: Option<&Versions>, bugfixes: bool) -> bool {
     let versions = if bugfixes {
         bugfix_features().get(name).unwrap_or_else(|| &features()[name])
@@ -58,3 +114,26 @@ pub fn can_enable_plugin(name: &str, targets: Option<&Versions>, bugfixes: bool)
     };
     targets.is_some_and(|v| v.should_enable(versions))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::can_enable_plugin;
+    use crate::{env::EnvOptions, ESTarget};
+
+    #[test]
+    fn es_target_enables_newer_features() {
+        let targets = |target| EnvOptions::from_target(target).get_targets().unwrap();
+
+        let es5 = targets(ESTarget::ES5);
+        assert!(can_enable_plugin("transform-arrow-functions", Some(&es5), false));
+        assert!(can_enable_plugin("transform-template-literals", Some(&es5), true));
+
+        let es2015 = targets(ESTarget::ES2015);
+        assert!(!can_enable_plugin("transform-arrow-functions", Some(&es2015), false));
+        assert!(!can_enable_plugin("transform-template-literals", Some(&es2015), true));
+        assert!(can_enable_plugin("transform-nullish-coalescing-operator", Some(&es2015), false));
+
+        let esnext = targets(ESTarget::ESNext);
+        assert!(!can_enable_plugin("transform-unicode-sets-regex", Some(&esnext), false));
+    }
+}
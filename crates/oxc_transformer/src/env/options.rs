@@ -2,7 +2,8 @@ use oxc_diagnostics::Error;
 use serde::Deserialize;
 use serde_json::Value;
 
-use super::targets::{query::Targets, Versions};
+use super::targets::{query::Targets, version::Version, Versions};
+use crate::ESTarget;
 
 fn default_as_true() -> bool {
     true
@@ -55,6 +56,31 @@ pub struct EnvOptions {
 }
 
 impl EnvOptions {
+    /// Target an ECMAScript version instead of browsers.
+    /// Plugins are enabled for features which are newer than `target`.
+    #[must_use]
+    pub fn from_target(target: ESTarget) -> Self {
+        let major = match target {
+            ESTarget::ES5 => Some(5),
+            ESTarget::ES2015 => Some(2015),
+            ESTarget::ES2016 => Some(2016),
+            ESTarget::ES2017 => Some(2017),
+            ESTarget::ES2018 => Some(2018),
+            ESTarget::ES2019 => Some(2019),
+            ESTarget::ES2020 => Some(2020),
+            ESTarget::ES2021 => Some(2021),
+            ESTarget::ES2022 => Some(2022),
+            ESTarget::ES2023 => Some(2023),
+            ESTarget::ES2024 => Some(2024),
+            ESTarget::ESNext => None,
+        };
+        let mut versions = Versions::default();
+        if let Some(major) = major {
+            versions.insert("es".to_string(), Version { major, minor: 0, patch: 0 });
+        }
+        Self { targets: Targets::Versions(versions), ..Self::default() }
+    }
+
     /// # Errors
     ///
     pub fn get_targets(&self) -> Result<Versions, Error> {
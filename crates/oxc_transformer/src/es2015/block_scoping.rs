@@ -0,0 +1,1689 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const fns = [];
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! {
+//!   let i = 10;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var fns = [];
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! {
+//!   var _i = 10;
+//! }
+//! ```
+//!
+//! Bindings are moved into the scope of the enclosing function. They are renamed if this would
+//! cause a conflict with another binding.
+//!
+//! If a binding declared in a loop is captured by a closure, each iteration of the loop needs its
+//! own copy of the binding. The body of the loop is moved into a function `_loop`, which is called
+//! on each iteration, with the bindings declared in the loop's head passed to it as arguments.
+//! If the body modifies those bindings, their new values are copied back before the iteration ends.
+//!
+//! `break`, `continue` and `return` in the body are converted to return a value from `_loop`,
+//! which the loop then acts on:
+//!
+//! ```js
+//! var _loop = function (x) {
+//!   if (x === 0) return { v: x };
+//!   if (x === 1) return 0;
+//!   fns.push(() => x);
+//! };
+//! for (var x of arr) {
+//!   var _ret = _loop(x);
+//!   if (_ret === 0) break;
+//!   if (_ret) return _ret.v;
+//! }
+//! ```
+//!
+//! If the body contains `yield` or `await`, `_loop` is a generator or async function,
+//! and is called with `yield*` or `await`.
+//!
+//! Reassigning a `const` binding throws a `TypeError` at runtime:
+//! `const x = 1; x = 2;` -> `var x = 1; 2, babelHelpers.readOnlyError("x");`.
+//! With `reportConstViolations` option, an error is reported at transform time instead.
+//!
+//! With `tdz` option, references to a binding before it is initialized throw a `ReferenceError`
+//! at runtime: `x; let x;` -> `babelHelpers.tdz("x"); var x;`.
+//!
+//! ## Missing features
+//!
+//! * Loops whose body contains `super` or `new.target` cannot be moved into a function.
+//!   An error is reported if they need per-iteration bindings.
+//! * Reassignments of `const` bindings in destructuring assignments, and in the heads of
+//!   `for...in` and `for...of` loops, are not detected.
+//! * `tdz` option only checks references which are certain to be evaluated before the binding
+//!   is initialized. References inside functions, and assignments, are not checked.
+//! * `throwIfClosureRequired` option.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-block-scoping>
+//! * Let and const declarations specification: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+//! * Per-iteration bindings specification: <https://tc39.es/ecma262/#sec-createperiterationenvironment>
+
+use std::cell::Cell;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{
+    ast::*,
+    syntax_directed_operations::BoundNames,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{NodeId, SymbolTable};
+use oxc_span::{CompactStr, GetSpan, Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+    TransformCtx,
+};
+
+use super::regenerator::ScopeReparenter;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    /// Throw at runtime on references to bindings before they are initialized
+    pub tdz: bool,
+    /// Report reassignments of `const` bindings as errors, instead of throwing at runtime
+    pub report_const_violations: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    options: BlockScopingOptions,
+    ctx: &'ctx TransformCtx<'a>,
+    /// Bindings which were renamed when moved into scope of enclosing function
+    renamed: FxHashSet<SymbolId>,
+    /// Bindings which were declared with `const`
+    read_only: FxHashSet<SymbolId>,
+    /// Bindings which have not been initialized yet, and the scopes they were declared in.
+    /// Only populated if `tdz` option is enabled.
+    uninitialized: FxHashMap<SymbolId, ScopeId>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(options: BlockScopingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            options,
+            ctx,
+            renamed: FxHashSet::default(),
+            read_only: FxHashSet::default(),
+            uninitialized: FxHashMap::default(),
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for BlockScoping<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(program.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(func.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_scope(arrow.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(block.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(block.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_scope(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    /// Move bodies of loops which need per-iteration bindings into functions.
+    ///
+    /// This happens before the loops are entered, so bindings in their heads are moved
+    /// into the functions before the loops' scopes are hoisted.
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !stmts.iter().any(Self::is_loop) {
+            return;
+        }
+        let analyses = stmts.iter().map(|stmt| self.analyze_loop(stmt, ctx)).collect::<Vec<_>>();
+        if analyses.iter().all(Option::is_none) {
+            return;
+        }
+
+        let parent_scope_id = ctx.current_scope_id();
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() + 1);
+        for (stmt, loop_analysis) in stmts.drain(..).zip(analyses) {
+            match loop_analysis {
+                Some(loop_analysis) => {
+                    new_stmts.extend(self.transform_loop(
+                        stmt,
+                        loop_analysis,
+                        parent_scope_id,
+                        ctx,
+                    ));
+                }
+                None => new_stmts.push(stmt),
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labelled loops are transformed along with their labels.
+        // Statements in statement lists are transformed in `enter_statements`.
+        if matches!(
+            ctx.parent(),
+            Ancestor::LabeledStatementBody(_)
+                | Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::TSModuleBlockBody(_)
+        ) || !Self::is_loop(stmt)
+        {
+            return;
+        }
+        let Some(analysis) = self.analyze_loop(stmt, ctx) else { return };
+
+        // `if (x) for (let i of y) {}` -> `if (x) { var _loop = ...; for (var i of y) {} }`
+        let span = stmt.span();
+        let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+        let loop_stmt = ctx.ast.move_statement(stmt);
+        let stmts = self.transform_loop(loop_stmt, analysis, scope_id, ctx);
+        let block = BlockStatement::new_with_scope_id(span, ctx.ast.vec_from_iter(stmts), scope_id);
+        *stmt = Statement::BlockStatement(ctx.ast.alloc(block));
+    }
+
+    /// `let x = 1;` -> `var x = 1;`
+    ///
+    /// `let x;` in the body of a loop -> `var x = void 0;`,
+    /// so `x` is reset to `undefined` on each iteration.
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.kind.is_lexical() {
+            return;
+        }
+
+        let reset = decl.kind == VariableDeclarationKind::Let && Self::is_in_loop_body(ctx);
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = VariableDeclarationKind::Var;
+            if reset && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+        }
+    }
+
+    fn exit_variable_declarator(
+        &mut self,
+        declarator: &mut VariableDeclarator<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.uninitialized.is_empty() {
+            return;
+        }
+        declarator.id.bound_names(&mut |ident| {
+            self.uninitialized.remove(&ident.symbol_id.get().unwrap());
+        });
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed.is_empty() {
+            return;
+        }
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            if self.renamed.contains(&symbol_id) {
+                ident.name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+            }
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(symbol_id) = self.get_renamed_symbol(ident, ctx) {
+            ident.name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+        }
+    }
+
+    /// `({ x })` -> `({ x: _x })`, if `x` was renamed
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if prop.shorthand {
+            if let Expression::Identifier(ident) = &prop.value {
+                if self.get_renamed_symbol(ident, ctx).is_some() {
+                    prop.shorthand = false;
+                }
+            }
+        }
+    }
+
+    /// `let { x } = obj` -> `var { x: _x } = obj`, if `x` was renamed
+    fn enter_binding_property(
+        &mut self,
+        prop: &mut BindingProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !prop.shorthand {
+            return;
+        }
+        let ident = match &prop.value.kind {
+            BindingPatternKind::BindingIdentifier(ident) => ident,
+            BindingPatternKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+                BindingPatternKind::BindingIdentifier(ident) => ident,
+                _ => return,
+            },
+            _ => return,
+        };
+        if ident.symbol_id.get().is_some_and(|symbol_id| self.renamed.contains(&symbol_id)) {
+            prop.shorthand = false;
+        }
+    }
+
+    /// `({ x } = obj)` -> `({ x: _x } = obj)`, if `x` was renamed
+    fn enter_assignment_target_property(
+        &mut self,
+        prop: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop_ident) = prop else {
+            return;
+        };
+        if self.get_renamed_symbol(&prop_ident.binding, ctx).is_none() {
+            return;
+        }
+
+        let key = ctx
+            .ast
+            .property_key_identifier_name(prop_ident.binding.span, prop_ident.binding.name.clone());
+        let span = prop_ident.span;
+        // Binding is renamed when the new property is walked
+        let binding = ctx.clone_identifier_reference(&prop_ident.binding, ReferenceFlags::Write);
+        ctx.delete_reference_for_identifier(&prop_ident.binding);
+        let target = AssignmentTarget::from(
+            ctx.ast.simple_assignment_target_from_identifier_reference(binding),
+        );
+        let binding = match prop_ident.init.take() {
+            Some(init) => ctx
+                .ast
+                .assignment_target_maybe_default_assignment_target_with_default(span, target, init),
+            None => AssignmentTargetMaybeDefault::from(target),
+        };
+        *prop = ctx
+            .ast
+            .assignment_target_property_assignment_target_property_property(span, key, binding);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let replacement = match expr {
+            Expression::Identifier(ident) => self.transform_tdz_reference(ident, ctx),
+            Expression::AssignmentExpression(assign) => {
+                self.transform_const_assignment(assign, ctx)
+            }
+            Expression::UpdateExpression(update) => self.transform_const_update(update, ctx),
+            _ => None,
+        };
+        if let Some(replacement) = replacement {
+            *expr = replacement;
+        }
+    }
+}
+
+// Hoisting
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    /// Convert `let` and `const` bindings declared in scope to `var` bindings,
+    /// and move them into scope of the enclosing function.
+    fn hoist_scope(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let symbol_ids = ctx
+            .scopes()
+            .iter_bindings_in(scope_id)
+            .filter(|&symbol_id| {
+                let flags = ctx.symbols().get_flags(symbol_id);
+                flags.contains(SymbolFlags::BlockScopedVariable)
+                    && !flags.intersects(
+                        SymbolFlags::Function | SymbolFlags::CatchVariable | SymbolFlags::Class,
+                    )
+            })
+            .collect::<Vec<_>>();
+        if symbol_ids.is_empty() {
+            return;
+        }
+
+        let var_scope_id = Self::find_var_scope(scope_id, ctx);
+        for symbol_id in symbol_ids {
+            let flags = ctx.symbols().get_flags(symbol_id);
+            if flags.is_const_variable() {
+                self.read_only.insert(symbol_id);
+            }
+            if self.options.tdz {
+                self.uninitialized.insert(symbol_id, scope_id);
+            }
+            *ctx.symbols_mut().get_flags_mut(symbol_id) =
+                SymbolFlags::FunctionScopedVariable | (flags & SymbolFlags::Export);
+            if scope_id != var_scope_id {
+                self.move_binding(symbol_id, scope_id, var_scope_id, ctx);
+            }
+        }
+    }
+
+    /// Move binding into scope of enclosing function.
+    ///
+    /// Binding is renamed if it would clash with another binding in the function,
+    /// or shadow a binding used in it.
+    fn move_binding(
+        &mut self,
+        symbol_id: SymbolId,
+        scope_id: ScopeId,
+        var_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+        ctx.scopes_mut().remove_binding(scope_id, &name);
+        let parent_scope_id = ctx.scopes().get_parent_id(scope_id).unwrap();
+        if ctx.scopes().find_binding(parent_scope_id, &name).is_some()
+            || ctx.scopes().root_unresolved_references().contains_key(name.as_str())
+        {
+            name = ctx.generate_uid_name(&name);
+            ctx.symbols_mut().set_name(symbol_id, name.clone());
+            self.renamed.insert(symbol_id);
+        }
+        ctx.scopes_mut().add_binding(var_scope_id, name, symbol_id);
+        ctx.symbols_mut().set_scope_id(symbol_id, var_scope_id);
+    }
+
+    /// Find scope which `var` declarations in `scope_id` are bound in.
+    fn find_var_scope(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        ctx.scopes()
+            .ancestors(scope_id)
+            .find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var())
+            .unwrap()
+    }
+
+    fn get_renamed_symbol(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<SymbolId> {
+        if self.renamed.is_empty() {
+            return None;
+        }
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()?).symbol_id()?;
+        self.renamed.contains(&symbol_id).then_some(symbol_id)
+    }
+
+    /// Check if a declaration is in the body of a loop, in the same function as the loop.
+    fn is_in_loop_body(ctx: &TraverseCtx<'a>) -> bool {
+        if matches!(ctx.parent(), Ancestor::ForInStatementLeft(_) | Ancestor::ForOfStatementLeft(_))
+        {
+            return false;
+        }
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
+// TDZ and `const` violations
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    /// `x` -> `babelHelpers.tdz("x")`, if `x` is certain to not be initialized yet
+    fn transform_tdz_reference(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if self.uninitialized.is_empty() {
+            return None;
+        }
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()?).symbol_id()?;
+        let &scope_id = self.uninitialized.get(&symbol_id)?;
+
+        // A reference inside a function or class may be evaluated after a binding declared outside
+        // it is initialized
+        for ancestor in ctx.ancestors() {
+            let function_scope_id = match ancestor {
+                Ancestor::FunctionParams(func) => func.scope_id(),
+                Ancestor::FunctionBody(func) => func.scope_id(),
+                Ancestor::ArrowFunctionExpressionParams(arrow) => arrow.scope_id(),
+                Ancestor::ArrowFunctionExpressionBody(arrow) => arrow.scope_id(),
+                Ancestor::ClassBody(class) => class.scope_id(),
+                _ => continue,
+            };
+            let function_scope_id = function_scope_id.get().unwrap();
+            if ctx.scopes().ancestors(function_scope_id).skip(1).any(|id| id == scope_id) {
+                return None;
+            }
+            break;
+        }
+
+        ctx.delete_reference_for_identifier(ident);
+        Some(Self::create_error_call(Helper::Tdz, ident.name.clone(), ident.span, ctx))
+    }
+
+    /// `x = 1` -> `1, babelHelpers.readOnlyError("x")`
+    /// `x ||= 1` -> `x || (1, babelHelpers.readOnlyError("x"))`
+    fn transform_const_assignment(
+        &self,
+        assign: &mut AssignmentExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return None;
+        };
+        if !self.is_read_only(ident, assign.span, ctx) {
+            return None;
+        }
+
+        let left = assign
+            .operator
+            .is_logical()
+            .then(|| ctx.clone_identifier_reference(ident, ReferenceFlags::Read));
+        ctx.delete_reference_for_identifier(ident);
+        let error = Self::create_error_call(Helper::ReadOnlyError, ident.name.clone(), SPAN, ctx);
+        let value = ctx.ast.move_expression(&mut assign.right);
+        let sequence =
+            ctx.ast.expression_sequence(assign.span, ctx.ast.vec_from_iter([value, error]));
+        Some(match (left, assign.operator.to_logical_operator()) {
+            (Some(left), Some(operator)) => ctx.ast.expression_logical(
+                assign.span,
+                ctx.ast.expression_from_identifier_reference(left),
+                operator,
+                sequence,
+            ),
+            _ => sequence,
+        })
+    }
+
+    /// `x++` -> `babelHelpers.readOnlyError("x")`
+    fn transform_const_update(
+        &self,
+        update: &mut UpdateExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return None;
+        };
+        if !self.is_read_only(ident, update.span, ctx) {
+            return None;
+        }
+
+        ctx.delete_reference_for_identifier(ident);
+        Some(Self::create_error_call(Helper::ReadOnlyError, ident.name.clone(), update.span, ctx))
+    }
+
+    /// Check if `ident` refers to a `const` binding, which is being reassigned at `span`.
+    ///
+    /// If `reportConstViolations` option is enabled, an error is reported, and `false` is returned,
+    /// so the reassignment is left as is.
+    fn is_read_only(
+        &self,
+        ident: &IdentifierReference<'a>,
+        span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        if self.read_only.is_empty() {
+            return false;
+        }
+        let Some(reference_id) = ident.reference_id() else { return false };
+        let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+            return false;
+        };
+        if !self.read_only.contains(&symbol_id) {
+            return false;
+        }
+        if self.options.report_const_violations {
+            self.ctx.error(
+                OxcDiagnostic::error(format!("\"{}\" is read-only", ident.name)).with_label(span),
+            );
+            return false;
+        }
+        true
+    }
+
+    /// `babelHelpers.<helper>("x")`
+    fn create_error_call(
+        helper: Helper,
+        name: Atom<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, name)));
+        let mut call = helper_loader::call(helper, arguments, ctx);
+        if let Expression::CallExpression(call) = &mut call {
+            call.span = span;
+        }
+        call
+    }
+}
+
+/// Result of analysing a loop which needs per-iteration bindings.
+struct LoopAnalysis {
+    /// `let` and `const` bindings declared in loop's head
+    head_symbol_ids: Vec<SymbolId>,
+    /// `true` if bindings declared in head of a `for` loop are modified in loop's body
+    writes_head: bool,
+    /// `true` if loop's body contains `yield`
+    has_yield: bool,
+    /// `true` if loop's body contains `await`
+    has_await: bool,
+}
+
+/// Binding declared in head of a loop, and the parameter of `_loop` which replaces it in the body.
+struct LoopBinding<'a> {
+    outer: BoundIdentifier<'a>,
+    inner: BoundIdentifier<'a>,
+}
+
+/// Completion of an iteration of a loop, other than reaching the end of the body.
+#[derive(Clone, PartialEq, Eq)]
+enum Jump<'a> {
+    /// `continue` of the loop
+    Continue,
+    /// `break` of the loop
+    Break,
+    /// `continue label` of an outer loop
+    LabeledContinue(Atom<'a>),
+    /// `break label` of an outer statement
+    LabeledBreak(Atom<'a>),
+}
+
+// Loops
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    fn is_loop(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => true,
+            Statement::LabeledStatement(labeled) => Self::is_loop(&labeled.body),
+            _ => false,
+        }
+    }
+
+    fn loop_body_mut<'s>(stmt: &'s mut Statement<'a>) -> &'s mut Statement<'a> {
+        match stmt {
+            Statement::ForStatement(for_stmt) => &mut for_stmt.body,
+            Statement::ForInStatement(for_in) => &mut for_in.body,
+            Statement::ForOfStatement(for_of) => &mut for_of.body,
+            Statement::WhileStatement(while_stmt) => &mut while_stmt.body,
+            Statement::DoWhileStatement(do_while) => &mut do_while.body,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Check if loop needs per-iteration bindings, because a binding declared in its head
+    /// or body is captured by a closure.
+    ///
+    /// Returns `None` if loop does not need them, or its body cannot be moved into a function.
+    /// The latter is reported as an error.
+    fn analyze_loop(&self, stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> Option<LoopAnalysis> {
+        let mut stmt = stmt;
+        while let Statement::LabeledStatement(labeled) = stmt {
+            stmt = &labeled.body;
+        }
+
+        let (head, body) = match stmt {
+            Statement::ForStatement(for_stmt) => match &for_stmt.init {
+                Some(ForStatementInit::VariableDeclaration(decl)) => {
+                    (Some(&**decl), &for_stmt.body)
+                }
+                _ => (None, &for_stmt.body),
+            },
+            Statement::ForInStatement(for_in) => match &for_in.left {
+                ForStatementLeft::VariableDeclaration(decl) => (Some(&**decl), &for_in.body),
+                _ => (None, &for_in.body),
+            },
+            Statement::ForOfStatement(for_of) => match &for_of.left {
+                ForStatementLeft::VariableDeclaration(decl) => (Some(&**decl), &for_of.body),
+                _ => (None, &for_of.body),
+            },
+            Statement::WhileStatement(while_stmt) => (None, &while_stmt.body),
+            Statement::DoWhileStatement(do_while) => (None, &do_while.body),
+            _ => return None,
+        };
+
+        let mut head_symbol_ids = vec![];
+        if let Some(decl) = head.filter(|decl| decl.kind.is_lexical()) {
+            decl.bound_names(&mut |ident| head_symbol_ids.push(ident.symbol_id.get().unwrap()));
+        }
+
+        let mut analyzer = LoopAnalyzer::new(&head_symbol_ids, ctx.symbols());
+        analyzer.visit_statement(body);
+        if !head_symbol_ids
+            .iter()
+            .chain(&analyzer.declared)
+            .any(|symbol_id| analyzer.captured.contains(symbol_id))
+        {
+            return None;
+        }
+        if let Some(span) = analyzer.unsupported {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Cannot transform loop with closures which contains `super` or `new.target`.",
+                )
+                .with_label(span),
+            );
+            return None;
+        }
+
+        let writes_head = analyzer.writes_head && matches!(stmt, Statement::ForStatement(_));
+        let has_yield = analyzer.has_yield;
+        let has_await = analyzer.has_await;
+        Some(LoopAnalysis { head_symbol_ids, writes_head, has_yield, has_await })
+    }
+
+    /// Move body of loop into a function `_loop`, which is called on each iteration.
+    ///
+    /// ```js
+    /// for (let i = 0; i < 3; i++) { if (i === 1) break; fns.push(() => i); }
+    /// ```
+    /// ->
+    /// ```js
+    /// var _loop = function (i) { if (i === 1) return 0; fns.push(() => i); };
+    /// for (let i = 0; i < 3; i++) { var _ret = _loop(i); if (_ret === 0) break; }
+    /// ```
+    ///
+    /// Returns the declaration of `_loop`, and the loop.
+    fn transform_loop(
+        &mut self,
+        stmt: Statement<'a>,
+        analysis: LoopAnalysis,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> [Statement<'a>; 2] {
+        // Unwrap labels
+        let mut labels = vec![];
+        let mut stmt = stmt;
+        while let Statement::LabeledStatement(labeled) = stmt {
+            let labeled = labeled.unbox();
+            labels.push((labeled.span, labeled.label));
+            stmt = labeled.body;
+        }
+
+        let var_scope_id = Self::find_var_scope(parent_scope_id, ctx);
+        let loop_scope_id = match &stmt {
+            Statement::ForStatement(for_stmt) => for_stmt.scope_id.get(),
+            Statement::ForInStatement(for_in) => for_in.scope_id.get(),
+            Statement::ForOfStatement(for_of) => for_of.scope_id.get(),
+            _ => None,
+        };
+
+        // Create scope for `_loop`. If body is a block, its scope is reused.
+        let body = ctx.ast.move_statement(Self::loop_body_mut(&mut stmt));
+        let (function_scope_id, mut statements) = match body {
+            Statement::BlockStatement(block) => {
+                let scope_id = block.scope_id.get().unwrap();
+                let flags = ctx.scopes().get_new_scope_flags(ScopeFlags::Function, parent_scope_id);
+                *ctx.scopes_mut().get_flags_mut(scope_id) = flags;
+                ScopeReparenter::new(parent_scope_id, ctx).visit_block_statement(&block);
+                (scope_id, block.unbox().body)
+            }
+            body => {
+                let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+                ScopeReparenter::new(scope_id, ctx).visit_statement(&body);
+                (scope_id, ctx.ast.vec1(body))
+            }
+        };
+
+        // Move bindings declared in loop's head into `_loop`, as its parameters.
+        // Loop gets new bindings, which are renamed if their values need to be copied back.
+        let mut bindings = Vec::with_capacity(analysis.head_symbol_ids.len());
+        let mut params = ctx.ast.vec_with_capacity(analysis.head_symbol_ids.len());
+        for symbol_id in analysis.head_symbol_ids {
+            let loop_scope_id = loop_scope_id.unwrap();
+            let flags = ctx.symbols().get_flags(symbol_id);
+            if flags.is_const_variable() {
+                self.read_only.insert(symbol_id);
+            }
+
+            let name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+            ctx.scopes_mut().remove_binding(loop_scope_id, &name);
+            // Body may declare a binding with same name, which shadows the parameter
+            let mut inner_name = name.clone();
+            if ctx.scopes().get_binding(function_scope_id, &name).is_some() {
+                inner_name = ctx.generate_uid_name(&name);
+                ctx.symbols_mut().set_name(symbol_id, inner_name.clone());
+            }
+            ctx.scopes_mut().add_binding(function_scope_id, inner_name.clone(), symbol_id);
+            ctx.symbols_mut().set_scope_id(symbol_id, function_scope_id);
+            *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+
+            let outer_name = if analysis.writes_head { ctx.generate_uid_name(&name) } else { name };
+            let span = ctx.symbols().get_span(symbol_id);
+            let outer_symbol_id = ctx.symbols_mut().create_symbol(
+                span,
+                outer_name.clone(),
+                flags,
+                loop_scope_id,
+                NodeId::DUMMY,
+            );
+            ctx.scopes_mut().add_binding(loop_scope_id, outer_name.clone(), outer_symbol_id);
+
+            let inner = BoundIdentifier { name: ctx.ast.atom(&inner_name), symbol_id };
+            let outer =
+                BoundIdentifier { name: ctx.ast.atom(&outer_name), symbol_id: outer_symbol_id };
+            params.push(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                ctx.ast.binding_pattern(
+                    ctx.ast.binding_pattern_kind_from_binding_identifier(
+                        inner.create_binding_identifier(),
+                    ),
+                    NONE,
+                    false,
+                ),
+                None,
+                false,
+                false,
+            ));
+            bindings.push(LoopBinding { outer, inner });
+        }
+
+        // Point bindings and references in loop's head to the loop's new bindings
+        if !bindings.is_empty() {
+            let mut repointer =
+                HeadRepointer { bindings: &bindings, renamed: analysis.writes_head, ctx };
+            match &mut stmt {
+                Statement::ForStatement(for_stmt) => {
+                    if let Some(init) = &mut for_stmt.init {
+                        repointer.visit_for_statement_init(init);
+                    }
+                    if let Some(test) = &mut for_stmt.test {
+                        repointer.visit_expression(test);
+                    }
+                    if let Some(update) = &mut for_stmt.update {
+                        repointer.visit_expression(update);
+                    }
+                }
+                Statement::ForInStatement(for_in) => {
+                    repointer.visit_for_statement_left(&mut for_in.left);
+                }
+                Statement::ForOfStatement(for_of) => {
+                    repointer.visit_for_statement_left(&mut for_of.left);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        // Convert `break`, `continue` and `return` in body, and hoist `var` declarations out of it
+        let label_names = labels.iter().map(|(_, label)| label.name.clone()).collect::<Vec<_>>();
+        let mut transformer = LoopBodyTransformer::new(
+            &bindings,
+            analysis.writes_head,
+            &label_names,
+            function_scope_id,
+            var_scope_id,
+            self.ctx,
+            ctx,
+        );
+        transformer.visit_statements(&mut statements);
+        let jumps = transformer.jumps;
+        let has_return = transformer.has_return;
+
+        // Copy values of bindings back to the loop at end of the body
+        if analysis.writes_head {
+            for binding in &bindings {
+                let write_back = LoopBodyTransformer::create_write_back(binding, ctx);
+                statements.push(ctx.ast.statement_expression(SPAN, write_back));
+            }
+        }
+
+        // `var _loop = function (i) { ... };`
+        let loop_fn = BoundIdentifier::new_uid(
+            "loop",
+            var_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let func = ctx.ast.alloc_function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            analysis.has_yield,
+            analysis.has_await,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+        );
+        func.scope_id.set(Some(function_scope_id));
+        let declaration =
+            Self::create_var_declaration(&loop_fn, Expression::FunctionExpression(func), ctx);
+
+        // `_loop(i)` / `yield* _loop(i)` / `await _loop(i)`
+        let mut arguments = ctx.ast.vec_with_capacity(bindings.len());
+        for binding in &bindings {
+            arguments
+                .push(Argument::from(ctx.ast.expression_from_identifier_reference(
+                    binding.outer.create_read_reference(ctx),
+                )));
+        }
+        let callee =
+            ctx.ast.expression_from_identifier_reference(loop_fn.create_read_reference(ctx));
+        let mut call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        if analysis.has_yield {
+            call = ctx.ast.expression_yield(SPAN, true, Some(call));
+        } else if analysis.has_await {
+            call = ctx.ast.expression_await(SPAN, call);
+        }
+
+        // New body of loop
+        let body_scope_id =
+            ctx.create_child_scope(loop_scope_id.unwrap_or(parent_scope_id), ScopeFlags::empty());
+        let mut body_stmts = ctx.ast.vec();
+        if jumps.is_empty() && !has_return {
+            // `_loop(i);`
+            body_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        } else {
+            // `var _ret = _loop(i);`
+            let ret = BoundIdentifier::new_uid(
+                "ret",
+                var_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+                ctx,
+            );
+            body_stmts.push(Self::create_var_declaration(&ret, call, ctx));
+
+            // `if (_ret === 0) break;`, `if (_ret === "continue|outer") continue outer;`
+            for jump in jumps {
+                let (value, stmt) = match jump {
+                    Jump::Break => (
+                        ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal),
+                        ctx.ast.statement_break(SPAN, None),
+                    ),
+                    Jump::LabeledBreak(label) => (
+                        ctx.ast.expression_string_literal(
+                            SPAN,
+                            ctx.ast.atom(&format!("break|{label}")),
+                        ),
+                        ctx.ast.statement_break(SPAN, Some(ctx.ast.label_identifier(SPAN, label))),
+                    ),
+                    Jump::LabeledContinue(label) => (
+                        ctx.ast.expression_string_literal(
+                            SPAN,
+                            ctx.ast.atom(&format!("continue|{label}")),
+                        ),
+                        ctx.ast
+                            .statement_continue(SPAN, Some(ctx.ast.label_identifier(SPAN, label))),
+                    ),
+                    Jump::Continue => unreachable!(),
+                };
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ctx.ast.expression_from_identifier_reference(ret.create_read_reference(ctx)),
+                    BinaryOperator::StrictEquality,
+                    value,
+                );
+                body_stmts.push(ctx.ast.statement_if(SPAN, test, stmt, None));
+            }
+
+            // `if (_ret) return _ret.v;`
+            if has_return {
+                let test =
+                    ctx.ast.expression_from_identifier_reference(ret.create_read_reference(ctx));
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ctx.ast.expression_from_identifier_reference(ret.create_read_reference(ctx)),
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                let return_stmt = ctx.ast.statement_return(SPAN, Some(value));
+                body_stmts.push(ctx.ast.statement_if(SPAN, test, return_stmt, None));
+            }
+        }
+        let body = BlockStatement::new_with_scope_id(SPAN, body_stmts, body_scope_id);
+        *Self::loop_body_mut(&mut stmt) = Statement::BlockStatement(ctx.ast.alloc(body));
+
+        // Re-apply labels
+        for (span, label) in labels.into_iter().rev() {
+            stmt = ctx.ast.statement_labeled(span, label, stmt);
+        }
+
+        [declaration, stmt]
+    }
+
+    /// `var <binding> = <init>;`
+    fn create_var_declaration(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let id = ctx.ast.binding_pattern(
+            ctx.ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier()),
+            NONE,
+            false,
+        );
+        let declarator =
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+}
+
+/// Visitor which finds bindings in the body of a loop which are captured by closures.
+struct LoopAnalyzer<'s> {
+    head_symbol_ids: &'s [SymbolId],
+    symbols: &'s SymbolTable,
+    /// `let` and `const` bindings declared in loop's body, outside of closures
+    declared: Vec<SymbolId>,
+    /// Bindings referenced inside closures
+    captured: FxHashSet<SymbolId>,
+    writes_head: bool,
+    has_yield: bool,
+    has_await: bool,
+    /// Span of `super` or `new.target` in body, which prevent moving it into a function
+    unsupported: Option<Span>,
+    /// Depth of non-arrow functions and classes
+    function_depth: usize,
+    /// Depth of all functions and classes
+    closure_depth: usize,
+}
+
+impl<'s> LoopAnalyzer<'s> {
+    fn new(head_symbol_ids: &'s [SymbolId], symbols: &'s SymbolTable) -> Self {
+        Self {
+            head_symbol_ids,
+            symbols,
+            declared: vec![],
+            captured: FxHashSet::default(),
+            writes_head: false,
+            has_yield: false,
+            has_await: false,
+            unsupported: None,
+            function_depth: 0,
+            closure_depth: 0,
+        }
+    }
+}
+
+impl<'s, 'a> Visit<'a> for LoopAnalyzer<'s> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id() else { return };
+        let reference = self.symbols.get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        if self.closure_depth > 0 {
+            self.captured.insert(symbol_id);
+        }
+        if reference.is_write() && self.head_symbol_ids.contains(&symbol_id) {
+            self.writes_head = true;
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if self.closure_depth == 0 && decl.kind.is_lexical() {
+            decl.bound_names(&mut |ident| self.declared.push(ident.symbol_id.get().unwrap()));
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.closure_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.closure_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.closure_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.closure_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        self.closure_depth += 1;
+        walk::walk_class(self, class);
+        self.function_depth -= 1;
+        self.closure_depth -= 1;
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.has_yield = true;
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.closure_depth == 0 {
+            self.has_await = true;
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await && self.closure_depth == 0 {
+            self.has_await = true;
+        }
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_super(&mut self, it: &Super) {
+        if self.function_depth == 0 {
+            self.unsupported.get_or_insert(it.span);
+        }
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if self.function_depth == 0 && it.meta.name == "new" {
+            self.unsupported.get_or_insert(it.span);
+        }
+    }
+}
+
+/// Visitor which points bindings and references in the head of a loop to the loop's new bindings.
+struct HeadRepointer<'b, 'c, 'a> {
+    bindings: &'b [LoopBinding<'a>],
+    /// `true` if the new bindings have different names
+    renamed: bool,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'b, 'c, 'a> HeadRepointer<'b, 'c, 'a> {
+    fn find(&self, symbol_id: Option<SymbolId>) -> Option<&'b LoopBinding<'a>> {
+        let symbol_id = symbol_id?;
+        self.bindings.iter().find(|binding| binding.inner.symbol_id == symbol_id)
+    }
+}
+
+impl<'b, 'c, 'a> VisitMut<'a> for HeadRepointer<'b, 'c, 'a> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(binding) = self.find(ident.symbol_id.get()) {
+            ident.name = binding.outer.name.clone();
+            ident.symbol_id.set(Some(binding.outer.symbol_id));
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id() else { return };
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        let flags = reference.flags();
+        if let Some(binding) = self.find(reference.symbol_id()) {
+            self.ctx.delete_reference_for_identifier(ident);
+            *ident = self.ctx.create_bound_reference_id(
+                ident.span,
+                binding.outer.name.clone(),
+                binding.outer.symbol_id,
+                flags,
+            );
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if self.renamed {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        if self.renamed {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_binding_property(self, prop);
+    }
+}
+
+/// Visitor which converts the body of a loop, so it can be moved into `_loop` function.
+///
+/// * `continue` -> `return;`
+/// * `break` -> `return 0;`
+/// * `break label` / `continue label` -> `return "break|label";` / `return "continue|label";`
+/// * `return x` -> `return { v: x };`
+/// * `var x = 1` -> `x = 1`, with `x` declared outside `_loop`.
+/// * `this` -> `_this`, and `arguments` -> `_arguments`.
+struct LoopBodyTransformer<'b, 'c, 'ctx, 'a> {
+    bindings: &'b [LoopBinding<'a>],
+    write_back: bool,
+    /// Labels of the loop
+    labels: &'b [Atom<'a>],
+    /// Labels of statements inside the body
+    inner_labels: Vec<Atom<'a>>,
+    scope_stack: Vec<ScopeId>,
+    var_scope_id: ScopeId,
+    /// Depth of loops inside the body
+    loop_depth: usize,
+    /// Depth of `switch` statements inside the body
+    switch_depth: usize,
+    /// Depth of arrow functions inside the body
+    arrow_depth: usize,
+    jumps: Vec<Jump<'a>>,
+    has_return: bool,
+    this: Option<BoundIdentifier<'a>>,
+    arguments: Option<BoundIdentifier<'a>>,
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'b, 'c, 'ctx, 'a> LoopBodyTransformer<'b, 'c, 'ctx, 'a> {
+    fn new(
+        bindings: &'b [LoopBinding<'a>],
+        write_back: bool,
+        labels: &'b [Atom<'a>],
+        function_scope_id: ScopeId,
+        var_scope_id: ScopeId,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            bindings,
+            write_back,
+            labels,
+            inner_labels: vec![],
+            scope_stack: vec![function_scope_id],
+            var_scope_id,
+            loop_depth: 0,
+            switch_depth: 0,
+            arrow_depth: 0,
+            jumps: vec![],
+            has_return: false,
+            this: None,
+            arguments: None,
+            transform_ctx,
+            ctx,
+        }
+    }
+
+    /// `_i = i`
+    fn create_write_back(binding: &LoopBinding<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.outer.create_write_reference(ctx),
+            )),
+            ctx.ast.expression_from_identifier_reference(binding.inner.create_read_reference(ctx)),
+        )
+    }
+
+    /// Get completion of `break` / `continue` statement, if it exits the loop's body.
+    fn get_jump(&self, label: Option<&LabelIdentifier<'a>>, is_continue: bool) -> Option<Jump<'a>> {
+        match label {
+            None if is_continue => (self.loop_depth == 0).then_some(Jump::Continue),
+            None => (self.loop_depth == 0 && self.switch_depth == 0).then_some(Jump::Break),
+            Some(label) if self.inner_labels.contains(&label.name) => None,
+            Some(label) if self.labels.contains(&label.name) => {
+                Some(if is_continue { Jump::Continue } else { Jump::Break })
+            }
+            Some(label) if is_continue => Some(Jump::LabeledContinue(label.name.clone())),
+            Some(label) => Some(Jump::LabeledBreak(label.name.clone())),
+        }
+    }
+
+    /// Create `return` statement for completion, and record the completion.
+    fn create_jump(&mut self, jump: Jump<'a>, span: Span) -> Statement<'a> {
+        let ctx = &mut *self.ctx;
+        let value = match &jump {
+            Jump::Continue => None,
+            Jump::Break => {
+                Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal))
+            }
+            Jump::LabeledBreak(label) => Some(
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&format!("break|{label}"))),
+            ),
+            Jump::LabeledContinue(label) => Some(
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&format!("continue|{label}"))),
+            ),
+        };
+        let return_stmt = ctx.ast.statement_return(span, value);
+
+        if jump != Jump::Continue {
+            if !self.jumps.contains(&jump) {
+                self.jumps.push(jump);
+            }
+            return return_stmt;
+        }
+        if !self.write_back {
+            return return_stmt;
+        }
+
+        // `{ _i = i; return; }`
+        let mut stmts = ctx.ast.vec_with_capacity(self.bindings.len() + 1);
+        for binding in self.bindings {
+            let write_back = Self::create_write_back(binding, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, write_back));
+        }
+        stmts.push(return_stmt);
+        let scope_id =
+            ctx.create_child_scope(*self.scope_stack.last().unwrap(), ScopeFlags::empty());
+        Statement::BlockStatement(
+            ctx.ast.alloc(BlockStatement::new_with_scope_id(span, stmts, scope_id)),
+        )
+    }
+
+    /// Declare binding with `var` outside `_loop`, and return a write reference to it.
+    fn declare_binding(&mut self, ident: &BindingIdentifier<'a>) -> IdentifierReference<'a> {
+        let symbol_id = ident.symbol_id.get().unwrap();
+        self.transform_ctx.var_declarations.insert(ident.name.clone(), symbol_id, None, self.ctx);
+        self.ctx.create_bound_reference_id(
+            ident.span,
+            ident.name.clone(),
+            symbol_id,
+            ReferenceFlags::Write,
+        )
+    }
+
+    /// Declare bindings in pattern outside `_loop`, and convert it to an assignment target.
+    fn convert_pattern(&mut self, pattern: BindingPattern<'a>) -> AssignmentTarget<'a> {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                let reference = self.declare_binding(&ident);
+                AssignmentTarget::from(
+                    self.ctx.ast.simple_assignment_target_from_identifier_reference(reference),
+                )
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let mut properties = self.ctx.ast.vec_with_capacity(pattern.properties.len());
+                for prop in pattern.properties {
+                    let binding = self.convert_pattern_maybe_default(prop.value);
+                    properties.push(
+                        self.ctx
+                            .ast
+                            .assignment_target_property_assignment_target_property_property(
+                                prop.span, prop.key, binding,
+                            ),
+                    );
+                }
+                let rest = pattern.rest.map(|rest| {
+                    let rest = rest.unbox();
+                    let target = self.convert_pattern(rest.argument);
+                    self.ctx.ast.assignment_target_rest(rest.span, target)
+                });
+                AssignmentTarget::ObjectAssignmentTarget(
+                    self.ctx.ast.alloc_object_assignment_target(pattern.span, properties, rest),
+                )
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let mut elements = self.ctx.ast.vec_with_capacity(pattern.elements.len());
+                for element in pattern.elements {
+                    elements
+                        .push(element.map(|element| self.convert_pattern_maybe_default(element)));
+                }
+                let rest = pattern.rest.map(|rest| {
+                    let rest = rest.unbox();
+                    let target = self.convert_pattern(rest.argument);
+                    self.ctx.ast.assignment_target_rest(rest.span, target)
+                });
+                AssignmentTarget::ArrayAssignmentTarget(self.ctx.ast.alloc_array_assignment_target(
+                    pattern.span,
+                    elements,
+                    rest,
+                    None,
+                ))
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                // Only occurs nested in another pattern, which is handled by `convert_pattern_maybe_default`
+                self.convert_pattern(pattern.unbox().left)
+            }
+        }
+    }
+
+    fn convert_pattern_maybe_default(
+        &mut self,
+        pattern: BindingPattern<'a>,
+    ) -> AssignmentTargetMaybeDefault<'a> {
+        match pattern.kind {
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let binding = self.convert_pattern(pattern.left);
+                self.ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+                    pattern.span,
+                    binding,
+                    pattern.right,
+                )
+            }
+            kind => {
+                let pattern = self.ctx.ast.binding_pattern(kind, NONE, false);
+                AssignmentTargetMaybeDefault::from(self.convert_pattern(pattern))
+            }
+        }
+    }
+
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    fn convert_var_declaration(&mut self, decl: VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut assignments = self.ctx.ast.vec();
+        for declarator in decl.declarations {
+            match declarator.init {
+                Some(init) => {
+                    let target = self.convert_pattern(declarator.id);
+                    assignments.push(self.ctx.ast.expression_assignment(
+                        declarator.span,
+                        AssignmentOperator::Assign,
+                        target,
+                        init,
+                    ));
+                }
+                None => {
+                    declarator.id.bound_names(&mut |ident| {
+                        let symbol_id = ident.symbol_id.get().unwrap();
+                        self.transform_ctx.var_declarations.insert(
+                            ident.name.clone(),
+                            symbol_id,
+                            None,
+                            self.ctx,
+                        );
+                    });
+                }
+            }
+        }
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, assignments)),
+        }
+    }
+
+    /// `for (var x in obj)` -> `for (x in obj)`
+    fn convert_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            if decl.kind.is_var() {
+                let declarator = self.ctx.ast.move_vec(&mut decl.declarations).remove(0);
+                *left = ForStatementLeft::from(self.convert_pattern(declarator.id));
+            }
+        }
+    }
+}
+
+impl<'b, 'c, 'ctx, 'a> VisitMut<'a> for LoopBodyTransformer<'b, 'c, 'ctx, 'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_stack.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        walk_mut::walk_statements(self, stmts);
+        // Remove `var` declarations without initializers
+        stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        // Completions and `var` declarations inside arrow functions belong to the arrow function
+        if self.arrow_depth > 0 {
+            walk_mut::walk_statement(self, stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::BreakStatement(break_stmt) => {
+                if let Some(jump) = self.get_jump(break_stmt.label.as_ref(), false) {
+                    let span = break_stmt.span;
+                    *stmt = self.create_jump(jump, span);
+                }
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                if let Some(jump) = self.get_jump(continue_stmt.label.as_ref(), true) {
+                    let span = continue_stmt.span;
+                    *stmt = self.create_jump(jump, span);
+                }
+            }
+            Statement::ReturnStatement(return_stmt) => {
+                // `return x` -> `return { v: x }`
+                walk_mut::walk_return_statement(self, return_stmt);
+                self.has_return = true;
+                let value =
+                    return_stmt.argument.take().unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+                let property = self.ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    self.ctx.ast.property_key_identifier_name(SPAN, "v"),
+                    value,
+                    None,
+                    false,
+                    false,
+                    false,
+                );
+                return_stmt.argument =
+                    Some(self.ctx.ast.expression_object(SPAN, self.ctx.ast.vec1(property), None));
+            }
+            Statement::VariableDeclaration(decl) if decl.kind.is_var() => {
+                let span = decl.span;
+                let Statement::VariableDeclaration(decl) = self.ctx.ast.move_statement(stmt) else {
+                    unreachable!()
+                };
+                *stmt = match self.convert_var_declaration(decl.unbox()) {
+                    Some(expr) => self.ctx.ast.statement_expression(span, expr),
+                    None => self.ctx.ast.statement_empty(span),
+                };
+                walk_mut::walk_statement(self, stmt);
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.inner_labels.push(labeled.label.name.clone());
+                walk_mut::walk_labeled_statement(self, labeled);
+                self.inner_labels.pop();
+            }
+            Statement::SwitchStatement(switch) => {
+                self.switch_depth += 1;
+                walk_mut::walk_switch_statement(self, switch);
+                self.switch_depth -= 1;
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if self.arrow_depth == 0
+            && matches!(&stmt.init, Some(ForStatementInit::VariableDeclaration(decl)) if decl.kind.is_var())
+        {
+            let Some(ForStatementInit::VariableDeclaration(decl)) = stmt.init.take() else {
+                unreachable!()
+            };
+            stmt.init = self.convert_var_declaration(decl.unbox()).map(ForStatementInit::from);
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        if self.arrow_depth == 0 {
+            self.convert_for_statement_left(&mut stmt.left);
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if self.arrow_depth == 0 {
+            self.convert_for_statement_left(&mut stmt.left);
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    /// `this` -> `_this`
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            let span = this.span;
+            if self.this.is_none() {
+                let binding = BoundIdentifier::new_uid(
+                    "this",
+                    self.var_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                    self.ctx,
+                );
+                let init = self.ctx.ast.expression_this(SPAN);
+                self.transform_ctx.var_declarations.insert(
+                    binding.name.clone(),
+                    binding.symbol_id,
+                    Some(init),
+                    self.ctx,
+                );
+                self.this = Some(binding);
+            }
+            let this = self.this.as_ref().unwrap();
+            *expr = self.ctx.ast.expression_from_identifier_reference(
+                this.create_spanned_read_reference(span, self.ctx),
+            );
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    /// `arguments` -> `_arguments`
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name != "arguments"
+            || !self.ctx.scopes().get_flags(self.var_scope_id).is_function()
+        {
+            return;
+        }
+        let Some(reference_id) = ident.reference_id() else { return };
+        if self.ctx.symbols().get_reference(reference_id).symbol_id().is_some() {
+            return;
+        }
+        if self.arguments.is_none() {
+            let binding = BoundIdentifier::new_uid(
+                "arguments",
+                self.var_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+                self.ctx,
+            );
+            let arguments = self.ctx.create_unbound_reference_id(
+                SPAN,
+                self.ctx.ast.atom("arguments"),
+                ReferenceFlags::Read,
+            );
+            let init = self.ctx.ast.expression_from_identifier_reference(arguments);
+            self.transform_ctx.var_declarations.insert(
+                binding.name.clone(),
+                binding.symbol_id,
+                Some(init),
+                self.ctx,
+            );
+            self.arguments = Some(binding);
+        }
+        let arguments = self.arguments.as_ref().unwrap();
+        let arguments = arguments.create_spanned_read_reference(ident.span, self.ctx);
+        self.ctx.delete_reference_for_identifier(ident);
+        *ident = arguments;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    /// `this`, `arguments` and completions inside functions belong to the function
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    /// Only `extends` clause of a class is evaluated in the enclosing function's `this` context
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
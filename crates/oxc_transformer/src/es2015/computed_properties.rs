@@ -0,0 +1,185 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms computed property keys in object literals (`{ [k]: v }`).
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   a: 1,
+//!   ["x" + foo]: "heh",
+//!   b: 2,
+//!   get [bar]() { return 3; },
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = Object.defineProperty(
+//!   babelHelpers.defineProperty(
+//!     babelHelpers.defineProperty({ a: 1 }, "x" + foo, "heh"),
+//!     "b",
+//!     2,
+//!   ),
+//!   bar,
+//!   { get: function() { return 3; }, enumerable: true, configurable: true },
+//! );
+//! ```
+//!
+//! Properties before the first computed key remain in the object literal.
+//! All properties from the first computed key onwards are defined one by one, in order,
+//! so each key and value is evaluated exactly once, in the same order as the original object literal.
+//! Each definition returns the object, so the definitions are nested, with the innermost first.
+//!
+//! Spread properties after a computed key are copied with `babelHelpers.objectSpread2`.
+//!
+//! ## Missing features
+//!
+//! * Methods containing `super` cannot be moved out of the object literal.
+//! * `__proto__: value` after a computed key defines an own property instead of setting the prototype.
+//! * `loose` option.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babel.dev/docs/babel-plugin-transform-computed-properties).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-computed-properties>
+//! * Object initializer specification: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::reference::ReferenceFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::common::helper_loader::{self, Helper};
+
+pub struct ComputedProperties;
+
+impl ComputedProperties {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for ComputedProperties {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj) = expr else { return };
+        let Some(first_computed) = obj.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+
+        let span = obj.span;
+        let rest = obj.properties.split_off(first_computed);
+        let mut object = ctx.ast.move_expression(expr);
+        for prop in rest {
+            object = self.define_property(object, prop, ctx);
+        }
+        if let Expression::CallExpression(call) = &mut object {
+            call.span = span;
+        }
+        *expr = object;
+    }
+}
+
+impl<'a> ComputedProperties {
+    /// Define property on `object`. Returns an expression which evaluates to `object`.
+    ///
+    /// * `{ [k]: v }` -> `babelHelpers.defineProperty(object, k, v)`
+    /// * `{ get [k]() {} }` -> `Object.defineProperty(object, k, { get: function() {}, enumerable: true, configurable: true })`
+    /// * `{ ...x }` -> `babelHelpers.objectSpread2(object, x)`
+    #[expect(clippy::unused_self)]
+    fn define_property(
+        &self,
+        object: Expression<'a>,
+        prop: ObjectPropertyKind<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let prop = match prop {
+            ObjectPropertyKind::ObjectProperty(prop) => prop.unbox(),
+            ObjectPropertyKind::SpreadProperty(spread) => {
+                let spread = spread.unbox();
+                let arguments = ctx
+                    .ast
+                    .vec_from_iter([Argument::from(object), Argument::from(spread.argument)]);
+                return helper_loader::call(Helper::ObjectSpread2, arguments, ctx);
+            }
+        };
+
+        let key = match prop.key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name.clone())
+            }
+            key => key.into_expression(),
+        };
+
+        if prop.kind == PropertyKind::Init {
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(object),
+                Argument::from(key),
+                Argument::from(prop.value),
+            ]);
+            return helper_loader::call(Helper::DefineProperty, arguments, ctx);
+        }
+
+        // `{ get: function() {}, enumerable: true, configurable: true }`
+        let accessor_name = if prop.kind == PropertyKind::Get { "get" } else { "set" };
+        let descriptor = ctx.ast.expression_object(
+            SPAN,
+            ctx.ast.vec_from_iter([
+                Self::create_descriptor_property(accessor_name, prop.value, ctx),
+                Self::create_descriptor_property(
+                    "enumerable",
+                    ctx.ast.expression_boolean_literal(SPAN, true),
+                    ctx,
+                ),
+                Self::create_descriptor_property(
+                    "configurable",
+                    ctx.ast.expression_boolean_literal(SPAN, true),
+                    ctx,
+                ),
+            ]),
+            None,
+        );
+
+        // `Object.defineProperty(object, key, descriptor)`
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "Object");
+        let ident =
+            ctx.create_reference_id(SPAN, Atom::from("Object"), symbol_id, ReferenceFlags::Read);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(ident),
+            ctx.ast.identifier_name(SPAN, "defineProperty"),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(object),
+            Argument::from(key),
+            Argument::from(descriptor),
+        ]);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `name: value`
+    fn create_descriptor_property(
+        name: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_identifier_name(SPAN, name),
+            value,
+            None,
+            false,
+            false,
+            false,
+        )
+    }
+}
@@ -0,0 +1,998 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms array and object destructuring in variable declarations,
+//! assignments, function parameters, catch clauses and `for-in` / `for-of` heads.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: { c } = {}, ...rest } = obj;
+//! var [x = 1, , y] = arr;
+//! [a, b] = [b, a];
+//! function f({ a }, [b] = []) {}
+//! try {} catch ({ message }) {}
+//! for (const [k, v] of map) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = obj.a,
+//!   _obj$b = obj.b,
+//!   _obj$b2 = _obj$b === void 0 ? {} : _obj$b,
+//!   c = _obj$b2.c,
+//!   rest = babelHelpers.objectWithoutProperties(obj, ["a", "b"]);
+//! var _arr = babelHelpers.slicedToArray(arr, 3),
+//!   _arr$ = _arr[0],
+//!   x = _arr$ === void 0 ? 1 : _arr$,
+//!   y = _arr[2];
+//! var _ref, _ref2;
+//! _ref = [b, a], _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1];
+//! function f(_ref3, _ref4 = []) {
+//!   var a = _ref3.a, _ref5 = babelHelpers.slicedToArray(_ref4, 1), b = _ref5[0];
+//! }
+//! try {} catch (_ref6) {
+//!   let message = _ref6.message;
+//! }
+//! for (const _ref7 of map) {
+//!   const _ref8 = babelHelpers.slicedToArray(_ref7, 2), k = _ref8[0], v = _ref8[1];
+//! }
+//! ```
+//!
+//! Each value is evaluated exactly once. Values which are referenced more than once
+//! (objects which have more than one property destructured, values with defaults)
+//! are stored in temp vars, unless they are unmodified bindings.
+//!
+//! Default values are only evaluated if the value is `undefined`.
+//!
+//! Arrays are converted with `babelHelpers.slicedToArray`, which consumes only as many items
+//! from the iterator as the pattern has elements (including holes), or `babelHelpers.toArray`
+//! if the pattern has a rest element.
+//!
+//! ## Missing features
+//!
+//! * Parameter defaults which reference destructured parameters (`function f({ a }, b = a) {}`).
+//! * `loose` and `useBuiltIns` options.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment specification: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+//! * Destructuring binding patterns specification: <https://tc39.es/ecma262/#sec-destructuring-binding-patterns>
+
+use std::mem;
+
+use oxc_allocator::{CloneIn, Vec as ArenaVec};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder, NONE};
+use oxc_span::{CompactStr, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+    TransformCtx,
+};
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Destructuring<'a, 'ctx> {
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `for (var [a] of x)` is handled in `exit_for_of_statement` / `exit_for_in_statement`.
+        // `export var [a] = x` is handled in `exit_statements`, as exports need to be split out.
+        if matches!(
+            ctx.parent(),
+            Ancestor::ForInStatementLeft(_)
+                | Ancestor::ForOfStatementLeft(_)
+                | Ancestor::ExportNamedDeclarationDeclaration(_)
+        ) {
+            return;
+        }
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { return };
+        if assign.operator == AssignmentOperator::Assign
+            && assign.left.is_assignment_target_pattern()
+        {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(body) = func.body.as_mut() else { return };
+        let scope_id = func.scope_id.get().unwrap();
+        self.transform_params(&mut func.params, body, scope_id, ctx);
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // If statements are added to body of `([a]) => a`, it's converted to `(_ref) => { ...; return a; }`
+        // by `TransformerImpl::exit_arrow_function_expression`.
+        let scope_id = arrow.scope_id.get().unwrap();
+        self.transform_params(&mut arrow.params, &mut arrow.body, scope_id, ctx);
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let left = match ctx.parent() {
+            Ancestor::ForOfStatementBody(for_of) => for_of.left(),
+            Ancestor::ForInStatementBody(for_in) => for_in.left(),
+            _ => return,
+        };
+        if !Self::needs_block_body(left, stmt, ctx) {
+            return;
+        }
+
+        // `for (const [a] of x) f(a)` -> `for (const [a] of x) { f(a) }`.
+        // Lowered pattern is inserted into the block in `exit_for_of_statement`.
+        let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+        let body = ctx.ast.move_statement(stmt);
+        let block = BlockStatement::new_with_scope_id(SPAN, ctx.ast.vec1(body), scope_id);
+        *stmt = Statement::BlockStatement(ctx.ast.alloc(block));
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id.get().unwrap();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id.get().unwrap();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if !param.pattern.kind.is_destructuring_pattern() {
+            return;
+        }
+
+        // `catch ({ a }) {}` -> `catch (_ref) { let a = _ref.a; }`.
+        // Bindings move from catch clause's scope to body's scope.
+        let catch_scope_id = clause.scope_id.get().unwrap();
+        let body_scope_id = clause.body.scope_id.get().unwrap();
+        let mut symbol_ids = vec![];
+        param.pattern.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id.get().unwrap()));
+        for symbol_id in symbol_ids {
+            let name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+            ctx.scopes_mut().remove_binding(catch_scope_id, &name);
+            ctx.scopes_mut().add_binding(body_scope_id, name, symbol_id);
+            ctx.symbols_mut().set_scope_id(symbol_id, body_scope_id);
+            *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::BlockScopedVariable;
+        }
+
+        let binding = BoundIdentifier::new_uid(
+            "ref",
+            catch_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let pattern = mem::replace(&mut param.pattern, Self::create_binding_pattern(&binding, ctx));
+
+        let mut lowering = Lowering::new(Some(VariableDeclarationKind::Let), body_scope_id, ctx);
+        let value =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        self.lower(Pattern::from_binding_pattern(pattern), value, &mut lowering, ctx);
+        let stmt =
+            Self::create_declaration(VariableDeclarationKind::Let, lowering.declarators, ctx);
+        clause.body.body.insert(0, stmt);
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_exported_pattern = |stmt: &Statement<'a>| match stmt {
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => Self::has_pattern(decl),
+                _ => false,
+            },
+            _ => false,
+        };
+        if !stmts.iter().any(is_exported_pattern) {
+            return;
+        }
+
+        // `export var { a, b } = obj;` -> `var a = obj.a, b = obj.b; export { a, b };`
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() + 1);
+        for stmt in stmts.drain(..) {
+            if !is_exported_pattern(&stmt) {
+                new_stmts.push(stmt);
+                continue;
+            }
+            let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+            let export = export.unbox();
+            let Some(Declaration::VariableDeclaration(mut decl)) = export.declaration else {
+                unreachable!()
+            };
+
+            let mut bindings = vec![];
+            decl.bound_names(&mut |ident| {
+                bindings.push(BoundIdentifier {
+                    name: ident.name.clone(),
+                    symbol_id: ident.symbol_id.get().unwrap(),
+                });
+            });
+            let specifiers = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+                ctx.ast.export_specifier(
+                    SPAN,
+                    ModuleExportName::IdentifierReference(binding.create_read_reference(ctx)),
+                    ctx.ast.module_export_name_identifier_name(SPAN, binding.name.clone()),
+                    ImportOrExportKind::Value,
+                )
+            }));
+
+            self.transform_variable_declaration(&mut decl, ctx);
+            new_stmts.push(Statement::VariableDeclaration(decl));
+            new_stmts.push(Statement::ExportNamedDeclaration(
+                ctx.ast.alloc_export_named_declaration(
+                    export.span,
+                    None,
+                    specifiers,
+                    None,
+                    ImportOrExportKind::Value,
+                    NONE,
+                ),
+            ));
+        }
+        *stmts = new_stmts;
+    }
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    fn has_pattern(decl: &VariableDeclaration<'a>) -> bool {
+        decl.declarations.iter().any(|declarator| !declarator.id.kind.is_binding_identifier())
+    }
+
+    /// `var [a, b] = x;` -> `var _x = babelHelpers.slicedToArray(x, 2), a = _x[0], b = _x[1];`
+    fn transform_variable_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !Self::has_pattern(decl) {
+            return;
+        }
+
+        let mut lowering = Lowering::new(Some(decl.kind), ctx.current_scope_id(), ctx);
+        for declarator in ctx.ast.move_vec(&mut decl.declarations) {
+            if declarator.id.kind.is_binding_identifier() {
+                lowering.declarators.push(declarator);
+                continue;
+            }
+            let value = declarator.init.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            self.lower(Pattern::from_binding_pattern(declarator.id), value, &mut lowering, ctx);
+        }
+        decl.declarations = lowering.declarators;
+    }
+
+    /// `[a, b] = x` -> `(_x = x, _x2 = babelHelpers.slicedToArray(_x, 2), a = _x2[0], b = _x2[1], _x)`
+    ///
+    /// If value of the expression is unused, value is not stored:
+    /// `[a, b] = x;` -> `_x = babelHelpers.slicedToArray(x, 2), a = _x[0], b = _x[1];`
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Value of expression body of an arrow function is its return value
+        let is_value_unused = matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_))
+            && !matches!(ctx.ancestor(2), Ancestor::ArrowFunctionExpressionBody(arrow) if *arrow.expression());
+        let Expression::AssignmentExpression(assign) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let assign = assign.unbox();
+        let pattern = Pattern::from_assignment_target(assign.left, ctx.ast);
+
+        let mut lowering = Lowering::new(None, ctx.current_scope_id(), ctx);
+        if is_value_unused {
+            self.lower(pattern, assign.right, &mut lowering, ctx);
+        } else {
+            let reference = self.create_reference(assign.right, &mut lowering, ctx);
+            let value = reference.create(ctx);
+            self.lower(pattern, value, &mut lowering, ctx);
+            lowering.expressions.push(reference.create(ctx));
+        }
+
+        let mut expressions = lowering.expressions;
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(assign.span, expressions)
+        };
+    }
+
+    /// Get pattern in head of a `for-in` / `for-of` loop, if it's a destructuring pattern.
+    fn for_statement_pattern<'b>(
+        left: &'b ForStatementLeft<'a>,
+    ) -> Option<ForStatementPattern<'b, 'a>> {
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let pattern = &decl.declarations.first()?.id;
+                pattern
+                    .kind
+                    .is_destructuring_pattern()
+                    .then_some(ForStatementPattern::Binding(pattern))
+            }
+            left => left
+                .as_assignment_target()
+                .filter(|target| target.is_assignment_target_pattern())
+                .map(|_| ForStatementPattern::Target),
+        }
+    }
+
+    /// Check if body of a `for-in` / `for-of` loop needs to be wrapped in a new block
+    /// before its destructuring pattern can be lowered into it.
+    ///
+    /// A block body can be used as is, unless it declares a binding with same name
+    /// as a `let` / `const` binding in the loop head: `for (const [a] of x) { let a; }`.
+    fn needs_block_body(
+        left: &ForStatementLeft<'a>,
+        body: &Statement<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let Some(pattern) = Self::for_statement_pattern(left) else { return false };
+        let Statement::BlockStatement(block) = body else { return true };
+        let ForStatementPattern::Binding(pattern) = pattern else { return false };
+        if !left.is_lexical_declaration() {
+            return false;
+        }
+        let block_scope_id = block.scope_id.get().unwrap();
+        let mut is_shadowed = false;
+        pattern.bound_names(&mut |ident| {
+            is_shadowed |= ctx.scopes().has_binding(block_scope_id, &ident.name);
+        });
+        is_shadowed
+    }
+
+    /// `for (const [a, b] of x) {}` ->
+    /// `for (const _ref of x) { const _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1]; }`
+    ///
+    /// `for ([a, b] of x) {}` ->
+    /// `var _ref2; for (var _ref of x) { _ref2 = babelHelpers.slicedToArray(_ref, 2); a = _ref2[0]; b = _ref2[1]; }`
+    ///
+    /// Body has already been wrapped in a block by `enter_statement` if required.
+    fn transform_for_statement_left(
+        &self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(pattern) = Self::for_statement_pattern(left) else { return };
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        let block_scope_id = block.scope_id.get().unwrap();
+
+        if let ForStatementPattern::Binding(_) = pattern {
+            let ForStatementLeft::VariableDeclaration(decl) = left else { unreachable!() };
+            let kind = decl.kind;
+            let declarator = decl.declarations.first_mut().unwrap();
+
+            // `let` / `const` bindings move from loop's scope to body's scope.
+            // `var` temp vars are created in current scope, same as for other declarations.
+            let (temp_scope_id, lowering_scope_id, flags) = if kind.is_lexical() {
+                let mut symbol_ids = vec![];
+                declarator
+                    .id
+                    .bound_names(&mut |ident| symbol_ids.push(ident.symbol_id.get().unwrap()));
+                for symbol_id in symbol_ids {
+                    let name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+                    ctx.scopes_mut().remove_binding(for_scope_id, &name);
+                    ctx.scopes_mut().add_binding(block_scope_id, name, symbol_id);
+                    ctx.symbols_mut().set_scope_id(symbol_id, block_scope_id);
+                }
+                let flags = if kind == VariableDeclarationKind::Const {
+                    SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+                } else {
+                    SymbolFlags::BlockScopedVariable
+                };
+                (for_scope_id, block_scope_id, flags)
+            } else {
+                let scope_id = ctx.current_scope_id();
+                (scope_id, scope_id, SymbolFlags::FunctionScopedVariable)
+            };
+
+            let binding = BoundIdentifier::new_uid("ref", temp_scope_id, flags, ctx);
+            let pattern =
+                mem::replace(&mut declarator.id, Self::create_binding_pattern(&binding, ctx));
+            let mut lowering = Lowering::new(Some(kind), lowering_scope_id, ctx);
+            let value =
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+            self.lower(Pattern::from_binding_pattern(pattern), value, &mut lowering, ctx);
+            let stmt = Self::create_declaration(kind, lowering.declarators, ctx);
+            block.body.insert(0, stmt);
+        } else {
+            let scope_id = ctx.current_scope_id();
+            let binding =
+                BoundIdentifier::new_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable, ctx);
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                Self::create_binding_pattern(&binding, ctx),
+                None,
+                false,
+            );
+            let decl = ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            );
+            let left = mem::replace(left, ForStatementLeft::VariableDeclaration(decl));
+            let pattern = Pattern::from_assignment_target(left.into_assignment_target(), ctx.ast);
+
+            let mut lowering = Lowering::new(None, scope_id, ctx);
+            let value =
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+            self.lower(pattern, value, &mut lowering, ctx);
+            block.body.splice(
+                0..0,
+                lowering
+                    .expressions
+                    .into_iter()
+                    .map(|expr| ctx.ast.statement_expression(SPAN, expr)),
+            );
+        }
+    }
+
+    /// `function f({ a }, [b] = []) {}` -> `function f(_ref, _ref2 = []) { var a = _ref.a, ... }`
+    fn transform_params(
+        &self,
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut lowering = Lowering::new(Some(VariableDeclarationKind::Var), scope_id, ctx);
+
+        for param in params.items.iter_mut() {
+            let pattern = &mut param.pattern;
+            if !pattern.kind.is_destructuring_pattern() {
+                continue;
+            }
+            // Default value remains on the parameter: `[b] = []` -> `_ref2 = []`
+            let pattern = match &mut pattern.kind {
+                BindingPatternKind::AssignmentPattern(assign) => &mut assign.left,
+                _ => pattern,
+            };
+            self.replace_param_pattern(pattern, &mut lowering, ctx);
+        }
+
+        if let Some(rest) = &mut params.rest {
+            if rest.argument.kind.is_destructuring_pattern() {
+                self.replace_param_pattern(&mut rest.argument, &mut lowering, ctx);
+            }
+        }
+
+        if !lowering.declarators.is_empty() {
+            let stmt =
+                Self::create_declaration(VariableDeclarationKind::Var, lowering.declarators, ctx);
+            body.statements.insert(0, stmt);
+        }
+    }
+
+    /// Replace pattern of a parameter with a temp var, and destructure the temp var.
+    fn replace_param_pattern(
+        &self,
+        pattern: &mut BindingPattern<'a>,
+        lowering: &mut Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let binding = BoundIdentifier::new_uid(
+            "ref",
+            lowering.scope_id,
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let pattern = mem::replace(pattern, Self::create_binding_pattern(&binding, ctx));
+        let value =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        self.lower(Pattern::from_binding_pattern(pattern), value, lowering, ctx);
+    }
+
+    /// Destructure `value` into `pattern`.
+    fn lower(
+        &self,
+        pattern: Pattern<'a>,
+        value: Expression<'a>,
+        lowering: &mut Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern {
+            Pattern::Binding(ident) => {
+                // `a = value` (declaration)
+                let kind = lowering.kind.unwrap();
+                let id = ctx.ast.binding_pattern(
+                    ctx.ast.binding_pattern_kind_from_binding_identifier(ident),
+                    NONE,
+                    false,
+                );
+                lowering.declarators.push(ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    id,
+                    Some(value),
+                    false,
+                ));
+            }
+            Pattern::Target(target) => {
+                // `a = value` (assignment)
+                lowering.expressions.push(ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                ));
+            }
+            Pattern::Default(pattern, default) => {
+                // `_value === void 0 ? default : _value`
+                let reference = self.create_reference(value, lowering, ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    reference.create(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let value =
+                    ctx.ast.expression_conditional(SPAN, test, default, reference.create(ctx));
+                self.lower(*pattern, value, lowering, ctx);
+            }
+            Pattern::Object(properties, rest) => {
+                self.lower_object(properties, rest, value, lowering, ctx);
+            }
+            Pattern::Array(elements, rest) => {
+                self.lower_array(elements, rest, value, lowering, ctx);
+            }
+        }
+    }
+
+    /// `{ a, [b]: c, ...rest } = value` ->
+    /// `a = _value.a, c = _value[b], rest = babelHelpers.objectWithoutProperties(_value, ["a", _b])`
+    fn lower_object(
+        &self,
+        properties: Vec<(PropertyKey<'a>, Pattern<'a>)>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        lowering: &mut Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if properties.is_empty() && rest.is_none() {
+            // `{} = value` -> `_value = babelHelpers.objectDestructuringEmpty(value)`.
+            // Throws if `value` is `null` or `undefined`.
+            let binding = self.create_temp_binding(&value, lowering, ctx);
+            let value = helper_loader::call(
+                Helper::ObjectDestructuringEmpty,
+                ctx.ast.vec1(Argument::from(value)),
+                ctx,
+            );
+            self.init_temp_binding(&binding, value, lowering, ctx);
+            return;
+        }
+
+        let reference = self.create_reference(value, lowering, ctx);
+        let mut excluded_keys = ctx.ast.vec();
+        for (key, pattern) in properties {
+            let value = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    if rest.is_some() {
+                        excluded_keys.push(ArrayExpressionElement::from(
+                            ctx.ast.expression_string_literal(SPAN, ident.name.clone()),
+                        ));
+                    }
+                    Expression::from(ctx.ast.member_expression_static(
+                        SPAN,
+                        reference.create(ctx),
+                        ctx.ast.identifier_name(ident.span, ident.name.clone()),
+                        false,
+                    ))
+                }
+                key => {
+                    let mut key = key.into_expression();
+                    if rest.is_some() {
+                        if let Expression::StringLiteral(lit) = &key {
+                            excluded_keys.push(ArrayExpressionElement::from(
+                                ctx.ast.expression_string_literal(SPAN, lit.value.clone()),
+                            ));
+                        } else {
+                            // Key is referenced again in excluded keys, so evaluate it once.
+                            // `_b = babelHelpers.toPropertyKey(b)`
+                            let binding = self.create_temp_binding(&key, lowering, ctx);
+                            let value = helper_loader::call(
+                                Helper::ToPropertyKey,
+                                ctx.ast.vec1(Argument::from(key)),
+                                ctx,
+                            );
+                            self.init_temp_binding(&binding, value, lowering, ctx);
+                            excluded_keys.push(ArrayExpressionElement::from(
+                                ctx.ast.expression_from_identifier_reference(
+                                    binding.create_read_reference(ctx),
+                                ),
+                            ));
+                            key = ctx.ast.expression_from_identifier_reference(
+                                binding.create_read_reference(ctx),
+                            );
+                        }
+                    }
+                    Expression::from(ctx.ast.member_expression_computed(
+                        SPAN,
+                        reference.create(ctx),
+                        key,
+                        false,
+                    ))
+                }
+            };
+            self.lower(pattern, value, lowering, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `babelHelpers.objectWithoutProperties(_value, ["a", _b])`
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(reference.create(ctx)),
+                Argument::from(ctx.ast.expression_array(SPAN, excluded_keys, None)),
+            ]);
+            let value = helper_loader::call(Helper::ObjectWithoutProperties, arguments, ctx);
+            self.lower(*rest, value, lowering, ctx);
+        }
+    }
+
+    /// `[a, , b, ...rest] = value` ->
+    /// `_value = babelHelpers.toArray(value), a = _value[0], b = _value[2], rest = _value.slice(3)`
+    fn lower_array(
+        &self,
+        elements: Vec<Option<Pattern<'a>>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        lowering: &mut Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let len = elements.len();
+        let binding = self.create_temp_binding(&value, lowering, ctx);
+        let value = if rest.is_some() {
+            helper_loader::call(Helper::ToArray, ctx.ast.vec1(Argument::from(value)), ctx)
+        } else {
+            // `babelHelpers.slicedToArray(value, len)`
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(value),
+                Argument::from(Self::create_number(len, ctx)),
+            ]);
+            helper_loader::call(Helper::SlicedToArray, arguments, ctx)
+        };
+        self.init_temp_binding(&binding, value, lowering, ctx);
+
+        for (index, element) in elements.into_iter().enumerate() {
+            // Holes are skipped
+            let Some(pattern) = element else { continue };
+            let value = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+                Self::create_number(index, ctx),
+                false,
+            ));
+            self.lower(pattern, value, lowering, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `_value.slice(len)`
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+                ctx.ast.identifier_name(SPAN, "slice"),
+                false,
+            ));
+            let arguments = ctx.ast.vec1(Argument::from(Self::create_number(len, ctx)));
+            let value = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            self.lower(*rest, value, lowering, ctx);
+        }
+    }
+
+    /// Get a reference to `value` which can be used multiple times, evaluating `value` only once.
+    ///
+    /// Unmodified bindings and `this` are used directly. Anything else is stored in a temp var.
+    fn create_reference(
+        &self,
+        value: Expression<'a>,
+        lowering: &mut Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Reference<'a> {
+        if ctx.is_static(&value) {
+            // Every use of the reference is a clone, so original identifier is not used
+            if let Expression::Identifier(ident) = &value {
+                ctx.delete_reference_for_identifier(ident);
+            }
+            return Reference::Static(value);
+        }
+        let binding = self.create_temp_binding(&value, lowering, ctx);
+        self.init_temp_binding(&binding, value, lowering, ctx);
+        Reference::Temp(binding)
+    }
+
+    /// Create a temp binding, with name based on `node`.
+    #[expect(clippy::unused_self)]
+    fn create_temp_binding(
+        &self,
+        node: &Expression<'a>,
+        lowering: &Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let flags = match lowering.kind {
+            None | Some(VariableDeclarationKind::Var) => SymbolFlags::FunctionScopedVariable,
+            Some(VariableDeclarationKind::Const) => {
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+            }
+            Some(_) => SymbolFlags::BlockScopedVariable,
+        };
+        let symbol_id = ctx.generate_uid_based_on_node(node, lowering.scope_id, flags);
+        BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id }
+    }
+
+    /// Initialize temp binding with `value`.
+    ///
+    /// * Declaration: `_temp = value` declarator.
+    /// * Assignment: `var _temp` in enclosing block, and `_temp = value` expression.
+    fn init_temp_binding(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        lowering: &mut Lowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(kind) = lowering.kind {
+            let id = Self::create_binding_pattern(binding, ctx);
+            lowering.declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                id,
+                Some(value),
+                false,
+            ));
+        } else {
+            self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+            lowering.expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                    binding.create_write_reference(ctx),
+                )),
+                value,
+            ));
+        }
+    }
+
+    fn create_binding_pattern(
+        binding: &BoundIdentifier<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> BindingPattern<'a> {
+        ctx.ast.binding_pattern(
+            ctx.ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier()),
+            NONE,
+            false,
+        )
+    }
+
+    fn create_declaration(
+        kind: VariableDeclarationKind,
+        declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarators,
+            false,
+        ))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn create_number(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_numeric_literal(
+            SPAN,
+            value as f64,
+            ctx.ast.str(&value.to_string()),
+            NumberBase::Decimal,
+        )
+    }
+}
+
+/// Destructuring pattern in head of a `for-in` / `for-of` loop.
+enum ForStatementPattern<'b, 'a> {
+    /// `[a]` in `for (const [a] of x)`
+    Binding(&'b BindingPattern<'a>),
+    /// `[a]` in `for ([a] of x)`
+    Target,
+}
+
+/// Output of destructuring.
+struct Lowering<'a> {
+    /// Kind of declaration. `None` for assignments.
+    kind: Option<VariableDeclarationKind>,
+    /// Scope to create temp vars in
+    scope_id: ScopeId,
+    /// Declarators (declarations)
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+    /// Expressions (assignments)
+    expressions: ArenaVec<'a, Expression<'a>>,
+}
+
+impl<'a> Lowering<'a> {
+    fn new(
+        kind: Option<VariableDeclarationKind>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        Self { kind, scope_id, declarators: ctx.ast.vec(), expressions: ctx.ast.vec() }
+    }
+}
+
+/// A reference to a value which has been evaluated.
+enum Reference<'a> {
+    /// Temp var holding the value
+    Temp(BoundIdentifier<'a>),
+    /// Unmodified binding or `this`
+    Static(Expression<'a>),
+}
+
+impl<'a> Reference<'a> {
+    fn create(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Temp(binding) => {
+                ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+            }
+            Self::Static(Expression::Identifier(ident)) => {
+                ctx.ast.expression_from_identifier_reference(
+                    ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
+                )
+            }
+            Self::Static(expr) => expr.clone_in(ctx.ast.allocator),
+        }
+    }
+}
+
+/// Destructuring pattern.
+///
+/// Binding patterns (declarations) and assignment target patterns (assignments)
+/// are converted to this common form.
+enum Pattern<'a> {
+    /// `a` in `var [a] = x`
+    Binding(BindingIdentifier<'a>),
+    /// `a` or `obj.a` in `[a, obj.a] = x`
+    Target(SimpleAssignmentTarget<'a>),
+    /// `a = 1`
+    Default(Box<Pattern<'a>>, Expression<'a>),
+    /// `{ a, b: c, ...rest }`
+    Object(Vec<(PropertyKey<'a>, Pattern<'a>)>, Option<Box<Pattern<'a>>>),
+    /// `[a, , b, ...rest]`
+    Array(Vec<Option<Pattern<'a>>>, Option<Box<Pattern<'a>>>),
+}
+
+impl<'a> Pattern<'a> {
+    fn from_binding_pattern(pattern: BindingPattern<'a>) -> Self {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Self::Binding(ident.unbox()),
+            BindingPatternKind::AssignmentPattern(assign) => {
+                let assign = assign.unbox();
+                Self::Default(Box::new(Self::from_binding_pattern(assign.left)), assign.right)
+            }
+            BindingPatternKind::ObjectPattern(object) => {
+                let object = object.unbox();
+                let properties = object
+                    .properties
+                    .into_iter()
+                    .map(|prop| (prop.key, Self::from_binding_pattern(prop.value)))
+                    .collect();
+                let rest = object
+                    .rest
+                    .map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Object(properties, rest)
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                let array = array.unbox();
+                let elements = array
+                    .elements
+                    .into_iter()
+                    .map(|element| element.map(Self::from_binding_pattern))
+                    .collect();
+                let rest = array
+                    .rest
+                    .map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Array(elements, rest)
+            }
+        }
+    }
+
+    fn from_assignment_target(target: AssignmentTarget<'a>, ast: AstBuilder<'a>) -> Self {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(object) => {
+                let object = object.unbox();
+                let properties = object
+                    .properties
+                    .into_iter()
+                    .map(|prop| Self::from_assignment_target_property(prop, ast))
+                    .collect();
+                let rest = object
+                    .rest
+                    .map(|rest| Box::new(Self::from_assignment_target(rest.target, ast)));
+                Self::Object(properties, rest)
+            }
+            AssignmentTarget::ArrayAssignmentTarget(array) => {
+                let array = array.unbox();
+                let elements = array
+                    .elements
+                    .into_iter()
+                    .map(|element| element.map(|element| Self::from_maybe_default(element, ast)))
+                    .collect();
+                let rest =
+                    array.rest.map(|rest| Box::new(Self::from_assignment_target(rest.target, ast)));
+                Self::Array(elements, rest)
+            }
+            target => Self::Target(target.into_simple_assignment_target()),
+        }
+    }
+
+    fn from_maybe_default(target: AssignmentTargetMaybeDefault<'a>, ast: AstBuilder<'a>) -> Self {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let target = target.unbox();
+                Self::Default(
+                    Box::new(Self::from_assignment_target(target.binding, ast)),
+                    target.init,
+                )
+            }
+            target => Self::from_assignment_target(target.into_assignment_target(), ast),
+        }
+    }
+
+    fn from_assignment_target_property(
+        prop: AssignmentTargetProperty<'a>,
+        ast: AstBuilder<'a>,
+    ) -> (PropertyKey<'a>, Self) {
+        match prop {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) => {
+                // `{ a = 1 } = x`
+                let prop = prop.unbox();
+                let key =
+                    ast.property_key_identifier_name(prop.binding.span, prop.binding.name.clone());
+                let target = Self::Target(SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                    ast.alloc(prop.binding),
+                ));
+                let pattern = match prop.init {
+                    Some(init) => Self::Default(Box::new(target), init),
+                    None => target,
+                };
+                (key, pattern)
+            }
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                let prop = prop.unbox();
+                (prop.name, Self::from_maybe_default(prop.binding, ast))
+            }
+        }
+    }
+}
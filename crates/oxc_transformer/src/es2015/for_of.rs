@@ -0,0 +1,543 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops to `for` loops which use the iterator protocol directly.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of arr) {
+//!   if (x) break;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     if (x) break;
+//!   }
+//! } catch (_err) {
+//!   _iterator.e(_err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! If the loop exits early (`break`, `return`, or an exception), `_iterator.f()` calls the iterator's
+//! `return()` method, so the iterator can clean up, as it would be with a `for...of` loop.
+//!
+//! Labels on the loop remain on the `for` loop, so `break label` and `continue label` are still valid.
+//! Breaking out of an inner loop to an outer label also exits the inner `try`, so the inner iterator
+//! is closed too.
+//!
+//! With `assumeArray` (or `loose`) option, the iterable is assumed to be an array,
+//! and is iterated over by index. The iterator protocol is not used:
+//!
+//! ```js
+//! for (let _i = 0, _arr = arr; _i < _arr.length; _i++) {
+//!   const x = _arr[_i];
+//!   if (x) break;
+//! }
+//! ```
+//!
+//! ## Missing features
+//!
+//! * `for await...of` loops.
+//! * `allowArrayLike` option.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-for-of>
+//! * For-in, for-of and for-await-of statements specification: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator, UpdateOperator},
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use serde::Deserialize;
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    /// Iterate over arrays by index, instead of with the iterator protocol
+    #[serde(alias = "loose")]
+    pub assume_array: bool,
+}
+
+pub struct ForOf {
+    options: ForOfOptions,
+}
+
+impl ForOf {
+    pub fn new(options: ForOfOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a> for ForOf {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.assume_array {
+            if matches!(stmt, Statement::ForOfStatement(for_of) if !for_of.r#await) {
+                Self::transform_for_of_array(stmt, ctx);
+            }
+            return;
+        }
+
+        // Labelled loops are transformed along with their labels.
+        // Statements in statement lists are transformed in `exit_statements`.
+        if matches!(
+            ctx.parent(),
+            Ancestor::LabeledStatementBody(_)
+                | Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::TSModuleBlockBody(_)
+        ) || !Self::is_for_of(stmt)
+        {
+            return;
+        }
+
+        // `if (x) for (const a of b) {}` -> `if (x) { var _iterator = ...; try { ... } }`
+        let span = stmt.span();
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        let for_of = ctx.ast.move_statement(stmt);
+        let stmts = Self::transform_for_of_iterator(for_of, scope_id, ctx);
+        let block = BlockStatement::new_with_scope_id(span, ctx.ast.vec_from_iter(stmts), scope_id);
+        *stmt = Statement::BlockStatement(ctx.ast.alloc(block));
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.assume_array || !stmts.iter().any(Self::is_for_of) {
+            return;
+        }
+
+        let parent_scope_id = ctx.current_scope_id();
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() + 1);
+        for stmt in stmts.drain(..) {
+            if Self::is_for_of(&stmt) {
+                new_stmts.extend(Self::transform_for_of_iterator(stmt, parent_scope_id, ctx));
+            } else {
+                new_stmts.push(stmt);
+            }
+        }
+        *stmts = new_stmts;
+    }
+}
+
+impl<'a> ForOf {
+    /// Check if statement is a `for...of` loop, possibly labelled.
+    fn is_for_of(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ForOfStatement(for_of) => !for_of.r#await,
+            Statement::LabeledStatement(labeled) => Self::is_for_of(&labeled.body),
+            _ => false,
+        }
+    }
+
+    /// `label: for (const x of arr) body` ->
+    /// ```js
+    /// var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+    /// try {
+    ///   label: for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; body }
+    /// } catch (_err) {
+    ///   _iterator.e(_err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    ///
+    /// New scopes are created as children of `parent_scope_id`.
+    fn transform_for_of_iterator(
+        stmt: Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> [Statement<'a>; 2] {
+        // Unwrap labels
+        let mut labels = vec![];
+        let mut stmt = stmt;
+        while let Statement::LabeledStatement(labeled) = stmt {
+            let labeled = labeled.unbox();
+            labels.push((labeled.span, labeled.label));
+            stmt = labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = stmt else { unreachable!() };
+        let mut for_of = for_of.unbox();
+
+        let iterator = BoundIdentifier::new_uid_in_current_scope(
+            "iterator",
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let step = BoundIdentifier::new_uid_in_current_scope(
+            "step",
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+
+        // `var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;`
+        let iterator_init = helper_loader::call(
+            Helper::CreateForOfIteratorHelper,
+            ctx.ast.vec1(Argument::from(ctx.ast.move_expression(&mut for_of.right))),
+            ctx,
+        );
+        let declarations = ctx.ast.vec_from_iter([
+            Self::create_declarator(
+                &iterator,
+                VariableDeclarationKind::Var,
+                Some(iterator_init),
+                ctx,
+            ),
+            Self::create_declarator(&step, VariableDeclarationKind::Var, None, ctx),
+        ]);
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+
+        // `for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; body }`
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let for_of_scope_id = for_of.scope_id.get().unwrap();
+        let for_scope_id = Self::create_for_scope(&for_of, try_scope_id, ctx);
+        let init = Self::create_method_call(&iterator, "s", ctx.ast.vec(), ctx);
+        let next = Self::create_method_call(&iterator, "n", ctx.ast.vec(), ctx);
+        let next = Self::create_assignment(&step, next, ctx);
+        let done = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_parenthesized(SPAN, next),
+            ctx.ast.identifier_name(SPAN, "done"),
+            false,
+        ));
+        let test = ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done);
+        let value = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(step.create_read_reference(ctx)),
+            ctx.ast.identifier_name(SPAN, "value"),
+            false,
+        ));
+        let body = Self::create_body(
+            for_of.left,
+            for_of.body,
+            value,
+            for_scope_id == for_of_scope_id,
+            for_of_scope_id,
+            ctx,
+        );
+        let for_stmt = ctx.ast.for_statement(
+            for_of.span,
+            Some(ctx.ast.for_statement_init_expression(init)),
+            Some(test),
+            None,
+            body,
+        );
+        for_stmt.scope_id.set(Some(for_scope_id));
+        let mut for_stmt = Statement::ForStatement(ctx.ast.alloc(for_stmt));
+
+        // Re-apply labels
+        for (span, label) in labels.into_iter().rev() {
+            for_stmt = ctx.ast.statement_labeled(span, label, for_stmt);
+        }
+
+        // `try { ... } catch (_err) { _iterator.e(_err); } finally { _iterator.f(); }`
+        let block = BlockStatement::new_with_scope_id(SPAN, ctx.ast.vec1(for_stmt), try_scope_id);
+
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let err = BoundIdentifier::new_uid(
+            "err",
+            catch_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let err_reference =
+            ctx.ast.expression_from_identifier_reference(err.create_read_reference(ctx));
+        let catch_stmt = ctx.ast.statement_expression(
+            SPAN,
+            Self::create_method_call(
+                &iterator,
+                "e",
+                ctx.ast.vec1(Argument::from(err_reference)),
+                ctx,
+            ),
+        );
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let catch_body =
+            BlockStatement::new_with_scope_id(SPAN, ctx.ast.vec1(catch_stmt), catch_body_scope_id);
+        let param = ctx.ast.catch_parameter(
+            SPAN,
+            ctx.ast.binding_pattern(
+                ctx.ast
+                    .binding_pattern_kind_from_binding_identifier(err.create_binding_identifier()),
+                NONE,
+                false,
+            ),
+        );
+        let handler = ctx.ast.catch_clause(SPAN, Some(param), catch_body);
+        handler.scope_id.set(Some(catch_scope_id));
+
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let finally_stmt = ctx.ast.statement_expression(
+            SPAN,
+            Self::create_method_call(&iterator, "f", ctx.ast.vec(), ctx),
+        );
+        let finalizer =
+            BlockStatement::new_with_scope_id(SPAN, ctx.ast.vec1(finally_stmt), finally_scope_id);
+
+        let try_stmt = ctx.ast.statement_try(for_of.span, block, Some(handler), Some(finalizer));
+        [declaration, try_stmt]
+    }
+
+    /// `for (const x of arr) body` ->
+    /// `for (let _i = 0, _arr = arr; _i < _arr.length; _i++) { const x = _arr[_i]; body }`
+    fn transform_for_of_array(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ForOfStatement(for_of) = ctx.ast.move_statement(stmt) else {
+            unreachable!()
+        };
+        let for_of = for_of.unbox();
+
+        let for_of_scope_id = for_of.scope_id.get().unwrap();
+        let for_scope_id = Self::create_for_scope(&for_of, ctx.current_scope_id(), ctx);
+        let index =
+            BoundIdentifier::new_uid("i", for_scope_id, SymbolFlags::BlockScopedVariable, ctx);
+        let symbol_id = ctx.generate_uid_based_on_node(
+            &for_of.right,
+            for_scope_id,
+            SymbolFlags::BlockScopedVariable,
+        );
+        let array =
+            BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id };
+
+        // `let _i = 0, _arr = arr`
+        let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+        let declarations = ctx.ast.vec_from_iter([
+            Self::create_declarator(&index, VariableDeclarationKind::Let, Some(zero), ctx),
+            Self::create_declarator(&array, VariableDeclarationKind::Let, Some(for_of.right), ctx),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Let,
+            declarations,
+            false,
+        );
+
+        // `_i < _arr.length`
+        let length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(array.create_read_reference(ctx)),
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(index.create_read_reference(ctx)),
+            BinaryOperator::LessThan,
+            length,
+        );
+
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            ctx.ast.simple_assignment_target_from_identifier_reference(
+                index.create_read_write_reference(ctx),
+            ),
+        );
+
+        // `_arr[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(array.create_read_reference(ctx)),
+            ctx.ast.expression_from_identifier_reference(index.create_read_reference(ctx)),
+            false,
+        ));
+        let body = Self::create_body(
+            for_of.left,
+            for_of.body,
+            value,
+            for_scope_id == for_of_scope_id,
+            for_of_scope_id,
+            ctx,
+        );
+
+        let for_stmt =
+            ctx.ast.for_statement(for_of.span, Some(init), Some(test), Some(update), body);
+        for_stmt.scope_id.set(Some(for_scope_id));
+        *stmt = Statement::ForStatement(ctx.ast.alloc(for_stmt));
+    }
+
+    /// Get scope for the `for` statement which replaces `for_of`.
+    ///
+    /// If body of `for_of` is a block statement, bindings declared in the loop head are moved into
+    /// the block's scope, and the `for...of` statement's scope is reused for the `for` statement.
+    ///
+    /// Otherwise (or if a binding in the loop head is shadowed in the block), a new scope is created
+    /// for the `for` statement, and the `for...of` statement's scope is used for a new block
+    /// statement which wraps the body.
+    fn create_for_scope(
+        for_of: &ForOfStatement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        let for_of_scope_id = for_of.scope_id.get().unwrap();
+        let body_scope_id = match &for_of.body {
+            Statement::BlockStatement(block) => {
+                let body_scope_id = block.scope_id.get().unwrap();
+                let scopes = ctx.scopes();
+                let is_shadowed = scopes
+                    .get_bindings(for_of_scope_id)
+                    .keys()
+                    .any(|name| scopes.has_binding(body_scope_id, name));
+                (!is_shadowed).then_some(body_scope_id)
+            }
+            _ => None,
+        };
+
+        if let Some(body_scope_id) = body_scope_id {
+            let bindings = ctx
+                .scopes()
+                .get_bindings(for_of_scope_id)
+                .iter()
+                .map(|(name, &symbol_id)| (name.clone(), symbol_id))
+                .collect::<Vec<_>>();
+            for (name, symbol_id) in bindings {
+                ctx.scopes_mut().remove_binding(for_of_scope_id, &name);
+                ctx.scopes_mut().add_binding(body_scope_id, name, symbol_id);
+                ctx.symbols_mut().set_scope_id(symbol_id, body_scope_id);
+            }
+            Self::move_scope(for_of_scope_id, parent_scope_id, ctx);
+            for_of_scope_id
+        } else {
+            let for_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+            Self::move_scope(for_of_scope_id, for_scope_id, ctx);
+            for_scope_id
+        }
+    }
+
+    /// Set parent of `scope_id` to `parent_scope_id`.
+    fn move_scope(scope_id: ScopeId, parent_scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let scopes = ctx.scopes_mut();
+        if scopes.has_child_ids() {
+            if let Some(old_parent_id) = scopes.get_parent_id(scope_id) {
+                scopes.get_child_ids_mut(old_parent_id).retain(|&id| id != scope_id);
+            }
+        }
+        scopes.set_parent_id(scope_id, Some(parent_scope_id));
+    }
+
+    /// Create body of `for` loop, with loop head of `for...of` loop assigned `value`.
+    ///
+    /// * `const x` -> `{ const x = value; body }`
+    /// * `x` -> `{ x = value; body }`
+    fn create_body(
+        left: ForStatementLeft<'a>,
+        body: Statement<'a>,
+        value: Expression<'a>,
+        is_body_merged: bool,
+        for_of_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let assign_stmt = match left {
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                decl.declarations.first_mut().unwrap().init = Some(value);
+                Statement::VariableDeclaration(decl)
+            }
+            left => {
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    left.into_assignment_target(),
+                    value,
+                );
+                ctx.ast.statement_expression(SPAN, assignment)
+            }
+        };
+
+        match body {
+            Statement::BlockStatement(mut block) if is_body_merged => {
+                block.body.insert(0, assign_stmt);
+                Statement::BlockStatement(block)
+            }
+            body => {
+                let block = BlockStatement::new_with_scope_id(
+                    SPAN,
+                    ctx.ast.vec_from_iter([assign_stmt, body]),
+                    for_of_scope_id,
+                );
+                Statement::BlockStatement(ctx.ast.alloc(block))
+            }
+        }
+    }
+
+    /// `<binding>.<method>(...arguments)`
+    fn create_method_call(
+        binding: &BoundIdentifier<'a>,
+        method: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+            ctx.ast.identifier_name(SPAN, method),
+            false,
+        ));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `<binding> = <value>`
+    fn create_assignment(
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.create_write_reference(ctx),
+            )),
+            value,
+        )
+    }
+
+    fn create_declarator(
+        binding: &BoundIdentifier<'a>,
+        kind: VariableDeclarationKind,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let id = ctx.ast.binding_pattern(
+            ctx.ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier()),
+            NONE,
+            false,
+        );
+        ctx.ast.variable_declarator(SPAN, kind, id, init, false)
+    }
+}
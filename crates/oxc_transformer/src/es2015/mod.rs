@@ -1,37 +1,92 @@
 mod arrow_functions;
+mod block_scoping;
+mod computed_properties;
+mod destructuring;
+mod for_of;
+mod new_target;
 mod options;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
+mod typeof_symbol;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use computed_properties::ComputedProperties;
+pub use destructuring::Destructuring;
+pub use for_of::{ForOf, ForOfOptions};
+pub use new_target::NewTarget;
 pub use options::ES2015Options;
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
+pub use regenerator::Regenerator;
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::Spread;
+pub use template_literals::TemplateLiterals;
+pub use typeof_symbol::TypeofSymbol;
 
-pub struct ES2015<'a> {
+use crate::TransformCtx;
+
+pub struct ES2015<'a, 'ctx> {
     options: ES2015Options,
 
     // Plugins
     arrow_functions: ArrowFunctions<'a>,
+    shorthand_properties: ShorthandProperties,
+    computed_properties: ComputedProperties,
+    template_literals: TemplateLiterals<'a, 'ctx>,
+    destructuring: Destructuring<'a, 'ctx>,
+    for_of: ForOf,
+    spread: Spread<'a, 'ctx>,
+    regenerator: Regenerator<'a, 'ctx>,
+    block_scoping: BlockScoping<'a, 'ctx>,
+    typeof_symbol: TypeofSymbol,
+    new_target: NewTarget<'a>,
 }
 
-impl<'a> ES2015<'a> {
-    pub fn new(options: ES2015Options) -> Self {
+impl<'a, 'ctx> ES2015<'a, 'ctx> {
+    pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(
                 options.arrow_function.clone().unwrap_or_default(),
             ),
+            shorthand_properties: ShorthandProperties::new(),
+            computed_properties: ComputedProperties::new(),
+            template_literals: TemplateLiterals::new(ctx),
+            destructuring: Destructuring::new(ctx),
+            for_of: ForOf::new(options.for_of.unwrap_or_default()),
+            spread: Spread::new(ctx),
+            regenerator: Regenerator::new(ctx),
+            block_scoping: BlockScoping::new(options.block_scoping.unwrap_or_default(), ctx),
+            typeof_symbol: TypeofSymbol::new(),
+            new_target: NewTarget::new(),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a> for ES2015<'a> {
+impl<'a, 'ctx> Traverse<'a> for ES2015<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_program(program, ctx);
+        }
+    }
+
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.arrow_function.is_some() {
             self.arrow_functions.exit_program(program, ctx);
         }
+        if self.options.template_literals {
+            self.template_literals.exit_program(program, ctx);
+        }
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_function(func, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.enter_function(func, ctx);
         }
@@ -41,21 +96,244 @@ impl<'a> Traverse<'a> for ES2015<'a> {
         if self.options.arrow_function.is_some() {
             self.arrow_functions.exit_function(func, ctx);
         }
+        if self.options.destructuring {
+            self.destructuring.exit_function(func, ctx);
+        }
+        if self.options.use_regenerator {
+            self.regenerator.exit_function(func, ctx);
+        }
+        if self.options.new_target {
+            self.new_target.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring {
+            self.destructuring.exit_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring {
+            self.destructuring.exit_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn exit_variable_declarator(
+        &mut self,
+        declarator: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_variable_declarator(declarator, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn enter_binding_property(
+        &mut self,
+        prop: &mut BindingProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_binding_property(prop, ctx);
+        }
+    }
+
+    fn enter_assignment_target_property(
+        &mut self,
+        prop: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_assignment_target_property(prop, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_catch_clause(clause, ctx);
+        }
+    }
+
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_statements(stmts, ctx);
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_statements(stmts, ctx);
+        }
+        if self.options.for_of.is_some() {
+            self.for_of.exit_statements(stmts, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_statement(stmt, ctx);
+        }
+        if self.options.destructuring {
+            self.destructuring.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.for_of.is_some() {
+            self.for_of.exit_statement(stmt, ctx);
+        }
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.new_target {
+            self.new_target.enter_expression(expr, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.enter_expression(expr, ctx);
         }
+        if self.options.template_literals {
+            self.template_literals.enter_expression(expr, ctx);
+        }
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_expression(expr, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.exit_expression(expr, ctx);
         }
+        if self.options.computed_properties {
+            self.computed_properties.exit_expression(expr, ctx);
+        }
+        if self.options.destructuring {
+            self.destructuring.exit_expression(expr, ctx);
+        }
+        if self.options.spread {
+            self.spread.exit_expression(expr, ctx);
+        }
+        if self.options.use_regenerator {
+            self.regenerator.exit_expression(expr, ctx);
+        }
+        if self.options.typeof_symbol {
+            self.typeof_symbol.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_object_property(prop, ctx);
+        }
+        if self.options.shorthand_properties {
+            self.shorthand_properties.enter_object_property(prop, ctx);
+        }
+    }
+
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.use_regenerator {
+            self.regenerator.exit_object_property(prop, ctx);
+        }
     }
 
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_static_block(block, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.enter_static_block(block, ctx);
         }
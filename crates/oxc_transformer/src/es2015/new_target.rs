@@ -0,0 +1,209 @@
+//! ES2015: New Target
+//!
+//! This plugin transforms `new.target` meta properties.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function Foo() {
+//!   console.log(new.target);
+//! }
+//! var Bar = function() {
+//!   console.log(new.target);
+//! };
+//! class Baz {
+//!   constructor() {
+//!     console.log(new.target);
+//!   }
+//!   method() {
+//!     console.log(new.target);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function Foo() {
+//!   console.log(this instanceof Foo ? this.constructor : void 0);
+//! }
+//! var Bar = function _target() {
+//!   console.log(this instanceof _target ? this.constructor : void 0);
+//! };
+//! class Baz {
+//!   constructor() {
+//!     console.log(this.constructor);
+//!   }
+//!   method() {
+//!     console.log(void 0);
+//!   }
+//! }
+//! ```
+//!
+//! Anonymous functions containing `new.target` are given a name, so they can be referenced.
+//!
+//! Methods, getters, setters, class field initializers and static blocks cannot be called
+//! with `new`, so `new.target` is always `undefined` in them.
+//!
+//! `new.target` in arrow functions refers to `new.target` of the enclosing function.
+//!
+//! ## Missing features
+//!
+//! * Bindings in the function which shadow the function's name are not renamed.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-new-target](https://babel.dev/docs/babel-plugin-transform-new-target).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-new-target>
+//! * Meta properties specification: <https://tc39.es/ecma262/#sec-meta-properties>
+
+use oxc_ast::ast::*;
+use oxc_span::{Atom, Span, SPAN};
+use oxc_syntax::{
+    operator::BinaryOperator,
+    reference::ReferenceFlags,
+    scope::ScopeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use rustc_hash::FxHashMap;
+
+use crate::helpers::bindings::BoundIdentifier;
+
+pub struct NewTarget<'a> {
+    /// Names generated for anonymous functions containing `new.target`, keyed by function scope
+    names: FxHashMap<ScopeId, BoundIdentifier<'a>>,
+}
+
+impl<'a> NewTarget<'a> {
+    pub fn new() -> Self {
+        Self { names: FxHashMap::default() }
+    }
+}
+
+/// Kind of function `new.target` refers to.
+enum Target<'a> {
+    /// Class constructor
+    Constructor,
+    /// Function which cannot be called with `new`
+    NotConstructable,
+    /// Function with a name
+    Named(Atom<'a>, Option<SymbolId>),
+    /// Anonymous function
+    Anonymous(ScopeId),
+}
+
+impl<'a> Traverse<'a> for NewTarget<'a> {
+    // Transform on entry, so `this` in the replacement is transformed by arrow functions plugin
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::MetaProperty(meta) = expr else { return };
+        if meta.meta.name != "new" || meta.property.name != "target" {
+            return;
+        }
+        let span = meta.span;
+        let Some(target) = Self::get_target(ctx) else { return };
+
+        *expr = match target {
+            Target::Constructor => Self::create_this_constructor(span, ctx),
+            Target::NotConstructable => ctx.ast.void_0(span),
+            Target::Named(name, symbol_id) => {
+                let ident = ctx.create_reference_id(SPAN, name, symbol_id, ReferenceFlags::Read);
+                Self::create_conditional(span, ident, ctx)
+            }
+            Target::Anonymous(scope_id) => {
+                let binding = self
+                    .names
+                    .entry(scope_id)
+                    .or_insert_with(|| {
+                        BoundIdentifier::new_uid("target", scope_id, SymbolFlags::Function, ctx)
+                    })
+                    .clone();
+                let ident = binding.create_read_reference(ctx);
+                Self::create_conditional(span, ident, ctx)
+            }
+        };
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if func.id.is_some() || self.names.is_empty() {
+            return;
+        }
+        if let Some(binding) = self.names.remove(&func.scope_id.get().unwrap()) {
+            // `function() {}` -> `function _target() {}`
+            func.id = Some(binding.create_binding_identifier());
+        }
+    }
+}
+
+impl<'a> NewTarget<'a> {
+    /// Find the function which `new.target` refers to.
+    ///
+    /// Returns `None` if `new.target` is not inside a function, which is a syntax error.
+    fn get_target(ctx: &TraverseCtx<'a>) -> Option<Target<'a>> {
+        let mut ancestors = ctx.ancestors();
+        while let Some(ancestor) = ancestors.next() {
+            let (id, scope_id) = match ancestor {
+                Ancestor::FunctionParams(func) => (func.id(), func.scope_id()),
+                Ancestor::FunctionBody(func) => (func.id(), func.scope_id()),
+                Ancestor::PropertyDefinitionValue(_)
+                | Ancestor::AccessorPropertyValue(_)
+                | Ancestor::StaticBlockBody(_) => return Some(Target::NotConstructable),
+                _ => continue,
+            };
+            let target = match ancestors.next() {
+                Some(Ancestor::MethodDefinitionValue(method)) => {
+                    if *method.kind() == MethodDefinitionKind::Constructor {
+                        Target::Constructor
+                    } else {
+                        Target::NotConstructable
+                    }
+                }
+                Some(Ancestor::ObjectPropertyValue(prop))
+                    if *prop.method() || *prop.kind() != PropertyKind::Init =>
+                {
+                    Target::NotConstructable
+                }
+                _ => match id {
+                    Some(id) => Target::Named(id.name.clone(), id.symbol_id.get()),
+                    None => Target::Anonymous(scope_id.get().unwrap()),
+                },
+            };
+            return Some(target);
+        }
+        None
+    }
+
+    /// `this.constructor`
+    fn create_this_constructor(span: Span, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(ctx.ast.member_expression_static(
+            span,
+            ctx.ast.expression_this(SPAN),
+            ctx.ast.identifier_name(SPAN, "constructor"),
+            false,
+        ))
+    }
+
+    /// `this instanceof Foo ? this.constructor : void 0`
+    fn create_conditional(
+        span: Span,
+        ident: IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            ctx.ast.expression_this(SPAN),
+            BinaryOperator::Instanceof,
+            ctx.ast.expression_from_identifier_reference(ident),
+        );
+        ctx.ast.expression_conditional(
+            span,
+            test,
+            Self::create_this_constructor(SPAN, ctx),
+            ctx.ast.void_0(SPAN),
+        )
+    }
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use super::ArrowFunctionsOptions;
+use super::{ArrowFunctionsOptions, BlockScopingOptions, ForOfOptions};
 use crate::env::{can_enable_plugin, Versions};
 
 #[derive(Debug, Default, Clone, Deserialize)]
@@ -8,6 +8,36 @@ use crate::env::{can_enable_plugin, Versions};
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    #[serde(skip)]
+    pub shorthand_properties: bool,
+
+    #[serde(skip)]
+    pub computed_properties: bool,
+
+    #[serde(skip)]
+    pub template_literals: bool,
+
+    #[serde(skip)]
+    pub destructuring: bool,
+
+    #[serde(skip)]
+    pub for_of: Option<ForOfOptions>,
+
+    #[serde(skip)]
+    pub spread: bool,
+
+    #[serde(skip)]
+    pub use_regenerator: bool,
+
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    #[serde(skip)]
+    pub typeof_symbol: bool,
+
+    #[serde(skip)]
+    pub new_target: bool,
 }
 
 impl ES2015Options {
@@ -19,11 +49,64 @@ impl ES2015Options {
         self
     }
 
+    pub fn with_shorthand_properties(&mut self, enable: bool) -> &mut Self {
+        self.shorthand_properties = enable;
+        self
+    }
+
+    pub fn with_computed_properties(&mut self, enable: bool) -> &mut Self {
+        self.computed_properties = enable;
+        self
+    }
+
+    pub fn with_template_literals(&mut self, enable: bool) -> &mut Self {
+        self.template_literals = enable;
+        self
+    }
+
+    pub fn with_destructuring(&mut self, enable: bool) -> &mut Self {
+        self.destructuring = enable;
+        self
+    }
+
+    pub fn with_for_of(&mut self, for_of: Option<ForOfOptions>) -> &mut Self {
+        self.for_of = for_of;
+        self
+    }
+
+    pub fn with_spread(&mut self, enable: bool) -> &mut Self {
+        self.spread = enable;
+        self
+    }
+
+    pub fn with_use_regenerator(&mut self, enable: bool) -> &mut Self {
+        self.use_regenerator = enable;
+        self
+    }
+
+    pub fn with_block_scoping(&mut self, block_scoping: Option<BlockScopingOptions>) -> &mut Self {
+        self.block_scoping = block_scoping;
+        self
+    }
+
+    pub fn with_typeof_symbol(&mut self, enable: bool) -> &mut Self {
+        self.typeof_symbol = enable;
+        self
+    }
+
+    pub fn with_new_target(&mut self, enable: bool) -> &mut Self {
+        self.new_target = enable;
+        self
+    }
+
+    /// Only transforms which pass the conformance tests are enabled by targets.
+    /// Others must be enabled explicitly.
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
             arrow_function: can_enable_plugin("transform-arrow-functions", targets, bugfixes)
                 .then(Default::default),
+            ..Self::default()
         }
     }
 }
@@ -0,0 +1,1691 @@
+//! Emitter for regenerator transform.
+//!
+//! Converts the statements of a generator function into the cases of a `switch` statement,
+//! which the regenerator runtime runs as a state machine. Execution resumes at the case
+//! recorded in `_context.next`.
+//!
+//! Statements which do not contain a `yield` (or a `return`, `break` or `continue`, which must
+//! be converted to jumps) are output unchanged. Other statements and expressions are "exploded"
+//! into a series of operations. Intermediate values which must survive a `yield` are stored
+//! in temp properties of the context object (`_context.t0`).
+//!
+//! Declarations are hoisted into the generator function, so their values are preserved
+//! between calls to the state machine function.
+//!
+//! Based on [regenerator's emitter](https://github.com/facebook/regenerator/blob/main/packages/transform/src/emit.js).
+
+use std::mem;
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{ast::*, visit::walk_mut, Visit, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, CompactStr, GetSpan, Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::TraverseCtx;
+use rustc_hash::FxHashSet;
+
+use super::create_runtime_call;
+use crate::{helpers::bindings::BoundIdentifier, TransformCtx};
+
+/// A location in the state machine which can be jumped to.
+///
+/// Its value (index in list of operations) is only known once it has been marked.
+#[derive(Clone, Copy)]
+pub struct Loc(usize);
+
+/// Operation in the state machine.
+enum Op<'a> {
+    /// Any statement
+    Statement(Statement<'a>),
+    /// `_context.next = <loc>; break;`
+    Jump(Loc),
+    /// `if (<test>) { _context.next = <loc>; break; }`
+    JumpIf(Expression<'a>, Loc),
+    /// `_context.next = <loc>; return <value>;`
+    Yield(Option<Expression<'a>>, Loc),
+    /// `return _context.delegateYield(<iterable>, "t<temp>", <loc>);`
+    DelegateYield(Expression<'a>, usize, Loc),
+    /// `return _context.abrupt("break", <loc>);` or `return _context.abrupt("continue", <loc>);`
+    Abrupt(&'static str, Loc),
+    /// `return _context.abrupt("return", <value>);`
+    Return(Option<Expression<'a>>),
+    /// `_context.prev = <loc>;`
+    SetPrev(Loc),
+    /// `<target> = _context["catch"](<try_loc>);`
+    Catch(Option<AssignmentTarget<'a>>, Loc),
+    /// `return _context.finish(<finally_loc>);`
+    Finish(Loc),
+}
+
+impl<'a> Op<'a> {
+    /// Returns `true` if following operations in the same case are unreachable.
+    fn is_completion(&self) -> bool {
+        match self {
+            Self::Statement(stmt) => matches!(stmt, Statement::ThrowStatement(_)),
+            Self::JumpIf(..) | Self::SetPrev(_) | Self::Catch(..) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Statement which `break` or `continue` can target.
+enum Leap<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Atom<'a> },
+}
+
+/// Locations of parts of a `try` statement, passed to the runtime.
+#[allow(clippy::struct_field_names)]
+struct TryEntry {
+    try_loc: Loc,
+    catch_loc: Option<Loc>,
+    finally_loc: Option<Loc>,
+    after_loc: Loc,
+}
+
+pub struct Emitter<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `_context` param of state machine function
+    context: BoundIdentifier<'a>,
+    /// Scope of generator function, which bindings are hoisted into
+    function_scope_id: ScopeId,
+    ops: Vec<Op<'a>>,
+    /// Index in `ops` of each location, once it is known
+    locs: Vec<Option<usize>>,
+    /// Indexes in `ops` which start a new case
+    marks: FxHashSet<usize>,
+    leaps: Vec<Leap<'a>>,
+    try_entries: Vec<TryEntry>,
+    /// Number of temp properties of `_context` used
+    temp_count: usize,
+    /// Bindings to be declared with `var` in generator function
+    pub hoisted: Vec<SymbolId>,
+    hoisted_ids: FxHashSet<SymbolId>,
+    /// Hoisted bindings which were renamed to avoid conflicts
+    pub renamed: FxHashSet<SymbolId>,
+    /// Function declarations to be hoisted into generator function
+    pub functions: Vec<Statement<'a>>,
+}
+
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    pub fn new(
+        ctx: &'ctx TransformCtx<'a>,
+        context: BoundIdentifier<'a>,
+        function_scope_id: ScopeId,
+    ) -> Self {
+        Self {
+            ctx,
+            context,
+            function_scope_id,
+            ops: vec![],
+            locs: vec![],
+            marks: FxHashSet::default(),
+            leaps: vec![],
+            try_entries: vec![],
+            temp_count: 0,
+            hoisted: vec![],
+            hoisted_ids: FxHashSet::default(),
+            renamed: FxHashSet::default(),
+            functions: vec![],
+        }
+    }
+
+    /// Create a new location, whose value is not yet known.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Set location to current position, and start a new case there.
+    fn mark(&mut self, loc: Loc) {
+        let index = self.ops.len();
+        self.locs[loc.0] = Some(index);
+        self.marks.insert(index);
+    }
+
+    /// Create a location at current position, without starting a new case.
+    fn current_loc(&mut self) -> Loc {
+        self.locs.push(Some(self.ops.len()));
+        Loc(self.locs.len() - 1)
+    }
+
+    fn emit(&mut self, op: Op<'a>) {
+        self.ops.push(op);
+    }
+
+    fn emit_statement(&mut self, stmt: Statement<'a>) {
+        self.emit(Op::Statement(stmt));
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        self.emit_statement(ctx.ast.statement_expression(SPAN, expr));
+    }
+
+    fn jump(&mut self, loc: Loc) {
+        self.emit(Op::Jump(loc));
+    }
+
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        self.emit(Op::JumpIf(test, loc));
+    }
+
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc, ctx: &TraverseCtx<'a>) {
+        let test = ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test);
+        self.emit(Op::JumpIf(test, loc));
+    }
+
+    fn make_temp(&mut self) -> usize {
+        self.temp_count += 1;
+        self.temp_count - 1
+    }
+
+    /// `_context.t<temp> = <value>;`. Returns `_context.t<temp>`.
+    fn emit_assign_temp(
+        &mut self,
+        temp: usize,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let target = self.create_temp_target(temp, ctx);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment, ctx);
+        self.create_temp(temp, ctx)
+    }
+
+    fn report_unsupported(&self, message: &'static str, span: Span) {
+        self.ctx.error(OxcDiagnostic::error(message).with_label(span));
+    }
+}
+
+// Statements
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    /// Explode statement into operations.
+    ///
+    /// `label` is the label of a `LabeledStatement` which `stmt` is the body of.
+    pub fn explode_statement(
+        &mut self,
+        stmt: Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Declarations are always hoisted, so their bindings persist between calls
+        let mut stmt = match stmt {
+            Statement::VariableDeclaration(decl) => {
+                self.explode_variable_declaration(decl.unbox(), ctx);
+                return;
+            }
+            Statement::FunctionDeclaration(func) => {
+                self.hoist_function(func, ctx);
+                return;
+            }
+            Statement::ClassDeclaration(class) => {
+                self.explode_class_declaration(class, ctx);
+                return;
+            }
+            stmt => stmt,
+        };
+
+        if !LeapFinder::statement(&stmt) {
+            VarHoister { emitter: self, ctx }.visit_statement(&mut stmt);
+            self.emit_statement(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true, ctx);
+            }
+            Statement::BlockStatement(block) => {
+                Self::remove_scope(block.scope_id.get().unwrap(), ctx);
+                for stmt in block.unbox().body {
+                    self.explode_statement(stmt, None, ctx);
+                }
+            }
+            Statement::LabeledStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let after = self.loc();
+                self.leaps.push(Leap::Labeled { break_loc: after, label: stmt.label.name.clone() });
+                self.explode_statement(stmt.body, Some(stmt.label.name), ctx);
+                self.leaps.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode(stmt.test, ctx);
+                self.jump_if_not(test, after, ctx);
+                self.leaps.push(Leap::Loop { break_loc: after, continue_loc: before, label });
+                self.explode_statement(stmt.body, None, ctx);
+                self.leaps.pop();
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.leaps.push(Leap::Loop { break_loc: after, continue_loc: test_loc, label });
+                self.explode_statement(stmt.body, None, ctx);
+                self.leaps.pop();
+                self.mark(test_loc);
+                let test = self.explode(stmt.test, ctx);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let stmt = stmt.unbox();
+                Self::remove_scope(stmt.scope_id.get().unwrap(), ctx);
+                let head = self.loc();
+                let update_loc = self.loc();
+                let after = self.loc();
+                match stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.explode_variable_declaration(decl.unbox(), ctx);
+                    }
+                    Some(init) => {
+                        self.explode_expression(init.into_expression(), true, ctx);
+                    }
+                    None => {}
+                }
+                self.mark(head);
+                if let Some(test) = stmt.test {
+                    let test = self.explode(test, ctx);
+                    self.jump_if_not(test, after, ctx);
+                }
+                self.leaps.push(Leap::Loop { break_loc: after, continue_loc: update_loc, label });
+                self.explode_statement(stmt.body, None, ctx);
+                self.leaps.pop();
+                self.mark(update_loc);
+                if let Some(update) = stmt.update {
+                    self.explode_expression(update, true, ctx);
+                }
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => self.explode_for_in(stmt.unbox(), label, ctx),
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                match self.find_break_loc(label) {
+                    Some(loc) => self.emit(Op::Abrupt("break", loc)),
+                    None => self.report_unsupported(
+                        "Regenerator transform could not find target of `break`",
+                        stmt.span,
+                    ),
+                }
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                match self.find_continue_loc(label) {
+                    Some(loc) => self.emit(Op::Abrupt("continue", loc)),
+                    None => self.report_unsupported(
+                        "Regenerator transform could not find target of `continue`",
+                        stmt.span,
+                    ),
+                }
+            }
+            Statement::ReturnStatement(stmt) => {
+                let argument = stmt.unbox().argument.map(|argument| self.explode(argument, ctx));
+                self.emit(Op::Return(argument));
+            }
+            Statement::ThrowStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let argument = self.explode(stmt.argument, ctx);
+                self.emit_statement(ctx.ast.statement_throw(stmt.span, argument));
+            }
+            Statement::IfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let else_loc = stmt.alternate.is_some().then(|| self.loc());
+                let after = self.loc();
+                let test = self.explode(stmt.test, ctx);
+                self.jump_if_not(test, else_loc.unwrap_or(after), ctx);
+                self.explode_statement(stmt.consequent, None, ctx);
+                if let (Some(alternate), Some(else_loc)) = (stmt.alternate, else_loc) {
+                    self.jump(after);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None, ctx);
+                }
+                self.mark(after);
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch(stmt.unbox(), ctx),
+            Statement::TryStatement(stmt) => self.explode_try(stmt.unbox(), ctx),
+            Statement::ForOfStatement(stmt) => {
+                self.report_unsupported(
+                    "Regenerator transform does not support `yield` in `for...of` loops. Enable the for-of transform.",
+                    stmt.span,
+                );
+                self.emit_statement(Statement::ForOfStatement(stmt));
+            }
+            stmt => {
+                self.report_unsupported(
+                    "Regenerator transform does not support this statement in generator functions",
+                    stmt.span(),
+                );
+                self.emit_statement(stmt);
+            }
+        }
+    }
+
+    /// `let x = 1, y;` -> `x = 1; y = void 0;`
+    fn explode_variable_declaration(
+        &mut self,
+        decl: VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if matches!(decl.kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+        {
+            self.report_unsupported(
+                "Regenerator transform does not support `using` declarations",
+                decl.span,
+            );
+            self.emit_statement(Statement::VariableDeclaration(ctx.ast.alloc(decl)));
+            return;
+        }
+
+        for declarator in decl.declarations {
+            let value = match declarator.init {
+                Some(init) => self.explode(init, ctx),
+                // `var x;` does not reset value of `x`
+                None if decl.kind.is_var() => {
+                    if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                        self.hoist_symbol(ident.symbol_id.get().unwrap(), ctx);
+                    }
+                    continue;
+                }
+                // `let x;` in a loop resets value of `x` on each iteration
+                None => ctx.ast.void_0(SPAN),
+            };
+            let target = self.hoist_pattern(declarator.id, ctx);
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            self.emit_expression(assignment, ctx);
+        }
+    }
+
+    /// `class A {}` -> `A = class {};`
+    fn explode_class_declaration(
+        &mut self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if LeapFinder::class(&class) {
+            self.report_unsupported(
+                "Regenerator transform does not support `yield` in class declarations",
+                class.span,
+            );
+        }
+        let Some(id) = class.id.take() else {
+            self.emit_statement(Statement::ClassDeclaration(class));
+            return;
+        };
+        let target = AssignmentTarget::from(
+            ctx.ast
+                .simple_assignment_target_from_identifier_reference(self.hoist_binding(&id, ctx)),
+        );
+        class.r#type = ClassType::ClassExpression;
+        let assignment = ctx.ast.expression_assignment(
+            class.span,
+            AssignmentOperator::Assign,
+            target,
+            Expression::ClassExpression(class),
+        );
+        self.emit_expression(assignment, ctx);
+    }
+
+    /// ```js
+    /// for (x in obj) body;
+    /// ```
+    /// ->
+    /// ```js
+    /// _context.t0 = babelHelpers.regeneratorRuntime().keys(obj);
+    /// case 1:
+    ///   if ((_context.t1 = _context.t0()).done) { _context.next = 4; break; }
+    ///   x = _context.t1.value;
+    ///   body;
+    ///   _context.next = 1;
+    ///   break;
+    /// case 4:
+    /// ```
+    fn explode_for_in(
+        &mut self,
+        stmt: ForInStatement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        Self::remove_scope(stmt.scope_id.get().unwrap(), ctx);
+        let head = self.loc();
+        let after = self.loc();
+
+        let keys_temp = self.make_temp();
+        let right = self.explode(stmt.right, ctx);
+        let keys = create_runtime_call("keys", ctx.ast.vec1(Argument::from(right)), ctx);
+        self.emit_assign_temp(keys_temp, keys, ctx);
+
+        self.mark(head);
+        let current_key_temp = self.make_temp();
+        let next_key = ctx.ast.expression_call(
+            SPAN,
+            self.create_temp(keys_temp, ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.create_temp_target(current_key_temp, ctx),
+            next_key,
+        );
+        let test = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_parenthesized(SPAN, assignment),
+            ctx.ast.identifier_name(SPAN, "done"),
+            false,
+        ));
+        self.jump_if(test, after);
+
+        let target = match stmt.left {
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                let declarator = decl.declarations.remove(0);
+                self.hoist_pattern(declarator.id, ctx)
+            }
+            left => left.into_assignment_target(),
+        };
+        let value = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            self.create_temp(current_key_temp, ctx),
+            ctx.ast.identifier_name(SPAN, "value"),
+            false,
+        ));
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment, ctx);
+
+        self.leaps.push(Leap::Loop { break_loc: after, continue_loc: head, label });
+        self.explode_statement(stmt.body, None, ctx);
+        self.leaps.pop();
+        self.jump(head);
+        self.mark(after);
+    }
+
+    /// Cases are tested in order, jumping to the first which matches, or else to `default`.
+    fn explode_switch(&mut self, stmt: SwitchStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::remove_scope(stmt.scope_id.get().unwrap(), ctx);
+        let discriminant_temp = self.make_temp();
+        let discriminant = self.explode(stmt.discriminant, ctx);
+        self.emit_assign_temp(discriminant_temp, discriminant, ctx);
+
+        let after = self.loc();
+        let mut cases = stmt.cases.into_iter().collect::<Vec<_>>();
+        let case_locs = cases.iter().map(|_| self.loc()).collect::<Vec<_>>();
+        let mut default_loc = None;
+        for (case, &loc) in cases.iter_mut().zip(&case_locs) {
+            if let Some(test) = case.test.take() {
+                let test = self.explode(test, ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    self.create_temp(discriminant_temp, ctx),
+                    BinaryOperator::StrictEquality,
+                    test,
+                );
+                self.jump_if(test, loc);
+            } else {
+                default_loc = Some(loc);
+            }
+        }
+        self.jump(default_loc.unwrap_or(after));
+
+        self.leaps.push(Leap::Switch { break_loc: after });
+        for (case, loc) in cases.into_iter().zip(case_locs) {
+            self.mark(loc);
+            for stmt in case.consequent {
+                self.explode_statement(stmt, None, ctx);
+            }
+        }
+        self.leaps.pop();
+        self.mark(after);
+    }
+
+    /// The runtime is passed the locations of the `try`, `catch` and `finally` blocks,
+    /// and uses `_context.prev` to determine which block an exception was thrown in.
+    fn explode_try(&mut self, stmt: TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let after = self.loc();
+        let catch_loc = stmt.handler.is_some().then(|| self.loc());
+        let finally_loc = stmt.finalizer.is_some().then(|| self.loc());
+        let try_loc = self.current_loc();
+        self.try_entries.push(TryEntry { try_loc, catch_loc, finally_loc, after_loc: after });
+
+        self.emit(Op::SetPrev(try_loc));
+        self.explode_statement(Statement::BlockStatement(stmt.block), None, ctx);
+
+        if let (Some(handler), Some(catch_loc)) = (stmt.handler, catch_loc) {
+            self.jump(finally_loc.unwrap_or(after));
+            self.mark(catch_loc);
+            self.emit(Op::SetPrev(catch_loc));
+            let handler = handler.unbox();
+            Self::remove_scope(handler.scope_id.get().unwrap(), ctx);
+            let target = handler.param.map(|param| self.hoist_pattern(param.pattern, ctx));
+            self.emit(Op::Catch(target, try_loc));
+            self.explode_statement(Statement::BlockStatement(handler.body), None, ctx);
+        }
+
+        if let (Some(finalizer), Some(finally_loc)) = (stmt.finalizer, finally_loc) {
+            self.mark(finally_loc);
+            self.emit(Op::SetPrev(finally_loc));
+            self.explode_statement(Statement::BlockStatement(finalizer), None, ctx);
+            self.emit(Op::Finish(finally_loc));
+        }
+
+        self.mark(after);
+    }
+
+    fn find_break_loc(&self, label: Option<&Atom<'a>>) -> Option<Loc> {
+        self.leaps.iter().rev().find_map(|leap| match (leap, label) {
+            (Leap::Loop { break_loc, .. } | Leap::Switch { break_loc }, None) => Some(*break_loc),
+            (
+                Leap::Loop { break_loc, label: Some(leap_label), .. }
+                | Leap::Labeled { break_loc, label: leap_label },
+                Some(label),
+            ) if leap_label == label => Some(*break_loc),
+            _ => None,
+        })
+    }
+
+    fn find_continue_loc(&self, label: Option<&Atom<'a>>) -> Option<Loc> {
+        self.leaps.iter().rev().find_map(|leap| match leap {
+            Leap::Loop { continue_loc, label: leap_label, .. }
+                if label.map_or(true, |label| leap_label.as_ref() == Some(label)) =>
+            {
+                Some(*continue_loc)
+            }
+            _ => None,
+        })
+    }
+}
+
+// Expressions
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    /// Explode expression into operations.
+    ///
+    /// If `ignore_result` is `true`, the expression is emitted as a statement and `None` is returned.
+    /// Otherwise, returns an expression for the result.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !LeapFinder::expression(&expr) {
+            return self.finish(expr, ignore_result, ctx);
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                self.explode_expression(paren.unbox().expression, ignore_result, ctx)
+            }
+            Expression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode(member.object, ctx);
+                let expr = Expression::from(ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ));
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode(member.object, ctx);
+                let property = self.explode_via_temp(member.expression, ctx);
+                let expr = Expression::from(ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ));
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::CallExpression(call) => {
+                let expr = self.explode_call(call.unbox(), ctx);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::NewExpression(new) => {
+                let new = new.unbox();
+                let callee = self.explode_via_temp(new.callee, ctx);
+                let arguments = self.explode_arguments(new.arguments, ctx);
+                let expr = ctx.ast.expression_new(new.span, callee, arguments, NONE);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::ObjectExpression(mut object) => {
+                for prop in object.properties.iter_mut() {
+                    match prop {
+                        ObjectPropertyKind::ObjectProperty(prop) => {
+                            if prop.computed && LeapFinder::property_key(&prop.key) {
+                                self.report_unsupported(
+                                    "Regenerator transform does not support `yield` in computed keys",
+                                    prop.key.span(),
+                                );
+                            }
+                            if prop.kind == PropertyKind::Init && !prop.method {
+                                let value = ctx.ast.move_expression(&mut prop.value);
+                                prop.value = self.explode_via_temp(value, ctx);
+                                prop.shorthand = false;
+                            }
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let argument = ctx.ast.move_expression(&mut spread.argument);
+                            spread.argument = self.explode_via_temp(argument, ctx);
+                        }
+                    }
+                }
+                self.finish(Expression::ObjectExpression(object), ignore_result, ctx)
+            }
+            Expression::ArrayExpression(mut array) => {
+                let elements = ctx.ast.move_vec(&mut array.elements);
+                for element in elements {
+                    let element = match element {
+                        ArrayExpressionElement::SpreadElement(mut spread) => {
+                            let argument = ctx.ast.move_expression(&mut spread.argument);
+                            spread.argument = self.explode_via_temp(argument, ctx);
+                            ArrayExpressionElement::SpreadElement(spread)
+                        }
+                        ArrayExpressionElement::Elision(elision) => {
+                            ArrayExpressionElement::Elision(elision)
+                        }
+                        element @ match_expression!(ArrayExpressionElement) => {
+                            ArrayExpressionElement::from(
+                                self.explode_via_temp(element.into_expression(), ctx),
+                            )
+                        }
+                    };
+                    array.elements.push(element);
+                }
+                self.finish(Expression::ArrayExpression(array), ignore_result, ctx)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let sequence = sequence.unbox();
+                let last_index = sequence.expressions.len() - 1;
+                let mut result = None;
+                for (index, expr) in sequence.expressions.into_iter().enumerate() {
+                    if index == last_index {
+                        result = self.explode_expression(expr, ignore_result, ctx);
+                    } else {
+                        self.explode_expression(expr, true, ctx);
+                    }
+                }
+                result
+            }
+            Expression::LogicalExpression(logical) => {
+                // `a && b` -> `if (!(_context.t0 = a)) jump after; _context.t0 = b; after:`
+                let logical = logical.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp());
+                let left = self.explode_to_temp(result, logical.left, false, ctx).unwrap();
+                match logical.operator {
+                    LogicalOperator::And => self.jump_if_not(left, after, ctx),
+                    LogicalOperator::Or => self.jump_if(left, after),
+                    LogicalOperator::Coalesce => {
+                        let test = ctx.ast.expression_binary(
+                            SPAN,
+                            left,
+                            BinaryOperator::Inequality,
+                            ctx.ast.expression_null_literal(SPAN),
+                        );
+                        self.jump_if(test, after);
+                    }
+                }
+                self.explode_to_temp(result, logical.right, ignore_result, ctx);
+                self.mark(after);
+                result.map(|temp| self.create_temp(temp, ctx))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let conditional = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode(conditional.test, ctx);
+                self.jump_if_not(test, else_loc, ctx);
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.explode_to_temp(result, conditional.consequent, ignore_result, ctx);
+                self.jump(after);
+                self.mark(else_loc);
+                self.explode_to_temp(result, conditional.alternate, ignore_result, ctx);
+                self.mark(after);
+                result.map(|temp| self.create_temp(temp, ctx))
+            }
+            Expression::UnaryExpression(unary) => {
+                let unary = unary.unbox();
+                let argument = self.explode(unary.argument, ctx);
+                let expr = ctx.ast.expression_unary(unary.span, unary.operator, argument);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::BinaryExpression(binary) => {
+                let binary = binary.unbox();
+                let left = self.explode_via_temp(binary.left, ctx);
+                let right = self.explode_via_temp(binary.right, ctx);
+                let expr = ctx.ast.expression_binary(binary.span, left, binary.operator, right);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::AssignmentExpression(assignment)
+                if !LeapFinder::assignment_target(&assignment.left) =>
+            {
+                let expr = self.explode_assignment(assignment.unbox(), ctx);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::YieldExpression(expr) => {
+                let expr = expr.unbox();
+                let after = self.loc();
+                let argument = expr.argument.map(|argument| self.explode(argument, ctx));
+                if expr.delegate {
+                    let temp = self.make_temp();
+                    let argument = argument.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                    self.emit(Op::DelegateYield(argument, temp, after));
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.create_temp(temp, ctx));
+                }
+                self.emit(Op::Yield(argument, after));
+                self.mark(after);
+                (!ignore_result).then(|| self.create_context_property("sent", ctx))
+            }
+            Expression::TemplateLiteral(mut lit) => {
+                let expressions = ctx.ast.move_vec(&mut lit.expressions);
+                for expr in expressions {
+                    let expr = self.explode_via_temp(expr, ctx);
+                    lit.expressions.push(expr);
+                }
+                self.finish(Expression::TemplateLiteral(lit), ignore_result, ctx)
+            }
+            expr => {
+                self.report_unsupported(
+                    "Regenerator transform does not support `yield` in this position",
+                    expr.span(),
+                );
+                self.finish(expr, ignore_result, ctx)
+            }
+        }
+    }
+
+    /// Explode expression, and return expression for the result.
+    fn explode(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.explode_expression(expr, false, ctx).unwrap()
+    }
+
+    /// Explode expression, and store result in a temp, unless it is a literal.
+    ///
+    /// Used for operands which are evaluated before a sibling which contains a `yield`,
+    /// so their values are not affected by code which runs while the generator is paused.
+    fn explode_via_temp(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.explode_to_temp(None, expr, false, ctx).unwrap()
+    }
+
+    /// Explode expression, and store result in `temp` (or a new temp if `temp` is `None`
+    /// and the result is not a literal).
+    fn explode_to_temp(
+        &mut self,
+        temp: Option<usize>,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let result = self.explode_expression(expr, ignore_result, ctx)?;
+        if temp.is_none() && result.is_literal() {
+            return Some(result);
+        }
+        let temp = temp.unwrap_or_else(|| self.make_temp());
+        Some(self.emit_assign_temp(temp, result, ctx))
+    }
+
+    fn explode_arguments(
+        &mut self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        let mut new_arguments = ctx.ast.vec_with_capacity(arguments.len());
+        for argument in arguments {
+            let argument = match argument {
+                Argument::SpreadElement(mut spread) => {
+                    let argument = ctx.ast.move_expression(&mut spread.argument);
+                    spread.argument = self.explode_via_temp(argument, ctx);
+                    Argument::SpreadElement(spread)
+                }
+                argument => Argument::from(self.explode_via_temp(argument.into_expression(), ctx)),
+            };
+            new_arguments.push(argument);
+        }
+        new_arguments
+    }
+
+    /// * `obj.f(yield x)` -> `_context.t0 = obj; ...; _context.t0.f.call(_context.t0, _context.sent)`
+    /// * `f(yield x)` -> `_context.t0 = f; ...; (0, _context.t0)(_context.sent)`
+    fn explode_call(
+        &mut self,
+        call: CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_leaping_arguments = call.arguments.iter().any(LeapFinder::argument);
+        let mut this_arg = None;
+
+        let callee = match call.callee {
+            callee @ match_member_expression!(Expression) if has_leaping_arguments => {
+                // Evaluate object once, and pass it as `this`
+                let object_temp = self.make_temp();
+                let member = match callee.into_member_expression() {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        let member = member.unbox();
+                        let object =
+                            self.explode_to_temp(Some(object_temp), member.object, false, ctx);
+                        ctx.ast.member_expression_static(
+                            member.span,
+                            object.unwrap(),
+                            member.property,
+                            false,
+                        )
+                    }
+                    MemberExpression::ComputedMemberExpression(member) => {
+                        let member = member.unbox();
+                        let object =
+                            self.explode_to_temp(Some(object_temp), member.object, false, ctx);
+                        let property = self.explode_via_temp(member.expression, ctx);
+                        ctx.ast.member_expression_computed(
+                            member.span,
+                            object.unwrap(),
+                            property,
+                            false,
+                        )
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        let member = member.unbox();
+                        let object =
+                            self.explode_to_temp(Some(object_temp), member.object, false, ctx);
+                        ctx.ast.member_expression_private_field_expression(
+                            member.span,
+                            object.unwrap(),
+                            member.field,
+                            false,
+                        )
+                    }
+                };
+                this_arg = Some(self.create_temp(object_temp, ctx));
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    Expression::from(member),
+                    ctx.ast.identifier_name(SPAN, "call"),
+                    false,
+                ))
+            }
+            callee @ match_member_expression!(Expression) => self.explode(callee, ctx),
+            callee => {
+                let callee = self.explode_via_temp(callee, ctx);
+                // `_context.t0()` would be called with `_context` as `this`
+                if callee.is_member_expression() {
+                    ctx.ast.expression_sequence(
+                        SPAN,
+                        ctx.ast.vec_from_iter([ctx.ast.number_0(), callee]),
+                    )
+                } else {
+                    callee
+                }
+            }
+        };
+
+        let arguments = if has_leaping_arguments {
+            let mut arguments = self.explode_arguments(call.arguments, ctx);
+            if let Some(this_arg) = this_arg {
+                arguments.insert(0, Argument::from(this_arg));
+            }
+            arguments
+        } else {
+            call.arguments
+        };
+
+        ctx.ast.expression_call(call.span, callee, NONE, arguments, false)
+    }
+
+    /// * `x = yield y` -> `x = _context.sent`
+    /// * `x += yield y` -> `_context.t0 = x; ...; x = _context.t0 += _context.sent`
+    fn explode_assignment(
+        &mut self,
+        assignment: AssignmentExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if assignment.operator == AssignmentOperator::Assign {
+            let right = self.explode(assignment.right, ctx);
+            return ctx.ast.expression_assignment(
+                assignment.span,
+                AssignmentOperator::Assign,
+                assignment.left,
+                right,
+            );
+        }
+
+        // Read current value of target before evaluating right side
+        let (target, current_value) = match assignment.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let current_value = ctx.ast.expression_from_identifier_reference(
+                    ctx.clone_identifier_reference(&ident, ReferenceFlags::Read),
+                );
+                (AssignmentTarget::AssignmentTargetIdentifier(ident), current_value)
+            }
+            target @ match_member_expression!(AssignmentTarget) => {
+                let (object_temp, property) =
+                    self.explode_member_target(target.into_member_expression(), ctx);
+                let current_value = self.create_member(object_temp, &property, ctx);
+                let target = self.create_member(object_temp, &property, ctx);
+                (AssignmentTarget::from(target), Expression::from(current_value))
+            }
+            target => {
+                self.report_unsupported(
+                    "Regenerator transform does not support `yield` in this position",
+                    assignment.span,
+                );
+                let right = self.explode(assignment.right, ctx);
+                return ctx.ast.expression_assignment(
+                    assignment.span,
+                    assignment.operator,
+                    target,
+                    right,
+                );
+            }
+        };
+
+        let temp = self.make_temp();
+        self.emit_assign_temp(temp, current_value, ctx);
+        let right = self.explode(assignment.right, ctx);
+        let value = ctx.ast.expression_assignment(
+            SPAN,
+            assignment.operator,
+            self.create_temp_target(temp, ctx),
+            right,
+        );
+        ctx.ast.expression_assignment(assignment.span, AssignmentOperator::Assign, target, value)
+    }
+}
+
+/// Property of a member expression whose object has been stored in a temp.
+enum MemberProperty<'a> {
+    Static(IdentifierName<'a>),
+    /// Temp containing computed key
+    Computed(usize),
+    Private(PrivateIdentifier<'a>),
+}
+
+// Helpers
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    /// If `ignore_result` is `true`, emit `expr` as a statement. Otherwise, return it.
+    fn finish(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            Self::remove_read_flag(&expr, ctx);
+            self.emit_expression(expr, ctx);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// `x = 1` and `x++` only write `x` when they are statements, but also read it in other
+    /// positions (e.g. update of a `for` loop).
+    fn remove_read_flag(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let ident = match expr.without_parentheses() {
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return,
+            },
+            Expression::AssignmentExpression(assignment)
+                if assignment.operator == AssignmentOperator::Assign =>
+            {
+                match &assignment.left {
+                    AssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+        if let Some(reference_id) = ident.reference_id() {
+            *ctx.symbols_mut().get_reference_mut(reference_id).flags_mut() -= ReferenceFlags::Read;
+        }
+    }
+
+    /// Remove scope of a statement which is exploded into operations from its parent.
+    /// Scopes of nodes inside it are moved into the `switch` once cases are created.
+    fn remove_scope(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let scopes = ctx.scopes_mut();
+        if let (Some(parent_id), true) = (scopes.get_parent_id(scope_id), scopes.has_child_ids()) {
+            scopes.get_child_ids_mut(parent_id).retain(|&id| id != scope_id);
+        }
+    }
+
+    /// Store object of member expression (and computed key) in temps,
+    /// so the member expression can be both read and assigned to.
+    fn explode_member_target(
+        &mut self,
+        member: MemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (usize, MemberProperty<'a>) {
+        let object_temp = self.make_temp();
+        let property = match member {
+            MemberExpression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                self.explode_to_temp(Some(object_temp), member.object, false, ctx);
+                MemberProperty::Static(member.property)
+            }
+            MemberExpression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                self.explode_to_temp(Some(object_temp), member.object, false, ctx);
+                let property_temp = self.make_temp();
+                self.explode_to_temp(Some(property_temp), member.expression, false, ctx);
+                MemberProperty::Computed(property_temp)
+            }
+            MemberExpression::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                self.explode_to_temp(Some(object_temp), member.object, false, ctx);
+                MemberProperty::Private(member.field)
+            }
+        };
+        (object_temp, property)
+    }
+
+    /// `_context.t<object_temp>.prop` or `_context.t<object_temp>[_context.t<property_temp>]`
+    fn create_member(
+        &self,
+        object_temp: usize,
+        property: &MemberProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let object = self.create_temp(object_temp, ctx);
+        match property {
+            MemberProperty::Static(property) => {
+                ctx.ast.member_expression_static(SPAN, object, property.clone(), false)
+            }
+            MemberProperty::Computed(property_temp) => {
+                let property = self.create_temp(*property_temp, ctx);
+                ctx.ast.member_expression_computed(SPAN, object, property, false)
+            }
+            MemberProperty::Private(field) => ctx.ast.member_expression_private_field_expression(
+                SPAN,
+                object,
+                field.clone(),
+                false,
+            ),
+        }
+    }
+
+    /// `_context`
+    fn create_context(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_from_identifier_reference(self.context.create_read_reference(ctx))
+    }
+
+    /// `_context.<name>`
+    fn create_context_member(&self, name: &str, ctx: &mut TraverseCtx<'a>) -> MemberExpression<'a> {
+        let object = self.create_context(ctx);
+        let property = ctx.ast.identifier_name(SPAN, ctx.ast.atom(name));
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    }
+
+    /// `_context.<name>`
+    fn create_context_property(&self, name: &str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(self.create_context_member(name, ctx))
+    }
+
+    /// `_context.<method>(...arguments)`
+    fn create_context_call(
+        &self,
+        method: &str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.create_context_property(method, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `_context.t<temp>`
+    fn create_temp(&self, temp: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.create_context_property(&format!("t{temp}"), ctx)
+    }
+
+    /// `_context.t<temp>` as assignment target
+    fn create_temp_target(&self, temp: usize, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        AssignmentTarget::from(self.create_context_member(&format!("t{temp}"), ctx))
+    }
+
+    /// `_context.<name> = <value>;`
+    fn create_context_assignment(
+        &self,
+        name: &str,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let target = AssignmentTarget::from(self.create_context_member(name, ctx));
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        ctx.ast.statement_expression(SPAN, assignment)
+    }
+
+    fn create_loc(&self, loc: Loc, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        Self::create_number(self.locs[loc.0].expect("Location should have been marked"), ctx)
+    }
+}
+
+// Hoisting
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    /// Declare binding with `var` in generator function.
+    fn hoist_symbol(&mut self, symbol_id: SymbolId, ctx: &mut TraverseCtx<'a>) {
+        if !self.hoisted_ids.insert(symbol_id) {
+            return;
+        }
+        self.move_binding(symbol_id, ctx);
+        *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+        self.hoisted.push(symbol_id);
+    }
+
+    /// Move binding into scope of generator function.
+    ///
+    /// Binding is renamed if it would clash with another binding in the generator function,
+    /// or shadow a binding used in it.
+    fn move_binding(&mut self, symbol_id: SymbolId, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        if scope_id == self.function_scope_id {
+            return;
+        }
+
+        let mut name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+        ctx.scopes_mut().remove_binding(scope_id, &name);
+        if ctx.scopes().find_binding(self.function_scope_id, &name).is_some()
+            || ctx.scopes().root_unresolved_references().contains_key(name.as_str())
+        {
+            name = ctx.generate_uid_name(&name);
+            ctx.symbols_mut().set_name(symbol_id, name.clone());
+            self.renamed.insert(symbol_id);
+        }
+        ctx.scopes_mut().add_binding(self.function_scope_id, name, symbol_id);
+        ctx.symbols_mut().set_scope_id(symbol_id, self.function_scope_id);
+    }
+
+    /// Hoist binding, and return a write reference to it.
+    fn hoist_binding(
+        &mut self,
+        ident: &BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> IdentifierReference<'a> {
+        let symbol_id = ident.symbol_id.get().unwrap();
+        self.hoist_symbol(symbol_id, ctx);
+        let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+        ctx.create_bound_reference_id(ident.span, name, symbol_id, ReferenceFlags::Write)
+    }
+
+    /// Hoist bindings in pattern, and convert it to an assignment target.
+    ///
+    /// `{ a, b: [c] }` -> `{ a: a, b: [c] }`
+    fn hoist_pattern(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                    self.hoist_binding(&ident, ctx),
+                ))
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let mut properties = ctx.ast.vec_with_capacity(pattern.properties.len());
+                for prop in pattern.properties {
+                    let binding = self.hoist_pattern_maybe_default(prop.value, ctx);
+                    // Property form, as binding may have been renamed
+                    properties.push(
+                        ctx.ast.assignment_target_property_assignment_target_property_property(
+                            prop.span, prop.key, binding,
+                        ),
+                    );
+                }
+                let rest = pattern.rest.map(|rest| {
+                    let rest = rest.unbox();
+                    ctx.ast
+                        .assignment_target_rest(rest.span, self.hoist_pattern(rest.argument, ctx))
+                });
+                AssignmentTarget::ObjectAssignmentTarget(ctx.ast.alloc_object_assignment_target(
+                    pattern.span,
+                    properties,
+                    rest,
+                ))
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let mut elements = ctx.ast.vec_with_capacity(pattern.elements.len());
+                for element in pattern.elements {
+                    elements.push(
+                        element.map(|element| self.hoist_pattern_maybe_default(element, ctx)),
+                    );
+                }
+                let rest = pattern.rest.map(|rest| {
+                    let rest = rest.unbox();
+                    ctx.ast
+                        .assignment_target_rest(rest.span, self.hoist_pattern(rest.argument, ctx))
+                });
+                AssignmentTarget::ArrayAssignmentTarget(ctx.ast.alloc_array_assignment_target(
+                    pattern.span,
+                    elements,
+                    rest,
+                    None,
+                ))
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                // Only occurs nested in another pattern, which is handled by `hoist_pattern_maybe_default`
+                self.hoist_pattern(pattern.unbox().left, ctx)
+            }
+        }
+    }
+
+    fn hoist_pattern_maybe_default(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTargetMaybeDefault<'a> {
+        match pattern.kind {
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let binding = self.hoist_pattern(pattern.left, ctx);
+                ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+                    pattern.span,
+                    binding,
+                    pattern.right,
+                )
+            }
+            kind => {
+                let pattern = ctx.ast.binding_pattern(kind, NONE, false);
+                AssignmentTargetMaybeDefault::from(self.hoist_pattern(pattern, ctx))
+            }
+        }
+    }
+
+    /// Hoist `var` declaration, and convert declarators with initializers to assignments.
+    ///
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    fn convert_var_declaration(
+        &mut self,
+        decl: VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut assignments = ctx.ast.vec();
+        for declarator in decl.declarations {
+            match declarator.init {
+                Some(init) => {
+                    let target = self.hoist_pattern(declarator.id, ctx);
+                    assignments.push(ctx.ast.expression_assignment(
+                        declarator.span,
+                        AssignmentOperator::Assign,
+                        target,
+                        init,
+                    ));
+                }
+                None => {
+                    if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                        self.hoist_symbol(ident.symbol_id.get().unwrap(), ctx);
+                    }
+                }
+            }
+        }
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(ctx.ast.expression_sequence(decl.span, assignments)),
+        }
+    }
+
+    /// Move function declaration into generator function, so it is hoisted as normal.
+    fn hoist_function(&mut self, func: ArenaBox<'a, Function<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(id) = &func.id {
+            self.move_binding(id.symbol_id.get().unwrap(), ctx);
+        }
+        self.functions.push(Statement::FunctionDeclaration(func));
+    }
+}
+
+// Cases
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    /// Convert operations into cases of `switch` statement.
+    ///
+    /// A new case is started at each marked location. Operations following a completion
+    /// (e.g. a jump) in the same case are unreachable, and are omitted.
+    pub fn create_cases(
+        &mut self,
+        switch_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, SwitchCase<'a>> {
+        let mut cases = ctx.ast.vec();
+        let mut case_start = 0;
+        let mut consequent = ctx.ast.vec();
+        let mut is_reachable = true;
+        let ops = mem::take(&mut self.ops);
+        let len = ops.len();
+        for (index, op) in ops.into_iter().enumerate() {
+            if index > 0 && self.marks.contains(&index) {
+                let test = Self::create_number(case_start, ctx);
+                cases.push(ctx.ast.switch_case(SPAN, Some(test), consequent));
+                consequent = ctx.ast.vec();
+                case_start = index;
+                is_reachable = true;
+            }
+            if !is_reachable {
+                continue;
+            }
+            is_reachable = !op.is_completion();
+            self.emit_op(op, &mut consequent, switch_scope_id, ctx);
+        }
+        if len > 0 {
+            let test = Self::create_number(case_start, ctx);
+            cases.push(ctx.ast.switch_case(SPAN, Some(test), consequent));
+        }
+
+        // `case <len>: case "end": return _context.stop();`
+        let end = Self::create_number(len, ctx);
+        cases.push(ctx.ast.switch_case(SPAN, Some(end), ctx.ast.vec()));
+        let stop = self.create_context_call("stop", ctx.ast.vec(), ctx);
+        cases.push(ctx.ast.switch_case(
+            SPAN,
+            Some(ctx.ast.expression_string_literal(SPAN, "end")),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+        cases
+    }
+
+    /// Convert operation to statements, and push them to `consequent`.
+    fn emit_op(
+        &self,
+        op: Op<'a>,
+        consequent: &mut ArenaVec<'a, Statement<'a>>,
+        switch_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match op {
+            Op::Statement(stmt) => consequent.push(stmt),
+            Op::Jump(loc) => {
+                // `_context.next = <loc>; break;`
+                consequent.push(self.create_context_assignment(
+                    "next",
+                    self.create_loc(loc, ctx),
+                    ctx,
+                ));
+                consequent.push(ctx.ast.statement_break(SPAN, None));
+            }
+            Op::JumpIf(test, loc) => {
+                // `if (<test>) { _context.next = <loc>; break; }`
+                let scope_id = ctx.create_child_scope(switch_scope_id, ScopeFlags::empty());
+                let body = ctx.ast.vec_from_iter([
+                    self.create_context_assignment("next", self.create_loc(loc, ctx), ctx),
+                    ctx.ast.statement_break(SPAN, None),
+                ]);
+                let block = BlockStatement::new_with_scope_id(SPAN, body, scope_id);
+                let block = Statement::BlockStatement(ctx.ast.alloc(block));
+                consequent.push(ctx.ast.statement_if(SPAN, test, block, None));
+            }
+            Op::Yield(value, loc) => {
+                // `_context.next = <loc>; return <value>;`
+                consequent.push(self.create_context_assignment(
+                    "next",
+                    self.create_loc(loc, ctx),
+                    ctx,
+                ));
+                consequent.push(ctx.ast.statement_return(SPAN, value));
+            }
+            Op::DelegateYield(iterable, temp, loc) => {
+                // `return _context.delegateYield(<iterable>, "t<temp>", <loc>);`
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(iterable),
+                    Argument::from(
+                        ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&format!("t{temp}"))),
+                    ),
+                    Argument::from(self.create_loc(loc, ctx)),
+                ]);
+                let call = self.create_context_call("delegateYield", arguments, ctx);
+                consequent.push(ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            Op::Abrupt(kind, loc) => {
+                // `return _context.abrupt("break", <loc>);`
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, kind)),
+                    Argument::from(self.create_loc(loc, ctx)),
+                ]);
+                let call = self.create_context_call("abrupt", arguments, ctx);
+                consequent.push(ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            Op::Return(value) => {
+                // `return _context.abrupt("return", <value>);`
+                let mut arguments =
+                    ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, "return")));
+                if let Some(value) = value {
+                    arguments.push(Argument::from(value));
+                }
+                let call = self.create_context_call("abrupt", arguments, ctx);
+                consequent.push(ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            Op::SetPrev(loc) => {
+                consequent.push(self.create_context_assignment(
+                    "prev",
+                    self.create_loc(loc, ctx),
+                    ctx,
+                ));
+            }
+            Op::Catch(target, loc) => {
+                // `<target> = _context["catch"](<try_loc>);`
+                let callee = Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    self.create_context(ctx),
+                    ctx.ast.expression_string_literal(SPAN, "catch"),
+                    false,
+                ));
+                let arguments = ctx.ast.vec1(Argument::from(self.create_loc(loc, ctx)));
+                let mut expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                if let Some(target) = target {
+                    expr = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        target,
+                        expr,
+                    );
+                }
+                consequent.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+            Op::Finish(loc) => {
+                // `return _context.finish(<finally_loc>);`
+                let arguments = ctx.ast.vec1(Argument::from(self.create_loc(loc, ctx)));
+                let call = self.create_context_call("finish", arguments, ctx);
+                consequent.push(ctx.ast.statement_return(SPAN, Some(call)));
+            }
+        }
+    }
+
+    /// List of locations of `try` statements, passed to runtime's `wrap`.
+    ///
+    /// `[[try_loc, catch_loc, finally_loc, after_loc]]`. `catch_loc` is a hole if there is no `catch`
+    /// block, and `finally_loc` and `after_loc` are omitted if there is no `finally` block.
+    pub fn create_try_locs_list(&self, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let entries = ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs =
+                ctx.ast.vec1(ArrayExpressionElement::from(self.create_loc(entry.try_loc, ctx)));
+            match entry.catch_loc {
+                Some(catch_loc) => {
+                    locs.push(ArrayExpressionElement::from(self.create_loc(catch_loc, ctx)));
+                }
+                None => locs.push(ctx.ast.array_expression_element_elision(SPAN)),
+            }
+            if let Some(finally_loc) = entry.finally_loc {
+                locs.push(ArrayExpressionElement::from(self.create_loc(finally_loc, ctx)));
+                locs.push(ArrayExpressionElement::from(self.create_loc(entry.after_loc, ctx)));
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, locs, None))
+        }));
+        Some(ctx.ast.expression_array(SPAN, entries, None))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn create_number(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_numeric_literal(
+            SPAN,
+            value as f64,
+            value.to_string(),
+            NumberBase::Decimal,
+        )
+    }
+}
+
+/// Visitor which finds `yield`, `return`, `break` or `continue`.
+///
+/// Does not enter nested functions.
+#[derive(Default)]
+struct LeapFinder {
+    found: bool,
+}
+
+impl LeapFinder {
+    fn statement(stmt: &Statement) -> bool {
+        let mut finder = Self::default();
+        finder.visit_statement(stmt);
+        finder.found
+    }
+
+    fn expression(expr: &Expression) -> bool {
+        let mut finder = Self::default();
+        finder.visit_expression(expr);
+        finder.found
+    }
+
+    fn argument(argument: &Argument) -> bool {
+        let mut finder = Self::default();
+        finder.visit_argument(argument);
+        finder.found
+    }
+
+    fn property_key(key: &PropertyKey) -> bool {
+        let mut finder = Self::default();
+        finder.visit_property_key(key);
+        finder.found
+    }
+
+    fn assignment_target(target: &AssignmentTarget) -> bool {
+        let mut finder = Self::default();
+        finder.visit_assignment_target(target);
+        finder.found
+    }
+
+    fn class(class: &Class) -> bool {
+        let mut finder = Self::default();
+        finder.visit_class(class);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _it: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _it: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _it: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+}
+
+/// Visitor which hoists `var` declarations in a statement which is emitted unchanged.
+///
+/// `var x = 1;` -> `x = 1;`
+struct VarHoister<'e, 'a, 'ctx> {
+    emitter: &'e mut Emitter<'a, 'ctx>,
+    ctx: &'e mut TraverseCtx<'a>,
+}
+
+impl<'e, 'a, 'ctx> VisitMut<'a> for VarHoister<'e, 'a, 'ctx> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if matches!(stmt, Statement::VariableDeclaration(decl) if decl.kind.is_var()) {
+            let Statement::VariableDeclaration(decl) = self.ctx.ast.move_statement(stmt) else {
+                unreachable!()
+            };
+            let span = decl.span;
+            *stmt = match self.emitter.convert_var_declaration(decl.unbox(), self.ctx) {
+                Some(expr) => self.ctx.ast.statement_expression(span, expr),
+                None => self.ctx.ast.statement_empty(span),
+            };
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if matches!(&stmt.init, Some(ForStatementInit::VariableDeclaration(decl)) if decl.kind.is_var())
+        {
+            let Some(ForStatementInit::VariableDeclaration(decl)) = stmt.init.take() else {
+                unreachable!()
+            };
+            stmt.init = self
+                .emitter
+                .convert_var_declaration(decl.unbox(), self.ctx)
+                .map(ForStatementInit::from);
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.hoist_for_left(&mut stmt.left);
+        walk_mut::walk_for_in_statement(self, stmt);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.hoist_for_left(&mut stmt.left);
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &mut StaticBlock<'a>) {}
+}
+
+impl<'e, 'a, 'ctx> VarHoister<'e, 'a, 'ctx> {
+    /// `for (var x in obj)` -> `for (x in obj)`
+    fn hoist_for_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else {
+            return;
+        };
+        if !decl.kind.is_var() {
+            return;
+        }
+        let declarator = decl.declarations.remove(0);
+        *left = ForStatementLeft::from(self.emitter.hoist_pattern(declarator.id, self.ctx));
+    }
+}
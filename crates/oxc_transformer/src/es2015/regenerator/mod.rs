@@ -0,0 +1,679 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions into state machines, run by the regenerator runtime.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* gen() {
+//!   for (let i = 0; i < 3; i++) {
+//!     yield i;
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+//! function gen() {
+//!   var i;
+//!   return babelHelpers.regeneratorRuntime().wrap(function (_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         i = 0;
+//!       case 1:
+//!         if (!(i < 3)) {
+//!           _context.next = 7;
+//!           break;
+//!         }
+//!         _context.next = 4;
+//!         return i;
+//!       case 4:
+//!         i++;
+//!         _context.next = 1;
+//!         break;
+//!       case 7:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked);
+//! }
+//! ```
+//!
+//! The body of the generator function is moved into a function which the runtime calls each time
+//! the generator is resumed. `_context.next` records the case to resume execution at.
+//! Bindings declared in the body are hoisted into the generator function as `var`s,
+//! so their values persist between calls. Bindings are renamed if hoisting them would cause
+//! a conflict with another binding.
+//!
+//! Generator methods in objects are converted to properties with a marked function as value.
+//! Class generator methods call a marked function with their `this` and `arguments`.
+//!
+//! `this` and `arguments` refer to the generator function's `this` and `arguments`.
+//! `this` is passed to `wrap`, and `arguments` is stored in a var `_args`.
+//!
+//! ## Missing features
+//!
+//! * Async generator functions (`async function*`).
+//! * `yield` inside `for...of` loops. Enable the for-of transform, which converts them to
+//!   `for` loops, to support this.
+//! * `super` inside generator methods.
+//! * Class generator methods call a marked function which is created on each call,
+//!   so generator objects they return do not inherit from the method's `prototype`,
+//!   and the method's `length` is 0.
+//! * `let` and `const` bindings are hoisted as `var`s, so closures created in different iterations
+//!   of a loop containing a `yield` share the binding. Enable the block-scoping transform
+//!   to avoid this.
+//! * `yield` in some positions e.g. within a default value in a destructuring pattern.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator),
+//! which uses [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/transform).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-regenerator>
+//! * Regenerator runtime: <https://github.com/facebook/regenerator/blob/main/packages/runtime/runtime.js>
+//! * Generator function specification: <https://tc39.es/ecma262/#sec-generator-function-definitions>
+
+mod emit;
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, visit::walk_mut, Visit, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+    TransformCtx,
+};
+
+use emit::Emitter;
+
+pub struct Regenerator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Regenerator<'a, 'ctx> {
+    /// Transform generator function declarations and class methods.
+    ///
+    /// Generator function expressions are transformed in `exit_expression`, and object methods
+    /// in `exit_object_property`, as they are replaced with a call to `mark`.
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::is_transformable(func) {
+            return;
+        }
+
+        if func.is_function_declaration() {
+            let Some(uses_this) = self.check_function(func) else { return };
+            // `var _marked = babelHelpers.regeneratorRuntime().mark(gen);`
+            let outer_fn = func.id.as_ref().map(|id| {
+                let marked = BoundIdentifier::new_uid_in_current_scope(
+                    "marked",
+                    SymbolFlags::FunctionScopedVariable,
+                    ctx,
+                );
+                let symbol_id = id.symbol_id.get().unwrap();
+                let gen = ctx.create_bound_reference_id(
+                    SPAN,
+                    id.name.clone(),
+                    symbol_id,
+                    ReferenceFlags::Read,
+                );
+                let mark = create_runtime_call(
+                    "mark",
+                    ctx.ast.vec1(Argument::from(ctx.ast.expression_from_identifier_reference(gen))),
+                    ctx,
+                );
+                self.ctx.var_declarations.insert(
+                    marked.name.clone(),
+                    marked.symbol_id,
+                    Some(mark),
+                    ctx,
+                );
+                ctx.ast.expression_from_identifier_reference(marked.create_read_reference(ctx))
+            });
+            self.transform_function(func, outer_fn, uses_this, ctx);
+            return;
+        }
+
+        if matches!(ctx.parent(), Ancestor::MethodDefinitionValue(_)) {
+            let Some(uses_this) = self.check_function(func) else { return };
+            self.transform_class_method(func, uses_this, ctx);
+        }
+    }
+
+    /// `function* () {}` -> `babelHelpers.regeneratorRuntime().mark(function _callee() {})`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        if !Self::is_transformable(func)
+            || matches!(ctx.parent(), Ancestor::ObjectPropertyValue(prop) if *prop.method())
+        {
+            return;
+        }
+        let Some(uses_this) = self.check_function(func) else { return };
+        self.transform_function_expression(expr, uses_this, ctx);
+    }
+
+    /// `{ *g() {} }` -> `{ g: babelHelpers.regeneratorRuntime().mark(function _callee() {}) }`
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !prop.method {
+            return;
+        }
+        let Expression::FunctionExpression(func) = &prop.value else { return };
+        if !Self::is_transformable(func) {
+            return;
+        }
+        let Some(uses_this) = self.check_function(func) else { return };
+        self.transform_function_expression(&mut prop.value, uses_this, ctx);
+        prop.method = false;
+    }
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    fn is_transformable(func: &Function<'a>) -> bool {
+        func.generator && !func.r#async && func.body.is_some()
+    }
+
+    /// Check function can be transformed. Returns whether function body contains `this`.
+    ///
+    /// Reports an error and returns `None` if function body contains `super`,
+    /// which cannot be moved into another function.
+    fn check_function(&self, func: &Function<'a>) -> Option<bool> {
+        let mut finder = ThisSuperFinder::default();
+        finder.visit_function_body(func.body.as_ref().unwrap());
+        if let Some(span) = finder.super_span {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Regenerator transform does not support `super` in generator functions",
+                )
+                .with_label(span),
+            );
+            return None;
+        }
+        Some(finder.has_this)
+    }
+
+    /// Transform generator function expression, and mark it.
+    ///
+    /// `function* () {}` -> `babelHelpers.regeneratorRuntime().mark(function _callee() {})`
+    fn transform_function_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        uses_this: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::FunctionExpression(func) = expr else { unreachable!() };
+
+        // Function must have a name, to pass it to `wrap`
+        let binding = match &func.id {
+            Some(id) => {
+                BoundIdentifier { name: id.name.clone(), symbol_id: id.symbol_id.get().unwrap() }
+            }
+            None => {
+                let binding = BoundIdentifier::new_uid(
+                    "callee",
+                    func.scope_id.get().unwrap(),
+                    SymbolFlags::Function,
+                    ctx,
+                );
+                func.id = Some(binding.create_binding_identifier());
+                binding
+            }
+        };
+        let outer_fn =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        self.transform_function(func, Some(outer_fn), uses_this, ctx);
+
+        let func = ctx.ast.move_expression(expr);
+        *expr = create_runtime_call("mark", ctx.ast.vec1(Argument::from(func)), ctx);
+    }
+
+    /// Class methods cannot be replaced with a marked function, so the marked function is created
+    /// inside the method, and called with the method's `this` and `arguments`.
+    ///
+    /// `*g(x) {}` ->
+    /// `g() { return babelHelpers.regeneratorRuntime().mark(function _callee(x) {}).apply(this, arguments); }`
+    fn transform_class_method(
+        &self,
+        func: &mut Function<'a>,
+        uses_this: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Original function becomes `_callee`, and is replaced by a new function with a new scope
+        let callee_scope_id = func.scope_id.get().unwrap();
+        let class_scope_id = ctx.scopes().get_parent_id(callee_scope_id).unwrap();
+        let flags = ctx.scopes().get_flags(callee_scope_id);
+        let method_scope_id = ctx.create_child_scope(class_scope_id, flags);
+        let scopes = ctx.scopes_mut();
+        if scopes.has_child_ids() {
+            scopes.get_child_ids_mut(class_scope_id).retain(|&id| id != callee_scope_id);
+        }
+        scopes.set_parent_id(callee_scope_id, Some(method_scope_id));
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec());
+        let method = ctx.ast.function(
+            FunctionType::FunctionExpression,
+            func.span,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        );
+        method.scope_id.set(Some(method_scope_id));
+        let callee = mem::replace(func, method);
+        let mut callee = Expression::FunctionExpression(ctx.ast.alloc(callee));
+        self.transform_function_expression(&mut callee, uses_this, ctx);
+
+        // `return <callee>.apply(this, arguments);`
+        let apply = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, "apply"),
+            false,
+        ));
+        let arguments =
+            ctx.create_unbound_reference_id(SPAN, ctx.ast.atom("arguments"), ReferenceFlags::Read);
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(ctx.ast.expression_from_identifier_reference(arguments)),
+        ]);
+        let call = ctx.ast.expression_call(SPAN, apply, NONE, arguments, false);
+        func.body.as_mut().unwrap().statements.push(ctx.ast.statement_return(SPAN, Some(call)));
+    }
+
+    /// Move body of generator function into a state machine function, passed to `wrap`.
+    ///
+    /// ```js
+    /// function* gen(x) { let y = yield x; }
+    /// ```
+    /// ->
+    /// ```js
+    /// function gen(x) {
+    ///   var y;
+    ///   return babelHelpers.regeneratorRuntime().wrap(function (_context) {
+    ///     while (1) switch (_context.prev = _context.next) { /* cases */ }
+    ///   }, <outer_fn>);
+    /// }
+    /// ```
+    fn transform_function(
+        &self,
+        func: &mut Function<'a>,
+        outer_fn: Option<Expression<'a>>,
+        uses_this: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        func.generator = false;
+
+        let function_scope_id = func.scope_id.get().unwrap();
+        let strict_mode = ctx.scopes().get_flags(function_scope_id) & ScopeFlags::StrictMode;
+        let inner_scope_id =
+            ctx.create_child_scope(function_scope_id, ScopeFlags::Function | strict_mode);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let context = BoundIdentifier::new_uid(
+            "context",
+            inner_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+
+        // Convert body statements into cases
+        let body = func.body.as_mut().unwrap();
+        let mut emitter = Emitter::new(self.ctx, context.clone(), function_scope_id);
+        for stmt in ctx.ast.move_vec(&mut body.statements) {
+            emitter.explode_statement(stmt, None, ctx);
+        }
+        let mut cases = emitter.create_cases(switch_scope_id, ctx);
+        let try_locs_list = emitter.create_try_locs_list(ctx);
+
+        // Move scopes of statements into `switch`, and scopes of hoisted functions
+        // into generator function
+        let mut reparenter = ScopeReparenter::new(switch_scope_id, ctx);
+        for case in &cases {
+            reparenter.visit_switch_case(case);
+        }
+        reparenter.parent_scope_id = function_scope_id;
+        for stmt in &emitter.functions {
+            reparenter.visit_statement(stmt);
+        }
+
+        // Rename hoisted bindings, and replace `arguments`
+        let mut finalizer = Finalizer {
+            renamed: &emitter.renamed,
+            args: None,
+            function_scope_id,
+            function_depth: 0,
+            ctx,
+        };
+        for case in cases.iter_mut() {
+            finalizer.visit_switch_case(case);
+        }
+        for stmt in &mut emitter.functions {
+            finalizer.visit_statement(stmt);
+        }
+        let args = finalizer.args;
+
+        let mut statements = ctx.ast.vec();
+
+        // `var x, y, _args = arguments;`
+        let mut declarators = ctx.ast.vec_from_iter(
+            emitter.hoisted.iter().map(|&symbol_id| Self::create_declarator(symbol_id, None, ctx)),
+        );
+        if let Some(args) = args {
+            let arguments = ctx.create_unbound_reference_id(
+                SPAN,
+                ctx.ast.atom("arguments"),
+                ReferenceFlags::Read,
+            );
+            declarators.push(Self::create_declarator(
+                args.symbol_id,
+                Some(ctx.ast.expression_from_identifier_reference(arguments)),
+                ctx,
+            ));
+        }
+        if !declarators.is_empty() {
+            statements.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            )));
+        }
+
+        statements.extend(emitter.functions.drain(..));
+
+        // `function (_context) { while (1) switch (_context.prev = _context.next) { ... } }`
+        let discriminant = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(ctx.ast.member_expression_static(
+                SPAN,
+                ctx.ast.expression_from_identifier_reference(context.create_read_reference(ctx)),
+                ctx.ast.identifier_name(SPAN, "prev"),
+                false,
+            )),
+            Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                ctx.ast.expression_from_identifier_reference(context.create_read_reference(ctx)),
+                ctx.ast.identifier_name(SPAN, "next"),
+                false,
+            )),
+        );
+        let switch = ctx.ast.switch_statement(SPAN, discriminant, cases);
+        switch.scope_id.set(Some(switch_scope_id));
+        let loop_stmt = ctx.ast.statement_while(
+            SPAN,
+            ctx.ast.expression_numeric_literal(SPAN, 1.0, "1", NumberBase::Decimal),
+            Statement::SwitchStatement(ctx.ast.alloc(switch)),
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                ctx.ast.binding_pattern(
+                    ctx.ast.binding_pattern_kind_from_binding_identifier(
+                        context.create_binding_identifier(),
+                    ),
+                    NONE,
+                    false,
+                ),
+                None,
+                false,
+                false,
+            )),
+            NONE,
+        );
+        let inner_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(loop_stmt));
+        let inner_fn = ctx.ast.alloc_function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+        );
+        inner_fn.scope_id.set(Some(inner_scope_id));
+
+        // `return babelHelpers.regeneratorRuntime().wrap(<inner_fn>, <outer_fn>, this, [...]);`
+        let mut arguments = ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(Expression::FunctionExpression(inner_fn)));
+        arguments.push(Argument::from(
+            outer_fn.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN)),
+        ));
+        if uses_this {
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        } else if try_locs_list.is_some() {
+            arguments.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+        }
+        if let Some(try_locs_list) = try_locs_list {
+            arguments.push(Argument::from(try_locs_list));
+        }
+        let wrap = create_runtime_call("wrap", arguments, ctx);
+        statements.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+
+        func.body.as_mut().unwrap().statements = statements;
+    }
+
+    /// `x` or `x = <init>`
+    fn create_declarator(
+        symbol_id: SymbolId,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+        // Keep span of the original declaration, which the symbol still refers to
+        let span = ctx.symbols().get_span(symbol_id);
+        let ident = BindingIdentifier::new_with_symbol_id(span, name, symbol_id);
+        let id = ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_from_binding_identifier(ident),
+            NONE,
+            false,
+        );
+        ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, init, false)
+    }
+}
+
+/// `babelHelpers.regeneratorRuntime().<method>(...arguments)`
+fn create_runtime_call<'a>(
+    method: &'static str,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let runtime = helper_loader::call(Helper::RegeneratorRuntime, ctx.ast.vec(), ctx);
+    let callee = Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        runtime,
+        ctx.ast.identifier_name(SPAN, method),
+        false,
+    ));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Visitor which finds `this` and `super` which refer to those of the generator function.
+#[derive(Default)]
+struct ThisSuperFinder {
+    has_this: bool,
+    super_span: Option<Span>,
+}
+
+impl<'a> Visit<'a> for ThisSuperFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.has_this = true;
+    }
+
+    fn visit_super(&mut self, it: &Super) {
+        self.super_span.get_or_insert(it.span);
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    /// Only `extends` clause of a class is evaluated in the enclosing function's `this` context
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
+
+/// Visitor which moves scopes of nodes at top level of visited nodes to be children of
+/// `parent_scope_id`.
+pub(super) struct ScopeReparenter<'c, 'a> {
+    parent_scope_id: ScopeId,
+    depth: usize,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'c, 'a> ScopeReparenter<'c, 'a> {
+    pub(super) fn new(parent_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { parent_scope_id, depth: 0, ctx }
+    }
+}
+
+impl<'c, 'a> Visit<'a> for ScopeReparenter<'c, 'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            let scopes = self.ctx.scopes_mut();
+            let old_parent_id = scopes.get_parent_id(scope_id);
+            if old_parent_id != Some(self.parent_scope_id) {
+                if let (Some(old_parent_id), true) = (old_parent_id, scopes.has_child_ids()) {
+                    scopes.get_child_ids_mut(old_parent_id).retain(|&id| id != scope_id);
+                }
+                scopes.set_parent_id(scope_id, Some(self.parent_scope_id));
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Visitor which renames references to hoisted bindings which were renamed,
+/// and replaces `arguments` with `_args`.
+struct Finalizer<'r, 'c, 'a> {
+    renamed: &'r FxHashSet<SymbolId>,
+    /// `_args` var, created when first `arguments` is found
+    args: Option<BoundIdentifier<'a>>,
+    function_scope_id: ScopeId,
+    /// Depth of non-arrow functions. `arguments` inside them refers to their own `arguments`.
+    function_depth: usize,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'r, 'c, 'a> VisitMut<'a> for Finalizer<'r, 'c, 'a> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id() else { return };
+        match self.ctx.symbols().get_reference(reference_id).symbol_id() {
+            Some(symbol_id) => {
+                if self.renamed.contains(&symbol_id) {
+                    ident.name = self.ctx.ast.atom(self.ctx.symbols().get_name(symbol_id));
+                }
+            }
+            None if ident.name == "arguments" && self.function_depth == 0 => {
+                if self.args.is_none() {
+                    self.args = Some(BoundIdentifier::new_uid(
+                        "args",
+                        self.function_scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                        self.ctx,
+                    ));
+                }
+                let args = self.args.as_ref().unwrap();
+                let args = args.create_spanned_read_reference(ident.span, self.ctx);
+                self.ctx.delete_reference_for_identifier(ident);
+                *ident = args;
+            }
+            None => {}
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            if self.renamed.contains(&symbol_id) {
+                ident.name = self.ctx.ast.atom(self.ctx.symbols().get_name(symbol_id));
+            }
+        }
+    }
+
+    /// `({ x } = obj)` -> `({ x: _x } = obj)`, if `x` was renamed
+    fn visit_assignment_target_property(&mut self, prop: &mut AssignmentTargetProperty<'a>) {
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop_ident) = prop {
+            let symbol_id = prop_ident.binding.reference_id().and_then(|reference_id| {
+                self.ctx.symbols().get_reference(reference_id).symbol_id()
+            });
+            if symbol_id.is_some_and(|symbol_id| self.renamed.contains(&symbol_id)) {
+                let key = self.ctx.ast.property_key_identifier_name(
+                    prop_ident.binding.span,
+                    prop_ident.binding.name.clone(),
+                );
+                let span = prop_ident.span;
+                // Binding is renamed when the new property is walked below
+                let binding =
+                    self.ctx.clone_identifier_reference(&prop_ident.binding, ReferenceFlags::Write);
+                self.ctx.delete_reference_for_identifier(&prop_ident.binding);
+                let target = AssignmentTarget::from(
+                    self.ctx.ast.simple_assignment_target_from_identifier_reference(binding),
+                );
+                let binding = match prop_ident.init.take() {
+                    Some(init) => {
+                        self.ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, target, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *prop =
+                    self.ctx.ast.assignment_target_property_assignment_target_property_property(
+                        span, key, binding,
+                    );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, prop);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+}
@@ -0,0 +1,105 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties (`{ a }`) and method shorthand (`{ m() {} }`)
+//! in object literals to ordinary properties.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var o = { a, b, c };
+//! var obj = {
+//!   method() {
+//!     return 1;
+//!   },
+//!   __proto__,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var o = { a: a, b: b, c: c };
+//! var obj = {
+//!   method: function() {
+//!     return 1;
+//!   },
+//!   ["__proto__"]: __proto__,
+//! };
+//! ```
+//!
+//! `{ __proto__ }` defines an own property named `__proto__`, whereas `{ __proto__: __proto__ }`
+//! sets the object's prototype, so a shorthand `__proto__` becomes a computed property.
+//!
+//! Methods which contain `super` are not transformed, as `super` is not valid in a function expression.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babel.dev/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-shorthand-properties>
+//! * Object initializer specification: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::{ast::*, Visit};
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct ShorthandProperties;
+
+impl ShorthandProperties {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for ShorthandProperties {
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if prop.shorthand {
+            // `{ a }` -> `{ a: a }`
+            prop.shorthand = false;
+            if let PropertyKey::StaticIdentifier(ident) = &prop.key {
+                if ident.name == "__proto__" {
+                    // `{ __proto__ }` -> `{ ["__proto__"]: __proto__ }`
+                    let key = ctx.ast.expression_string_literal(ident.span, ident.name.clone());
+                    prop.key = ctx.ast.property_key_expression(key);
+                    prop.computed = true;
+                }
+            }
+        } else if prop.method {
+            // `{ m() {} }` -> `{ m: function() {} }`
+            let Expression::FunctionExpression(func) = &prop.value else { return };
+            let mut finder = SuperFinder { found: false };
+            finder.visit_formal_parameters(&func.params);
+            if let Some(body) = &func.body {
+                finder.visit_function_body(body);
+            }
+            if !finder.found {
+                prop.method = false;
+            }
+        }
+    }
+}
+
+/// Find `super` in a method.
+///
+/// Does not descend into nested functions or classes, which have their own `super`.
+struct SuperFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        // `class extends super.x {}` refers to outer `super`
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+}
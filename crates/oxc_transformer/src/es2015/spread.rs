@@ -0,0 +1,320 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var arr = [a, ...b, c];
+//! f(a, ...b, c);
+//! obj.method(...args);
+//! new C(...args);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var arr = [a].concat(babelHelpers.toConsumableArray(b), [c]);
+//! f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b), [c]));
+//! obj.method.apply(obj, babelHelpers.toConsumableArray(args));
+//! babelHelpers.construct(C, babelHelpers.toConsumableArray(args));
+//! ```
+//!
+//! `babelHelpers.toConsumableArray` converts any iterable to an array.
+//!
+//! When calling a method, the object is passed to `apply` as `this`. If the object is not
+//! a plain reference, it is stored in a temp var, so it is evaluated only once:
+//! `foo().method(...args)` -> `(_foo = foo()).method.apply(_foo, babelHelpers.toConsumableArray(args))`.
+//!
+//! ## Missing features
+//!
+//! * `super(...args)` calls. They are reported as errors.
+//! * Calling a method of an object which is not a plain reference, inside an optional chain,
+//!   e.g. `a?.b.c(...args)`. Storing `a?.b` in a temp var would end the chain, so these are
+//!   reported as errors too.
+//! * `loose` and `allowArrayLike` options.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-spread>
+//! * Array initializer specification: <https://tc39.es/ecma262/#sec-array-initializer>
+//! * Argument lists specification: <https://tc39.es/ecma262/#sec-argument-lists>
+
+use oxc_allocator::{CloneIn, Vec as ArenaVec};
+use oxc_ast::{ast::*, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::SPAN;
+use oxc_syntax::{operator::AssignmentOperator, reference::ReferenceFlags, symbol::SymbolFlags};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+    TransformCtx,
+};
+
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Spread<'a, 'ctx> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array)
+                if array
+                    .elements
+                    .iter()
+                    .any(|element| matches!(element, ArrayExpressionElement::SpreadElement(_))) =>
+            {
+                let span = array.span;
+                let elements = ctx.ast.move_vec(&mut array.elements);
+                let parts = elements.into_iter().map(|element| match element {
+                    ArrayExpressionElement::SpreadElement(spread) => {
+                        Part::Spread(spread.unbox().argument)
+                    }
+                    element => Part::Element(element),
+                });
+                let mut array = Self::create_array(parts, ctx);
+                if let Expression::CallExpression(call) = &mut array {
+                    call.span = span;
+                }
+                *expr = array;
+            }
+            Expression::CallExpression(call) if call.arguments.iter().any(Argument::is_spread) => {
+                self.transform_call(call, ctx);
+            }
+            // `a?.b(...args)`
+            Expression::ChainExpression(chain) => {
+                if let ChainElement::CallExpression(call) = &mut chain.expression {
+                    if call.arguments.iter().any(Argument::is_spread) {
+                        self.transform_call(call, ctx);
+                    }
+                }
+            }
+            Expression::NewExpression(new) if new.arguments.iter().any(Argument::is_spread) => {
+                // `new C(...args)` -> `babelHelpers.construct(C, babelHelpers.toConsumableArray(args))`
+                let span = new.span;
+                let callee = ctx.ast.move_expression(&mut new.callee);
+                let arguments = Self::create_arguments_array(&mut new.arguments, ctx);
+                let arguments =
+                    ctx.ast.vec_from_iter([Argument::from(callee), Argument::from(arguments)]);
+                let mut construct = helper_loader::call(Helper::Construct, arguments, ctx);
+                if let Expression::CallExpression(call) = &mut construct {
+                    call.span = span;
+                }
+                *expr = construct;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Part of a list of elements or arguments.
+enum Part<'a> {
+    /// `...x`
+    Spread(Expression<'a>),
+    /// Any other element, including holes
+    Element(ArrayExpressionElement<'a>),
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    /// `f(a, ...b)` -> `f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b)))`
+    /// `obj.f(...b)` -> `obj.f.apply(obj, babelHelpers.toConsumableArray(b))`
+    fn transform_call(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(call.callee, Expression::Super(_)) {
+            self.ctx.error(
+                OxcDiagnostic::error("Spread arguments in `super()` calls are not supported.")
+                    .with_label(call.span),
+            );
+            return;
+        }
+
+        let object = match &mut call.callee {
+            Expression::StaticMemberExpression(member) => Some(&mut member.object),
+            Expression::ComputedMemberExpression(member) => Some(&mut member.object),
+            Expression::PrivateFieldExpression(member) => Some(&mut member.object),
+            _ => None,
+        };
+        let this_arg = match object {
+            Some(object) => {
+                // `(_a$b = a?.b).c` would end the chain, so `c` is accessed even if `a` is nullish
+                if Self::is_optional_chain(object) {
+                    self.ctx.error(
+                        OxcDiagnostic::error(
+                            "Spread arguments in method calls on optional chains are not supported.",
+                        )
+                        .with_help("Store the object of the method in a variable first.")
+                        .with_label(call.span),
+                    );
+                    return;
+                }
+                self.get_this_arg(object, ctx)
+            }
+            None => ctx.ast.void_0(SPAN),
+        };
+        let arguments = Self::create_arguments_array(&mut call.arguments, ctx);
+
+        // `f?.(...args)` -> `f?.apply(void 0, args)`
+        let callee = ctx.ast.move_expression(&mut call.callee);
+        call.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, "apply"),
+            call.optional,
+        ));
+        call.optional = false;
+        call.arguments =
+            ctx.ast.vec_from_iter([Argument::from(this_arg), Argument::from(arguments)]);
+    }
+
+    /// Get `this` value for calling a method of `object`.
+    ///
+    /// If `object` may have side effects or change value, it is replaced with `(_object = object)`,
+    /// and `_object` is returned.
+    fn get_this_arg(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // `super.f(...args)` -> `super.f.apply(this, args)`
+        if matches!(object, Expression::Super(_)) {
+            return ctx.ast.expression_this(SPAN);
+        }
+        if ctx.is_static(object) {
+            return match object {
+                Expression::Identifier(ident) => ctx.ast.expression_from_identifier_reference(
+                    ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
+                ),
+                _ => object.clone_in(ctx.ast.allocator),
+            };
+        }
+
+        let symbol_id = ctx.generate_uid_in_current_scope_based_on_node(
+            object,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let binding =
+            BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id };
+        self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.create_write_reference(ctx),
+            )),
+            ctx.ast.move_expression(object),
+        );
+        *object = ctx.ast.expression_parenthesized(SPAN, assignment);
+        ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+    }
+
+    /// `true` if `expr` contains an optional link of the chain which it is part of,
+    /// e.g. `a?.b` or `a?.().b`, but not `(a?.b).c`.
+    fn is_optional_chain(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::StaticMemberExpression(member) => {
+                member.optional || Self::is_optional_chain(&member.object)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                member.optional || Self::is_optional_chain(&member.object)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                member.optional || Self::is_optional_chain(&member.object)
+            }
+            Expression::CallExpression(call) => {
+                call.optional || Self::is_optional_chain(&call.callee)
+            }
+            _ => false,
+        }
+    }
+
+    /// Convert arguments to a single expression evaluating to an array of the arguments.
+    fn create_arguments_array(
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.move_vec(arguments);
+        let parts = arguments.into_iter().map(|argument| match argument {
+            Argument::SpreadElement(spread) => Part::Spread(spread.unbox().argument),
+            argument => Part::Element(ArrayExpressionElement::from(argument.into_expression())),
+        });
+        Self::create_array(parts, ctx)
+    }
+
+    /// Create an expression evaluating to an array of `parts`.
+    ///
+    /// Runs of elements which are not spread are grouped into array literals:
+    /// `a, ...b, c, d` -> `[a].concat(babelHelpers.toConsumableArray(b), [c, d])`
+    fn create_array(
+        parts: impl Iterator<Item = Part<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arrays = vec![];
+        let mut elements: Option<ArenaVec<'a, ArrayExpressionElement<'a>>> = None;
+        for part in parts {
+            match part {
+                Part::Spread(argument) => {
+                    if let Some(elements) = elements.take() {
+                        arrays.push(ctx.ast.expression_array(SPAN, elements, None));
+                    }
+                    arrays.push(helper_loader::call(
+                        Helper::ToConsumableArray,
+                        ctx.ast.vec1(Argument::from(argument)),
+                        ctx,
+                    ));
+                }
+                Part::Element(element) => {
+                    elements.get_or_insert_with(|| ctx.ast.vec()).push(element);
+                }
+            }
+        }
+        if let Some(elements) = elements {
+            arrays.push(ctx.ast.expression_array(SPAN, elements, None));
+        }
+
+        if arrays.len() == 1 {
+            // `...a` -> `babelHelpers.toConsumableArray(a)`
+            return arrays.pop().unwrap();
+        }
+
+        // Concat onto first array literal, or onto `[]` if first part is a spread,
+        // so result is always a new array
+        let object = if matches!(arrays[0], Expression::ArrayExpression(_)) {
+            arrays.remove(0)
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec(), None)
+        };
+        Self::create_concat_call(
+            object,
+            ctx.ast.vec_from_iter(arrays.into_iter().map(Argument::from)),
+            ctx,
+        )
+    }
+
+    /// `<object>.concat(...arguments)`
+    fn create_concat_call(
+        object: Expression<'a>,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+}
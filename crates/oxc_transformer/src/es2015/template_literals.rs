@@ -0,0 +1,237 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals (`` `a${x}b` ``) to string concatenation,
+//! and tagged templates (`` tag`a${x}b` ``) to function calls.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`a${x}b\n`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(
+//!   _templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "b\n"], ["a", "b\\n"])),
+//!   x
+//! );
+//! ```
+//!
+//! Each `concat` call receives only one argument which is not a string literal,
+//! so each expression is converted to a string before the next is evaluated, as in a template literal.
+//!
+//! The strings array passed to a tag function is created once per tagged template,
+//! and cached in a top level `var`, so the tag receives the same frozen array each time
+//! the tagged template is evaluated. `raw` strings are only passed to `taggedTemplateLiteral`
+//! if they differ from the cooked strings.
+//!
+//! ## Missing features
+//!
+//! * `loose` option.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-template-literals>
+//! * Template literal specification: <https://tc39.es/ecma262/#sec-template-literals>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_syntax::symbol::SymbolFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+    TransformCtx,
+};
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// Top level vars caching strings arrays of tagged templates
+    template_objects: Vec<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, template_objects: vec![] }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for TemplateLiterals<'a, 'ctx> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.template_objects.is_empty() {
+            return;
+        }
+
+        // `var _templateObject, _templateObject2;`
+        let declarations = ctx.ast.vec_from_iter(self.template_objects.drain(..).map(|binding| {
+            let id = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                    binding.create_binding_identifier(),
+                ),
+                NONE,
+                false,
+            );
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, None, false)
+        }));
+        let stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+        self.ctx.top_level_statements.insert_statement(stmt);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => Self::transform_template_literal(expr, ctx),
+            Expression::TaggedTemplateExpression(_) => self.transform_tagged_template(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    /// `` `a${x}b${y}` `` -> `"a".concat(x, "b").concat(y)`
+    fn transform_template_literal(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(lit) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let lit = lit.unbox();
+
+        // Interleave non-empty strings and expressions
+        let mut parts = vec![];
+        let mut expressions = lit.expressions.into_iter();
+        for quasi in lit.quasis {
+            let cooked = quasi.value.cooked.unwrap_or(quasi.value.raw);
+            if !cooked.is_empty() {
+                parts.push(ctx.ast.expression_string_literal(quasi.span, cooked));
+            }
+            if let Some(expression) = expressions.next() {
+                parts.push(expression);
+            }
+        }
+
+        // First part must be a string, to be the object which `concat` is called on
+        let mut parts = parts.into_iter().peekable();
+        let mut result = match parts.next_if(|part| matches!(part, Expression::StringLiteral(_))) {
+            Some(part) => part,
+            None => ctx.ast.expression_string_literal(SPAN, ""),
+        };
+
+        let mut arguments = ctx.ast.vec();
+        let mut has_expression = false;
+        for part in parts {
+            let is_string = matches!(part, Expression::StringLiteral(_));
+            if !is_string {
+                if has_expression {
+                    result = Self::create_concat_call(result, arguments, ctx);
+                    arguments = ctx.ast.vec();
+                }
+                has_expression = true;
+            }
+            arguments.push(Argument::from(part));
+        }
+        if !arguments.is_empty() {
+            result = Self::create_concat_call(result, arguments, ctx);
+        }
+
+        match &mut result {
+            Expression::StringLiteral(string) => string.span = lit.span,
+            Expression::CallExpression(call) => call.span = lit.span,
+            _ => {}
+        }
+        *expr = result;
+    }
+
+    /// `<object>.concat(...arguments)`
+    fn create_concat_call(
+        object: Expression<'a>,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `` tag`a${x}b` `` ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "b"])), x)`
+    fn transform_tagged_template(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let tagged = tagged.unbox();
+        let quasi = tagged.quasi;
+
+        let raws_differ = quasi.quasis.iter().any(|element| {
+            element.value.cooked.as_ref().map_or(true, |cooked| *cooked != element.value.raw)
+        });
+        let mut cooked_strings = ctx.ast.vec_with_capacity(quasi.quasis.len());
+        let mut raw_strings = ctx.ast.vec_with_capacity(quasi.quasis.len());
+        for element in quasi.quasis {
+            // Cooked string is `undefined` if template contains an invalid escape sequence
+            let cooked = match element.value.cooked {
+                Some(cooked) => ctx.ast.expression_string_literal(SPAN, cooked),
+                None => ctx.ast.void_0(SPAN),
+            };
+            cooked_strings.push(ArrayExpressionElement::from(cooked));
+            if raws_differ {
+                let raw = ctx.ast.expression_string_literal(SPAN, element.value.raw);
+                raw_strings.push(ArrayExpressionElement::from(raw));
+            }
+        }
+
+        // `babelHelpers.taggedTemplateLiteral(["a", "b"])`
+        let mut arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, cooked_strings, None)));
+        if raws_differ {
+            arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raw_strings, None)));
+        }
+        let template_object = helper_loader::call(Helper::TaggedTemplateLiteral, arguments, ctx);
+
+        // `_templateObject || (_templateObject = <template_object>)`
+        let binding = BoundIdentifier::new_uid_in_root_scope(
+            "templateObject",
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.create_write_reference(ctx),
+            )),
+            template_object,
+        );
+        let template_object = ctx.ast.expression_logical(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+            LogicalOperator::Or,
+            ctx.ast.expression_parenthesized(SPAN, assignment),
+        );
+        self.template_objects.push(binding);
+
+        // `tag(<template_object>, x)`
+        let mut arguments = ctx.ast.vec_with_capacity(quasi.expressions.len() + 1);
+        arguments.push(Argument::from(template_object));
+        arguments.extend(quasi.expressions.into_iter().map(Argument::from));
+        *expr = ctx.ast.expression_call(tagged.span, tagged.tag, NONE, arguments, false);
+    }
+}
@@ -0,0 +1,120 @@
+//! ES2015: Typeof Symbol
+//!
+//! This plugin transforms `typeof` expressions, so they return `"symbol"` for polyfilled symbols.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! typeof sym === "symbol";
+//! typeof obj;
+//! typeof undeclared;
+//! typeof x === "string";
+//! ```
+//!
+//! Output:
+//! ```js
+//! babelHelpers.typeof(sym) === "symbol";
+//! babelHelpers.typeof(obj);
+//! typeof undeclared === "undefined" ? "undefined" : babelHelpers.typeof(undeclared);
+//! typeof x === "string";
+//! ```
+//!
+//! Comparisons of `typeof x` with a string other than `"symbol"` or `"object"` are not transformed,
+//! as the result is the same for polyfilled symbols.
+//!
+//! `typeof` of an unresolved identifier does not throw, whereas passing it to a function does,
+//! so the helper is only called if the identifier is defined.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-typeof-symbol](https://babel.dev/docs/babel-plugin-transform-typeof-symbol).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-typeof-symbol>
+//! * Typeof operator specification: <https://tc39.es/ecma262/#sec-typeof-operator>
+
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_syntax::{
+    operator::{BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::common::helper_loader::{self, Helper};
+
+pub struct TypeofSymbol;
+
+impl TypeofSymbol {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for TypeofSymbol {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UnaryExpression(unary) = expr else { return };
+        if unary.operator != UnaryOperator::Typeof || Self::is_safe_comparison(ctx) {
+            return;
+        }
+
+        let span = unary.span;
+        let argument = ctx.ast.move_expression(&mut unary.argument);
+
+        // `typeof undeclared` -> `typeof undeclared === "undefined" ? "undefined" : babelHelpers.typeof(undeclared)`
+        let unresolved = match &argument {
+            Expression::Identifier(ident) => ident
+                .reference_id()
+                .is_some_and(|id| ctx.symbols().get_reference(id).symbol_id().is_none()),
+            _ => false,
+        };
+        let test = if unresolved {
+            let Expression::Identifier(ident) = &argument else { unreachable!() };
+            let ident = ctx.clone_identifier_reference(ident, ReferenceFlags::Read);
+            Some(ctx.ast.expression_binary(
+                SPAN,
+                ctx.ast.expression_unary(
+                    SPAN,
+                    UnaryOperator::Typeof,
+                    ctx.ast.expression_from_identifier_reference(ident),
+                ),
+                BinaryOperator::StrictEquality,
+                ctx.ast.expression_string_literal(SPAN, "undefined"),
+            ))
+        } else {
+            None
+        };
+
+        let mut call =
+            helper_loader::call(Helper::Typeof, ctx.ast.vec1(Argument::from(argument)), ctx);
+        *expr = if let Some(test) = test {
+            let undefined = ctx.ast.expression_string_literal(SPAN, "undefined");
+            ctx.ast.expression_conditional(span, test, undefined, call)
+        } else {
+            if let Expression::CallExpression(call) = &mut call {
+                call.span = span;
+            }
+            call
+        };
+    }
+}
+
+impl TypeofSymbol {
+    /// Check if `typeof x` is compared to a string literal other than `"symbol"` or `"object"`.
+    /// e.g. `typeof x === "string"`.
+    fn is_safe_comparison(ctx: &TraverseCtx) -> bool {
+        let (operator, opposite) = match ctx.parent() {
+            Ancestor::BinaryExpressionLeft(binary) => (*binary.operator(), binary.right()),
+            Ancestor::BinaryExpressionRight(binary) => (*binary.operator(), binary.left()),
+            _ => return false,
+        };
+        operator.is_equality()
+            && matches!(
+                opposite,
+                Expression::StringLiteral(lit) if lit.value != "symbol" && lit.value != "object"
+            )
+    }
+}
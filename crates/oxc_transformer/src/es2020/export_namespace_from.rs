@@ -0,0 +1,120 @@
+//! ES2020: Export Namespace From
+//!
+//! This plugin transforms `export * as ns from "mod"` into an import of the module namespace
+//! object, and a named export of it.
+//!
+//! > This plugin is included in `preset-env`, in ES2020
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! export * as ns from "mod";
+//! export * as "some name" from "other";
+//! ```
+//!
+//! Output:
+//! ```js
+//! import * as _ns from "mod";
+//! export { _ns as ns };
+//! import * as _someName from "other";
+//! export { _someName as "some name" };
+//! ```
+//!
+//! Import attributes (`with { type: "json" }`) are moved to the import declaration.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-export-namespace-from](https://babel.dev/docs/babel-plugin-transform-export-namespace-from).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-export-namespace-from>
+//! * `export * as ns` TC39 proposal: <https://github.com/tc39/proposal-export-ns-from>
+//! * Exports specification: <https://tc39.es/ecma262/#sec-exports>
+
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::helpers::bindings::BoundIdentifier;
+
+pub struct ExportNamespaceFrom;
+
+impl ExportNamespaceFrom {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for ExportNamespaceFrom {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.body.iter().any(|stmt| {
+            matches!(
+                stmt,
+                Statement::ExportAllDeclaration(decl)
+                    if decl.exported.is_some() && decl.export_kind.is_value()
+            )
+        }) {
+            return;
+        }
+
+        let mut new_body = ctx.ast.vec_with_capacity(program.body.len() + 1);
+        for stmt in ctx.ast.move_vec(&mut program.body) {
+            match stmt {
+                Statement::ExportAllDeclaration(decl)
+                    if decl.exported.is_some() && decl.export_kind.is_value() =>
+                {
+                    let (import, export) = Self::transform_export_all(decl.unbox(), ctx);
+                    new_body.push(import);
+                    new_body.push(export);
+                }
+                stmt => new_body.push(stmt),
+            }
+        }
+        program.body = new_body;
+    }
+}
+
+impl ExportNamespaceFrom {
+    /// `export * as ns from "mod"` -> `import * as _ns from "mod"; export { _ns as ns };`
+    fn transform_export_all<'a>(
+        decl: ExportAllDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, Statement<'a>) {
+        let ExportAllDeclaration { span, exported, source, with_clause, .. } = decl;
+        let exported = exported.unwrap();
+
+        let root_scope_id = ctx.scopes().root_scope_id();
+        // Name the binding after the exported name, which may not be a valid identifier
+        let name = ctx.ast.expression_string_literal(SPAN, exported.name());
+        let symbol_id = ctx.generate_uid_based_on_node(&name, root_scope_id, SymbolFlags::Import);
+        let binding =
+            BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id };
+
+        let specifier = ctx.ast.import_declaration_specifier_import_namespace_specifier(
+            SPAN,
+            binding.create_binding_identifier(),
+        );
+        let import = ctx.ast.module_declaration_import_declaration(
+            span,
+            Some(ctx.ast.vec1(specifier)),
+            source,
+            with_clause,
+            ImportOrExportKind::Value,
+        );
+
+        let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+        let specifier = ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value);
+        let export = ctx.ast.module_declaration_export_named_declaration(
+            SPAN,
+            None,
+            ctx.ast.vec1(specifier),
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        );
+
+        (ctx.ast.statement_module_declaration(import), ctx.ast.statement_module_declaration(export))
+    }
+}
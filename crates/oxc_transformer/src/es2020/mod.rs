@@ -1,6 +1,8 @@
+mod export_namespace_from;
 mod nullish_coalescing_operator;
 mod options;
 
+pub use export_namespace_from::ExportNamespaceFrom;
 pub use nullish_coalescing_operator::NullishCoalescingOperator;
 pub use options::ES2020Options;
 use oxc_ast::ast::*;
@@ -13,15 +15,26 @@ pub struct ES2020<'a, 'ctx> {
 
     // Plugins
     nullish_coalescing_operator: NullishCoalescingOperator<'a, 'ctx>,
+    export_namespace_from: ExportNamespaceFrom,
 }
 
 impl<'a, 'ctx> ES2020<'a, 'ctx> {
     pub fn new(options: ES2020Options, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { nullish_coalescing_operator: NullishCoalescingOperator::new(ctx), options }
+        Self {
+            nullish_coalescing_operator: NullishCoalescingOperator::new(ctx),
+            export_namespace_from: ExportNamespaceFrom::new(),
+            options,
+        }
     }
 }
 
 impl<'a, 'ctx> Traverse<'a> for ES2020<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.export_namespace_from {
+            self.export_namespace_from.enter_program(program, ctx);
+        }
+    }
+
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.enter_expression(expr, ctx);
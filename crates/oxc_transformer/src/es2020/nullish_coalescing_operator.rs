@@ -20,6 +20,8 @@
 //!   : "default";
 //! ```
 //!
+//! With the `noDocumentAll` assumption, the test is `(_object$foo = object.foo) != null`.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-nullish-coalescing-operator](https://babeljs.io/docs/babel-plugin-transform-nullish-coalescing-operator).
@@ -63,7 +65,7 @@ impl<'a, 'ctx> Traverse<'a> for NullishCoalescingOperator<'a, 'ctx> {
 
         // skip creating extra reference when `left` is static
         if ctx.is_static(&logical_expr.left) {
-            *expr = Self::create_conditional_expression(
+            *expr = self.create_conditional_expression(
                 Self::clone_expression(&logical_expr.left, ctx),
                 logical_expr.left,
                 logical_expr.right,
@@ -101,7 +103,7 @@ impl<'a, 'ctx> Traverse<'a> for NullishCoalescingOperator<'a, 'ctx> {
         );
 
         let mut new_expr =
-            Self::create_conditional_expression(reference, assignment, logical_expr.right, ctx);
+            self.create_conditional_expression(reference, assignment, logical_expr.right, ctx);
 
         if is_parent_formal_parameter {
             // Replace `function (a, x = a.b ?? c) {}` to `function (a, x = (() => a.b ?? c)() ){}`
@@ -175,25 +177,33 @@ impl<'a, 'ctx> NullishCoalescingOperator<'a, 'ctx> {
     /// // Output
     /// qux = bar !== null && bar !== void 0 ? bar : "qux"
     /// //    ^^^ assignment  ^^^ reference           ^^^ default
+    ///
+    /// // Output with `noDocumentAll` assumption
+    /// qux = bar != null ? bar : "qux"
     /// ```
     ///
     /// reference and assignment are the same in this case, but they can be different
     fn create_conditional_expression(
+        &self,
         reference: Expression<'a>,
         assignment: Expression<'a>,
         default: Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
-        let op = BinaryOperator::StrictInequality;
         let null = ctx.ast.expression_null_literal(SPAN);
-        let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
-        let right = ctx.ast.expression_binary(
-            SPAN,
-            Self::clone_expression(&reference, ctx),
-            op,
-            ctx.ast.void_0(SPAN),
-        );
-        let test = ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right);
+        let test = if self.ctx.assumptions.no_document_all {
+            ctx.ast.expression_binary(SPAN, assignment, BinaryOperator::Inequality, null)
+        } else {
+            let op = BinaryOperator::StrictInequality;
+            let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
+            let right = ctx.ast.expression_binary(
+                SPAN,
+                Self::clone_expression(&reference, ctx),
+                op,
+                ctx.ast.void_0(SPAN),
+            );
+            ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+        };
 
         ctx.ast.expression_conditional(SPAN, test, reference, default)
     }
@@ -7,6 +7,9 @@ use crate::env::{can_enable_plugin, Versions};
 pub struct ES2020Options {
     #[serde(skip)]
     pub nullish_coalescing_operator: bool,
+
+    #[serde(skip)]
+    pub export_namespace_from: bool,
 }
 
 impl ES2020Options {
@@ -15,6 +18,11 @@ impl ES2020Options {
         self
     }
 
+    pub fn with_export_namespace_from(&mut self, enable: bool) -> &mut Self {
+        self.export_namespace_from = enable;
+        self
+    }
+
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
@@ -23,6 +31,11 @@ impl ES2020Options {
                 targets,
                 bugfixes,
             ),
+            export_namespace_from: can_enable_plugin(
+                "transform-export-namespace-from",
+                targets,
+                bugfixes,
+            ),
         }
     }
 }
@@ -45,6 +45,23 @@
 //! ? _obj$a$b
 //! : (_obj$a.b = c);
 //! ```
+//!
+//! ### With `pureGetters` assumption
+//!
+//! When member access is assumed to have no side effects, objects and properties which are
+//! only read through member access are not cached in temporaries:
+//!
+//! ```js
+//! a.b ??= c;
+//! obj.a.b ||= d;
+//! ```
+//!
+//! Output:
+//! ```js
+//! a.b ?? (a.b = c);
+//! obj.a.b || (obj.a.b = d);
+//! ```
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-logical-assignment-operators](https://babel.dev/docs/babel-plugin-transform-logical-assignment-operators).
@@ -341,16 +358,74 @@ impl<'a, 'ctx> LogicalAssignmentOperators<'a, 'ctx> {
             Expression::Identifier(ident) => ctx.ast.expression_from_identifier_reference(
                 ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
             ),
+            // Member expressions are only reused with `pureGetters` assumption
+            Expression::StaticMemberExpression(member) => {
+                let object = Self::clone_expression(&member.object, ctx);
+                Expression::from(ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property.clone_in(ctx.ast.allocator),
+                    member.optional,
+                ))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let object = Self::clone_expression(&member.object, ctx);
+                let expression = Self::clone_expression(&member.expression, ctx);
+                Expression::from(ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    expression,
+                    member.optional,
+                ))
+            }
             _ => expr.clone_in(ctx.ast.allocator),
         }
     }
 
+    /// Whether evaluating `expr` twice gives the same value, if getters have no side effects.
+    ///
+    /// Identifiers which are never assigned, `this`, literals, and member expressions
+    /// on those are pure.
+    fn is_pure_member_access(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                if ctx.is_static(expr) {
+                    return true;
+                }
+                // Global which is not assigned anywhere
+                let reference = ctx.symbols().get_reference(ident.reference_id().unwrap());
+                reference.symbol_id().is_none()
+                    && ctx
+                        .scopes()
+                        .root_unresolved_references()
+                        .get(ident.name.as_str())
+                        .is_some_and(|reference_ids| {
+                            reference_ids
+                                .iter()
+                                .all(|&id| !ctx.symbols().get_reference(id).is_write())
+                        })
+            }
+            Expression::ThisExpression(_) | Expression::Super(_) => true,
+            Expression::StaticMemberExpression(member) => {
+                Self::is_pure_member_access(&member.object, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                Self::is_pure_member_access(&member.object, ctx)
+                    && (member.expression.is_literal()
+                        || Self::is_pure_member_access(&member.expression, ctx))
+            }
+            _ => false,
+        }
+    }
+
     fn maybe_generate_memoised(
         &mut self,
         expr: &Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Option<IdentifierReference<'a>> {
-        if ctx.is_static(expr) {
+        if ctx.is_static(expr)
+            || (self.ctx.assumptions.pure_getters && Self::is_pure_member_access(expr, ctx))
+        {
             return None;
         }
 
@@ -1,7 +1,9 @@
 mod logical_assignment_operators;
+mod numeric_separator;
 mod options;
 
 pub use logical_assignment_operators::LogicalAssignmentOperators;
+pub use numeric_separator::NumericSeparator;
 pub use options::ES2021Options;
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
@@ -13,11 +15,16 @@ pub struct ES2021<'a, 'ctx> {
 
     // Plugins
     logical_assignment_operators: LogicalAssignmentOperators<'a, 'ctx>,
+    numeric_separator: NumericSeparator,
 }
 
 impl<'a, 'ctx> ES2021<'a, 'ctx> {
     pub fn new(options: ES2021Options, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { logical_assignment_operators: LogicalAssignmentOperators::new(ctx), options }
+        Self {
+            logical_assignment_operators: LogicalAssignmentOperators::new(ctx),
+            numeric_separator: NumericSeparator::new(),
+            options,
+        }
     }
 }
 
@@ -27,4 +34,16 @@ impl<'a, 'ctx> Traverse<'a> for ES2021<'a, 'ctx> {
             self.logical_assignment_operators.enter_expression(expr, ctx);
         }
     }
+
+    fn enter_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.numeric_separator {
+            self.numeric_separator.enter_numeric_literal(lit, ctx);
+        }
+    }
+
+    fn enter_big_int_literal(&mut self, lit: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.numeric_separator {
+            self.numeric_separator.enter_big_int_literal(lit, ctx);
+        }
+    }
 }
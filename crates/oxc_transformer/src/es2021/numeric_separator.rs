@@ -0,0 +1,60 @@
+//! ES2021: Numeric Separator
+//!
+//! This plugin removes numeric separators (`_`) from numeric and bigint literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2021
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let budget = 1_000_000_000_000;
+//! let nibbles = 0b1010_0001_1000_0101;
+//! let message = 0xA0_B0_C0;
+//! let big = 1_000n;
+//! ```
+//!
+//! Output:
+//! ```js
+//! let budget = 1000000000000;
+//! let nibbles = 0b1010000110000101;
+//! let message = 0xA0B0C0;
+//! let big = 1000n;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-numeric-separator](https://babel.dev/docs/babel-plugin-transform-numeric-separator).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-numeric-separator>
+//! * Numeric separator TC39 proposal: <https://github.com/tc39/proposal-numeric-separator>
+
+use std::borrow::Cow;
+
+use cow_utils::CowUtils;
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct NumericSeparator;
+
+impl NumericSeparator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for NumericSeparator {
+    fn enter_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Cow::Owned(raw) = lit.raw.cow_replace('_', "") {
+            lit.raw = ctx.ast.str(&raw);
+        }
+    }
+
+    fn enter_big_int_literal(&mut self, lit: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Cow::Owned(raw) = lit.raw.as_str().cow_replace('_', "") {
+            lit.raw = ctx.ast.atom(&raw);
+        }
+    }
+}
@@ -7,6 +7,9 @@ use crate::env::{can_enable_plugin, Versions};
 pub struct ES2021Options {
     #[serde(skip)]
     pub logical_assignment_operators: bool,
+
+    #[serde(skip)]
+    pub numeric_separator: bool,
 }
 
 impl ES2021Options {
@@ -15,6 +18,11 @@ impl ES2021Options {
         self
     }
 
+    pub fn with_numeric_separator(&mut self, enable: bool) -> &mut Self {
+        self.numeric_separator = enable;
+        self
+    }
+
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
@@ -23,6 +31,7 @@ impl ES2021Options {
                 targets,
                 bugfixes,
             ),
+            numeric_separator: can_enable_plugin("transform-numeric-separator", targets, bugfixes),
         }
     }
 }
@@ -0,0 +1,912 @@
+//! ES2022: Class Properties
+//!
+//! This plugin transforms class fields, private class elements (fields, methods and accessors)
+//! and `#x in obj` brand checks.
+//!
+//! > This plugin is included in `preset-env`, in ES2022
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A {
+//!   y = 2;
+//!   #x = 1;
+//!   #m() { return this.#x; }
+//!   static has(obj) { return #x in obj; }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _x = new WeakMap();
+//! var _A_brand = new WeakSet();
+//! class A {
+//!   constructor() {
+//!     babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+//!     babelHelpers.defineProperty(this, "y", 2);
+//!     babelHelpers.classPrivateFieldInitSpec(this, _x, 1);
+//!   }
+//!   static has(obj) { return _x.has(babelHelpers.checkInRHS(obj)); }
+//! }
+//! function _m() { return babelHelpers.classPrivateFieldGet2(_x, this); }
+//! ```
+//!
+//! Instance fields are initialized in the constructor in declaration order, after `super()`.
+//! Static fields are initialized after the class.
+//! With `setPublicClassFields` (`loose`) option, public fields are initialized with assignments
+//! (`this.y = 2`) instead of `babelHelpers.defineProperty`.
+//!
+//! Private instance fields are stored in a `WeakMap` per field, and static fields in an object
+//! `{ _: value }` created after the class. Private methods and accessors are moved out of the class
+//! into plain functions, and instances are branded with a `WeakSet` per class.
+//!
+//! When only `private_in` is enabled, private elements are left as is, and `#x in obj` is
+//! transformed to a check against a `WeakSet` which instances are added to in the constructor.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-class-properties](https://babel.dev/docs/babel-plugin-transform-class-properties),
+//! [@babel/plugin-transform-private-methods](https://babel.dev/docs/babel-plugin-transform-private-methods)
+//! and [@babel/plugin-transform-private-property-in-object](https://babel.dev/docs/babel-plugin-transform-private-property-in-object).
+//!
+//! Private elements are collected when entering a class, so that accesses within the class body
+//! can be transformed as they are visited. The class body is rewritten when exiting the class.
+//!
+//! ## References:
+//! * Babel plugin implementation:
+//!   <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-class-properties>
+//!   <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-private-methods>
+//!   <https://github.com/babel/babel/tree/main/packages/babel-helper-create-class-features-plugin>
+//! * Private class features TC39 proposal: <https://github.com/tc39/proposal-class-fields>
+//! * Ergonomic brand checks TC39 proposal: <https://github.com/tc39/proposal-private-fields-in-in>
+
+mod private;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, Span, SPAN};
+use oxc_syntax::{
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Traverse, TraverseCtx};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::{bindings::BoundIdentifier, constructor::insert_inits_into_constructor},
+    ES2022Options, TransformCtx,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClassPropertiesOptions {
+    /// Initialize public fields with assignments instead of `Object.defineProperty`.
+    /// Also enabled by the `setPublicClassFields` assumption.
+    #[serde(alias = "loose")]
+    pub set_public_class_fields: bool,
+}
+
+pub struct ClassProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// Options for public fields. `None` if public fields are not transformed.
+    public_fields: Option<ClassPropertiesOptions>,
+
+    /// Transform private fields, methods and accessors
+    transform_private_elements: bool,
+
+    /// Stack of classes being visited. `None` for classes which do not need transforming.
+    classes: Vec<Option<ClassInfo<'a>>>,
+
+    /// Statements to insert around class declarations
+    class_declaration_statements: Vec<ClassStatements<'a>>,
+
+    /// Expressions to evaluate around the class expression which was just exited
+    class_expression_wrapper: Option<ClassExpressionWrapper<'a>>,
+}
+
+/// Statements to insert around a class declaration.
+struct ClassStatements<'a> {
+    /// Span of the class
+    span: Span,
+    before: Vec<Statement<'a>>,
+    after: Vec<Statement<'a>>,
+}
+
+/// Expressions to evaluate around a class expression.
+///
+/// `(<before>, _Class = class {}, <after>, _Class)`
+struct ClassExpressionWrapper<'a> {
+    before: Vec<Expression<'a>>,
+    /// Temp var the class is assigned to. Must be `Some` if `after` is not empty.
+    class_binding: Option<BoundIdentifier<'a>>,
+    after: Vec<Expression<'a>>,
+}
+
+/// Key of a public field.
+enum FieldKey<'a> {
+    /// `x = 1`
+    Identifier(Atom<'a>),
+    /// `"x" = 1`, `[x] = 1`
+    Expression(Expression<'a>),
+}
+
+/// Details of a class which contains private elements or public fields to transform.
+struct ClassInfo<'a> {
+    /// Private elements declared in the class body, keyed by name (without `#`)
+    private_props: FxHashMap<Atom<'a>, PrivateProp<'a>>,
+    /// `WeakSet` which instances of the class are added to.
+    /// Used for brand checks on private methods and accessors,
+    /// and on all instance elements when only `#x in obj` is transformed.
+    brand: Option<BoundIdentifier<'a>>,
+    /// Binding referring to the class, used for brand checks on static elements.
+    /// Either the class's name, or a temp var for class expressions.
+    class_binding: Option<BoundIdentifier<'a>>,
+}
+
+#[derive(Clone)]
+struct PrivateProp<'a> {
+    is_static: bool,
+    kind: PrivatePropKind<'a>,
+}
+
+#[derive(Clone)]
+enum PrivatePropKind<'a> {
+    /// `#x = 1`. `WeakMap` for instance fields, `{ _: value }` object for static fields.
+    Field(BoundIdentifier<'a>),
+    /// `#m() {}`. Function the method is moved into.
+    Method(BoundIdentifier<'a>),
+    /// `get #a() {}` and/or `set #a(v) {}`. Functions the getter and setter are moved into.
+    Accessor { getter: Option<BoundIdentifier<'a>>, setter: Option<BoundIdentifier<'a>> },
+    /// Private element which is not transformed. Only `#x in obj` referring to it is.
+    Native,
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    pub fn new(options: &ES2022Options, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            public_fields: options.class_properties.map(|mut options| {
+                options.set_public_class_fields |= ctx.assumptions.set_public_class_fields;
+                options
+            }),
+            // Private fields are initialized in order with public fields,
+            // so have to be transformed too when public fields are.
+            transform_private_elements: options.private_methods
+                || options.class_properties.is_some(),
+            classes: vec![],
+            class_declaration_statements: vec![],
+            class_expression_wrapper: None,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ClassProperties<'a, 'ctx> {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let class_info = self.create_class_info(class, ctx);
+        self.classes.push(class_info);
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(Some(class_info)) = self.classes.pop() else { return };
+        self.transform_class(class, class_info, ctx);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::PrivateFieldExpression(_) => self.transform_private_field_get(expr, ctx),
+            Expression::AssignmentExpression(_) => self.transform_assignment(expr, ctx),
+            Expression::UpdateExpression(_) => self.transform_update(expr, ctx),
+            Expression::CallExpression(_) => self.transform_call(expr, ctx),
+            Expression::PrivateInExpression(_) => self.transform_private_in(expr, ctx),
+            Expression::ChainExpression(_) => self.transform_chain(expr, ctx),
+            _ => {}
+        }
+    }
+
+    fn enter_assignment_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.transform_assignment_target(target, ctx);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::ClassExpression(_)) {
+            return;
+        }
+        let Some(wrapper) = self.class_expression_wrapper.take() else { return };
+
+        // `class {}` -> `(<before>, _Class = class {}, <after>, _Class)`
+        let class = ctx.ast.move_expression(expr);
+        let mut expressions = ctx.ast.vec_from_iter(wrapper.before);
+        if let Some(class_binding) = wrapper.class_binding {
+            expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                    class_binding.create_write_reference(ctx),
+                )),
+                class,
+            ));
+            expressions.extend(wrapper.after);
+            expressions
+                .push(ctx.ast.expression_from_identifier_reference(
+                    class_binding.create_read_reference(ctx),
+                ));
+        } else {
+            debug_assert!(wrapper.after.is_empty());
+            expressions.push(class);
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, expressions);
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.class_declaration_statements.is_empty() {
+            return;
+        }
+
+        let class_span = |stmt: &Statement<'a>| match stmt {
+            Statement::ClassDeclaration(class) => Some(class.span),
+            Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(class.span),
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class.span),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let find = |statements: &[ClassStatements<'a>], span: Span| {
+            statements.iter().position(|statements| statements.span == span)
+        };
+
+        if !stmts
+            .iter()
+            .filter_map(class_span)
+            .any(|span| find(&self.class_declaration_statements, span).is_some())
+        {
+            return;
+        }
+
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            let index =
+                class_span(&stmt).and_then(|span| find(&self.class_declaration_statements, span));
+            if let Some(index) = index {
+                let statements = self.class_declaration_statements.swap_remove(index);
+                new_stmts.extend(statements.before);
+                new_stmts.push(stmt);
+                new_stmts.extend(statements.after);
+            } else {
+                new_stmts.push(stmt);
+            }
+        }
+        *stmts = new_stmts;
+    }
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// `true` if property is a public field which is transformed.
+    ///
+    /// TypeScript `declare` and `abstract` fields have no runtime representation.
+    fn is_transformed_public_field(&self, prop: &PropertyDefinition<'a>) -> bool {
+        self.public_fields.is_some()
+            && !matches!(prop.key, PropertyKey::PrivateIdentifier(_))
+            && !prop.declare
+            && prop.r#type == PropertyDefinitionType::PropertyDefinition
+    }
+
+    /// Collect private elements of class, and create bindings for them.
+    ///
+    /// Returns `None` if class has no private elements or public fields to transform.
+    fn create_class_info(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ClassInfo<'a>> {
+        let mut private_props: FxHashMap<Atom<'a>, PrivateProp<'a>> = FxHashMap::default();
+        let mut has_instance_brand = false;
+        let mut has_static = false;
+        let mut has_public_fields = false;
+
+        for element in &class.body.body {
+            if let ClassElement::PropertyDefinition(prop) = element {
+                if self.is_transformed_public_field(prop) {
+                    has_public_fields = true;
+                    has_static |= prop.r#static;
+                    continue;
+                }
+            }
+
+            let (ident, is_static, method_kind) = match element {
+                ClassElement::PropertyDefinition(prop) => match &prop.key {
+                    PropertyKey::PrivateIdentifier(ident) => (ident, prop.r#static, None),
+                    _ => continue,
+                },
+                ClassElement::MethodDefinition(method) => match &method.key {
+                    PropertyKey::PrivateIdentifier(ident) => {
+                        (ident, method.r#static, Some(method.kind))
+                    }
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            has_static |= is_static;
+
+            let name = ident.name.clone();
+            let kind = if self.transform_private_elements {
+                // Methods are moved into function declarations after class declarations,
+                // which are block scoped in strict mode
+                let flags = if method_kind.is_some()
+                    && class.is_declaration()
+                    && ctx.current_scope_flags().is_strict_mode()
+                {
+                    SymbolFlags::Function | SymbolFlags::BlockScopedVariable
+                } else {
+                    SymbolFlags::FunctionScopedVariable
+                };
+                let scope_id = ctx.current_scope_id();
+                match method_kind {
+                    None => PrivatePropKind::Field(BoundIdentifier::new_uid(
+                        name.as_str(),
+                        scope_id,
+                        flags,
+                        ctx,
+                    )),
+                    Some(MethodDefinitionKind::Get | MethodDefinitionKind::Set) => {
+                        let is_getter = method_kind == Some(MethodDefinitionKind::Get);
+                        let uid_name = format!("{}_{name}", if is_getter { "get" } else { "set" });
+                        let binding = BoundIdentifier::new_uid(&uid_name, scope_id, flags, ctx);
+                        let existing = private_props.remove(&name).map(|prop| prop.kind);
+                        let (getter, setter) = match existing {
+                            Some(PrivatePropKind::Accessor { getter, setter }) => (getter, setter),
+                            _ => (None, None),
+                        };
+                        if is_getter {
+                            PrivatePropKind::Accessor { getter: Some(binding), setter }
+                        } else {
+                            PrivatePropKind::Accessor { getter, setter: Some(binding) }
+                        }
+                    }
+                    Some(_) => PrivatePropKind::Method(BoundIdentifier::new_uid(
+                        name.as_str(),
+                        scope_id,
+                        flags,
+                        ctx,
+                    )),
+                }
+            } else {
+                PrivatePropKind::Native
+            };
+
+            if !is_static && !matches!(kind, PrivatePropKind::Field(_)) {
+                has_instance_brand = true;
+            }
+
+            private_props.insert(name, PrivateProp { is_static, kind });
+        }
+
+        if private_props.is_empty() && !has_public_fields {
+            return None;
+        }
+
+        let class_name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+        let brand = has_instance_brand.then(|| {
+            BoundIdentifier::new_uid(
+                &format!("{class_name}_brand"),
+                ctx.current_scope_id(),
+                SymbolFlags::FunctionScopedVariable,
+                ctx,
+            )
+        });
+
+        let class_binding =
+            if has_static { Some(self.create_class_binding(class, ctx)) } else { None };
+
+        Some(ClassInfo { private_props, brand, class_binding })
+    }
+
+    /// Get binding referring to the class from outside it.
+    ///
+    /// Class declarations use their name. Class expressions are assigned to a temp var,
+    /// as their name (if any) is not accessible outside the class.
+    fn create_class_binding(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if class.is_declaration() {
+            if let Some(id) = &class.id {
+                return BoundIdentifier {
+                    name: id.name.clone(),
+                    symbol_id: id.symbol_id.get().unwrap(),
+                };
+            }
+            // `export default class {}`. Give the class a name.
+            let binding =
+                BoundIdentifier::new_uid("Class", ctx.current_scope_id(), SymbolFlags::Class, ctx);
+            class.id = Some(binding.create_binding_identifier());
+            return binding;
+        }
+
+        let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+        let binding = BoundIdentifier::new_uid(
+            name,
+            ctx.current_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+        binding
+    }
+
+    /// Transform class body on exit.
+    ///
+    /// * Fields are initialized in the constructor (instance) or after the class (static),
+    ///   in declaration order.
+    /// * Computed keys of public fields are evaluated before the class, after the super class.
+    /// * Private methods and accessors are moved out of the class.
+    /// * Instances are added to the brand `WeakSet` in the constructor.
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        class_info: ClassInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_declaration = class.is_declaration();
+        let mut inits = vec![];
+        let mut expressions_before = vec![];
+        let mut statements_after = vec![];
+        let mut expressions_after = vec![];
+
+        if let Some(brand) = &class_info.brand {
+            // `var _A_brand = new WeakSet();`
+            let init = Self::create_new_weak_collection("WeakSet", ctx);
+            self.ctx.var_declarations.insert(brand.name.clone(), brand.symbol_id, Some(init), ctx);
+
+            let init = if self.transform_private_elements {
+                // `babelHelpers.classPrivateMethodInitSpec(this, _A_brand)`
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(ctx.ast.expression_this(SPAN)),
+                    Argument::from(
+                        ctx.ast
+                            .expression_from_identifier_reference(brand.create_read_reference(ctx)),
+                    ),
+                ]);
+                helper_loader::call(Helper::ClassPrivateMethodInitSpec, arguments, ctx)
+            } else {
+                // `_A_brand.add(this)`
+                let object =
+                    ctx.ast.expression_from_identifier_reference(brand.create_read_reference(ctx));
+                let callee = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    ctx.ast.identifier_name(SPAN, "add"),
+                    false,
+                ));
+                let arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN)));
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            };
+            inits.push(init);
+        }
+
+        if self.transform_private_elements {
+            let elements = ctx.ast.move_vec(&mut class.body.body);
+            for element in elements {
+                match element {
+                    ClassElement::PropertyDefinition(prop)
+                        if self.is_transformed_public_field(&prop) =>
+                    {
+                        let prop = prop.unbox();
+                        let key = self.create_field_key(
+                            prop.key,
+                            is_declaration,
+                            &mut expressions_before,
+                            ctx,
+                        );
+                        let mut value = prop.value.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+
+                        if prop.r#static {
+                            // `this` in static initializers refers to the class
+                            let class_binding = class_info.class_binding.as_ref().unwrap();
+                            ReplaceThis { replacement: class_binding, ctx }
+                                .visit_expression(&mut value);
+
+                            let target = ctx.ast.expression_from_identifier_reference(
+                                class_binding.create_read_reference(ctx),
+                            );
+                            let init = self.create_field_init(target, key, value, ctx);
+                            if is_declaration {
+                                statements_after.push(ctx.ast.statement_expression(SPAN, init));
+                            } else {
+                                expressions_after.push(init);
+                            }
+                        } else {
+                            let target = ctx.ast.expression_this(SPAN);
+                            inits.push(self.create_field_init(target, key, value, ctx));
+                        }
+                    }
+                    ClassElement::PropertyDefinition(prop)
+                        if matches!(prop.key, PropertyKey::PrivateIdentifier(_)) =>
+                    {
+                        let prop = prop.unbox();
+                        let PropertyKey::PrivateIdentifier(ident) = &prop.key else {
+                            unreachable!()
+                        };
+                        let PrivatePropKind::Field(binding) =
+                            &class_info.private_props[&ident.name].kind
+                        else {
+                            unreachable!()
+                        };
+                        let mut value = prop.value.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+
+                        if prop.r#static {
+                            // `this` in static initializers refers to the class
+                            let replacement = class_info.class_binding.as_ref().unwrap();
+                            ReplaceThis { replacement, ctx }.visit_expression(&mut value);
+
+                            // `{ _: value }`
+                            let object = ctx.ast.expression_object(
+                                SPAN,
+                                ctx.ast.vec1(ctx.ast.object_property_kind_object_property(
+                                    SPAN,
+                                    PropertyKind::Init,
+                                    ctx.ast.property_key_identifier_name(SPAN, "_"),
+                                    value,
+                                    None,
+                                    false,
+                                    false,
+                                    false,
+                                )),
+                                None,
+                            );
+                            if is_declaration {
+                                // `var _x = { _: value };`
+                                statements_after
+                                    .push(Self::create_var_statement(binding, object, ctx));
+                            } else {
+                                // `var _x;` + `_x = { _: value }`
+                                self.ctx.var_declarations.insert(
+                                    binding.name.clone(),
+                                    binding.symbol_id,
+                                    None,
+                                    ctx,
+                                );
+                                expressions_after.push(ctx.ast.expression_assignment(
+                                    SPAN,
+                                    AssignmentOperator::Assign,
+                                    AssignmentTarget::from(
+                                        ctx.ast.simple_assignment_target_from_identifier_reference(
+                                            binding.create_write_reference(ctx),
+                                        ),
+                                    ),
+                                    object,
+                                ));
+                            }
+                        } else {
+                            // `var _x = new WeakMap();`
+                            let init = Self::create_new_weak_collection("WeakMap", ctx);
+                            self.ctx.var_declarations.insert(
+                                binding.name.clone(),
+                                binding.symbol_id,
+                                Some(init),
+                                ctx,
+                            );
+                            // `babelHelpers.classPrivateFieldInitSpec(this, _x, value)`
+                            let arguments = ctx.ast.vec_from_iter([
+                                Argument::from(ctx.ast.expression_this(SPAN)),
+                                Argument::from(ctx.ast.expression_from_identifier_reference(
+                                    binding.create_read_reference(ctx),
+                                )),
+                                Argument::from(value),
+                            ]);
+                            inits.push(helper_loader::call(
+                                Helper::ClassPrivateFieldInitSpec,
+                                arguments,
+                                ctx,
+                            ));
+                        }
+                    }
+                    ClassElement::MethodDefinition(method)
+                        if matches!(method.key, PropertyKey::PrivateIdentifier(_)) =>
+                    {
+                        let method = method.unbox();
+                        let PropertyKey::PrivateIdentifier(ident) = &method.key else {
+                            unreachable!()
+                        };
+                        let binding = match &class_info.private_props[&ident.name].kind {
+                            PrivatePropKind::Method(binding) => binding,
+                            PrivatePropKind::Accessor { getter, setter } => {
+                                if method.kind == MethodDefinitionKind::Get {
+                                    getter.as_ref().unwrap()
+                                } else {
+                                    setter.as_ref().unwrap()
+                                }
+                            }
+                            _ => unreachable!(),
+                        };
+                        let mut function = method.value;
+                        function.id = Some(binding.create_binding_identifier());
+                        let scope_id = function.scope_id.get().unwrap();
+                        Self::move_function_scope(scope_id, ctx);
+                        if method.kind.is_accessor() {
+                            // Getters and setters are called by helpers with object as 1st argument.
+                            // `get #a() { return this.x }` -> `function _get_a(_this) { return _this.x }`
+                            let this_binding = BoundIdentifier::new_uid(
+                                "this",
+                                scope_id,
+                                SymbolFlags::FunctionScopedVariable,
+                                ctx,
+                            );
+                            if let Some(body) = function.body.as_mut() {
+                                ReplaceThis { replacement: &this_binding, ctx }
+                                    .visit_function_body(body);
+                            }
+                            let pattern = ctx.ast.binding_pattern(
+                                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                                    this_binding.create_binding_identifier(),
+                                ),
+                                NONE,
+                                false,
+                            );
+                            function
+                                .params
+                                .items
+                                .insert(0, ctx.ast.plain_formal_parameter(SPAN, pattern));
+                        }
+                        if is_declaration {
+                            // `function _m() {}`
+                            function.r#type = FunctionType::FunctionDeclaration;
+                            statements_after.push(Statement::FunctionDeclaration(function));
+                        } else {
+                            // `var _m = function _m() {};`
+                            function.r#type = FunctionType::FunctionExpression;
+                            let init = Expression::FunctionExpression(function);
+                            self.ctx.var_declarations.insert(
+                                binding.name.clone(),
+                                binding.symbol_id,
+                                Some(init),
+                                ctx,
+                            );
+                        }
+                    }
+                    element => class.body.body.push(element),
+                }
+            }
+        }
+
+        if !inits.is_empty() {
+            if let Err(span) = insert_inits_into_constructor(class, inits, ctx) {
+                self.ctx.error(OxcDiagnostic::error(
+                    "Class properties transform does not support derived class constructors without a `super()` call.",
+                ).with_label(span));
+            }
+        }
+
+        // Computed keys are evaluated after the super class, so it must be evaluated before them.
+        // `class A extends B() { [k()] = 1 }` -> `_B = B(), _k = k()` + `class A extends _B {}`
+        if !expressions_before.is_empty() {
+            if let Some(super_class) = &mut class.super_class {
+                if !ctx.is_static(super_class) {
+                    let super_class_expr = ctx.ast.move_expression(super_class);
+                    let (assignment, reference) =
+                        self.create_temp_before_class(super_class_expr, is_declaration, ctx);
+                    *super_class = reference;
+                    expressions_before.insert(0, assignment);
+                }
+            }
+        }
+
+        if is_declaration {
+            if !expressions_before.is_empty() || !statements_after.is_empty() {
+                let before = expressions_before
+                    .into_iter()
+                    .map(|expr| ctx.ast.statement_expression(SPAN, expr))
+                    .collect();
+                self.class_declaration_statements.push(ClassStatements {
+                    span: class.span,
+                    before,
+                    after: statements_after,
+                });
+            }
+        } else if !expressions_before.is_empty() || class_info.class_binding.is_some() {
+            // Class binding must be assigned even if there's nothing to evaluate after the class,
+            // as static elements are brand-checked against it
+            self.class_expression_wrapper = Some(ClassExpressionWrapper {
+                before: expressions_before,
+                class_binding: class_info.class_binding,
+                after: expressions_after,
+            });
+        }
+    }
+
+    /// Convert key of a public field.
+    ///
+    /// Computed keys which are not literals are evaluated once, before the class,
+    /// and stored in a temp var. `[foo()] = 1` -> `_foo = foo()` + `this[_foo] = 1`.
+    fn create_field_key(
+        &self,
+        key: PropertyKey<'a>,
+        is_declaration: bool,
+        expressions_before: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> FieldKey<'a> {
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                return FieldKey::Identifier(ident.name.clone())
+            }
+            key => key.into_expression(),
+        };
+        if key.is_literal() {
+            return FieldKey::Expression(key);
+        }
+
+        let (assignment, reference) = self.create_temp_before_class(key, is_declaration, ctx);
+        expressions_before.push(assignment);
+        FieldKey::Expression(reference)
+    }
+
+    /// Store an expression evaluated before the class in a temp var.
+    ///
+    /// Returns `(_foo = foo(), _foo)`.
+    fn create_temp_before_class(
+        &self,
+        expr: Expression<'a>,
+        is_declaration: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        let symbol_id = ctx.generate_uid_in_current_scope_based_on_node(
+            &expr,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let binding =
+            BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id };
+        self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+
+        // Assignments before class expressions are in a sequence, so their value is read
+        let flags =
+            if is_declaration { ReferenceFlags::Write } else { ReferenceFlags::read_write() };
+        let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+            binding.create_spanned_reference(SPAN, flags, ctx),
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            expr,
+        );
+        let reference =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        (assignment, reference)
+    }
+
+    /// Initialize a public field.
+    ///
+    /// * `babelHelpers.defineProperty(<target>, "x", <value>)`
+    /// * `<target>.x = <value>` with `setPublicClassFields`
+    fn create_field_init(
+        &self,
+        target: Expression<'a>,
+        key: FieldKey<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let set_public_class_fields =
+            self.public_fields.is_some_and(|options| options.set_public_class_fields);
+        if set_public_class_fields {
+            let member = match key {
+                FieldKey::Identifier(name) => ctx.ast.member_expression_static(
+                    SPAN,
+                    target,
+                    ctx.ast.identifier_name(SPAN, name),
+                    false,
+                ),
+                FieldKey::Expression(key) => {
+                    ctx.ast.member_expression_computed(SPAN, target, key, false)
+                }
+            };
+            ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(SimpleAssignmentTarget::from(member)),
+                value,
+            )
+        } else {
+            let key = match key {
+                FieldKey::Identifier(name) => ctx.ast.expression_string_literal(SPAN, name),
+                FieldKey::Expression(key) => key,
+            };
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(target),
+                Argument::from(key),
+                Argument::from(value),
+            ]);
+            helper_loader::call(Helper::DefineProperty, arguments, ctx)
+        }
+    }
+
+    /// Move scope of a method out of the class, into the current scope.
+    ///
+    /// The function is no longer a method, and is only in strict mode if the current scope is.
+    fn move_function_scope(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let parent_scope_id = ctx.current_scope_id();
+        let strict_mode = ctx.current_scope_flags() & ScopeFlags::StrictMode;
+        let scopes = ctx.scopes_mut();
+        if scopes.has_child_ids() {
+            if let Some(old_parent_id) = scopes.get_parent_id(scope_id) {
+                scopes.get_child_ids_mut(old_parent_id).retain(|&id| id != scope_id);
+            }
+        }
+        scopes.set_parent_id(scope_id, Some(parent_scope_id));
+        *scopes.get_flags_mut(scope_id) = ScopeFlags::Function | strict_mode;
+    }
+
+    /// `new WeakMap()` / `new WeakSet()`
+    fn create_new_weak_collection(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+        let ident =
+            ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+        let callee = ctx.ast.expression_from_identifier_reference(ident);
+        ctx.ast.expression_new(SPAN, callee, ctx.ast.vec(), NONE)
+    }
+
+    /// `var <binding> = <init>;`
+    fn create_var_statement(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let id = ctx
+            .ast
+            .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier());
+        let id = ctx.ast.binding_pattern(id, NONE, false);
+        let declarator =
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+}
+
+/// Replace `this` with a reference to a binding.
+///
+/// Used for static initializers moved outside the class (`this` is the class),
+/// and getters and setters (`this` is passed as 1st argument).
+///
+/// Does not descend into functions or classes, which have their own `this`.
+struct ReplaceThis<'a, 'b> {
+    replacement: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for ReplaceThis<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            let ident = self.replacement.create_spanned_read_reference(this.span, self.ctx);
+            *expr = self.ctx.ast.expression_from_identifier_reference(ident);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
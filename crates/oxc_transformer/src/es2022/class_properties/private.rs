@@ -0,0 +1,714 @@
+//! Transform accesses to private class elements.
+//!
+//! * `obj.#x` -> `babelHelpers.classPrivateFieldGet2(_x, obj)`
+//! * `obj.#x = value` -> `babelHelpers.classPrivateFieldSet2(_x, obj, value)`
+//! * `obj.#m(...args)` -> `babelHelpers.assertClassBrand(_A_brand, obj, _m).call(obj, ...args)`
+//! * `#x in obj` -> `_x.has(babelHelpers.checkInRHS(obj))`
+//! * `[obj.#x] = arr` -> `[babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, obj])._] = arr`
+//! * `obj?.#x` -> `obj === null || obj === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, obj)`
+
+use oxc_allocator::CloneIn;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolFlags};
+use oxc_traverse::TraverseCtx;
+
+use super::{ClassProperties, PrivateProp, PrivatePropKind};
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+};
+
+/// Private element, with the bindings of the class which declares it.
+struct ResolvedPrivateProp<'a> {
+    prop: PrivateProp<'a>,
+    brand: Option<BoundIdentifier<'a>>,
+    class_binding: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a> ResolvedPrivateProp<'a> {
+    /// Binding to brand check objects against when accessing methods and accessors.
+    /// The class for static elements, otherwise the brand `WeakSet`.
+    fn brand_check_target(&self) -> &BoundIdentifier<'a> {
+        if self.prop.is_static {
+            self.class_binding.as_ref().unwrap()
+        } else {
+            self.brand.as_ref().unwrap()
+        }
+    }
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// Find private element with `name` in the innermost class which declares it.
+    fn resolve_private_prop(&self, name: &Atom<'a>) -> Option<ResolvedPrivateProp<'a>> {
+        self.classes.iter().rev().flatten().find_map(|class_info| {
+            class_info.private_props.get(name).map(|prop| ResolvedPrivateProp {
+                prop: prop.clone(),
+                brand: class_info.brand.clone(),
+                class_binding: class_info.class_binding.clone(),
+            })
+        })
+    }
+
+    /// Find private element with `name`, if it is being transformed.
+    fn resolve_transformed_private_prop(&self, name: &Atom<'a>) -> Option<ResolvedPrivateProp<'a>> {
+        self.resolve_private_prop(name)
+            .filter(|resolved| !matches!(resolved.prop.kind, PrivatePropKind::Native))
+    }
+
+    /// `obj.#x`
+    pub(super) fn transform_private_field_get(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateFieldExpression(field_expr) = expr else { unreachable!() };
+        let Some(resolved) = self.resolve_transformed_private_prop(&field_expr.field.name) else {
+            return;
+        };
+
+        let Expression::PrivateFieldExpression(field_expr) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let field_expr = field_expr.unbox();
+        *expr = Self::create_private_get(&resolved, &field_expr.field.name, field_expr.object, ctx);
+    }
+
+    /// `obj.#x = value`, `obj.#x += value`, `obj.#x ||= value`
+    pub(super) fn transform_assignment(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign_expr) = expr else { unreachable!() };
+        let AssignmentTarget::PrivateFieldExpression(field_expr) = &assign_expr.left else {
+            return;
+        };
+        let Some(resolved) = self.resolve_transformed_private_prop(&field_expr.field.name) else {
+            return;
+        };
+
+        let Expression::AssignmentExpression(assign_expr) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let assign_expr = assign_expr.unbox();
+        let AssignmentTarget::PrivateFieldExpression(field_expr) = assign_expr.left else {
+            unreachable!()
+        };
+        let field_expr = field_expr.unbox();
+        let name = &field_expr.field.name;
+        let object = field_expr.object;
+        let value = assign_expr.right;
+
+        *expr = if assign_expr.operator == AssignmentOperator::Assign {
+            // `set(obj, value)`
+            Self::create_private_set(&resolved, name, object, value, ctx)
+        } else if let Some(operator) = assign_expr.operator.to_logical_operator() {
+            // `get(_obj = obj) || set(_obj, value)`
+            let (object1, object2) = self.duplicate_object(object, ctx);
+            let get = Self::create_private_get(&resolved, name, object1, ctx);
+            let set = Self::create_private_set(&resolved, name, object2, value, ctx);
+            ctx.ast.expression_logical(SPAN, get, operator, set)
+        } else {
+            // `set(_obj = obj, get(_obj) + value)`
+            let operator = assign_expr.operator.to_binary_operator().unwrap();
+            let (object1, object2) = self.duplicate_object(object, ctx);
+            let get = Self::create_private_get(&resolved, name, object2, ctx);
+            let value = ctx.ast.expression_binary(SPAN, get, operator, value);
+            Self::create_private_set(&resolved, name, object1, value, ctx)
+        };
+    }
+
+    /// `++obj.#x`, `obj.#x--`
+    pub(super) fn transform_update(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::UpdateExpression(update_expr) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::PrivateFieldExpression(field_expr) = &update_expr.argument
+        else {
+            return;
+        };
+        let Some(resolved) = self.resolve_transformed_private_prop(&field_expr.field.name) else {
+            return;
+        };
+
+        let Expression::UpdateExpression(update_expr) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let update_expr = update_expr.unbox();
+        let SimpleAssignmentTarget::PrivateFieldExpression(field_expr) = update_expr.argument
+        else {
+            unreachable!()
+        };
+        let field_expr = field_expr.unbox();
+        let name = &field_expr.field.name;
+        let (object1, object2) = self.duplicate_object(field_expr.object, ctx);
+
+        // Value is read into a temp var and updated with `++` / `--`, to convert it to a numeric
+        // with the same semantics as the original, including for `BigInt`s.
+        // `_x = get(_obj)`
+        let temp = self.create_temp_var(name.as_str(), ctx);
+        let get = Self::create_private_get(&resolved, name, object2, ctx);
+        let assign_temp = Self::create_assignment(&temp, get, ctx);
+        let update_temp = |prefix, ctx: &mut TraverseCtx<'a>| {
+            let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+                temp.create_spanned_reference(
+                    SPAN,
+                    ReferenceFlags::Read | ReferenceFlags::Write,
+                    ctx,
+                ),
+            );
+            ctx.ast.expression_update(SPAN, update_expr.operator, prefix, target)
+        };
+
+        *expr = if update_expr.prefix {
+            // `set(_obj = obj, (_x = get(_obj), ++_x))`
+            let value = ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_iter([assign_temp, update_temp(true, ctx)]),
+            );
+            Self::create_private_set(&resolved, name, object1, value, ctx)
+        } else {
+            // `(set(_obj = obj, (_x = get(_obj), _x2 = _x++, _x)), _x2)`
+            let old_value = self.create_temp_var(name.as_str(), ctx);
+            let assign_old_value =
+                Self::create_assignment(&old_value, update_temp(false, ctx), ctx);
+            let value = ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_iter([
+                    assign_temp,
+                    assign_old_value,
+                    ctx.ast.expression_from_identifier_reference(temp.create_read_reference(ctx)),
+                ]),
+            );
+            let set = Self::create_private_set(&resolved, name, object1, value, ctx);
+            ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_iter([
+                    set,
+                    ctx.ast
+                        .expression_from_identifier_reference(old_value.create_read_reference(ctx)),
+                ]),
+            )
+        };
+    }
+
+    /// `obj.#m(...args)` -> `get(_obj = obj).call(_obj, ...args)`
+    pub(super) fn transform_call(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call_expr) = expr else { unreachable!() };
+        let Expression::PrivateFieldExpression(field_expr) = &call_expr.callee else { return };
+        let Some(resolved) = self.resolve_transformed_private_prop(&field_expr.field.name) else {
+            return;
+        };
+
+        let Expression::PrivateFieldExpression(field_expr) =
+            ctx.ast.move_expression(&mut call_expr.callee)
+        else {
+            unreachable!()
+        };
+        let field_expr = field_expr.unbox();
+        let (object1, object2) = self.duplicate_object(field_expr.object, ctx);
+        let get = Self::create_private_get(&resolved, &field_expr.field.name, object1, ctx);
+        call_expr.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            get,
+            ctx.ast.identifier_name(SPAN, "call"),
+            false,
+        ));
+        call_expr.arguments.insert(0, Argument::from(object2));
+    }
+
+    /// `#x in obj`
+    pub(super) fn transform_private_in(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateInExpression(private_in) = expr else { unreachable!() };
+        let Some(resolved) = self.resolve_private_prop(&private_in.left.name) else { return };
+
+        let Expression::PrivateInExpression(private_in) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let object = private_in.unbox().right;
+
+        // `babelHelpers.checkInRHS(obj)`
+        let checked =
+            helper_loader::call(Helper::CheckInRHS, ctx.ast.vec1(Argument::from(object)), ctx);
+
+        *expr = if resolved.prop.is_static {
+            // `babelHelpers.checkInRHS(obj) === A`
+            let class_binding = resolved.class_binding.as_ref().unwrap();
+            let class = ctx
+                .ast
+                .expression_from_identifier_reference(class_binding.create_read_reference(ctx));
+            ctx.ast.expression_binary(SPAN, checked, BinaryOperator::StrictEquality, class)
+        } else {
+            // `_x.has(babelHelpers.checkInRHS(obj))` / `_A_brand.has(babelHelpers.checkInRHS(obj))`
+            let set = match &resolved.prop.kind {
+                PrivatePropKind::Field(binding) => binding,
+                _ => resolved.brand.as_ref().unwrap(),
+            };
+            let set = ctx.ast.expression_from_identifier_reference(set.create_read_reference(ctx));
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                set,
+                ctx.ast.identifier_name(SPAN, "has"),
+                false,
+            ));
+            ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(checked)),
+                false,
+            )
+        };
+    }
+
+    /// `[obj.#x] = arr`, `({ a: obj.#x } = o)`, `for (obj.#x of arr)`
+    ///
+    /// Assignments to private elements in destructuring patterns and `for` heads are converted to
+    /// assignments to the `_` property of an object which sets the element.
+    pub(super) fn transform_assignment_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AssignmentTarget::PrivateFieldExpression(field_expr) = target else { return };
+        let Some(resolved) = self.resolve_transformed_private_prop(&field_expr.field.name) else {
+            return;
+        };
+
+        let AssignmentTarget::PrivateFieldExpression(field_expr) =
+            ctx.ast.move_assignment_target(target)
+        else {
+            unreachable!()
+        };
+        let field_expr = field_expr.unbox();
+        let name = &field_expr.field.name;
+        let object = field_expr.object;
+
+        let read = |binding: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>| {
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+        };
+        let (helper, arguments, object) = match &resolved.prop.kind {
+            PrivatePropKind::Field(_) if resolved.prop.is_static => {
+                // `babelHelpers.assertClassBrand(A, obj, _x)._`
+                let Expression::StaticMemberExpression(member) =
+                    Self::create_private_get(&resolved, name, object, ctx)
+                else {
+                    unreachable!()
+                };
+                *target = AssignmentTarget::StaticMemberExpression(member);
+                return;
+            }
+            PrivatePropKind::Field(binding) => {
+                // `babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, obj])._`
+                (Helper::ClassPrivateFieldSet2, vec![read(binding, ctx), object], None)
+            }
+            PrivatePropKind::Accessor { setter: Some(setter), .. } => {
+                // `babelHelpers.toSetter(babelHelpers.classPrivateSetter, [_A_brand, _set_a, obj])._`
+                let brand = read(resolved.brand_check_target(), ctx);
+                (Helper::ClassPrivateSetter, vec![brand, read(setter, ctx), object], None)
+            }
+            PrivatePropKind::Method(_) | PrivatePropKind::Accessor { setter: None, .. } => {
+                // `(obj, babelHelpers.toSetter(babelHelpers.readOnlyError, ["#m"]))._`
+                let name = ctx.ast.atom(&format!("#{name}"));
+                let name = ctx.ast.expression_string_literal(SPAN, name);
+                (Helper::ReadOnlyError, vec![name], Some(object))
+            }
+            PrivatePropKind::Native => unreachable!(),
+        };
+
+        let elements =
+            ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(helper_loader::load(helper, ctx)),
+            Argument::from(ctx.ast.expression_array(SPAN, elements, None)),
+        ]);
+        let mut setter = helper_loader::call(Helper::ToSetter, arguments, ctx);
+        if let Some(object) = object {
+            setter = ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, setter]));
+        }
+        let member = ctx.ast.member_expression_static(
+            SPAN,
+            setter,
+            ctx.ast.identifier_name(SPAN, "_"),
+            false,
+        );
+        *target = AssignmentTarget::from(SimpleAssignmentTarget::from(member));
+    }
+
+    /// `obj?.#x` -> `obj === null || obj === void 0 ? void 0 : obj.#x`
+    ///
+    /// Optional chains containing a transformed private element are converted to a conditional,
+    /// as helper calls cannot be part of a chain. Private elements in the conditional are then
+    /// transformed as it is visited.
+    pub(super) fn transform_chain(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ChainExpression(chain) = expr else { unreachable!() };
+        if !self.chain_contains_transformed_private_prop(&chain.expression) {
+            return;
+        }
+
+        let Expression::ChainExpression(chain) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let element = match chain.unbox().expression {
+            ChainElement::CallExpression(call) => Expression::CallExpression(call),
+            element => Expression::from(element.into_member_expression()),
+        };
+        let mut checks = vec![];
+        let value = self.lower_chain_element(element, &mut checks, ctx);
+        let test = checks
+            .into_iter()
+            .reduce(|left, right| {
+                ctx.ast.expression_logical(SPAN, left, LogicalOperator::Or, right)
+            })
+            .unwrap();
+        *expr = ctx.ast.expression_conditional(SPAN, test, ctx.ast.void_0(SPAN), value);
+    }
+
+    /// `true` if a member expression or call in the chain accesses a transformed private element.
+    fn chain_contains_transformed_private_prop(&self, element: &ChainElement<'a>) -> bool {
+        let is_transformed = |member: &MemberExpression<'a>| {
+            matches!(member, MemberExpression::PrivateFieldExpression(field_expr)
+                if self.resolve_transformed_private_prop(&field_expr.field.name).is_some())
+        };
+        let mut expr = match element {
+            ChainElement::CallExpression(call) => &call.callee,
+            element => {
+                let member = element.to_member_expression();
+                if is_transformed(member) {
+                    return true;
+                }
+                member.object()
+            }
+        };
+        loop {
+            expr = match expr {
+                Expression::CallExpression(call) => &call.callee,
+                _ => match expr.as_member_expression() {
+                    Some(member) if is_transformed(member) => return true,
+                    Some(member) => member.object(),
+                    None => return false,
+                },
+            };
+        }
+    }
+
+    /// Remove optional links from an element of a chain.
+    ///
+    /// For each optional link, checks for the object being `null` or `undefined` are pushed to
+    /// `checks`, in evaluation order.
+    fn lower_chain_element(
+        &self,
+        mut expr: Expression<'a>,
+        checks: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::CallExpression(call) = &mut expr {
+            self.lower_chain_call(call, checks, ctx);
+            return expr;
+        }
+        let Some((object, optional)) = Self::member_object_mut(&mut expr) else { return expr };
+        let lowered = self.lower_chain_element(ctx.ast.move_expression(object), checks, ctx);
+        *object = if *optional {
+            *optional = false;
+            self.create_null_check(lowered, checks, ctx)
+        } else {
+            lowered
+        };
+        expr
+    }
+
+    /// `o.m?.()` -> `(_o$m = (_o = o).m) === null || _o$m === void 0 ? void 0 : _o$m.call(_o)`
+    fn lower_chain_call(
+        &self,
+        call: &mut CallExpression<'a>,
+        checks: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut callee =
+            self.lower_chain_element(ctx.ast.move_expression(&mut call.callee), checks, ctx);
+        if !call.optional {
+            call.callee = callee;
+            return;
+        }
+        call.optional = false;
+
+        let Some((object, _)) = Self::member_object_mut(&mut callee) else {
+            // `f?.()` -> `f === null || f === void 0 ? void 0 : f()`
+            call.callee = self.create_null_check(callee, checks, ctx);
+            return;
+        };
+        // Function is called with the object as `this`
+        let (object1, object2) = self.duplicate_object(ctx.ast.move_expression(object), ctx);
+        *object = object1;
+        let function = self.create_null_check(callee, checks, ctx);
+        call.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            function,
+            ctx.ast.identifier_name(SPAN, "call"),
+            false,
+        ));
+        call.arguments.insert(0, Argument::from(object2));
+    }
+
+    /// Object and `optional` flag of a member expression.
+    fn member_object_mut<'b>(
+        expr: &'b mut Expression<'a>,
+    ) -> Option<(&'b mut Expression<'a>, &'b mut bool)> {
+        match expr {
+            Expression::StaticMemberExpression(member) => {
+                let member = &mut **member;
+                Some((&mut member.object, &mut member.optional))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = &mut **member;
+                Some((&mut member.object, &mut member.optional))
+            }
+            Expression::PrivateFieldExpression(member) => {
+                let member = &mut **member;
+                Some((&mut member.object, &mut member.optional))
+            }
+            _ => None,
+        }
+    }
+
+    /// Push `(_obj = obj) === null` and `_obj === void 0` to `checks`, and return `_obj`.
+    ///
+    /// `obj` is not assigned to a temp var if it is `this` or an unmodified binding.
+    fn create_null_check(
+        &self,
+        object: Expression<'a>,
+        checks: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (object1, object2) = self.duplicate_object(object, ctx);
+        let object3 = Self::clone_static_expression(&object2, ctx);
+        let null = ctx.ast.expression_null_literal(SPAN);
+        let is_null =
+            ctx.ast.expression_binary(SPAN, object1, BinaryOperator::StrictEquality, null);
+        let is_undefined = ctx.ast.expression_binary(
+            SPAN,
+            object2,
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        checks.extend([is_null, is_undefined]);
+        object3
+    }
+
+    /// Create expression reading a private element.
+    fn create_private_get(
+        resolved: &ResolvedPrivateProp<'a>,
+        name: &Atom<'a>,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match &resolved.prop.kind {
+            PrivatePropKind::Field(binding) if resolved.prop.is_static => {
+                // `babelHelpers.assertClassBrand(A, obj, _x)._`
+                let class_binding = resolved.class_binding.as_ref().unwrap();
+                let arguments = ctx.ast.vec_from_iter([
+                    Self::create_argument(class_binding, ctx),
+                    Argument::from(object),
+                    Self::create_argument(binding, ctx),
+                ]);
+                let object = helper_loader::call(Helper::AssertClassBrand, arguments, ctx);
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    ctx.ast.identifier_name(SPAN, "_"),
+                    false,
+                ))
+            }
+            PrivatePropKind::Field(binding) => {
+                // `babelHelpers.classPrivateFieldGet2(_x, obj)`
+                let arguments = ctx
+                    .ast
+                    .vec_from_iter([Self::create_argument(binding, ctx), Argument::from(object)]);
+                helper_loader::call(Helper::ClassPrivateFieldGet2, arguments, ctx)
+            }
+            PrivatePropKind::Method(binding) => {
+                // `babelHelpers.assertClassBrand(_A_brand, obj, _m)`
+                let arguments = ctx.ast.vec_from_iter([
+                    Self::create_argument(resolved.brand_check_target(), ctx),
+                    Argument::from(object),
+                    Self::create_argument(binding, ctx),
+                ]);
+                helper_loader::call(Helper::AssertClassBrand, arguments, ctx)
+            }
+            PrivatePropKind::Accessor { getter: Some(getter), .. } => {
+                // `babelHelpers.classPrivateGetter(_A_brand, obj, _get_a)`
+                let arguments = ctx.ast.vec_from_iter([
+                    Self::create_argument(resolved.brand_check_target(), ctx),
+                    Argument::from(object),
+                    Self::create_argument(getter, ctx),
+                ]);
+                helper_loader::call(Helper::ClassPrivateGetter, arguments, ctx)
+            }
+            PrivatePropKind::Accessor { getter: None, .. } => {
+                // `(obj, babelHelpers.writeOnlyError("#a"))`
+                let error = Self::create_access_error(Helper::WriteOnlyError, name, ctx);
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, error]))
+            }
+            PrivatePropKind::Native => unreachable!(),
+        }
+    }
+
+    /// Create expression writing to a private element.
+    fn create_private_set(
+        resolved: &ResolvedPrivateProp<'a>,
+        name: &Atom<'a>,
+        object: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match &resolved.prop.kind {
+            PrivatePropKind::Field(binding) if resolved.prop.is_static => {
+                // `_x._ = babelHelpers.assertClassBrand(A, obj, value)`
+                let class_binding = resolved.class_binding.as_ref().unwrap();
+                let arguments = ctx.ast.vec_from_iter([
+                    Self::create_argument(class_binding, ctx),
+                    Argument::from(object),
+                    Argument::from(value),
+                ]);
+                let value = helper_loader::call(Helper::AssertClassBrand, arguments, ctx);
+                let target = ctx.ast.member_expression_static(
+                    SPAN,
+                    ctx.ast
+                        .expression_from_identifier_reference(binding.create_read_reference(ctx)),
+                    ctx.ast.identifier_name(SPAN, "_"),
+                    false,
+                );
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(SimpleAssignmentTarget::from(target)),
+                    value,
+                )
+            }
+            PrivatePropKind::Field(binding) => {
+                // `babelHelpers.classPrivateFieldSet2(_x, obj, value)`
+                let arguments = ctx.ast.vec_from_iter([
+                    Self::create_argument(binding, ctx),
+                    Argument::from(object),
+                    Argument::from(value),
+                ]);
+                helper_loader::call(Helper::ClassPrivateFieldSet2, arguments, ctx)
+            }
+            PrivatePropKind::Accessor { setter: Some(setter), .. } => {
+                // `babelHelpers.classPrivateSetter(_A_brand, _set_a, obj, value)`
+                let arguments = ctx.ast.vec_from_iter([
+                    Self::create_argument(resolved.brand_check_target(), ctx),
+                    Self::create_argument(setter, ctx),
+                    Argument::from(object),
+                    Argument::from(value),
+                ]);
+                helper_loader::call(Helper::ClassPrivateSetter, arguments, ctx)
+            }
+            PrivatePropKind::Method(_) | PrivatePropKind::Accessor { setter: None, .. } => {
+                // `(obj, value, babelHelpers.readOnlyError("#m"))`
+                let error = Self::create_access_error(Helper::ReadOnlyError, name, ctx);
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, value, error]))
+            }
+            PrivatePropKind::Native => unreachable!(),
+        }
+    }
+
+    /// `babelHelpers.readOnlyError("#x")` / `babelHelpers.writeOnlyError("#x")`
+    fn create_access_error(
+        helper: Helper,
+        name: &Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let name = ctx.ast.atom(&format!("#{name}"));
+        let arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, name)));
+        helper_loader::call(helper, arguments, ctx)
+    }
+
+    fn create_argument(binding: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>) -> Argument<'a> {
+        Argument::from(
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx)),
+        )
+    }
+
+    /// `<binding> = <value>`
+    pub(super) fn create_assignment(
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(ctx.ast.simple_assignment_target_from_identifier_reference(
+                binding.create_write_reference(ctx),
+            )),
+            value,
+        )
+    }
+
+    /// Create a temp var, declared with `var` in enclosing statement block.
+    fn create_temp_var(&self, name: &str, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding = BoundIdentifier::new_uid(
+            name,
+            ctx.current_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+        binding
+    }
+
+    /// Duplicate object of a private element access, so it is evaluated only once
+    /// but can be referenced twice.
+    ///
+    /// Returns `(_obj = obj, _obj)`, or 2 copies of `obj` if it is `this` or an unmodified binding.
+    fn duplicate_object(
+        &self,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        if ctx.is_static(&object) {
+            let copy = Self::clone_static_expression(&object, ctx);
+            return (object, copy);
+        }
+
+        let symbol_id = ctx.generate_uid_in_current_scope_based_on_node(
+            &object,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let binding =
+            BoundIdentifier { name: ctx.ast.atom(ctx.symbols().get_name(symbol_id)), symbol_id };
+        self.ctx.var_declarations.insert(binding.name.clone(), binding.symbol_id, None, ctx);
+
+        // Value of the assignment is used, so the reference is read too
+        let target = ctx.ast.simple_assignment_target_from_identifier_reference(
+            binding.create_spanned_reference(SPAN, ReferenceFlags::read_write(), ctx),
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            object,
+        );
+        let reference =
+            ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        (assignment, reference)
+    }
+
+    /// Copy `this` or a reference to a binding.
+    fn clone_static_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => ctx.ast.expression_from_identifier_reference(
+                ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
+            ),
+            _ => expr.clone_in(ctx.ast.allocator),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+mod class_properties;
+mod options;
+
+pub use class_properties::{ClassProperties, ClassPropertiesOptions};
+pub use options::ES2022Options;
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct ES2022<'a, 'ctx> {
+    options: ES2022Options,
+
+    // Plugins
+    class_properties: ClassProperties<'a, 'ctx>,
+}
+
+impl<'a, 'ctx> ES2022<'a, 'ctx> {
+    pub fn new(options: ES2022Options, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { class_properties: ClassProperties::new(&options, ctx), options }
+    }
+
+    fn is_class_properties_enabled(&self) -> bool {
+        self.options.class_properties.is_some()
+            || self.options.private_methods
+            || self.options.private_in
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ES2022<'a, 'ctx> {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_class_properties_enabled() {
+            self.class_properties.enter_class(class, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_class_properties_enabled() {
+            self.class_properties.exit_class(class, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_class_properties_enabled() {
+            self.class_properties.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_class_properties_enabled() {
+            self.class_properties.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_assignment_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.is_class_properties_enabled() {
+            self.class_properties.enter_assignment_target(target, ctx);
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_class_properties_enabled() {
+            self.class_properties.exit_statements(stmts, ctx);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+use super::ClassPropertiesOptions;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ES2022Options {
+    /// Transform public class fields.
+    #[serde(skip)]
+    pub class_properties: Option<ClassPropertiesOptions>,
+
+    /// Transform private fields, methods and accessors.
+    #[serde(skip)]
+    pub private_methods: bool,
+
+    /// Transform `#x in obj` brand checks.
+    #[serde(skip)]
+    pub private_in: bool,
+}
+
+impl ES2022Options {
+    pub fn with_class_properties(&mut self, option: Option<ClassPropertiesOptions>) -> &mut Self {
+        self.class_properties = option;
+        self
+    }
+
+    pub fn with_private_methods(&mut self, enable: bool) -> &mut Self {
+        self.private_methods = enable;
+        self
+    }
+
+    pub fn with_private_in(&mut self, enable: bool) -> &mut Self {
+        self.private_in = enable;
+        self
+    }
+}
@@ -0,0 +1,602 @@
+//! ES2026: Explicit Resource Management
+//!
+//! This plugin transforms `using` and `await using` declarations into `const` declarations,
+//! with their resources disposed in a `try` / `finally` block.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! {
+//!   using a = getA();
+//!   await using b = getB();
+//!   use(a, b);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! {
+//!   try {
+//!     var _usingCtx = babelHelpers.usingCtx();
+//!     const a = _usingCtx.u(getA());
+//!     const b = _usingCtx.a(getB());
+//!     use(a, b);
+//!   } catch (_) {
+//!     _usingCtx.e = _;
+//!   } finally {
+//!     await _usingCtx.d();
+//!   }
+//! }
+//! ```
+//!
+//! `_usingCtx.u` registers a resource to be disposed with `Symbol.dispose`,
+//! and `_usingCtx.a` with `Symbol.asyncDispose` (or `Symbol.dispose` if it has no async dispose method).
+//! `_usingCtx.d` disposes all registered resources in reverse order of declaration,
+//! and rethrows the error caught in `catch`, if any, combined with errors thrown while disposing
+//! in a `SuppressedError`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-explicit-resource-management](https://babel.dev/docs/babel-plugin-transform-explicit-resource-management).
+//!
+//! All statements of a statement list containing `using` declarations are moved into the `try` block.
+//! At top level, imports are left outside the `try` block.
+//!
+//! At top level of modules with exports, exports must stay at top level, so:
+//! * Function declarations and `export ... from` stay outside the `try` block.
+//! * Other declarations are converted to `var` declarations inside the `try` block,
+//!   and exported with `export { x }`.
+//! * `export default expr` is converted to `var _default = expr`, exported with
+//!   `export { _default as default }`.
+//!
+//! `using` declarations are scoped to the whole `switch` statement, so the `switch` statement
+//! is wrapped in the `try` block, rather than the statements of each `case`.
+//!
+//! `for (using x of y) body` is converted to `for (const _x of y) { using x = _x; body }`,
+//! and `for (using x = y; ; ) body` to `{ using x = y; for (; ; ) body }`.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-explicit-resource-management>
+//! * Explicit Resource Management TC39 proposal: <https://github.com/tc39/proposal-explicit-resource-management>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, NONE};
+use oxc_span::{CompactStr, SPAN};
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::{self, Helper},
+    helpers::bindings::BoundIdentifier,
+};
+
+pub struct ExplicitResourceManagement;
+
+impl ExplicitResourceManagement {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for ExplicitResourceManagement {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `using` declarations in `switch` cases are transformed in `enter_statement`
+        if matches!(ctx.parent(), Ancestor::SwitchCaseConsequent(_)) {
+            return;
+        }
+        if !stmts.iter().any(is_using_declaration) {
+            return;
+        }
+
+        // Top level. (depth 1 = None, depth 2 = Program)
+        let hoist_exports = ctx.ancestors_depth() == 2 && stmts.iter().any(is_export);
+        let has_await_using = stmts.iter().any(is_await_using_declaration);
+        let using_ctx = create_using_ctx_binding(ctx);
+
+        let mut new_stmts = ctx.ast.vec();
+        let mut body = ctx.ast.vec_with_capacity(stmts.len() + 1);
+        for stmt in ctx.ast.move_vec(stmts) {
+            match stmt {
+                // Imports are hoisted, so can stay outside `try`
+                Statement::ImportDeclaration(_) => new_stmts.push(stmt),
+                Statement::VariableDeclaration(mut decl) if is_using(decl.kind) => {
+                    transform_using_declaration(&mut decl, &using_ctx, ctx);
+                    body.push(Statement::VariableDeclaration(decl));
+                }
+                _ if hoist_exports => {
+                    hoist_top_level_statement(stmt, &mut new_stmts, &mut body, ctx);
+                }
+                _ => body.push(stmt),
+            }
+        }
+
+        new_stmts.push(create_try_statement(body, &using_ctx, has_await_using, ctx));
+        *stmts = new_stmts;
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::SwitchStatement(_) => transform_switch_statement(stmt, ctx),
+            Statement::ForStatement(_) => transform_for_statement(stmt, ctx),
+            _ => {}
+        }
+    }
+
+    /// `for (using x of y) body` -> `for (const _x of y) { using x = _x; body }`
+    ///
+    /// The inserted `using` declaration is transformed when entering the body.
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = &mut stmt.left else { return };
+        if !is_using(decl.kind) {
+            return;
+        }
+        let kind = decl.kind;
+        let for_scope_id = stmt.scope_id.get().unwrap();
+
+        let declarator = decl.declarations.first_mut().unwrap();
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            unreachable!("`using` declarations can only declare identifiers")
+        };
+        let symbol_id = ident.symbol_id.get().unwrap();
+        let binding = BoundIdentifier::new_uid(
+            &ident.name,
+            for_scope_id,
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+            ctx,
+        );
+        let id = std::mem::replace(
+            &mut declarator.id,
+            ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                    binding.create_binding_identifier(),
+                ),
+                NONE,
+                false,
+            ),
+        );
+        declarator.kind = VariableDeclarationKind::Const;
+        decl.kind = VariableDeclarationKind::Const;
+
+        // Wrap body in a block, unless it's a block which doesn't declare a binding with same name
+        let name = ctx.symbols().get_name(symbol_id);
+        let block_scope_id = match &stmt.body {
+            Statement::BlockStatement(block)
+                if ctx.scopes().get_binding(block.scope_id.get().unwrap(), name).is_none() =>
+            {
+                block.scope_id.get().unwrap()
+            }
+            _ => {
+                let scope_id = ctx.insert_scope_below_statement(&stmt.body, ScopeFlags::empty());
+                // Child scopes of body were children of `for` statement's scope, not current scope
+                remove_child_scopes(for_scope_id, scope_id, ctx);
+                move_scope(scope_id, for_scope_id, ctx);
+                let body = ctx.ast.move_statement(&mut stmt.body);
+                let block = BlockStatement::new_with_scope_id(SPAN, ctx.ast.vec1(body), scope_id);
+                stmt.body = Statement::BlockStatement(ctx.ast.alloc(block));
+                scope_id
+            }
+        };
+        move_binding(symbol_id, for_scope_id, block_scope_id, ctx);
+
+        let Statement::BlockStatement(block) = &mut stmt.body else { unreachable!() };
+        let init = ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx));
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+        block.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+}
+
+/// `switch (x) { case 1: using y = z; }` ->
+/// `try { var _usingCtx = babelHelpers.usingCtx(); switch (x) { case 1: const y = _usingCtx.u(z); } } ...`
+fn transform_switch_statement<'a>(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+    let Statement::SwitchStatement(switch) = stmt else { unreachable!() };
+    let mut has_using = false;
+    let mut has_await_using = false;
+    for case in &switch.cases {
+        has_using |= case.consequent.iter().any(is_using_declaration);
+        has_await_using |= case.consequent.iter().any(is_await_using_declaration);
+    }
+    if !has_using {
+        return;
+    }
+
+    let using_ctx = create_using_ctx_binding(ctx);
+    for case in switch.cases.iter_mut() {
+        for stmt in case.consequent.iter_mut() {
+            if let Statement::VariableDeclaration(decl) = stmt {
+                if is_using(decl.kind) {
+                    transform_using_declaration(decl, &using_ctx, ctx);
+                }
+            }
+        }
+    }
+
+    let body = ctx.ast.vec1(ctx.ast.move_statement(stmt));
+    *stmt = create_try_statement(body, &using_ctx, has_await_using, ctx);
+}
+
+/// `for (using x = y; ; ) body` -> `{ using x = y; for (; ; ) body }`
+///
+/// The `using` declaration is transformed when entering the block.
+fn transform_for_statement<'a>(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+    let Statement::ForStatement(for_stmt) = stmt else { unreachable!() };
+    if !matches!(&for_stmt.init, Some(ForStatementInit::VariableDeclaration(decl)) if is_using(decl.kind))
+    {
+        return;
+    }
+    let Some(ForStatementInit::VariableDeclaration(decl)) = for_stmt.init.take() else {
+        unreachable!()
+    };
+    let for_scope_id = for_stmt.scope_id.get().unwrap();
+
+    let mut symbol_ids = vec![];
+    decl.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id.get().unwrap()));
+
+    let body =
+        ctx.ast.vec_from_iter([Statement::VariableDeclaration(decl), ctx.ast.move_statement(stmt)]);
+    let block_scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::empty());
+    // Child scopes of the declaration were children of `for` statement's scope, not current scope
+    remove_child_scopes(for_scope_id, block_scope_id, ctx);
+    for symbol_id in symbol_ids {
+        move_binding(symbol_id, for_scope_id, block_scope_id, ctx);
+    }
+
+    let block = BlockStatement::new_with_scope_id(SPAN, body, block_scope_id);
+    *stmt = Statement::BlockStatement(ctx.ast.alloc(block));
+}
+
+/// Move a statement at top level of a module with exports into `body` (the `try` block),
+/// keeping its exports in `new_stmts` (top level).
+///
+/// * `export const x = 1` -> `var x = 1` and `export { x }`.
+/// * `export class A {}` -> `var A = class {}` and `export { A }`.
+/// * `export default x` -> `var _default = x` and `export { _default as default }`.
+/// * Function declarations and other exports stay at top level.
+fn hoist_top_level_statement<'a>(
+    stmt: Statement<'a>,
+    new_stmts: &mut ArenaVec<'a, Statement<'a>>,
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    match stmt {
+        Statement::ExportNamedDeclaration(mut export) => {
+            if !matches!(
+                export.declaration,
+                Some(Declaration::VariableDeclaration(_) | Declaration::ClassDeclaration(_))
+            ) {
+                new_stmts.push(Statement::ExportNamedDeclaration(export));
+                return;
+            }
+            let declaration = export.declaration.take().unwrap();
+
+            let mut bindings = vec![];
+            declaration.bound_names(&mut |ident| {
+                let symbol_id = ident.symbol_id.get().unwrap();
+                ctx.symbols_mut().get_flags_mut(symbol_id).insert(SymbolFlags::Export);
+                bindings.push(BoundIdentifier { name: ident.name.clone(), symbol_id });
+            });
+            let specifiers = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+                ctx.ast.export_specifier(
+                    SPAN,
+                    ModuleExportName::IdentifierReference(binding.create_read_reference(ctx)),
+                    ctx.ast.module_export_name_identifier_name(SPAN, binding.name.clone()),
+                    ImportOrExportKind::Value,
+                )
+            }));
+            export.specifiers = specifiers;
+            new_stmts.push(Statement::ExportNamedDeclaration(export));
+            let stmt = match declaration {
+                Declaration::VariableDeclaration(decl) => Statement::VariableDeclaration(decl),
+                Declaration::ClassDeclaration(class) => Statement::ClassDeclaration(class),
+                _ => unreachable!(),
+            };
+            hoist_top_level_statement(stmt, new_stmts, body, ctx);
+        }
+        Statement::ExportDefaultDeclaration(export) => {
+            let export = export.unbox();
+            let (id, init) = match export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                    let id = match class.id.take() {
+                        Some(id) => {
+                            set_var_flags(id.symbol_id.get().unwrap(), ctx);
+                            id
+                        }
+                        None => create_default_binding(ctx).create_binding_identifier(),
+                    };
+                    class.r#type = ClassType::ClassExpression;
+                    (id, Expression::ClassExpression(class))
+                }
+                kind if kind.is_expression() => (
+                    create_default_binding(ctx).create_binding_identifier(),
+                    kind.into_expression(),
+                ),
+                declaration => {
+                    new_stmts.push(ctx.ast.statement_module_declaration(
+                        ctx.ast.module_declaration_export_default_declaration(
+                            export.span,
+                            declaration,
+                            export.exported,
+                        ),
+                    ));
+                    return;
+                }
+            };
+
+            let binding =
+                BoundIdentifier { name: id.name.clone(), symbol_id: id.symbol_id.get().unwrap() };
+            body.push(create_var_declaration(id, init, ctx));
+            let specifier = ctx.ast.export_specifier(
+                SPAN,
+                ModuleExportName::IdentifierReference(binding.create_read_reference(ctx)),
+                export.exported,
+                ImportOrExportKind::Value,
+            );
+            new_stmts.push(ctx.ast.statement_module_declaration(
+                ctx.ast.module_declaration_export_named_declaration(
+                    export.span,
+                    None,
+                    ctx.ast.vec1(specifier),
+                    None,
+                    ImportOrExportKind::Value,
+                    NONE,
+                ),
+            ));
+        }
+        // `let x = 1` -> `var x = 1`
+        Statement::VariableDeclaration(mut decl) if decl.kind.is_lexical() && !decl.declare => {
+            decl.kind = VariableDeclarationKind::Var;
+            for declarator in decl.declarations.iter_mut() {
+                declarator.kind = VariableDeclarationKind::Var;
+            }
+            decl.bound_names(&mut |ident| set_var_flags(ident.symbol_id.get().unwrap(), ctx));
+            body.push(Statement::VariableDeclaration(decl));
+        }
+        // `class A {}` -> `var A = class {}`
+        Statement::ClassDeclaration(mut class) if class.id.is_some() && !class.declare => {
+            let id = class.id.take().unwrap();
+            set_var_flags(id.symbol_id.get().unwrap(), ctx);
+            class.r#type = ClassType::ClassExpression;
+            body.push(create_var_declaration(id, Expression::ClassExpression(class), ctx));
+        }
+        Statement::FunctionDeclaration(_)
+        | Statement::ExportAllDeclaration(_)
+        | Statement::TSExportAssignment(_)
+        | Statement::TSNamespaceExportDeclaration(_) => new_stmts.push(stmt),
+        _ => body.push(stmt),
+    }
+}
+
+fn create_default_binding<'a>(ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    BoundIdentifier::new_uid_in_current_scope(
+        "default",
+        SymbolFlags::FunctionScopedVariable | SymbolFlags::Export,
+        ctx,
+    )
+}
+
+/// Change flags of a symbol declared with `let`, `const` or `class` to flags of `var`.
+fn set_var_flags(symbol_id: SymbolId, ctx: &mut TraverseCtx<'_>) {
+    let flags = ctx.symbols_mut().get_flags_mut(symbol_id);
+    flags
+        .remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable | SymbolFlags::Class);
+    flags.insert(SymbolFlags::FunctionScopedVariable);
+}
+
+/// Create `try { body } catch (_) { _usingCtx.e = _; } finally { _usingCtx.d(); }`,
+/// with `var _usingCtx = babelHelpers.usingCtx();` inserted at start of `body`.
+///
+/// `body` is in current scope, and is moved into scope of `try` block.
+fn create_try_statement<'a>(
+    mut body: ArenaVec<'a, Statement<'a>>,
+    using_ctx: &BoundIdentifier<'a>,
+    has_await_using: bool,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let try_scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::empty());
+    move_lexical_bindings(&body, try_scope_id, ctx);
+
+    // `var _usingCtx = babelHelpers.usingCtx();`
+    let init = helper_loader::call(Helper::UsingCtx, ctx.ast.vec(), ctx);
+    body.insert(0, create_var_declaration(using_ctx.create_binding_identifier(), init, ctx));
+    let block = ctx.ast.alloc_block_statement(SPAN, body);
+    block.scope_id.set(Some(try_scope_id));
+
+    // `catch (_) { _usingCtx.e = _; }`
+    let catch_scope_id = ctx.create_child_scope_of_current(ScopeFlags::CatchClause);
+    let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+    let error = BoundIdentifier::new_uid(
+        "",
+        catch_body_scope_id,
+        SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        ctx,
+    );
+    let object = ctx.ast.expression_from_identifier_reference(using_ctx.create_read_reference(ctx));
+    let property = ctx.ast.identifier_name(SPAN, "e");
+    let target =
+        AssignmentTarget::from(ctx.ast.member_expression_static(SPAN, object, property, false));
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        target,
+        ctx.ast.expression_from_identifier_reference(error.create_read_reference(ctx)),
+    );
+    let catch_body = ctx
+        .ast
+        .alloc_block_statement(SPAN, ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)));
+    catch_body.scope_id.set(Some(catch_body_scope_id));
+    let param = ctx.ast.catch_parameter(
+        SPAN,
+        ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_from_binding_identifier(error.create_binding_identifier()),
+            NONE,
+            false,
+        ),
+    );
+    let handler = ctx.ast.alloc_catch_clause(SPAN, Some(param), catch_body);
+    handler.scope_id.set(Some(catch_scope_id));
+
+    // `finally { _usingCtx.d(); }` or `finally { await _usingCtx.d(); }`
+    let mut dispose = create_using_ctx_call(using_ctx, "d", ctx.ast.vec(), ctx);
+    if has_await_using {
+        dispose = ctx.ast.expression_await(SPAN, dispose);
+    }
+    let finalizer = ctx
+        .ast
+        .alloc_block_statement(SPAN, ctx.ast.vec1(ctx.ast.statement_expression(SPAN, dispose)));
+    finalizer.scope_id.set(Some(ctx.create_child_scope_of_current(ScopeFlags::empty())));
+
+    ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer))
+}
+
+/// Create `_usingCtx` binding in current var scope.
+fn create_using_ctx_binding<'a>(ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    let var_scope_id =
+        ctx.ancestor_scopes().find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var()).unwrap();
+    BoundIdentifier::new_uid("usingCtx", var_scope_id, SymbolFlags::FunctionScopedVariable, ctx)
+}
+
+/// `using x = y` -> `const x = _usingCtx.u(y)`
+/// `await using x = y` -> `const x = _usingCtx.a(y)`
+fn transform_using_declaration<'a>(
+    decl: &mut VariableDeclaration<'a>,
+    using_ctx: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let method = if decl.kind.is_await() { "a" } else { "u" };
+    decl.kind = VariableDeclarationKind::Const;
+    for declarator in decl.declarations.iter_mut() {
+        declarator.kind = VariableDeclarationKind::Const;
+        if let Some(init) = declarator.init.take() {
+            let arguments = ctx.ast.vec1(Argument::from(init));
+            declarator.init = Some(create_using_ctx_call(using_ctx, method, arguments, ctx));
+        }
+    }
+    decl.bound_names(&mut |ident| {
+        *ctx.symbols_mut().get_flags_mut(ident.symbol_id.get().unwrap()) =
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+    });
+}
+
+fn is_using(kind: VariableDeclarationKind) -> bool {
+    matches!(kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+}
+
+fn is_using_declaration(stmt: &Statement<'_>) -> bool {
+    matches!(stmt, Statement::VariableDeclaration(decl) if is_using(decl.kind))
+}
+
+fn is_await_using_declaration(stmt: &Statement<'_>) -> bool {
+    matches!(stmt, Statement::VariableDeclaration(decl) if decl.kind == VariableDeclarationKind::AwaitUsing)
+}
+
+fn is_export(stmt: &Statement<'_>) -> bool {
+    matches!(
+        stmt,
+        Statement::ExportAllDeclaration(_)
+            | Statement::ExportDefaultDeclaration(_)
+            | Statement::ExportNamedDeclaration(_)
+            | Statement::TSExportAssignment(_)
+            | Statement::TSNamespaceExportDeclaration(_)
+    )
+}
+
+/// `var <id> = <init>;`
+fn create_var_declaration<'a>(
+    id: BindingIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let id = ctx.ast.binding_pattern(
+        ctx.ast.binding_pattern_kind_from_binding_identifier(id),
+        NONE,
+        false,
+    );
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `_usingCtx.<method>(...arguments)`
+fn create_using_ctx_call<'a>(
+    using_ctx: &BoundIdentifier<'a>,
+    method: &'static str,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object = ctx.ast.expression_from_identifier_reference(using_ctx.create_read_reference(ctx));
+    let property = ctx.ast.identifier_name(SPAN, method);
+    let callee = Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Move bindings of lexical declarations in `body` from current scope into scope of `try` block.
+fn move_lexical_bindings<'a>(
+    body: &ArenaVec<'a, Statement<'a>>,
+    try_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let mut symbol_ids = vec![];
+    for stmt in body {
+        match stmt {
+            Statement::VariableDeclaration(decl) if !decl.kind.is_var() => {
+                decl.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id.get().unwrap()));
+            }
+            Statement::FunctionDeclaration(func) => {
+                if let Some(id) = &func.id {
+                    symbol_ids.push(id.symbol_id.get().unwrap());
+                }
+            }
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    symbol_ids.push(id.symbol_id.get().unwrap());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let scope_id = ctx.current_scope_id();
+    for symbol_id in symbol_ids {
+        move_binding(symbol_id, scope_id, try_scope_id, ctx);
+    }
+}
+
+fn move_binding(symbol_id: SymbolId, from: ScopeId, to: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    let name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+    ctx.scopes_mut().remove_binding(from, &name);
+    ctx.scopes_mut().add_binding(to, name, symbol_id);
+    ctx.symbols_mut().set_scope_id(symbol_id, to);
+}
+
+/// Remove child scopes of `scope_id` from child scopes of `old_parent_id`.
+fn remove_child_scopes(old_parent_id: ScopeId, scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    let scopes = ctx.scopes_mut();
+    if scopes.has_child_ids() {
+        let child_ids = scopes.get_child_ids(scope_id).to_vec();
+        scopes.get_child_ids_mut(old_parent_id).retain(|id| !child_ids.contains(id));
+    }
+}
+
+/// Set parent of `scope_id` to `parent_scope_id`.
+fn move_scope(scope_id: ScopeId, parent_scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    let scopes = ctx.scopes_mut();
+    if scopes.has_child_ids() {
+        if let Some(old_parent_id) = scopes.get_parent_id(scope_id) {
+            scopes.get_child_ids_mut(old_parent_id).retain(|&id| id != scope_id);
+        }
+    }
+    scopes.set_parent_id(scope_id, Some(parent_scope_id));
+}
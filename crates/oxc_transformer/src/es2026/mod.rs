@@ -0,0 +1,41 @@
+mod explicit_resource_management;
+mod options;
+
+pub use explicit_resource_management::ExplicitResourceManagement;
+pub use options::ES2026Options;
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct ES2026 {
+    options: ES2026Options,
+
+    // Plugins
+    explicit_resource_management: ExplicitResourceManagement,
+}
+
+impl ES2026 {
+    pub fn new(options: ES2026Options) -> Self {
+        Self { explicit_resource_management: ExplicitResourceManagement::new(), options }
+    }
+}
+
+impl<'a> Traverse<'a> for ES2026 {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.explicit_resource_management {
+            self.explicit_resource_management.enter_statements(stmts, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.explicit_resource_management {
+            self.explicit_resource_management.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.explicit_resource_management {
+            self.explicit_resource_management.enter_for_of_statement(stmt, ctx);
+        }
+    }
+}
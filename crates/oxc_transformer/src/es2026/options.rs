@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ES2026Options {
+    #[serde(skip)]
+    pub explicit_resource_management: bool,
+}
+
+impl ES2026Options {
+    pub fn with_explicit_resource_management(&mut self, enable: bool) -> &mut Self {
+        self.explicit_resource_management = enable;
+        self
+    }
+}
@@ -64,7 +64,6 @@ impl<'a> BoundIdentifier<'a> {
     }
 
     /// Create `BoundIdentifier` for new binding in current scope
-    #[allow(unused)]
     pub fn new_uid_in_current_scope(
         name: &str,
         flags: SymbolFlags,
@@ -0,0 +1,276 @@
+//! Insert initialization of instances into class constructor.
+//!
+//! * No `super` class: Insert at start of constructor.
+//! * With `super` class: Insert after `super()` call.
+//!   If the constructor has more than one `super()` call, or it is not a top level statement,
+//!   create an arrow function which calls `super()` and then runs inits, and call it instead:
+//!   `var _super = (..._args) => (super(..._args), <inits>, this);`.
+//! * No constructor: Create one.
+//!   `constructor() { <inits> }` or `constructor(..._args) { super(..._args); <inits> }`.
+
+use oxc_allocator::Box as ArenaBox;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
+use oxc_span::{Span, SPAN};
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::TraverseCtx;
+
+use crate::helpers::bindings::BoundIdentifier;
+
+/// Insert `inits` into the constructor of `class`, to run when instances are initialized.
+///
+/// Returns `Err` with the span of the constructor if the class has a `super` class, and
+/// the constructor does not call `super()`.
+pub fn insert_inits_into_constructor<'a>(
+    class: &mut Class<'a>,
+    inits: Vec<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Result<(), Span> {
+    let has_super_class = class.super_class.is_some();
+
+    let constructor = class.body.body.iter_mut().find_map(|element| match element {
+        ClassElement::MethodDefinition(method)
+            if method.kind == MethodDefinitionKind::Constructor =>
+        {
+            Some(method)
+        }
+        _ => None,
+    });
+
+    if let Some(constructor) = constructor {
+        let constructor_span = constructor.span;
+        let function = &mut constructor.value;
+        let Some(scope_id) = function.scope_id.get() else { return Ok(()) };
+        let Some(body) = function.body.as_mut() else { return Ok(()) };
+        if !has_super_class {
+            body.statements.splice(0..0, create_statements(inits, ctx));
+            return Ok(());
+        }
+
+        let mut counter = SuperCallCounter::default();
+        counter.visit_function_body(body);
+        if counter.count == 0 {
+            return Err(constructor_span);
+        }
+        // `super(); <inits>`
+        let super_call_index = body.statements.iter().position(|stmt| {
+            matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
+        });
+        if let Some(index) = super_call_index.filter(|_| counter.count == 1) {
+            let index = index + 1;
+            body.statements.splice(index..index, create_statements(inits, ctx));
+            return Ok(());
+        }
+
+        // `var _super = (..._args) => (super(..._args), <inits>, this);`
+        let super_binding =
+            BoundIdentifier::new_uid("super", scope_id, SymbolFlags::FunctionScopedVariable, ctx);
+        ReplaceSuperCalls { binding: &super_binding, ctx }.visit_function_body(body);
+        let super_function = create_super_function(inits, scope_id, ctx);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(
+                    super_binding.create_binding_identifier(),
+                ),
+                NONE,
+                false,
+            ),
+            Some(super_function),
+            false,
+        );
+        let declaration = ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        );
+        body.statements.insert(0, Statement::from(declaration));
+        return Ok(());
+    }
+
+    // Create constructor
+    let class_scope_id = class.scope_id.get().unwrap();
+    let scope_id = ctx.create_child_scope(
+        class_scope_id,
+        ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+    );
+
+    let mut statements = ctx.ast.vec();
+    let params = if has_super_class {
+        // `constructor(..._args) { super(..._args); }`
+        let args_binding =
+            BoundIdentifier::new_uid("args", scope_id, SymbolFlags::FunctionScopedVariable, ctx);
+        let super_call = create_super_call(&args_binding, ctx);
+        statements.push(ctx.ast.statement_expression(SPAN, super_call));
+        create_rest_params(&args_binding, ctx)
+    } else {
+        ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        )
+    };
+    statements.extend(create_statements(inits, ctx));
+
+    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+    let function = ctx.ast.alloc_function(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+    );
+    function.scope_id.set(Some(scope_id));
+
+    let constructor = ctx.ast.class_element_method_definition(
+        MethodDefinitionType::MethodDefinition,
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.property_key_identifier_name(SPAN, "constructor"),
+        function,
+        MethodDefinitionKind::Constructor,
+        false,
+        false,
+        false,
+        false,
+        None,
+    );
+    class.body.body.insert(0, constructor);
+    Ok(())
+}
+
+/// `<init>;` for each of `inits`.
+fn create_statements<'a>(
+    inits: Vec<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> impl Iterator<Item = Statement<'a>> {
+    let ast = ctx.ast;
+    inits.into_iter().map(move |init| ast.statement_expression(SPAN, init))
+}
+
+/// `super(..._args)`
+fn create_super_call<'a>(
+    args_binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_call(
+        SPAN,
+        ctx.ast.expression_super(SPAN),
+        NONE,
+        ctx.ast.vec1(ctx.ast.argument_spread_element(
+            SPAN,
+            ctx.ast.expression_from_identifier_reference(args_binding.create_read_reference(ctx)),
+        )),
+        false,
+    )
+}
+
+/// `(..._args)`
+fn create_rest_params<'a>(
+    args_binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ArenaBox<'a, FormalParameters<'a>> {
+    let rest = ctx.ast.binding_pattern(
+        ctx.ast
+            .binding_pattern_kind_from_binding_identifier(args_binding.create_binding_identifier()),
+        NONE,
+        false,
+    );
+    let rest = ctx.ast.alloc_binding_rest_element(SPAN, rest);
+    ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::FormalParameter,
+        ctx.ast.vec(),
+        Some(rest),
+    )
+}
+
+/// `(..._args) => (super(..._args), <inits>, this)`
+fn create_super_function<'a>(
+    inits: Vec<Expression<'a>>,
+    constructor_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope(
+        constructor_scope_id,
+        ScopeFlags::Function | ScopeFlags::Arrow | ScopeFlags::StrictMode,
+    );
+    let args_binding =
+        BoundIdentifier::new_uid("args", scope_id, SymbolFlags::FunctionScopedVariable, ctx);
+
+    let mut expressions = ctx.ast.vec_with_capacity(inits.len() + 2);
+    expressions.push(create_super_call(&args_binding, ctx));
+    expressions.extend(inits);
+    expressions.push(ctx.ast.expression_this(SPAN));
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(
+            ctx.ast.statement_expression(SPAN, ctx.ast.expression_sequence(SPAN, expressions)),
+        ),
+    );
+
+    let params = create_rest_params(&args_binding, ctx);
+    let arrow =
+        ctx.ast.alloc_arrow_function_expression(SPAN, true, false, NONE, params, NONE, body);
+    arrow.scope_id.set(Some(scope_id));
+    Expression::ArrowFunctionExpression(arrow)
+}
+
+/// Count `super()` calls in a constructor.
+///
+/// Does not descend into functions or classes, which cannot call the constructor's `super()`.
+#[derive(Default)]
+struct SuperCallCounter {
+    count: usize,
+}
+
+impl<'a> Visit<'a> for SuperCallCounter {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if matches!(call.callee, Expression::Super(_)) {
+            self.count += 1;
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+/// Replace `super(...)` calls with `_super(...)`.
+///
+/// Does not descend into functions or classes, which cannot call the constructor's `super()`.
+struct ReplaceSuperCalls<'a, 'b> {
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for ReplaceSuperCalls<'a, 'b> {
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        if let Expression::Super(super_expr) = &call.callee {
+            let ident = self.binding.create_spanned_read_reference(super_expr.span, self.ctx);
+            call.callee = self.ctx.ast.expression_from_identifier_reference(ident);
+        }
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
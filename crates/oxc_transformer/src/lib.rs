@@ -16,6 +16,7 @@ mod compiler_assumptions;
 mod context;
 mod options;
 // Presets: <https://babel.dev/docs/presets>
+mod decorator;
 mod env;
 mod es2015;
 mod es2016;
@@ -23,6 +24,8 @@ mod es2018;
 mod es2019;
 mod es2020;
 mod es2021;
+mod es2022;
+mod es2026;
 mod react;
 mod regexp;
 mod typescript;
@@ -31,17 +34,21 @@ mod plugins;
 
 mod helpers {
     pub mod bindings;
+    pub mod constructor;
     pub mod stack;
 }
 
 use std::path::Path;
 
 use common::Common;
+use decorator::Decorators;
 use es2016::ES2016;
 use es2018::ES2018;
 use es2019::ES2019;
 use es2020::ES2020;
 use es2021::ES2021;
+use es2022::ES2022;
+use es2026::ES2026;
 use oxc_allocator::{Allocator, Vec};
 use oxc_ast::{ast::*, Trivias};
 use oxc_diagnostics::OxcDiagnostic;
@@ -52,12 +59,18 @@ use regexp::RegExp;
 
 pub use crate::{
     compiler_assumptions::CompilerAssumptions,
+    decorator::DecoratorOptions,
     env::{EnvOptions, Targets},
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ES2015Options, ForOfOptions},
+    es2020::ES2020Options,
+    es2021::ES2021Options,
+    es2022::{ClassPropertiesOptions, ES2022Options},
+    es2026::ES2026Options,
     options::{BabelOptions, TransformOptions},
     plugins::*,
-    react::{JsxOptions, JsxRuntime, ReactRefreshOptions},
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    react::{JsxOptions, JsxRuntime, ReactRefreshOptions, ReactRuntime},
+    regexp::RegExpOptions,
+    typescript::{ImportsNotUsedAsValues, RewriteExtensionsMode, TypeScriptOptions},
 };
 use crate::{context::TransformCtx, es2015::ES2015, react::React, typescript::TypeScript};
 
@@ -95,15 +108,23 @@ impl<'a> Transformer<'a> {
         let allocator = self.allocator;
         let ast_builder = AstBuilder::new(allocator);
 
+        let x1_react = React::new(self.options.react, ast_builder, &self.ctx);
         let mut transformer = TransformerImpl {
-            x0_typescript: TypeScript::new(self.options.typescript, &self.ctx),
-            x1_react: React::new(self.options.react, ast_builder, &self.ctx),
+            x0_decorator: Decorators::new(self.options.decorator, &self.ctx),
+            x0_typescript: TypeScript::new(
+                self.options.typescript,
+                x1_react.is_automatic_runtime(),
+                &self.ctx,
+            ),
+            x1_react,
+            x2_es2026: ES2026::new(self.options.es2026),
+            x2_es2022: ES2022::new(self.options.es2022, &self.ctx),
             x2_es2021: ES2021::new(self.options.es2021, &self.ctx),
             x2_es2020: ES2020::new(self.options.es2020, &self.ctx),
             x2_es2019: ES2019::new(self.options.es2019),
             x2_es2018: ES2018::new(self.options.es2018),
             x2_es2016: ES2016::new(self.options.es2016, &self.ctx),
-            x3_es2015: ES2015::new(self.options.es2015),
+            x3_es2015: ES2015::new(self.options.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.options.regexp, &self.ctx),
             common: Common::new(&self.ctx),
         };
@@ -115,14 +136,17 @@ impl<'a> Transformer<'a> {
 
 struct TransformerImpl<'a, 'ctx> {
     // NOTE: all callbacks must run in order.
+    x0_decorator: Decorators<'a, 'ctx>,
     x0_typescript: TypeScript<'a, 'ctx>,
     x1_react: React<'a, 'ctx>,
+    x2_es2026: ES2026,
+    x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
     x2_es2020: ES2020<'a, 'ctx>,
     x2_es2019: ES2019,
     x2_es2018: ES2018,
     x2_es2016: ES2016<'a, 'ctx>,
-    x3_es2015: ES2015<'a>,
+    x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
     common: Common<'a, 'ctx>,
 }
@@ -131,6 +155,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_program(program, ctx);
         self.x1_react.enter_program(program, ctx);
+        self.x2_es2020.enter_program(program, ctx);
+        self.x3_es2015.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -148,6 +174,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x0_typescript.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declarator(
@@ -158,6 +185,46 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         self.x0_typescript.enter_variable_declarator(decl, ctx);
     }
 
+    fn exit_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_variable_declarator(decl, ctx);
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_binding_identifier(ident, ctx);
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_identifier_reference(ident, ctx);
+    }
+
+    fn enter_binding_property(
+        &mut self,
+        prop: &mut BindingProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_binding_property(prop, ctx);
+    }
+
+    fn enter_assignment_target_property(
+        &mut self,
+        prop: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_assignment_target_property(prop, ctx);
+    }
+
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_binding_pattern(pat, ctx);
     }
@@ -168,7 +235,13 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_decorator.enter_class(class, ctx);
         self.x0_typescript.enter_class(class, ctx);
+        self.x2_es2022.enter_class(class, ctx);
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2022.exit_class(class, ctx);
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -193,7 +266,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_decorator.enter_expression(expr, ctx);
         self.x0_typescript.enter_expression(expr, ctx);
+        self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
@@ -204,9 +279,26 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x1_react.exit_expression(expr, ctx);
+        self.x2_es2022.exit_expression(expr, ctx);
         self.x3_es2015.exit_expression(expr, ctx);
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_object_property(prop, ctx);
+    }
+
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_object_property(prop, ctx);
+    }
+
+    fn enter_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2021.enter_numeric_literal(lit, ctx);
+    }
+
+    fn enter_big_int_literal(&mut self, lit: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2021.enter_big_int_literal(lit, ctx);
+    }
+
     fn enter_simple_assignment_target(
         &mut self,
         node: &mut SimpleAssignmentTarget<'a>,
@@ -221,6 +313,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x0_typescript.enter_assignment_target(node, ctx);
+        self.x2_es2022.enter_assignment_target(node, ctx);
     }
 
     fn enter_formal_parameter(
@@ -308,8 +401,11 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_statements(stmts, ctx);
+        self.x0_decorator.enter_statements(stmts, ctx);
         self.x0_typescript.enter_statements(stmts, ctx);
         self.x1_react.enter_statements(stmts, ctx);
+        self.x2_es2026.enter_statements(stmts, ctx);
+        self.x3_es2015.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -317,6 +413,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        self.x3_es2015.exit_arrow_function_expression(arrow, ctx);
+
         // Some plugins may add new statements to the ArrowFunctionExpression's body,
         // which can cause issues with the `() => x;` case, as it only allows a single statement.
         // To address this, we wrap the last statement in a return statement and set the expression to false.
@@ -339,6 +437,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.exit_statements(stmts, ctx);
         self.x1_react.exit_statements(stmts, ctx);
+        self.x2_es2022.exit_statements(stmts, ctx);
+        self.x3_es2015.exit_statements(stmts, ctx);
         self.common.exit_statements(stmts, ctx);
     }
 
@@ -352,12 +452,22 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_statement(stmt, ctx);
+        self.x2_es2026.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_declaration(decl, ctx);
     }
 
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_if_statement(stmt, ctx);
     }
@@ -376,20 +486,60 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_for_statement(stmt, ctx);
+        self.x3_es2015.enter_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_for_of_statement(stmt, ctx);
+        self.x2_es2026.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_of_statement(stmt, ctx);
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_in_statement(stmt, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
     }
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
     }
 
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_catch_clause(clause, ctx);
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_variable_declaration(decl, ctx);
+    }
+
     fn enter_import_declaration(
         &mut self,
         node: &mut ImportDeclaration<'a>,
@@ -413,12 +563,4 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     ) {
         self.x0_typescript.enter_export_named_declaration(node, ctx);
     }
-
-    fn enter_ts_export_assignment(
-        &mut self,
-        export_assignment: &mut TSExportAssignment<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        self.x0_typescript.enter_ts_export_assignment(export_assignment, ctx);
-    }
 }
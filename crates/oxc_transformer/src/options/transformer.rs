@@ -5,13 +5,16 @@ use serde_json::{from_value, json, Value};
 
 use crate::{
     compiler_assumptions::CompilerAssumptions,
+    decorator::DecoratorOptions,
     env::{can_enable_plugin, EnvOptions, Versions},
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ES2015Options, ForOfOptions},
     es2016::ES2016Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
     es2019::ES2019Options,
     es2020::ES2020Options,
     es2021::ES2021Options,
+    es2022::{ClassPropertiesOptions, ES2022Options},
+    es2026::ES2026Options,
     options::babel::BabelOptions,
     react::JsxOptions,
     regexp::RegExpOptions,
@@ -40,6 +43,12 @@ pub struct TransformOptions {
     /// [preset-react](https://babeljs.io/docs/babel-preset-react)
     pub react: JsxOptions,
 
+    /// [proposal-decorators](https://babeljs.io/docs/babel-plugin-proposal-decorators)
+    ///
+    /// Class decorators are transformed with the TC39 semantics,
+    /// or the legacy semantics when `experimental_decorators` is set.
+    pub decorator: Option<DecoratorOptions>,
+
     pub regexp: RegExpOptions,
 
     pub es2015: ES2015Options,
@@ -53,6 +62,10 @@ pub struct TransformOptions {
     pub es2020: ES2020Options,
 
     pub es2021: ES2021Options,
+
+    pub es2022: ES2022Options,
+
+    pub es2026: ES2026Options,
 }
 
 impl TransformOptions {
@@ -67,6 +80,8 @@ impl TransformOptions {
                 refresh: Some(ReactRefreshOptions::default()),
                 ..JsxOptions::default()
             },
+            // Turned off because it is not ready.
+            decorator: None,
             regexp: RegExpOptions {
                 sticky_flag: true,
                 unicode_flag: true,
@@ -80,12 +95,32 @@ impl TransformOptions {
             es2015: ES2015Options {
                 // Turned off because it is not ready.
                 arrow_function: None,
+                shorthand_properties: false,
+                computed_properties: false,
+                template_literals: false,
+                destructuring: false,
+                for_of: None,
+                spread: false,
+                use_regenerator: false,
+                block_scoping: None,
+                typeof_symbol: false,
+                new_target: false,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2018: ES2018Options { object_rest_spread: Some(ObjectRestSpreadOptions::default()) },
             es2019: ES2019Options { optional_catch_binding: true },
-            es2020: ES2020Options { nullish_coalescing_operator: true },
-            es2021: ES2021Options { logical_assignment_operators: true },
+            es2020: ES2020Options {
+                nullish_coalescing_operator: true,
+                export_namespace_from: true,
+            },
+            es2021: ES2021Options { logical_assignment_operators: true, numeric_separator: true },
+            es2022: ES2022Options {
+                // Turned off because it is not ready.
+                class_properties: None,
+                private_methods: false,
+                private_in: false,
+            },
+            es2026: ES2026Options { explicit_resource_management: true },
         }
     }
 
@@ -98,6 +133,7 @@ impl TransformOptions {
             es2020: ES2020Options::from_targets_and_bugfixes(targets, bugfixes),
             es2021: ES2021Options::from_targets_and_bugfixes(targets, bugfixes),
             regexp: RegExpOptions::from_targets_and_bugfixes(targets, bugfixes),
+            // Class transforms are not enabled by targets, and must be enabled explicitly
             ..Default::default()
         }
     }
@@ -201,6 +237,66 @@ impl TransformOptions {
             )
         });
 
+        transformer_options.es2015.with_shorthand_properties({
+            let plugin_name = "transform-shorthand-properties";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_computed_properties({
+            let plugin_name = "transform-computed-properties";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_template_literals({
+            let plugin_name = "transform-template-literals";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_destructuring({
+            let plugin_name = "transform-destructuring";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_for_of({
+            let plugin_name = "transform-for-of";
+            get_explicit_plugin_options(plugin_name, options).map(|options| {
+                from_value::<ForOfOptions>(options).unwrap_or_else(|err| {
+                    report_error(plugin_name, &err, false, &mut errors);
+                    ForOfOptions::default()
+                })
+            })
+        });
+
+        transformer_options.es2015.with_spread({
+            let plugin_name = "transform-spread";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_use_regenerator({
+            let plugin_name = "transform-regenerator";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_block_scoping({
+            let plugin_name = "transform-block-scoping";
+            get_explicit_plugin_options(plugin_name, options).map(|options| {
+                from_value::<BlockScopingOptions>(options).unwrap_or_else(|err| {
+                    report_error(plugin_name, &err, false, &mut errors);
+                    BlockScopingOptions::default()
+                })
+            })
+        });
+
+        transformer_options.es2015.with_typeof_symbol({
+            let plugin_name = "transform-typeof-symbol";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2015.with_new_target({
+            let plugin_name = "transform-new-target";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
         transformer_options.es2016.with_exponentiation_operator({
             let plugin_name = "transform-exponentiation-operator";
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
@@ -228,11 +324,65 @@ impl TransformOptions {
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
         });
 
+        transformer_options.es2020.with_export_namespace_from({
+            let plugin_name = "transform-export-namespace-from";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
         transformer_options.es2021.with_logical_assignment_operators({
             let plugin_name = "transform-logical-assignment-operators";
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
         });
 
+        transformer_options.es2021.with_numeric_separator({
+            let plugin_name = "transform-numeric-separator";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
+        transformer_options.es2022.with_class_properties({
+            let plugin_name = "transform-class-properties";
+            get_explicit_plugin_options(plugin_name, options).map(|options| {
+                from_value::<ClassPropertiesOptions>(options).unwrap_or_else(|err| {
+                    report_error(plugin_name, &err, false, &mut errors);
+                    ClassPropertiesOptions::default()
+                })
+            })
+        });
+
+        transformer_options.es2022.with_private_methods({
+            let plugin_name = "transform-private-methods";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2022.with_private_in({
+            let plugin_name = "transform-private-property-in-object";
+            get_explicit_plugin_options(plugin_name, options).is_some()
+        });
+
+        transformer_options.es2026.with_explicit_resource_management(
+            options.has_plugin("transform-explicit-resource-management")
+                || options.has_plugin("proposal-explicit-resource-management"),
+        );
+
+        transformer_options.decorator = {
+            let plugin_name = "proposal-decorators";
+            get_explicit_plugin_options(plugin_name, options).map(|options| {
+                // Babel's legacy decorators are TypeScript's experimental decorators
+                let legacy = options.get("legacy").and_then(Value::as_bool).unwrap_or(false);
+                let experimental_decorators = match options.get("version").and_then(Value::as_str) {
+                    Some("legacy") => true,
+                    None | Some("2023-11") => legacy,
+                    Some(version) => {
+                        let message =
+                            format!("{plugin_name}: version {version:?} is not supported");
+                        errors.push(OxcDiagnostic::error(message).into());
+                        legacy
+                    }
+                };
+                DecoratorOptions { experimental_decorators }
+            })
+        };
+
         transformer_options.typescript = {
             let preset_name = "typescript";
             if options.has_preset("typescript") {
@@ -324,6 +474,12 @@ fn get_enabled_plugin_options(
     }
 }
 
+/// Options of a plugin which is only enabled explicitly, not by `targets`,
+/// because it does not pass the conformance tests yet.
+fn get_explicit_plugin_options(plugin_name: &str, babel_options: &BabelOptions) -> Option<Value> {
+    babel_options.has_plugin(plugin_name).then(|| get_plugin_options(plugin_name, babel_options))
+}
+
 fn report_error(name: &str, err: &serde_json::Error, is_preset: bool, errors: &mut Vec<Error>) {
     let message =
         if is_preset { format!("preset-{name}: {err}",) } else { format!("{name}: {err}",) };
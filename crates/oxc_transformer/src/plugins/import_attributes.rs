@@ -0,0 +1,53 @@
+use oxc_ast::{ast::*, VisitMut};
+
+/// Keyword of import attributes emitted by [ImportAttributes].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImportAttributesKeyword {
+    /// Keep the keyword of the source.
+    #[default]
+    Preserve,
+    /// `import json from "./foo.json" assert { type: "json" }`
+    Assert,
+    /// `import json from "./foo.json" with { type: "json" }`
+    With,
+}
+
+/// Rewrites the keyword of import attributes, for runtimes which only support one of
+/// `assert` (the older import assertions proposal) and `with`.
+///
+/// ```js
+/// import json from "./foo.json" assert { type: "json" };
+/// export * from "./bar.json" assert { type: "json" };
+/// ```
+/// -> with [ImportAttributesKeyword::With]
+/// ```js
+/// import json from "./foo.json" with { type: "json" };
+/// export * from "./bar.json" with { type: "json" };
+/// ```
+///
+/// Options of dynamic `import()` are not changed.
+pub struct ImportAttributes {
+    keyword: ImportAttributesKeyword,
+}
+
+impl<'a> VisitMut<'a> for ImportAttributes {
+    fn visit_with_clause(&mut self, clause: &mut WithClause<'a>) {
+        clause.attributes_keyword.name = match self.keyword {
+            ImportAttributesKeyword::Preserve => return,
+            ImportAttributesKeyword::Assert => Atom::from("assert"),
+            ImportAttributesKeyword::With => Atom::from("with"),
+        };
+    }
+}
+
+impl ImportAttributes {
+    pub fn new(keyword: ImportAttributesKeyword) -> Self {
+        Self { keyword }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'_>) {
+        if self.keyword != ImportAttributesKeyword::Preserve {
+            self.visit_program(program);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::*,
+    visit::{walk_mut, VisitMut},
+    AstBuilder, NONE,
+};
+use oxc_span::{Span, SPAN};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+/// How `import.meta` is handled by [ImportMeta].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMetaMode {
+    /// Leave `import.meta` as is.
+    #[default]
+    Preserve,
+    /// Replace `import.meta` with values available in CommonJS modules.
+    CommonJs,
+}
+
+/// Platform the output runs on, which decides how `import.meta.url` is replaced.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    /// `require("url").pathToFileURL(__filename).href`
+    #[default]
+    Node,
+    /// `document.currentScript && document.currentScript.src || document.baseURI`
+    Browser,
+    /// Checks for `document` at runtime, and uses the browser or Node.js value.
+    Neutral,
+}
+
+/// Replaces `import.meta`, which is only valid in ES modules.
+///
+/// In [ImportMetaMode::CommonJs] mode, with [Platform::Node]:
+///
+/// * `import.meta.url` -> `require("url").pathToFileURL(__filename).href`
+/// * `import.meta` -> `{ url: require("url").pathToFileURL(__filename).href }`
+///
+/// See [Platform] for the replacement of `import.meta.url` on other platforms.
+///
+/// This is meant to run after other transforms. `require`, `__filename` and `document` are
+/// assumed to be globals, so local bindings with those names must not be in scope.
+pub struct ImportMeta<'a> {
+    ast: AstBuilder<'a>,
+    mode: ImportMetaMode,
+    platform: Platform,
+}
+
+impl<'a> VisitMut<'a> for ImportMeta<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `import.meta.url`
+            Expression::StaticMemberExpression(member)
+                if member.property.name == "url" && is_import_meta(&member.object) =>
+            {
+                *expr = self.create_url(member.span);
+            }
+            // `import.meta`
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta" =>
+            {
+                let span = meta.span;
+                let url = self.create_url(SPAN);
+                let property = self.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    self.ast.property_key_identifier_name(SPAN, "url"),
+                    url,
+                    None,
+                    false,
+                    false,
+                    false,
+                );
+                *expr = self.ast.expression_object(span, self.ast.vec1(property), None);
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+}
+
+impl<'a> ImportMeta<'a> {
+    pub fn new(allocator: &'a Allocator, mode: ImportMetaMode) -> Self {
+        Self { ast: AstBuilder::new(allocator), mode, platform: Platform::default() }
+    }
+
+    #[must_use]
+    pub fn with_platform(mut self, platform: Platform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        if self.mode == ImportMetaMode::CommonJs {
+            self.visit_program(program);
+        }
+    }
+
+    fn create_url(&self, span: Span) -> Expression<'a> {
+        match self.platform {
+            Platform::Node => self.create_node_url(span),
+            Platform::Browser => self.create_browser_url(span),
+            // `typeof document === "undefined" ? <node url> : <browser url>`
+            Platform::Neutral => {
+                let test = self.ast.expression_binary(
+                    SPAN,
+                    self.ast.expression_unary(
+                        SPAN,
+                        UnaryOperator::Typeof,
+                        self.ast.expression_identifier_reference(SPAN, "document"),
+                    ),
+                    BinaryOperator::StrictEquality,
+                    self.ast.expression_string_literal(SPAN, "undefined"),
+                );
+                self.ast.expression_conditional(
+                    span,
+                    test,
+                    self.create_node_url(SPAN),
+                    self.create_browser_url(SPAN),
+                )
+            }
+        }
+    }
+
+    /// `require("url").pathToFileURL(__filename).href`
+    fn create_node_url(&self, span: Span) -> Expression<'a> {
+        let require = self.ast.expression_call(
+            SPAN,
+            self.ast.expression_identifier_reference(SPAN, "require"),
+            NONE,
+            self.ast.vec1(Argument::from(self.ast.expression_string_literal(SPAN, "url"))),
+            false,
+        );
+        let callee = Expression::from(self.ast.member_expression_static(
+            SPAN,
+            require,
+            self.ast.identifier_name(SPAN, "pathToFileURL"),
+            false,
+        ));
+        let filename = self.ast.expression_identifier_reference(SPAN, "__filename");
+        let call = self.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            self.ast.vec1(Argument::from(filename)),
+            false,
+        );
+        Expression::from(self.ast.member_expression_static(
+            span,
+            call,
+            self.ast.identifier_name(SPAN, "href"),
+            false,
+        ))
+    }
+
+    /// `document.currentScript && document.currentScript.src || document.baseURI`
+    fn create_browser_url(&self, span: Span) -> Expression<'a> {
+        let current_script = || self.create_document_member("currentScript");
+        let src = Expression::from(self.ast.member_expression_static(
+            SPAN,
+            current_script(),
+            self.ast.identifier_name(SPAN, "src"),
+            false,
+        ));
+        let script_src =
+            self.ast.expression_logical(SPAN, current_script(), LogicalOperator::And, src);
+        self.ast.expression_logical(
+            span,
+            script_src,
+            LogicalOperator::Or,
+            self.create_document_member("baseURI"),
+        )
+    }
+
+    /// `document.<property>`
+    fn create_document_member(&self, property: &'static str) -> Expression<'a> {
+        Expression::from(self.ast.member_expression_static(
+            SPAN,
+            self.ast.expression_identifier_reference(SPAN, "document"),
+            self.ast.identifier_name(SPAN, property),
+            false,
+        ))
+    }
+}
+
+fn is_import_meta(expr: &Expression) -> bool {
+    matches!(expr, Expression::MetaProperty(meta) if meta.meta.name == "import" && meta.property.name == "meta")
+}
@@ -0,0 +1,97 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{ast::*, visit::walk, Trivias, Visit};
+
+/// Removes `@__PURE__` annotations from calls of functions which are known to have side effects,
+/// so minifiers do not remove those calls when their result is unused.
+///
+/// ```js
+/// const a = /* @__PURE__ */ track();
+/// const b = /* @__PURE__ */ console.log("b");
+/// const c = /* @__PURE__ */ create();
+/// ```
+/// -> with `track` and `console.log`
+/// ```js
+/// const a = track();
+/// const b = console.log("b");
+/// const c = /* @__PURE__ */ create();
+/// ```
+///
+/// Functions are matched by the name of the callee, which is an identifier or
+/// a chain of static member expressions, e.g. `console.log`.
+pub struct ImpureFunctions<'n> {
+    names: &'n [String],
+    /// Starts of calls of impure functions
+    impure_calls: FxHashSet<u32>,
+}
+
+impl<'a, 'n> Visit<'a> for ImpureFunctions<'n> {
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        self.check_callee(&expr.callee, expr.span);
+        walk::walk_call_expression(self, expr);
+    }
+
+    fn visit_new_expression(&mut self, expr: &NewExpression<'a>) {
+        self.check_callee(&expr.callee, expr.span);
+        walk::walk_new_expression(self, expr);
+    }
+}
+
+impl<'n> ImpureFunctions<'n> {
+    pub fn new(names: &'n [String]) -> Self {
+        Self { names, impure_calls: FxHashSet::default() }
+    }
+
+    /// Returns `trivias` without the `@__PURE__` and `#__PURE__` comments of calls
+    /// of impure functions in `program`.
+    pub fn build(
+        &mut self,
+        program: &Program<'_>,
+        source_text: &str,
+        trivias: &Trivias,
+    ) -> Trivias {
+        if self.names.is_empty() {
+            return trivias.clone();
+        }
+        self.visit_program(program);
+
+        let comments = trivias
+            .comments()
+            .filter(|comment| {
+                !(self.impure_calls.contains(&comment.attached_to) && {
+                    let text = comment.span.source_text(source_text);
+                    text.contains("@__PURE__") || text.contains("#__PURE__")
+                })
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        Trivias::new(comments.into_boxed_slice(), trivias.irregular_whitespaces().to_vec())
+    }
+
+    fn check_callee(&mut self, callee: &Expression<'_>, span: Span) {
+        let mut name = String::new();
+        if callee_name(callee, &mut name) && self.names.iter().any(|impure| *impure == name) {
+            self.impure_calls.insert(span.start);
+        }
+    }
+}
+
+/// Write the name of `callee` to `name`, e.g. `console.log`.
+/// Returns `false` if `callee` is not an identifier or a chain of static member expressions.
+fn callee_name(callee: &Expression<'_>, name: &mut String) -> bool {
+    match callee.without_parentheses() {
+        Expression::Identifier(ident) => {
+            name.push_str(&ident.name);
+            true
+        }
+        Expression::StaticMemberExpression(member) => {
+            if !callee_name(&member.object, name) {
+                return false;
+            }
+            name.push('.');
+            name.push_str(&member.property.name);
+            true
+        }
+        _ => false,
+    }
+}
@@ -1,5 +1,21 @@
+mod import_attributes;
+mod import_meta;
+mod impure_functions;
 mod inject_global_variables;
+mod reattach_comments;
+mod remove_unused_imports;
 mod replace_global_defines;
+mod strip_types;
+mod top_level_this;
+mod unsupported_syntax;
 
+pub use import_attributes::*;
+pub use import_meta::*;
+pub use impure_functions::*;
 pub use inject_global_variables::*;
+pub use reattach_comments::*;
+pub use remove_unused_imports::*;
 pub use replace_global_defines::*;
+pub use strip_types::*;
+pub use top_level_this::*;
+pub use unsupported_syntax::*;
@@ -0,0 +1,98 @@
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, visit::walk, Trivias, Visit};
+use oxc_span::GetSpan;
+
+/// Moves comments attached to statements which were removed by transforms onto the
+/// following statement, so they are not lost in codegen.
+///
+/// ```ts
+/// /** Comment */
+/// import type { A } from "a";
+/// const b = 1;
+/// ```
+/// ->
+/// ```js
+/// /** Comment */
+/// const b = 1;
+/// ```
+///
+/// Create this before transforming, to record the statements of the original program,
+/// and call [`ReattachComments::build`] after all transforms have run.
+/// Comments on the last statements of a statement list are dropped.
+#[derive(Default)]
+pub struct ReattachComments {
+    /// Starts of the statements in each statement list of the original program
+    statement_lists: Vec<Vec<u32>>,
+}
+
+impl<'a> Visit<'a> for ReattachComments {
+    fn visit_statements(&mut self, stmts: &ArenaVec<'a, Statement<'a>>) {
+        self.statement_lists.push(stmts.iter().map(|stmt| stmt.span().start).collect());
+        for stmt in stmts {
+            self.visit_statement(stmt);
+        }
+    }
+}
+
+impl ReattachComments {
+    pub fn new(program: &Program<'_>) -> Self {
+        let mut reattach = Self::default();
+        reattach.visit_program(program);
+        reattach
+    }
+
+    /// Returns `trivias` with comments of removed statements attached to the next
+    /// statement which remains in `program`.
+    pub fn build(&self, program: &Program<'_>, trivias: &Trivias) -> Trivias {
+        let remaining = RemainingStatements::collect(program);
+
+        let mut comments = trivias.comments().copied().collect::<Vec<_>>();
+        for starts in &self.statement_lists {
+            for (index, &start) in starts.iter().enumerate() {
+                if remaining.contains(start) {
+                    continue;
+                }
+                let Some(&next) =
+                    starts[index + 1..].iter().find(|&&next| remaining.contains(next))
+                else {
+                    continue;
+                };
+                for comment in comments.iter_mut().filter(|comment| comment.attached_to == start) {
+                    comment.attached_to = next;
+                }
+            }
+        }
+
+        Trivias::new(comments.into_boxed_slice(), trivias.irregular_whitespaces().to_vec())
+    }
+}
+
+/// Starts of all statements in a program, excluding statements created by transforms.
+#[derive(Default)]
+struct RemainingStatements {
+    starts: FxHashSet<u32>,
+}
+
+impl<'a> Visit<'a> for RemainingStatements {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        let span = stmt.span();
+        if !span.is_unspanned() {
+            self.starts.insert(span.start);
+        }
+        walk::walk_statement(self, stmt);
+    }
+}
+
+impl RemainingStatements {
+    fn collect(program: &Program<'_>) -> Self {
+        let mut remaining = Self::default();
+        remaining.visit_program(program);
+        remaining
+    }
+
+    fn contains(&self, start: u32) -> bool {
+        self.starts.contains(&start)
+    }
+}
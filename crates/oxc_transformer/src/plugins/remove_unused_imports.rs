@@ -0,0 +1,41 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{ast::*, Visit};
+
+/// Removes import specifiers whose local bindings are no longer referenced.
+///
+/// Other transforms (e.g. TypeScript type stripping) may remove the last use of a value import.
+/// This is meant to run after them. References are matched by name, so a shadowed
+/// binding keeps its import alive.
+///
+/// Side-effect imports (`import "foo"` and `import {} from "foo"`) are always kept.
+#[derive(Default)]
+pub struct RemoveUnusedImports<'a> {
+    references: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> Visit<'a> for RemoveUnusedImports<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.insert(ident.name.clone());
+    }
+}
+
+impl<'a> RemoveUnusedImports<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+
+        program.body.retain_mut(|stmt| {
+            let Statement::ImportDeclaration(decl) = stmt else { return true };
+            let Some(specifiers) = &mut decl.specifiers else { return true };
+            if specifiers.is_empty() {
+                return true;
+            }
+            specifiers.retain(|specifier| self.references.contains(&specifier.local().name));
+            !specifiers.is_empty()
+        });
+    }
+}
@@ -0,0 +1,377 @@
+use std::iter;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{identifier::is_line_terminator, scope::ScopeFlags};
+
+/// Modifiers of class members and classes which only exist in TypeScript.
+const TS_MODIFIERS: &[&str] =
+    &["abstract", "override", "private", "protected", "public", "readonly"];
+
+/// Erases TypeScript type syntax by replacing it with whitespace, without any other transforms.
+///
+/// ```ts
+/// function f<T>(a: T, b?: number): T { return a as T; }
+/// ```
+/// ->
+/// ```js
+/// function f   (a   , b         )    { return a     ; }
+/// ```
+///
+/// Line breaks are kept, so the line and column of all remaining code are unchanged,
+/// and no source map is needed.
+///
+/// TypeScript syntax with runtime semantics cannot be erased, and is reported as an error:
+/// enums, namespaces, parameter properties, `import x = require()` and `export =`.
+/// Ambient (`declare`) forms of them are erased.
+pub struct StripTypes<'a> {
+    source_text: &'a str,
+    /// Ranges of `source_text` to replace with whitespace
+    spans: Vec<Span>,
+    /// Positions of blanked code to replace with `)`
+    close_parens: Vec<u32>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+pub struct StripTypesReturn {
+    pub source_text: String,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Visit<'a> for StripTypes<'a> {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        match stmt {
+            Statement::ImportDeclaration(decl) if decl.import_kind.is_type() => {
+                self.blank(decl.span);
+            }
+            Statement::ExportAllDeclaration(decl) if decl.export_kind.is_type() => {
+                self.blank(decl.span);
+            }
+            Statement::ExportNamedDeclaration(decl)
+                if decl.export_kind.is_type()
+                    || decl.declaration.as_ref().is_some_and(is_type_declaration) =>
+            {
+                self.blank(decl.span);
+            }
+            Statement::ExportDefaultDeclaration(decl)
+                if matches!(
+                    decl.declaration,
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+                ) =>
+            {
+                self.blank(decl.span);
+            }
+            Statement::TSImportEqualsDeclaration(decl) if decl.import_kind.is_type() => {
+                self.blank(decl.span);
+            }
+            Statement::TSNamespaceExportDeclaration(decl) => self.blank(decl.span),
+            Statement::TSExportAssignment(decl) => self.unsupported("`export =`", decl.span),
+            Statement::TSImportEqualsDeclaration(decl) => {
+                self.unsupported("`import =`", decl.span);
+            }
+            match_declaration!(Statement) if is_type_declaration(stmt.to_declaration()) => {
+                self.blank(stmt.span());
+            }
+            _ => walk::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_declaration(&mut self, decl: &Declaration<'a>) {
+        match decl {
+            Declaration::TSEnumDeclaration(decl) => self.unsupported("Enum", decl.span),
+            Declaration::TSModuleDeclaration(decl) => {
+                self.unsupported("Namespace", decl.span);
+            }
+            _ => walk::walk_declaration(self, decl),
+        }
+    }
+
+    fn visit_import_declaration_specifiers(
+        &mut self,
+        specifiers: &ArenaVec<'a, ImportDeclarationSpecifier<'a>>,
+    ) {
+        let spans = specifiers.iter().map(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(s) => (s.span, s.import_kind.is_type()),
+            _ => (specifier.span(), false),
+        });
+        self.blank_type_specifiers(&spans.collect::<Vec<_>>());
+    }
+
+    fn visit_export_specifiers(&mut self, specifiers: &ArenaVec<'a, ExportSpecifier<'a>>) {
+        let spans =
+            specifiers.iter().map(|specifier| (specifier.span, specifier.export_kind.is_type()));
+        self.blank_type_specifiers(&spans.collect::<Vec<_>>());
+    }
+
+    fn visit_ts_type_annotation(&mut self, annotation: &TSTypeAnnotation<'a>) {
+        self.blank(annotation.span);
+    }
+
+    fn visit_ts_type_parameter_declaration(&mut self, params: &TSTypeParameterDeclaration<'a>) {
+        self.blank(params.span);
+    }
+
+    fn visit_ts_type_parameter_instantiation(&mut self, params: &TSTypeParameterInstantiation<'a>) {
+        self.blank(params.span);
+    }
+
+    /// `x as T`
+    fn visit_ts_as_expression(&mut self, expr: &TSAsExpression<'a>) {
+        self.blank(Span::new(expr.expression.span().end, expr.span.end));
+        self.visit_expression(&expr.expression);
+    }
+
+    /// `x satisfies T`
+    fn visit_ts_satisfies_expression(&mut self, expr: &TSSatisfiesExpression<'a>) {
+        self.blank(Span::new(expr.expression.span().end, expr.span.end));
+        self.visit_expression(&expr.expression);
+    }
+
+    /// `x!`
+    fn visit_ts_non_null_expression(&mut self, expr: &TSNonNullExpression<'a>) {
+        self.blank(Span::new(expr.span.end - 1, expr.span.end));
+        self.visit_expression(&expr.expression);
+    }
+
+    /// `<T>x`
+    fn visit_ts_type_assertion(&mut self, expr: &TSTypeAssertion<'a>) {
+        self.blank(Span::new(expr.span.start, expr.expression.span().start));
+        self.visit_expression(&expr.expression);
+    }
+
+    /// `(a): T => a`
+    ///
+    /// A line break is not allowed before `=>`, so if the return type spans lines, the `)`
+    /// of the parameters is moved to the end of the return type.
+    #[allow(clippy::cast_possible_truncation)]
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        if let Some(return_type) = &arrow.return_type {
+            let start = return_type.span.start as usize;
+            let text = &self.source_text[start..return_type.span.end as usize];
+            if text.chars().any(is_line_terminator) {
+                if let Some(paren) = self.source_text[..start].rfind(')') {
+                    self.blank(Span::sized(paren as u32, 1));
+                    self.close_parens.push(return_type.span.end - 1);
+                }
+            }
+        }
+        walk::walk_arrow_function_expression(self, arrow);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        // `function f(this: T, a)`
+        if let Some(this_param) = &func.this_param {
+            let end =
+                func.params.items.first().map_or(this_param.span.end, |param| param.span.start);
+            self.blank(Span::new(this_param.span.start, end));
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    /// `a?: T`, `{ a }?: T`
+    fn visit_formal_parameter(&mut self, param: &FormalParameter<'a>) {
+        if param.accessibility.is_some() || param.readonly || param.r#override {
+            self.unsupported("Parameter property", param.span);
+        }
+        if param.pattern.optional {
+            // The span of an object or array pattern already includes the `?`,
+            // so search backwards from the type annotation, or the end of the parameter.
+            let end = param
+                .pattern
+                .type_annotation
+                .as_ref()
+                .map_or(param.span.end, |annotation| annotation.span.start);
+            self.blank_token_before(end, '?');
+        }
+        walk::walk_formal_parameter(self, param);
+    }
+
+    /// `let a!: T`
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if declarator.definite {
+            self.blank_token_after(declarator.id.kind.span().end, '!');
+        }
+        walk::walk_variable_declarator(self, declarator);
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if class.r#abstract {
+            let start = class.decorators.last().map_or(class.span.start, |d| d.span.end);
+            let end = class.id.as_ref().map_or(class.body.span.start, |id| id.span.start);
+            self.blank_modifiers(start, end);
+        }
+        walk::walk_class(self, class);
+    }
+
+    /// `implements A, B`
+    #[allow(clippy::cast_possible_truncation)]
+    fn visit_ts_class_implementses(&mut self, implements: &ArenaVec<'a, TSClassImplements<'a>>) {
+        let (Some(first), Some(last)) = (implements.first(), implements.last()) else { return };
+        let start = self.source_text[..first.span.start as usize]
+            .rfind("implements")
+            .map_or(first.span.start, |start| start as u32);
+        self.blank(Span::new(start, last.span.end));
+    }
+
+    fn visit_class_element(&mut self, element: &ClassElement<'a>) {
+        match element {
+            ClassElement::TSIndexSignature(_) => self.blank(element.span()),
+            ClassElement::MethodDefinition(method)
+                if method.r#type.is_abstract() || method.value.body.is_none() =>
+            {
+                self.blank(method.span);
+            }
+            ClassElement::MethodDefinition(method) => {
+                self.blank_member_modifiers(&method.decorators, method.span, &method.key);
+                if method.optional {
+                    self.blank_token_after(method.key.span().end, '?');
+                }
+                walk::walk_class_element(self, element);
+            }
+            ClassElement::PropertyDefinition(prop) if prop.r#type.is_abstract() || prop.declare => {
+                self.blank(prop.span);
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                self.blank_member_modifiers(&prop.decorators, prop.span, &prop.key);
+                if prop.optional {
+                    self.blank_token_after(prop.key.span().end, '?');
+                }
+                if prop.definite {
+                    self.blank_token_after(prop.key.span().end, '!');
+                }
+                walk::walk_class_element(self, element);
+            }
+            ClassElement::AccessorProperty(prop) if prop.r#type.is_abstract() => {
+                self.blank(prop.span);
+            }
+            ClassElement::AccessorProperty(prop) => {
+                self.blank_member_modifiers(&prop.decorators, prop.span, &prop.key);
+                if prop.definite {
+                    self.blank_token_after(prop.key.span().end, '!');
+                }
+                walk::walk_class_element(self, element);
+            }
+            ClassElement::StaticBlock(_) => walk::walk_class_element(self, element),
+        }
+    }
+}
+
+impl<'a> StripTypes<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text, spans: vec![], close_parens: vec![], errors: vec![] }
+    }
+
+    pub fn build(mut self, program: &Program<'a>) -> StripTypesReturn {
+        self.visit_program(program);
+
+        self.spans.sort_unstable_by_key(|span| span.start);
+        let mut source_text = String::with_capacity(self.source_text.len());
+        let mut end = 0;
+        for span in &self.spans {
+            let start = (span.start as usize).max(end);
+            let span_end = (span.end as usize).max(start);
+            source_text.push_str(&self.source_text[end..start]);
+            for c in self.source_text[start..span_end].chars() {
+                if is_line_terminator(c) {
+                    source_text.push(c);
+                } else {
+                    // Keep byte offsets of the following code unchanged
+                    source_text.extend(iter::repeat(' ').take(c.len_utf8()));
+                }
+            }
+            end = span_end;
+        }
+        source_text.push_str(&self.source_text[end..]);
+        for pos in self.close_parens {
+            let pos = pos as usize;
+            source_text.replace_range(pos..=pos, ")");
+        }
+
+        StripTypesReturn { source_text, errors: self.errors }
+    }
+
+    fn blank(&mut self, span: Span) {
+        self.spans.push(span);
+    }
+
+    fn unsupported(&mut self, syntax: &str, span: Span) {
+        self.errors.push(
+            OxcDiagnostic::error(format!("{syntax} is not supported when only stripping types."))
+                .with_label(span),
+        );
+    }
+
+    /// Blank `token` if it is the next character after `pos`, ignoring whitespace.
+    #[allow(clippy::cast_possible_truncation)]
+    fn blank_token_after(&mut self, pos: u32, token: char) {
+        let rest = &self.source_text[pos as usize..];
+        let offset = rest.len() - rest.trim_start().len();
+        if rest[offset..].starts_with(token) {
+            let start = pos + offset as u32;
+            self.blank(Span::sized(start, token.len_utf8() as u32));
+        }
+    }
+
+    /// Blank `token` if it is the previous character before `pos`, ignoring whitespace.
+    #[allow(clippy::cast_possible_truncation)]
+    fn blank_token_before(&mut self, pos: u32, token: char) {
+        let rest = self.source_text[..pos as usize].trim_end();
+        if rest.ends_with(token) {
+            let start = rest.len() as u32 - token.len_utf8() as u32;
+            self.blank(Span::sized(start, token.len_utf8() as u32));
+        }
+    }
+
+    /// Blank TypeScript modifier keywords between `start` and `end`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn blank_modifiers(&mut self, start: u32, end: u32) {
+        let text = &self.source_text[start as usize..end as usize];
+        let mut word_start = None;
+        for (index, c) in text.char_indices().chain(iter::once((text.len(), ' '))) {
+            if c.is_ascii_alphabetic() {
+                word_start.get_or_insert(index);
+            } else if let Some(word_start) = word_start.take() {
+                if TS_MODIFIERS.contains(&&text[word_start..index]) {
+                    self.blank(Span::new(start + word_start as u32, start + index as u32));
+                }
+            }
+        }
+    }
+
+    /// Blank modifiers of a class member, between its decorators and its key.
+    fn blank_member_modifiers(
+        &mut self,
+        decorators: &[Decorator<'a>],
+        span: Span,
+        key: &PropertyKey<'a>,
+    ) {
+        let start = decorators.last().map_or(span.start, |decorator| decorator.span.end);
+        self.blank_modifiers(start, key.span().start);
+    }
+
+    /// Blank type-only specifiers in `{ a, type B, c }`, with their trailing comma.
+    fn blank_type_specifiers(&mut self, specifiers: &[(Span, bool)]) {
+        for (index, &(span, is_type)) in specifiers.iter().enumerate() {
+            if !is_type {
+                continue;
+            }
+            let end = specifiers.get(index + 1).map_or(span.end, |(next, _)| next.start);
+            self.blank(Span::new(span.start, end));
+        }
+    }
+}
+
+/// Declarations which only exist in the type system, or are ambient.
+fn is_type_declaration(decl: &Declaration<'_>) -> bool {
+    match decl {
+        Declaration::TSInterfaceDeclaration(_) | Declaration::TSTypeAliasDeclaration(_) => true,
+        Declaration::VariableDeclaration(decl) => decl.declare,
+        Declaration::FunctionDeclaration(func) => func.declare || func.body.is_none(),
+        Declaration::ClassDeclaration(class) => class.declare,
+        Declaration::TSModuleDeclaration(decl) => decl.declare,
+        Declaration::TSEnumDeclaration(decl) => decl.declare,
+        Declaration::TSImportEqualsDeclaration(_) => false,
+    }
+}
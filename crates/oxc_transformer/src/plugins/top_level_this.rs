@@ -0,0 +1,87 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::*,
+    visit::{walk_mut, VisitMut},
+    AstBuilder,
+};
+use oxc_syntax::scope::ScopeFlags;
+
+/// Replaces top-level `this` in ES modules with `void 0`, for output which runs as CommonJS.
+///
+/// `this` is `undefined` at the top level of ES modules, but is `module.exports` in CommonJS
+/// modules. Same as Babel's `@babel/plugin-transform-modules-commonjs` without `allowTopLevelThis`.
+///
+/// ```js
+/// export const self = this;
+/// const f = () => this;
+/// function g() { return this; }
+/// ```
+/// ->
+/// ```js
+/// export const self = void 0;
+/// const f = () => void 0;
+/// function g() { return this; }
+/// ```
+///
+/// `this` inside functions, and inside class bodies other than computed keys, is not top-level,
+/// so is left as is.
+pub struct TopLevelThis<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> VisitMut<'a> for TopLevelThis<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            *expr = self.ast.void_0(this.span);
+        } else {
+            walk_mut::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
+        // Functions have their own `this`
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // Only the heritage, decorators and computed keys are evaluated with the outer `this`
+        self.visit_decorators(&mut class.decorators);
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(method) => {
+                    self.visit_decorators(&mut method.decorators);
+                    if method.computed {
+                        self.visit_property_key(&mut method.key);
+                    }
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    self.visit_decorators(&mut prop.decorators);
+                    if prop.computed {
+                        self.visit_property_key(&mut prop.key);
+                    }
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    self.visit_decorators(&mut prop.decorators);
+                    if prop.computed {
+                        self.visit_property_key(&mut prop.key);
+                    }
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => {}
+            }
+        }
+    }
+}
+
+impl<'a> TopLevelThis<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        if program.source_type.is_module() {
+            self.visit_program(program);
+        }
+    }
+}
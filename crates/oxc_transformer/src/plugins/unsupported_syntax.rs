@@ -0,0 +1,225 @@
+use std::{fmt, str::FromStr};
+
+use cow_utils::CowUtils;
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_syntax::scope::ScopeFlags;
+
+/// ECMAScript version which the output should run in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ESTarget {
+    ES5,
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+    ES2023,
+    ES2024,
+    ESNext,
+}
+
+impl FromStr for ESTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.cow_to_ascii_lowercase().as_ref() {
+            "es5" => Ok(Self::ES5),
+            "es6" | "es2015" => Ok(Self::ES2015),
+            "es2016" => Ok(Self::ES2016),
+            "es2017" => Ok(Self::ES2017),
+            "es2018" => Ok(Self::ES2018),
+            "es2019" => Ok(Self::ES2019),
+            "es2020" => Ok(Self::ES2020),
+            "es2021" => Ok(Self::ES2021),
+            "es2022" => Ok(Self::ES2022),
+            "es2023" => Ok(Self::ES2023),
+            "es2024" => Ok(Self::ES2024),
+            "esnext" => Ok(Self::ESNext),
+            _ => Err(format!("Invalid target \"{s}\".")),
+        }
+    }
+}
+
+impl fmt::Display for ESTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ESNext => f.write_str("ESNext"),
+            target => write!(f, "{target:?}"),
+        }
+    }
+}
+
+/// Reports syntax which cannot be transformed or polyfilled for `target`,
+/// so it would fail at runtime:
+///
+/// * BigInt literals (`10n`), before ES2020.
+///
+/// With [`UnsupportedSyntax::with_untransformed_syntax`], also reports syntax newer than `target`
+/// which could be transformed, but was not, e.g. `a ?? b` when targeting ES2015 without the
+/// nullish coalescing operator transform enabled. Run it after transforms for this, so only
+/// syntax which remains in the output is reported.
+pub struct UnsupportedSyntax {
+    target: ESTarget,
+    untransformed_syntax: bool,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Visit<'a> for UnsupportedSyntax {
+    fn visit_big_int_literal(&mut self, lit: &BigIntLiteral<'a>) {
+        if self.target < ESTarget::ES2020 {
+            self.errors.push(
+                OxcDiagnostic::warn("BigInt literals are not supported by the configured target.")
+                    .with_help("BigInt cannot be polyfilled. Use a target of ES2020 or later.")
+                    .with_label(lit.span),
+            );
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            self.report("`let` and `const` declarations are", ESTarget::ES2015, decl.span);
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.report("Arrow functions are", ESTarget::ES2015, arrow.span);
+        if arrow.r#async {
+            self.report("Async functions are", ESTarget::ES2017, arrow.span);
+        }
+        walk::walk_arrow_function_expression(self, arrow);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.generator {
+            self.report("Generators are", ESTarget::ES2015, func.span);
+        }
+        if func.r#async {
+            self.report("Async functions are", ESTarget::ES2017, func.span);
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.report("Classes are", ESTarget::ES2015, class.span);
+        walk::walk_class(self, class);
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        self.report("Template literals are", ESTarget::ES2015, lit.span);
+        walk::walk_template_literal(self, lit);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await {
+            self.report("`for await...of` statements are", ESTarget::ES2018, stmt.span);
+        } else {
+            self.report("`for...of` statements are", ESTarget::ES2015, stmt.span);
+        }
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        if expr.operator == BinaryOperator::Exponential {
+            self.report("The exponentiation operator (`**`) is", ESTarget::ES2016, expr.span);
+        }
+        walk::walk_binary_expression(self, expr);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        match expr.operator {
+            AssignmentOperator::Exponential => {
+                self.report("The exponentiation operator (`**=`) is", ESTarget::ES2016, expr.span);
+            }
+            AssignmentOperator::LogicalAnd
+            | AssignmentOperator::LogicalOr
+            | AssignmentOperator::LogicalNullish => {
+                self.report(
+                    "Logical assignment operators (`&&=`, `||=` and `??=`) are",
+                    ESTarget::ES2021,
+                    expr.span,
+                );
+            }
+            _ => {}
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_object_expression(&mut self, expr: &ObjectExpression<'a>) {
+        for property in &expr.properties {
+            if let ObjectPropertyKind::SpreadProperty(spread) = property {
+                self.report("Object spread properties are", ESTarget::ES2018, spread.span);
+            }
+        }
+        walk::walk_object_expression(self, expr);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
+        if clause.param.is_none() {
+            self.report("Optional catch bindings are", ESTarget::ES2019, clause.span);
+        }
+        walk::walk_catch_clause(self, clause);
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        if expr.operator == LogicalOperator::Coalesce {
+            self.report("The nullish coalescing operator (`??`) is", ESTarget::ES2020, expr.span);
+        }
+        walk::walk_logical_expression(self, expr);
+    }
+
+    fn visit_chain_expression(&mut self, expr: &ChainExpression<'a>) {
+        self.report("Optional chaining (`?.`) is", ESTarget::ES2020, expr.span);
+        walk::walk_chain_expression(self, expr);
+    }
+
+    fn visit_property_definition(&mut self, def: &PropertyDefinition<'a>) {
+        self.report("Class fields are", ESTarget::ES2022, def.span);
+        walk::walk_property_definition(self, def);
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        self.report("Class static blocks are", ESTarget::ES2022, block.span);
+        walk::walk_static_block(self, block);
+    }
+
+    fn visit_private_in_expression(&mut self, expr: &PrivateInExpression<'a>) {
+        self.report("Private field checks (`#x in obj`) are", ESTarget::ES2022, expr.span);
+        walk::walk_private_in_expression(self, expr);
+    }
+}
+
+impl UnsupportedSyntax {
+    pub fn new(target: ESTarget) -> Self {
+        Self { target, untransformed_syntax: false, errors: vec![] }
+    }
+
+    /// Also report syntax newer than the target which was not transformed.
+    #[must_use]
+    pub fn with_untransformed_syntax(mut self, yes: bool) -> Self {
+        self.untransformed_syntax = yes;
+        self
+    }
+
+    pub fn build(mut self, program: &Program<'_>) -> Vec<OxcDiagnostic> {
+        self.visit_program(program);
+        self.errors
+    }
+
+    /// Report untransformed syntax `feature`, which is supported since `since`.
+    fn report(&mut self, feature: &str, since: ESTarget, span: Span) {
+        if self.untransformed_syntax && self.target < since {
+            self.errors.push(
+                OxcDiagnostic::warn(format!("{feature} not supported by the configured target."))
+                    .with_help(format!(
+                        "Enable the transform for it, or use a target of {since} or later."
+                    ))
+                    .with_label(span),
+            );
+        }
+    }
+}
@@ -31,6 +31,8 @@ pub fn valueless_key(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-pub fn spread_children_are_not_supported(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Spread children are not supported in React.").with_label(span)
+pub fn missing_key(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Missing \"key\" prop for element in iterator.")
+        .with_help("Add a unique `key` prop to the element returned from the `.map()` callback.")
+        .with_label(span)
 }
@@ -34,7 +34,7 @@
 //!
 //! ```js
 //! // Development mode
-//! var _jsxFileName = "<CWD>/test.js";
+//! var _jsxFileName = "test.js";
 //! import { jsxDEV as _jsxDEV, Fragment as _Fragment } from "react/jsx-dev-runtime";
 //! _jsxDEV(
 //!     "div", { children: "foo" }, void 0, false,
@@ -68,7 +68,7 @@
 //!
 //! ```js
 //! // Development mode
-//! var _jsxFileName = "<CWD>/test.js";
+//! var _jsxFileName = "test.js";
 //! React.createElement("div", {
 //!     __self: this,
 //!     __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 }
@@ -80,6 +80,41 @@
 //! React.createElement(React.Fragment, null, "foo");
 //! ```
 //!
+//! ### Hoisting constant props
+//!
+//! With `hoist_constant_props` enabled in automatic runtime, props objects which contain only
+//! constant values are hoisted to module-level variables.
+//!
+//! Input:
+//! ```js
+//! <div className="foo" />;
+//! <div className={foo} />;
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { jsx as _jsx } from "react/jsx-runtime";
+//! var _props = { className: "foo" };
+//! _jsx("div", _props);
+//! _jsx("div", { className: foo });
+//! ```
+//!
+//! ### Runtimes compatible with React
+//!
+//! With `react_runtime` set to `Compat` in automatic runtime, children are passed as the third
+//! argument instead of the key, which is passed in props like other attributes.
+//!
+//! Input:
+//! ```js
+//! <div key="a">foo<br /></div>;
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+//! _jsxs("div", { key: "a" }, ["foo", _jsx("br", {})]);
+//! ```
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-react-jsx](https://babeljs.io/docs/babel-plugin-transform-react-jsx).
@@ -103,7 +138,7 @@ use super::diagnostics;
 pub use super::{
     jsx_self::ReactJsxSelf,
     jsx_source::ReactJsxSource,
-    options::{JsxOptions, JsxRuntime},
+    options::{JsxOptions, JsxRuntime, ReactRuntime},
 };
 use crate::{
     common::module_imports::NamedImport, helpers::bindings::BoundIdentifier, TransformCtx,
@@ -119,6 +154,9 @@ pub struct ReactJsx<'a, 'ctx> {
 
     // States
     bindings: Bindings<'a, 'ctx>,
+
+    /// Declarators of hoisted constant props objects
+    hoisted_props: std::vec::Vec<VariableDeclarator<'a>>,
 }
 
 /// Bindings for different import options
@@ -442,6 +480,7 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
             jsx_self: ReactJsxSelf::new(ctx),
             jsx_source: ReactJsxSource::new(ctx),
             bindings,
+            hoisted_props: vec![],
         }
     }
 }
@@ -449,6 +488,7 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
 impl<'a, 'ctx> Traverse<'a> for ReactJsx<'a, 'ctx> {
     fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.insert_filename_var_statement(ctx);
+        self.insert_hoisted_props(ctx);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -489,13 +529,52 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
         }
     }
 
+    /// Add `var _props = { ... }` declarators for hoisted props objects to top level.
+    fn insert_hoisted_props(&mut self, ctx: &mut TraverseCtx<'a>) {
+        for declarator in self.hoisted_props.drain(..) {
+            self.ctx.var_declarations.insert_declarator(declarator, ctx);
+        }
+    }
+
+    /// Hoist `{ className: "foo" }` to `var _props = { className: "foo" }`,
+    /// and return a reference to `_props`.
+    fn hoist_props(
+        &mut self,
+        properties: Vec<'a, ObjectPropertyKind<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = BoundIdentifier::new_uid_in_root_scope(
+            "props",
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let id = ctx.ast.binding_pattern(
+            ctx.ast
+                .binding_pattern_kind_from_binding_identifier(binding.create_binding_identifier()),
+            NONE,
+            false,
+        );
+        let init = ctx.ast.expression_object(SPAN, properties, None);
+        self.hoisted_props.push(ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            id,
+            Some(init),
+            false,
+        ));
+        ctx.ast.expression_from_identifier_reference(binding.create_read_reference(ctx))
+    }
+
     fn transform_jsx<'b>(
         &mut self,
         e: &JSXElementOrFragment<'a, 'b>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         let is_fragment = e.is_fragment();
-        let has_key_after_props_spread = e.has_key_after_props_spread();
+        // Children are the third argument, and the key is a prop
+        let children_argument =
+            !self.bindings.is_classic() && self.options.react_runtime == ReactRuntime::Compat;
+        let has_key_after_props_spread = !children_argument && e.has_key_after_props_spread();
         // If has_key_after_props_spread is true, we need to fallback to `createElement` same behavior as classic runtime
         let is_classic = self.bindings.is_classic() || has_key_after_props_spread;
         let is_automatic = !is_classic;
@@ -529,7 +608,7 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
                             }
                             // In automatic mode, extract the key before spread prop,
                             // and add it to the third argument later.
-                            if is_automatic {
+                            if is_automatic && !children_argument {
                                 key_prop = attr.value.as_ref();
                                 continue;
                             }
@@ -579,36 +658,48 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
         }
 
         let mut need_jsxs = false;
+        // Children as the third argument, when `children_argument` is true
+        let mut children_expr = None;
 
         let children = e.children();
-        let mut children_len = children.len();
 
         // Append children to object properties in automatic mode
         if is_automatic {
             let mut children = ctx.ast.vec_from_iter(
                 children.iter().filter_map(|child| self.transform_jsx_child(child, ctx)),
             );
-            children_len = children.len();
-            if children_len != 0 {
-                let value = if children_len == 1 {
-                    children.pop().unwrap()
+            if !children.is_empty() {
+                // `{...items}` -> `children: [...items]`
+                let has_spread = children.iter().any(Argument::is_spread);
+                let value = if children.len() == 1 && !has_spread {
+                    children.pop().unwrap().into_expression()
                 } else {
-                    let elements = ctx
-                        .ast
-                        .vec_from_iter(children.into_iter().map(ArrayExpressionElement::from));
-                    need_jsxs = true;
+                    // Only static children use `jsxs`. Spread children are dynamic,
+                    // so use `jsx` for React to check their keys.
+                    need_jsxs = !has_spread;
+                    let elements =
+                        ctx.ast.vec_from_iter(children.into_iter().map(|child| match child {
+                            Argument::SpreadElement(spread) => {
+                                ArrayExpressionElement::SpreadElement(spread)
+                            }
+                            child => ArrayExpressionElement::from(child.into_expression()),
+                        }));
                     ctx.ast.expression_array(SPAN, elements, None)
                 };
-                properties.push(ctx.ast.object_property_kind_object_property(
-                    SPAN,
-                    PropertyKind::Init,
-                    ctx.ast.property_key_identifier_name(SPAN, "children"),
-                    value,
-                    None,
-                    false,
-                    false,
-                    false,
-                ));
+                if children_argument {
+                    children_expr = Some(value);
+                } else {
+                    properties.push(ctx.ast.object_property_kind_object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        ctx.ast.property_key_identifier_name(SPAN, "children"),
+                        value,
+                        None,
+                        false,
+                        false,
+                        false,
+                    ));
+                }
             }
         }
 
@@ -653,7 +744,14 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
         arguments.insert(0, Argument::from(argument_expr));
 
         // If runtime is automatic that means we always to add `{ .. }` as the second argument even if it's empty
-        if is_automatic || !properties.is_empty() {
+        if is_automatic
+            && self.options.hoist_constant_props
+            && !properties.is_empty()
+            && properties.iter().all(is_constant_property)
+        {
+            let props = self.hoist_props(properties, ctx);
+            arguments.push(Argument::from(props));
+        } else if is_automatic || !properties.is_empty() {
             let object_expression = ctx.ast.expression_object(SPAN, properties, None);
             arguments.push(Argument::from(object_expression));
         } else if arguments.len() == 1 {
@@ -664,8 +762,10 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
 
         // Only jsx and jsxDev will have more than 2 arguments
         if is_automatic {
-            // key
-            if key_prop.is_some() {
+            // key, or children
+            if let Some(children_expr) = children_expr {
+                arguments.push(Argument::from(children_expr));
+            } else if key_prop.is_some() {
                 arguments.push(Argument::from(self.transform_jsx_attribute_value(key_prop, ctx)));
             } else if is_development {
                 arguments.push(Argument::from(ctx.ast.void_0(SPAN)));
@@ -675,41 +775,52 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
             if is_development {
                 arguments.push(Argument::from(ctx.ast.expression_boolean_literal(
                     SPAN,
-                    if is_fragment { false } else { children_len > 1 },
+                    if is_fragment { false } else { need_jsxs },
                 )));
             }
 
             // Fragment doesn't have source and self
             if !is_fragment {
                 // { __source: { fileName, lineNumber, columnNumber } }
+                let mut source_expr = None;
                 if self.options.jsx_source_plugin {
                     if let Some(span) = source_attr_span {
                         self.jsx_source.report_error(span);
                     } else {
                         let (line, column) = self.jsx_source.get_line_column(e.span().start);
-                        let expr = self.jsx_source.get_source_object(line, column, ctx);
-                        arguments.push(Argument::from(expr));
+                        source_expr = Some(self.jsx_source.get_source_object(line, column, ctx));
                     }
                 }
 
                 // this
+                let mut self_expr = None;
                 if self.options.jsx_self_plugin && self.jsx_self.can_add_self_attribute(ctx) {
                     if let Some(span) = self_attr_span {
                         self.jsx_self.report_error(span);
                     } else {
-                        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+                        self_expr = Some(ctx.ast.expression_this(SPAN));
+                    }
+                }
+
+                // `self` is the sixth argument, so `source` must be present before it:
+                // `jsxDEV(type, props, key, isStaticChildren, void 0, this)`
+                match (source_expr, self_expr) {
+                    (Some(source_expr), self_expr) => {
+                        arguments.push(Argument::from(source_expr));
+                        arguments.extend(self_expr.map(Argument::from));
+                    }
+                    (None, Some(self_expr)) => {
+                        arguments.push(Argument::from(ctx.ast.void_0(SPAN)));
+                        arguments.push(Argument::from(self_expr));
                     }
+                    (None, None) => {}
                 }
             }
         } else {
             // React.createElement(type, arguments, ...children)
             //                                      ^^^^^^^^^^^
-            arguments.extend(
-                children
-                    .iter()
-                    .filter_map(|child| self.transform_jsx_child(child, ctx))
-                    .map(Argument::from),
-            );
+            arguments
+                .extend(children.iter().filter_map(|child| self.transform_jsx_child(child, ctx)));
         }
 
         let callee = self.get_create_element(has_key_after_props_spread, need_jsxs, ctx);
@@ -842,27 +953,26 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
         &mut self,
         child: &JSXChild<'a>,
         ctx: &mut TraverseCtx<'a>,
-    ) -> Option<Expression<'a>> {
-        match child {
-            JSXChild::Text(text) => Self::transform_jsx_text(text, ctx),
+    ) -> Option<Argument<'a>> {
+        let expr = match child {
+            JSXChild::Text(text) => Self::transform_jsx_text(text, ctx)?,
             JSXChild::ExpressionContainer(e) => match &e.expression {
                 e @ match_expression!(JSXExpression) => {
                     // SAFETY: `ast.copy` is unsound! We need to fix.
-                    Some(unsafe { ctx.ast.copy(e.to_expression()) })
+                    unsafe { ctx.ast.copy(e.to_expression()) }
                 }
-                JSXExpression::EmptyExpression(_) => None,
+                JSXExpression::EmptyExpression(_) => return None,
             },
-            JSXChild::Element(e) => {
-                Some(self.transform_jsx(&JSXElementOrFragment::Element(e), ctx))
-            }
-            JSXChild::Fragment(e) => {
-                Some(self.transform_jsx(&JSXElementOrFragment::Fragment(e), ctx))
-            }
+            JSXChild::Element(e) => self.transform_jsx(&JSXElementOrFragment::Element(e), ctx),
+            JSXChild::Fragment(e) => self.transform_jsx(&JSXElementOrFragment::Fragment(e), ctx),
+            // `{...items}` -> `...items`
             JSXChild::Spread(e) => {
-                self.ctx.error(diagnostics::spread_children_are_not_supported(e.span));
-                None
+                // SAFETY: `ast.copy` is unsound! We need to fix.
+                let argument = unsafe { ctx.ast.copy(&e.expression) };
+                return Some(ctx.ast.argument_spread_element(e.span, argument));
             }
-        }
+        };
+        Some(Argument::from(expr))
     }
 
     fn get_attribute_name(name: &JSXAttributeName<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
@@ -1031,6 +1141,25 @@ impl<'a, 'b> JSXElementOrFragment<'a, 'b> {
 }
 
 /// Create `IdentifierReference` for var name in current scope which is read from
+/// Check if a property of a props object can be hoisted.
+///
+/// The key must be static and not `ref`, and the value must be a literal.
+fn is_constant_property(property: &ObjectPropertyKind) -> bool {
+    let ObjectPropertyKind::ObjectProperty(property) = property else { return false };
+    if property.computed || property.key.static_name().map_or(true, |name| name == "ref") {
+        return false;
+    }
+    match &property.value {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::BigIntLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        _ => false,
+    }
+}
+
 fn get_read_identifier_reference<'a>(
     span: Span,
     name: Atom<'a>,
@@ -0,0 +1,101 @@
+//! React JSX Key
+//!
+//! Warns about JSX elements returned from `.map()` callbacks which have no `key` prop,
+//! as React needs keys to reconcile lists of elements.
+//!
+//! ```jsx
+//! items.map((x) => <li>{x}</li>); // Warning
+//! items.map((x) => <li key={x}>{x}</li>);
+//! ```
+//!
+//! Elements with a spread attribute (`<li {...props} />`) are not reported, as the spread
+//! object may provide the key.
+//!
+//! This is an opt-in lint enabled by the `warnMissingKeys` option, and does not change output.
+//!
+//! ## References:
+//!
+//! * ESLint rule: <https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/jsx-key.md>
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use super::diagnostics;
+use crate::TransformCtx;
+
+pub struct ReactJsxKey<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ReactJsxKey<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ReactJsxKey<'a, 'ctx> {
+    fn enter_call_expression(
+        &mut self,
+        call_expr: &mut CallExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_map = call_expr
+            .callee
+            .get_member_expr()
+            .is_some_and(|member| member.static_property_name() == Some("map"));
+        if !is_map {
+            return;
+        }
+
+        match call_expr.arguments.first() {
+            Some(Argument::ArrowFunctionExpression(arrow)) => {
+                if let Some(expr) = arrow.get_expression() {
+                    self.check_returned_expression(expr);
+                } else {
+                    self.check_returned_statements(&arrow.body.statements);
+                }
+            }
+            Some(Argument::FunctionExpression(func)) => {
+                if let Some(body) = &func.body {
+                    self.check_returned_statements(&body.statements);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> ReactJsxKey<'a, 'ctx> {
+    /// Check `return` statements directly in the callback's body.
+    fn check_returned_statements(&self, stmts: &[Statement<'a>]) {
+        for stmt in stmts {
+            if let Statement::ReturnStatement(ret) = stmt {
+                if let Some(argument) = &ret.argument {
+                    self.check_returned_expression(argument);
+                }
+            }
+        }
+    }
+
+    fn check_returned_expression(&self, expr: &Expression<'a>) {
+        match expr.without_parentheses() {
+            Expression::JSXElement(element) => {
+                let has_key = element.opening_element.attributes.iter().any(|attr| match attr {
+                    JSXAttributeItem::Attribute(attr) => attr.is_key(),
+                    JSXAttributeItem::SpreadAttribute(_) => true,
+                });
+                if !has_key {
+                    self.ctx.error(diagnostics::missing_key(element.span));
+                }
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.check_returned_expression(&cond.consequent);
+                self.check_returned_expression(&cond.alternate);
+            }
+            Expression::LogicalExpression(logical) => {
+                self.check_returned_expression(&logical.right);
+            }
+            _ => {}
+        }
+    }
+}
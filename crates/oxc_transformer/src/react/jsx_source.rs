@@ -15,7 +15,7 @@
 //!
 //! Output:
 //! ```js
-//! var _jsxFileName = "<CWD>/test.js";
+//! var _jsxFileName = "test.js";
 //! <div __source={
 //!     { fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 }
 //! }>foo</div>;
@@ -29,6 +29,9 @@
 //!
 //! Implementation based on [@babel/plugin-transform-react-jsx-source](https://babeljs.io/docs/babel-plugin-transform-react-jsx-source).
 //!
+//! Babel uses the absolute path of the file for `fileName`. We use the path relative to the `cwd`
+//! option when the file is inside it, so output is the same on different machines.
+//!
 //! ## References:
 //!
 //! * Babel plugin implementation: <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-react-jsx-source/src/index.ts>
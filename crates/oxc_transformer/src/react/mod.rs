@@ -2,12 +2,14 @@ mod comments;
 mod diagnostics;
 mod display_name;
 mod jsx;
+mod jsx_key;
 mod jsx_self;
 mod jsx_source;
 mod options;
 mod refresh;
 mod utils;
 
+use jsx_key::ReactJsxKey;
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_traverse::{Traverse, TraverseCtx};
@@ -16,7 +18,7 @@ use refresh::ReactRefresh;
 pub use self::{
     display_name::ReactDisplayName,
     jsx::ReactJsx,
-    options::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    options::{JsxOptions, JsxRuntime, ReactRefreshOptions, ReactRuntime},
 };
 use crate::TransformCtx;
 
@@ -33,12 +35,15 @@ use comments::update_options_with_comments;
 pub struct React<'a, 'ctx> {
     jsx: ReactJsx<'a, 'ctx>,
     display_name: ReactDisplayName<'a, 'ctx>,
+    jsx_key: ReactJsxKey<'a, 'ctx>,
     refresh: ReactRefresh<'a, 'ctx>,
     jsx_plugin: bool,
+    automatic_runtime: bool,
     display_name_plugin: bool,
     jsx_self_plugin: bool,
     jsx_source_plugin: bool,
     refresh_plugin: bool,
+    warn_missing_keys: bool,
 }
 
 // Constructors
@@ -49,22 +54,38 @@ impl<'a, 'ctx> React<'a, 'ctx> {
             options.conform();
         }
         let JsxOptions {
-            jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
+            jsx_plugin,
+            display_name_plugin,
+            jsx_self_plugin,
+            jsx_source_plugin,
+            warn_missing_keys,
+            ..
         } = options;
+        let automatic_runtime = jsx_plugin && options.runtime.is_automatic();
         let refresh = options.refresh.clone();
         Self {
             jsx: ReactJsx::new(options, ast, ctx),
             display_name: ReactDisplayName::new(ctx),
+            jsx_key: ReactJsxKey::new(ctx),
             jsx_plugin,
+            automatic_runtime,
             display_name_plugin,
             jsx_self_plugin,
             jsx_source_plugin,
             refresh_plugin: refresh.is_some(),
             refresh: ReactRefresh::new(&refresh.unwrap_or_default(), ast, ctx),
+            warn_missing_keys,
         }
     }
 }
 
+impl<'a, 'ctx> React<'a, 'ctx> {
+    /// Whether JSX is transformed with the automatic runtime, after applying `@jsxRuntime` comments.
+    pub fn is_automatic_runtime(&self) -> bool {
+        self.automatic_runtime
+    }
+}
+
 impl<'a, 'ctx> Traverse<'a> for React<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.jsx_plugin {
@@ -107,6 +128,10 @@ impl<'a, 'ctx> Traverse<'a> for React<'a, 'ctx> {
             self.display_name.enter_call_expression(call_expr, ctx);
         }
 
+        if self.warn_missing_keys {
+            self.jsx_key.enter_call_expression(call_expr, ctx);
+        }
+
         if self.refresh_plugin {
             self.refresh.enter_call_expression(call_expr, ctx);
         }
@@ -28,6 +28,18 @@ impl JsxRuntime {
     }
 }
 
+/// Decides the arguments of the functions imported by the automatic runtime.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReactRuntime {
+    /// `jsx(type, { ...props, children }, key)`, as React.
+    #[default]
+    React,
+    /// `jsx(type, { ...props, key }, children)`, as runtimes which take children
+    /// as the third argument.
+    Compat,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct JsxOptions {
@@ -75,6 +87,27 @@ pub struct JsxOptions {
     #[serde(default)]
     pub import_source: Option<String>,
 
+    /// Hoists props objects passed to `jsx()` to module-level variables
+    /// when all of their values are constant, to avoid allocating them on each render.
+    ///
+    /// Props objects containing a `ref` are never hoisted.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub hoist_constant_props: bool,
+
+    /// Warns about JSX elements returned from `.map()` callbacks without a `key` prop.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub warn_missing_keys: bool,
+
+    /// Decides the arguments of `jsx()` and `jsxs()`, for runtimes compatible with React.
+    ///
+    /// Defaults to [`ReactRuntime::React`].
+    #[serde(default)]
+    pub react_runtime: ReactRuntime,
+
     // React Classic Runtime
     //
     /// Replace the function used when compiling JSX expressions.
@@ -119,6 +152,9 @@ impl Default for JsxOptions {
             throw_if_namespace: default_as_true(),
             pure: default_as_true(),
             import_source: None,
+            hoist_constant_props: false,
+            warn_missing_keys: false,
+            react_runtime: ReactRuntime::default(),
             pragma: None,
             pragma_frag: None,
             use_built_ins: None,
@@ -143,12 +179,17 @@ impl JsxOptions {
 pub struct ReactRefreshOptions {
     /// Specify the identifier of the refresh registration variable.
     ///
+    /// `[hash]` is replaced with a hash of the source path, so modules sharing a scope can
+    /// use different registration functions, e.g. `$RefreshReg$__[hash]`.
+    ///
     /// Defaults to `$RefreshReg$`.
     #[serde(default = "default_refresh_reg")]
     pub refresh_reg: String,
 
     /// Specify the identifier of the refresh signature variable.
     ///
+    /// `[hash]` is replaced with a hash of the source path, as in `refresh_reg`.
+    ///
     /// Defaults to `$RefreshSig$`.
     #[serde(default = "default_refresh_sig")]
     pub refresh_sig: String,
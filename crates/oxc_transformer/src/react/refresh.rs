@@ -1,6 +1,7 @@
-use std::iter::once;
+use std::{fmt::Write, iter::once, path::Path};
 
 use base64::prelude::{Engine, BASE64_STANDARD};
+use cow_utils::CowUtils;
 use oxc_allocator::CloneIn;
 use oxc_ast::{ast::*, match_expression, AstBuilder, NONE};
 use oxc_semantic::{Reference, ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
@@ -117,9 +118,13 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
         ast: AstBuilder<'a>,
         ctx: &'ctx TransformCtx<'a>,
     ) -> Self {
+        // `$RefreshReg$__[hash]` -> `$RefreshReg$__1a2b3c4d`
+        let hash = file_hash(&ctx.source_path);
+        let refresh_reg = options.refresh_reg.cow_replace("[hash]", &hash);
+        let refresh_sig = options.refresh_sig.cow_replace("[hash]", &hash);
         Self {
-            refresh_reg: RefreshIdentifierResolver::parse(&options.refresh_reg, ast),
-            refresh_sig: RefreshIdentifierResolver::parse(&options.refresh_sig, ast),
+            refresh_reg: RefreshIdentifierResolver::parse(&refresh_reg, ast),
+            refresh_sig: RefreshIdentifierResolver::parse(&refresh_sig, ast),
             emit_full_signatures: options.emit_full_signatures,
             signature_declarator_items: Vec::new(),
             registrations: Vec::default(),
@@ -979,3 +984,13 @@ fn get_symbol_id_from_function_and_declarator(stmt: &Statement<'_>) -> Vec<Symbo
 
     symbol_ids
 }
+
+/// First 8 hex digits of the SHA-1 hash of `path`, to make registration names unique per file.
+fn file_hash(path: &Path) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(path.to_string_lossy().as_bytes());
+    hasher.finalize()[..4].iter().fold(String::with_capacity(8), |mut hash, byte| {
+        let _ = write!(hash, "{byte:02x}");
+        hash
+    })
+}
@@ -16,18 +16,23 @@ use oxc_syntax::{
 use oxc_traverse::{Traverse, TraverseCtx};
 use rustc_hash::FxHashSet;
 
-use crate::{TransformCtx, TypeScriptOptions};
+use super::diagnostics;
+use crate::{ImportsNotUsedAsValues, TransformCtx, TypeScriptOptions};
 
 pub struct TypeScriptAnnotations<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
     // Options
     only_remove_type_imports: bool,
+    imports_not_used_as_values: ImportsNotUsedAsValues,
+    allow_declare_fields: bool,
 
     /// Assignments to be added to the constructor body
     assignments: Vec<Assignment<'a>>,
     has_super_call: bool,
 
+    /// JSX is transformed with the automatic runtime, so does not reference pragma imports
+    jsx_automatic_runtime: bool,
     has_jsx_element: bool,
     has_jsx_fragment: bool,
     jsx_element_import_name: String,
@@ -36,7 +41,11 @@ pub struct TypeScriptAnnotations<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
-    pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(
+        options: &TypeScriptOptions,
+        jsx_automatic_runtime: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
         let jsx_element_import_name = if options.jsx_pragma.contains('.') {
             options.jsx_pragma.split('.').next().map(String::from).unwrap()
         } else {
@@ -52,8 +61,11 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
         Self {
             ctx,
             only_remove_type_imports: options.only_remove_type_imports,
+            imports_not_used_as_values: options.imports_not_used_as_values,
+            allow_declare_fields: options.allow_declare_fields,
             has_super_call: false,
             assignments: vec![],
+            jsx_automatic_runtime,
             has_jsx_element: false,
             has_jsx_fragment: false,
             jsx_element_import_name,
@@ -110,6 +122,13 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                             decl.specifiers = None;
                             true
                         } else {
+                            let has_value_specifier = specifiers.iter().any(|specifier| {
+                                !matches!(
+                                    specifier,
+                                    ImportDeclarationSpecifier::ImportSpecifier(s)
+                                        if s.import_kind.is_type()
+                                )
+                            });
                             specifiers.retain(|specifier| {
                                 let id = match specifier {
                                     ImportDeclarationSpecifier::ImportSpecifier(s) => {
@@ -127,7 +146,24 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                                 };
                                 self.has_value_reference(&id.name, ctx)
                             });
-                            !specifiers.is_empty()
+                            if !specifiers.is_empty() {
+                                true
+                            } else if self.imports_not_used_as_values
+                                == ImportsNotUsedAsValues::Remove
+                            {
+                                false
+                            } else {
+                                if has_value_specifier
+                                    && self.imports_not_used_as_values
+                                        == ImportsNotUsedAsValues::Error
+                                {
+                                    self.ctx
+                                        .error(diagnostics::import_not_used_as_value(decl.span));
+                                }
+                                // `import { A } from 'mod'` -> `import 'mod'`
+                                decl.specifiers = None;
+                                true
+                            }
                         }
                     } else {
                         true
@@ -196,6 +232,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {
+        let allow_declare_fields = self.allow_declare_fields;
         // Remove type only members
         body.body.retain(|elem| match elem {
             ClassElement::MethodDefinition(method) => {
@@ -205,6 +242,14 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
             ClassElement::PropertyDefinition(prop) => {
                 if prop.declare {
                     false
+                } else if !allow_declare_fields
+                    && prop.value.is_none()
+                    && prop.decorators.is_empty()
+                    && !prop.key.is_private_identifier()
+                {
+                    // Without `allowDeclareFields`, uninitialized fields such as `x!: number`
+                    // are treated as type only, as Babel 7 does
+                    false
                 } else {
                     matches!(prop.r#type, PropertyDefinitionType::PropertyDefinition)
                 }
@@ -327,9 +372,11 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
     ) {
         if def.kind == MethodDefinitionKind::Constructor && !self.assignments.is_empty() {
             // When the constructor doesn't have a super call,
-            // we simply add assignments to the bottom of the function body
+            // we simply add assignments to the top of the function body
             if self.has_super_call {
+                // Assignments were already inserted after `super()` calls in `exit_statements`
                 self.assignments.clear();
+                self.has_super_call = false;
             } else {
                 def.value
                     .body
@@ -549,8 +596,12 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
 
 impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
     /// Check if the given name is a JSX pragma or fragment pragma import
-    /// and if the file contains JSX elements or fragments
+    /// and if the file contains JSX elements or fragments.
+    /// Pragmas are not used by the automatic runtime, so their imports are never retained.
     fn is_jsx_imports(&self, name: &str) -> bool {
+        if self.jsx_automatic_runtime {
+            return false;
+        }
         self.has_jsx_element && name == self.jsx_element_import_name
             || self.has_jsx_fragment && name == self.jsx_fragment_import_name
     }
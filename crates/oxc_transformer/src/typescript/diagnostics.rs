@@ -25,3 +25,13 @@ pub fn namespace_not_supported(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Namespace not marked type-only declare. Non-declarative namespaces are only supported experimentally in Babel. To enable and review caveats see: https://babeljs.io/docs/en/babel-plugin-transform-typescript")
         .with_label(span)
 }
+
+pub fn import_not_used_as_value(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("This import is never used as a value and must use 'import type' because 'importsNotUsedAsValues' is set to 'error'.")
+        .with_label(span)
+}
+
+pub fn exported_const_enum_in_isolated_modules(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Exported const enums are not supported with 'isolatedModules', as other modules cannot inline their members.\nPlease consider using a regular enum instead.")
+        .with_label(span)
+}
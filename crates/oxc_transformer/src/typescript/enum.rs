@@ -2,35 +2,56 @@ use oxc_allocator::Vec;
 use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
 use oxc_span::{Atom, Span, SPAN};
 use oxc_syntax::{
+    identifier::is_identifier_name,
     node::NodeId,
     number::{NumberBase, ToJsInt32, ToJsString},
     operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     reference::ReferenceFlags,
-    symbol::SymbolFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 use rustc_hash::FxHashMap;
 
-pub struct TypeScriptEnum<'a> {
+use super::diagnostics;
+use crate::{TransformCtx, TypeScriptOptions};
+
+pub struct TypeScriptEnum<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
     enums: FxHashMap<Atom<'a>, FxHashMap<Atom<'a>, ConstantValue>>,
+    /// Constant members of `const enum`s, to inline with `preserve_const_enums`
+    const_enums: FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+    optimize_const_enums: bool,
+    preserve_const_enums: bool,
+    isolated_modules: bool,
 }
 
-impl<'a> TypeScriptEnum<'a> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
+    pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            enums: FxHashMap::default(),
+            const_enums: FxHashMap::default(),
+            optimize_const_enums: options.optimize_const_enums,
+            preserve_const_enums: options.preserve_const_enums,
+            isolated_modules: options.isolated_modules,
+        }
     }
 }
 
-impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
+impl<'a, 'ctx> Traverse<'a> for TypeScriptEnum<'a, 'ctx> {
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
-                self.transform_ts_enum(ts_enum_decl, None, ctx)
+                let new_stmt = self.transform_ts_enum(ts_enum_decl, None, ctx);
+                self.record_const_enum(ts_enum_decl);
+                new_stmt
             }
             Statement::ExportNamedDeclaration(decl) => {
                 let span = decl.span;
                 if let Some(Declaration::TSEnumDeclaration(ts_enum_decl)) = &mut decl.declaration {
-                    self.transform_ts_enum(ts_enum_decl, Some(span), ctx)
+                    let new_stmt = self.transform_ts_enum(ts_enum_decl, Some(span), ctx);
+                    self.record_const_enum(ts_enum_decl);
+                    new_stmt
                 } else {
                     None
                 }
@@ -42,9 +63,58 @@ impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
             *stmt = new_stmt;
         }
     }
+
+    /// With `preserve_const_enums`, inline constant members of `const enum`s:
+    /// `Foo.X` / `Foo["X"]` -> `1`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+
+        let (object, member_name) = match expr {
+            Expression::StaticMemberExpression(member) => (&member.object, &member.property.name),
+            Expression::ComputedMemberExpression(member) => match &member.expression {
+                Expression::StringLiteral(str) => (&member.object, &str.value),
+                _ => return,
+            },
+            _ => return,
+        };
+        let Expression::Identifier(ident) = object else {
+            return;
+        };
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+        else {
+            return;
+        };
+        let Some(value) =
+            self.const_enums.get(&symbol_id).and_then(|members| members.get(member_name))
+        else {
+            return;
+        };
+
+        let new_expr = match value {
+            ConstantValue::Number(value) => Self::get_initializer_expr(*value, ctx),
+            ConstantValue::String(value) => ctx.ast.expression_string_literal(SPAN, value.clone()),
+        };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = new_expr;
+    }
 }
 
-impl<'a> TypeScriptEnum<'a> {
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
+    /// Record the constant members of a transformed `const enum`, to inline accesses to them.
+    fn record_const_enum(&mut self, decl: &TSEnumDeclaration<'a>) {
+        if !self.preserve_const_enums || !decl.r#const || decl.declare {
+            return;
+        }
+        if let Some(members) = self.enums.get(&decl.id.name) {
+            self.const_enums.insert(decl.id.symbol_id.get().unwrap(), members.clone());
+        }
+    }
+
     /// ```TypeScript
     /// enum Foo {
     ///   X = 1,
@@ -68,9 +138,20 @@ impl<'a> TypeScriptEnum<'a> {
             return None;
         }
 
+        let is_export = export_span.is_some();
+        if decl.r#const {
+            if is_export && self.isolated_modules {
+                self.ctx.error(diagnostics::exported_const_enum_in_isolated_modules(decl.id.span));
+            }
+            if self.optimize_const_enums {
+                if let Some(stmt) = self.transform_const_enum_to_object(decl, export_span, ctx) {
+                    return Some(stmt);
+                }
+            }
+        }
+
         let ast = ctx.ast;
 
-        let is_export = export_span.is_some();
         let is_not_top_scope = !ctx.scopes().get_flags(ctx.current_scope_id()).is_top();
 
         let enum_name = decl.id.name.clone();
@@ -184,6 +265,105 @@ impl<'a> TypeScriptEnum<'a> {
         Some(stmt)
     }
 
+    /// With `optimize_const_enums`:
+    /// ```TypeScript
+    /// const enum Foo {
+    ///   X = 1,
+    ///   Y
+    /// }
+    /// ```
+    /// ```JavaScript
+    /// var Foo = { X: 1, Y: 2 };
+    /// ```
+    ///
+    /// Returns `None` if the enum merges with an earlier declaration,
+    /// or any member does not have a constant value.
+    fn transform_const_enum_to_object(
+        &mut self,
+        decl: &TSEnumDeclaration<'a>,
+        export_span: Option<Span>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        if self.enums.contains_key(&decl.id.name) {
+            return None;
+        }
+
+        let ast = ctx.ast;
+        let mut members = FxHashMap::default();
+        let mut properties = ast.vec_with_capacity(decl.members.len());
+        let mut prev_value = ConstantValue::Number(-1.0);
+        for member in &decl.members {
+            let name = Self::get_member_name(&member.id)?;
+            let value = match &member.initializer {
+                Some(initializer) => self.computed_constant_value(initializer, &members)?,
+                None => match prev_value {
+                    ConstantValue::Number(value) => ConstantValue::Number(value + 1.0),
+                    ConstantValue::String(_) => return None,
+                },
+            };
+
+            let key = if is_identifier_name(&name) {
+                ast.property_key_identifier_name(SPAN, name.clone())
+            } else {
+                ast.property_key_expression(ast.expression_string_literal(SPAN, name.clone()))
+            };
+            let value_expr = match &value {
+                ConstantValue::Number(value) => Self::get_initializer_expr(*value, ctx),
+                ConstantValue::String(value) => ast.expression_string_literal(SPAN, value.clone()),
+            };
+            properties.push(ast.object_property_kind_object_property(
+                member.span,
+                PropertyKind::Init,
+                key,
+                value_expr,
+                None,
+                false,
+                false,
+                false,
+            ));
+
+            members.insert(name, value.clone());
+            prev_value = value;
+        }
+        self.enums.insert(decl.id.name.clone(), members);
+
+        let is_not_top_scope = !ctx.scopes().get_flags(ctx.current_scope_id()).is_top();
+        let kind = if export_span.is_some() || is_not_top_scope {
+            VariableDeclarationKind::Let
+        } else {
+            VariableDeclarationKind::Var
+        };
+        let binding = ast.binding_pattern(
+            ast.binding_pattern_kind_from_binding_identifier(decl.id.clone()),
+            NONE,
+            false,
+        );
+        let init = ast.expression_object(SPAN, properties, None);
+        let declarator = ast.variable_declarator(SPAN, kind, binding, Some(init), false);
+        let declaration = ast.declaration_variable(decl.span, kind, ast.vec1(declarator), false);
+        let stmt = if let Some(export_span) = export_span {
+            Statement::ExportNamedDeclaration(
+                ast.plain_export_named_declaration_declaration(export_span, declaration),
+            )
+        } else {
+            Statement::from(declaration)
+        };
+        Some(stmt)
+    }
+
+    /// Name of a member, or `None` for members with computed names.
+    fn get_member_name(id: &TSEnumMemberName<'a>) -> Option<Atom<'a>> {
+        match id {
+            TSEnumMemberName::StaticIdentifier(id) => Some(id.name.clone()),
+            TSEnumMemberName::StaticStringLiteral(str) | TSEnumMemberName::StringLiteral(str) => {
+                Some(str.value.clone())
+            }
+            TSEnumMemberName::StaticTemplateLiteral(template)
+            | TSEnumMemberName::TemplateLiteral(template) => template.quasi(),
+            _ => None,
+        }
+    }
+
     #[allow(clippy::needless_pass_by_value)]
     fn transform_ts_enum_members(
         &mut self,
@@ -363,7 +543,7 @@ enum ConstantValue {
     String(String),
 }
 
-impl<'a> TypeScriptEnum<'a> {
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
     fn computed_constant_value(
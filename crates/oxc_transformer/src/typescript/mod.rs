@@ -13,7 +13,7 @@ use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 use rewrite_extensions::TypeScriptRewriteExtensions;
 
-pub use self::options::{RewriteExtensionsMode, TypeScriptOptions};
+pub use self::options::{ImportsNotUsedAsValues, RewriteExtensionsMode, TypeScriptOptions};
 use self::{annotations::TypeScriptAnnotations, r#enum::TypeScriptEnum};
 use crate::TransformCtx;
 
@@ -42,19 +42,25 @@ pub struct TypeScript<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
     annotations: TypeScriptAnnotations<'a, 'ctx>,
-    r#enum: TypeScriptEnum<'a>,
+    r#enum: TypeScriptEnum<'a, 'ctx>,
     namespace: TypeScriptNamespace<'a, 'ctx>,
     module: TypeScriptModule<'a, 'ctx>,
     rewrite_extensions: Option<TypeScriptRewriteExtensions>,
 }
 
 impl<'a, 'ctx> TypeScript<'a, 'ctx> {
-    pub fn new(mut options: TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    /// `jsx_automatic_runtime` should be `true` if JSX is transformed with the automatic runtime,
+    /// in which case JSX does not reference the `jsxPragma` and `jsxPragmaFrag` imports.
+    pub fn new(
+        mut options: TypeScriptOptions,
+        jsx_automatic_runtime: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
         options.update_with_comments(ctx);
         Self {
             ctx,
-            annotations: TypeScriptAnnotations::new(&options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            annotations: TypeScriptAnnotations::new(&options, jsx_automatic_runtime, ctx),
+            r#enum: TypeScriptEnum::new(&options, ctx),
             namespace: TypeScriptNamespace::new(&options, ctx),
             module: TypeScriptModule::new(ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(&options),
@@ -121,6 +127,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -205,6 +212,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.r#enum.enter_statement(stmt, ctx);
+        self.module.enter_statement(stmt, ctx);
     }
 
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -284,12 +292,4 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
             rewrite_extensions.enter_export_named_declaration(node, ctx);
         }
     }
-
-    fn enter_ts_export_assignment(
-        &mut self,
-        node: &mut TSExportAssignment<'a>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        self.module.enter_ts_export_assignment(node, ctx);
-    }
 }
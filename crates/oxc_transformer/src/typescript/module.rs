@@ -1,7 +1,7 @@
 use oxc_allocator::Box;
 use oxc_ast::{ast::*, NONE};
-use oxc_span::SPAN;
-use oxc_syntax::reference::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{operator::AssignmentOperator, reference::ReferenceFlags, symbol::SymbolFlags};
 use oxc_traverse::{Traverse, TraverseCtx};
 
 use crate::TransformCtx;
@@ -20,10 +20,12 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptModule<'a, 'ctx> {
     /// ```TypeScript
     /// import b = babel;
     /// import AliasModule = LongNameModule;
+    /// import foo = require("foo");
     ///
     /// ```JavaScript
     /// var b = babel;
     /// var AliasModule = LongNameModule;
+    /// const foo = require("foo");
     /// ```
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
         match decl {
@@ -36,14 +38,15 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptModule<'a, 'ctx> {
         }
     }
 
-    fn enter_ts_export_assignment(
-        &mut self,
-        export_assignment: &mut TSExportAssignment<'a>,
-        _ctx: &mut TraverseCtx<'a>,
-    ) {
-        if self.ctx.source_type.is_module() {
-            self.ctx
-                .error(super::diagnostics::export_assignment_unsupported(export_assignment.span));
+    /// ```TypeScript
+    /// export = foo;
+    /// ```
+    /// ```JavaScript
+    /// module.exports = foo;
+    /// ```
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Statement::TSExportAssignment(export_assignment) = stmt {
+            *stmt = self.transform_ts_export_assignment(export_assignment, ctx);
         }
     }
 }
@@ -54,10 +57,21 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
         decl: &mut Box<'a, TSImportEqualsDeclaration<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Declaration<'a> {
-        let kind = VariableDeclarationKind::Var;
+        let kind = if matches!(decl.module_reference, TSModuleReference::ExternalModuleReference(_))
+        {
+            VariableDeclarationKind::Const
+        } else {
+            VariableDeclarationKind::Var
+        };
         let decls = {
+            let symbol_id = decl.id.symbol_id.get().unwrap();
+            *ctx.symbols_mut().get_flags_mut(symbol_id) = if kind.is_const() {
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+            } else {
+                SymbolFlags::FunctionScopedVariable
+            };
             let binding_pattern_kind =
-                ctx.ast.binding_pattern_kind_binding_identifier(SPAN, &decl.id.name);
+                ctx.ast.binding_pattern_kind_from_binding_identifier(decl.id.clone());
             let binding = ctx.ast.binding_pattern(binding_pattern_kind, NONE, false);
             let decl_span = decl.span;
 
@@ -72,7 +86,13 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
                         ));
                     }
 
-                    let callee = ctx.ast.expression_identifier_reference(SPAN, "require");
+                    let callee = ctx.ast.expression_from_identifier_reference(
+                        ctx.create_unbound_reference_id(
+                            SPAN,
+                            Atom::from("require"),
+                            ReferenceFlags::Read,
+                        ),
+                    );
                     let arguments = ctx.ast.vec1(Argument::from(
                         ctx.ast.expression_from_string_literal(reference.expression.clone()),
                     ));
@@ -85,6 +105,28 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
         ctx.ast.declaration_variable(SPAN, kind, decls, false)
     }
 
+    fn transform_ts_export_assignment(
+        &self,
+        export_assignment: &mut TSExportAssignment<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        if self.ctx.source_type.is_module() {
+            self.ctx
+                .error(super::diagnostics::export_assignment_unsupported(export_assignment.span));
+        }
+
+        let module =
+            ctx.create_unbound_reference_id(SPAN, Atom::from("module"), ReferenceFlags::Read);
+        let object = ctx.ast.expression_from_identifier_reference(module);
+        let property = ctx.ast.identifier_name(SPAN, "exports");
+        let target =
+            AssignmentTarget::from(ctx.ast.member_expression_static(SPAN, object, property, false));
+        let expression = ctx.ast.move_expression(&mut export_assignment.expression);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, expression);
+        ctx.ast.statement_expression(export_assignment.span, assignment)
+    }
+
     #[allow(clippy::only_used_in_recursion)]
     fn transform_ts_type_name(
         &self,
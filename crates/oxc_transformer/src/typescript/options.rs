@@ -38,6 +38,11 @@ pub struct TypeScriptOptions {
     /// This should only be used if you are using TypeScript >= 3.8.
     pub only_remove_type_imports: bool,
 
+    /// Legacy TypeScript `importsNotUsedAsValues` option, superseded by `verbatimModuleSyntax`.
+    /// Controls what happens to imports whose bindings are only used as types.
+    /// Defaults to [`ImportsNotUsedAsValues::Remove`].
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+
     // Enables compilation of TypeScript namespaces.
     #[serde(default = "default_as_true")]
     pub allow_namespaces: bool,
@@ -46,9 +51,18 @@ pub struct TypeScriptOptions {
     #[serde(default = "default_as_true")]
     pub allow_declare_fields: bool,
 
-    /// Unused.
+    /// Transform `const enum`s whose members all have constant values to plain objects
+    /// without reverse mappings, e.g. `const enum A { X }` -> `var A = { X: 0 }`.
     pub optimize_const_enums: bool,
 
+    /// Inline accesses to members of `const enum`s declared in the same file, e.g. `A.X` -> `0`,
+    /// while still emitting the enum object, as TypeScript's `preserveConstEnums` option.
+    pub preserve_const_enums: bool,
+
+    /// Report an error for exported `const enum`s, as their members cannot be inlined
+    /// by other modules which are compiled in isolation, as TypeScript's `isolatedModules` option.
+    pub isolated_modules: bool,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -58,6 +72,11 @@ pub struct TypeScriptOptions {
     /// When set to `true`, same as [`RewriteExtensionsMode::Rewrite`]. Defaults to `false` (do nothing).
     #[serde(deserialize_with = "deserialize_rewrite_import_extensions")]
     pub rewrite_import_extensions: Option<RewriteExtensionsMode>,
+
+    /// Append `.js` to relative import/export sources which have no extension,
+    /// e.g. `./a` -> `./a.js`. Needed for strict ESM resolution.
+    /// Bare specifiers are not changed. Defaults to `false`.
+    pub add_missing_extensions: bool,
 }
 
 impl TypeScriptOptions {
@@ -101,14 +120,32 @@ impl Default for TypeScriptOptions {
             jsx_pragma: default_for_jsx_pragma(),
             jsx_pragma_frag: default_for_jsx_pragma_frag(),
             only_remove_type_imports: false,
+            imports_not_used_as_values: ImportsNotUsedAsValues::default(),
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
             optimize_const_enums: false,
+            preserve_const_enums: false,
+            isolated_modules: false,
             rewrite_import_extensions: None,
+            add_missing_extensions: false,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportsNotUsedAsValues {
+    /// Remove imports which are only used as types.
+    #[default]
+    Remove,
+    /// Keep imports which are only used as types for their side effects, e.g.
+    /// `import { A } from 'mod'` -> `import 'mod'`.
+    Preserve,
+    /// As [`ImportsNotUsedAsValues::Preserve`], but also report an error for
+    /// each such import which does not use `import type`.
+    Error,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub enum RewriteExtensionsMode {
     /// Rewrite `.ts`/`.mts`/`.cts` extensions in import/export declarations to `.js`/`.mjs`/`.cjs`.
@@ -3,6 +3,10 @@
 //! This plugin is used to rewrite/remove extensions from import/export source.
 //! It is only handled source that contains `/` or `\` in the source.
 //!
+//! When `add_missing_extensions` is enabled, relative sources without an extension
+//! (e.g. `./a`) have `.js` appended, as required by strict ESM resolution.
+//! Bare specifiers (e.g. `lodash`, `a-package/file`) are left as is.
+//!
 //! Based on Babel's [plugin-rewrite-ts-imports](https://github.com/babel/babel/blob/3bcfee232506a4cebe410f02042fb0f0adeeb0b1/packages/babel-preset-typescript/src/plugin-rewrite-ts-imports.ts)
 
 use oxc_ast::ast::{
@@ -15,12 +19,19 @@ use crate::TypeScriptOptions;
 use super::options::RewriteExtensionsMode;
 
 pub struct TypeScriptRewriteExtensions {
-    mode: RewriteExtensionsMode,
+    mode: Option<RewriteExtensionsMode>,
+    add_missing_extensions: bool,
 }
 
 impl TypeScriptRewriteExtensions {
     pub fn new(options: &TypeScriptOptions) -> Option<Self> {
-        options.rewrite_import_extensions.map(|mode| Self { mode })
+        if options.rewrite_import_extensions.is_none() && !options.add_missing_extensions {
+            return None;
+        }
+        Some(Self {
+            mode: options.rewrite_import_extensions,
+            add_missing_extensions: options.add_missing_extensions,
+        })
     }
 
     pub fn rewrite_extensions<'a>(
@@ -33,6 +44,12 @@ impl TypeScriptRewriteExtensions {
             return;
         }
 
+        if self.add_missing_extensions && is_relative_without_extension(value) {
+            source.value = ctx.ast.atom(&format!("{value}.js"));
+            return;
+        }
+
+        let Some(mode) = self.mode else { return };
         let Some((_, extension)) = value.rsplit_once('.') else { return };
 
         let replace = match extension {
@@ -43,7 +60,7 @@ impl TypeScriptRewriteExtensions {
         };
 
         let value = value.trim_end_matches(extension);
-        source.value = if self.mode.is_remove() {
+        source.value = if mode.is_remove() {
             ctx.ast.atom(value.trim_end_matches('.'))
         } else {
             let mut value = value.to_string();
@@ -89,3 +106,14 @@ impl<'a> Traverse<'a> for TypeScriptRewriteExtensions {
         self.rewrite_extensions(&mut node.source, ctx);
     }
 }
+
+/// Check if `source` is a relative path to a file without an extension, e.g. `./a` or `../dir/a`.
+///
+/// Directories (`.`, `..`, `./dir/`) are not considered files.
+fn is_relative_without_extension(source: &str) -> bool {
+    if !(source.starts_with("./") || source.starts_with("../")) {
+        return false;
+    }
+    let file_name = source.rsplit(['/', '\\']).next().unwrap_or_default();
+    !file_name.is_empty() && file_name != "." && file_name != ".." && !file_name.contains('.')
+}
@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ClassPropertiesOptions, TransformOptions, Transformer};
+
+use super::run;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) = SemanticBuilder::new(source_text)
+        .build(&program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    let mut options = TransformOptions::default();
+    options.es2022.with_class_properties(Some(ClassPropertiesOptions::default()));
+    let ret = Transformer::new(
+        &allocator,
+        Path::new("test.js"),
+        source_type,
+        source_text,
+        ret.trivias,
+        options,
+    )
+    .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty(), "for source {source_text}");
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .source_text;
+    let expected = run(expected, source_type);
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+#[test]
+fn private_field_weak_map() {
+    test(
+        "class A { #x = 1; get() { return this.#x; } }",
+        "var _x = new WeakMap();
+        class A {
+            constructor() { babelHelpers.classPrivateFieldInitSpec(this, _x, 1); }
+            get() { return babelHelpers.classPrivateFieldGet2(_x, this); }
+        }",
+    );
+}
@@ -0,0 +1,3 @@
+mod class_properties;
+
+use super::run;
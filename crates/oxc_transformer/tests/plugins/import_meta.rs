@@ -0,0 +1,76 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{ImportMeta, ImportMetaMode, Platform};
+
+use super::run;
+
+fn test(source_text: &str, expected: &str, mode: ImportMetaMode) {
+    test_with_platform(source_text, expected, mode, Platform::default());
+}
+
+fn test_with_platform(source_text: &str, expected: &str, mode: ImportMetaMode, platform: Platform) {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    ImportMeta::new(&allocator, mode).with_platform(platform).build(program);
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(program)
+        .source_text;
+    let expected = run(expected, source_type);
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+#[test]
+fn commonjs_url() {
+    test(
+        "const url = import.meta.url; new URL('./a.js', import.meta.url);",
+        "const url = require('url').pathToFileURL(__filename).href; new URL('./a.js', require('url').pathToFileURL(__filename).href);",
+        ImportMetaMode::CommonJs,
+    );
+}
+
+#[test]
+fn commonjs_meta_object() {
+    test(
+        "const meta = import.meta; f(import.meta.resolve);",
+        "const meta = { url: require('url').pathToFileURL(__filename).href }; f({ url: require('url').pathToFileURL(__filename).href }.resolve);",
+        ImportMetaMode::CommonJs,
+    );
+}
+
+#[test]
+fn preserve() {
+    test(
+        "const url = import.meta.url; const meta = import.meta;",
+        "const url = import.meta.url; const meta = import.meta;",
+        ImportMetaMode::Preserve,
+    );
+}
+
+#[test]
+fn platform() {
+    let source_text = "const url = import.meta.url;";
+    test_with_platform(
+        source_text,
+        "const url = require('url').pathToFileURL(__filename).href;",
+        ImportMetaMode::CommonJs,
+        Platform::Node,
+    );
+    test_with_platform(
+        source_text,
+        "const url = document.currentScript && document.currentScript.src || document.baseURI;",
+        ImportMetaMode::CommonJs,
+        Platform::Browser,
+    );
+    test_with_platform(
+        source_text,
+        "const url = typeof document === 'undefined' ? require('url').pathToFileURL(__filename).href : document.currentScript && document.currentScript.src || document.baseURI;",
+        ImportMetaMode::CommonJs,
+        Platform::Neutral,
+    );
+    test_with_platform(source_text, source_text, ImportMetaMode::Preserve, Platform::Browser);
+}
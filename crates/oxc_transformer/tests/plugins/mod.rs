@@ -1,17 +1,7 @@
+mod import_meta;
 mod inject_global_variables;
+mod remove_unused_imports;
 mod replace_global_defines;
+mod strip_types;
 
-use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
-use oxc_parser::Parser;
-use oxc_span::SourceType;
-
-fn run(source_text: &str, source_type: SourceType) -> String {
-    let allocator = Allocator::default();
-    let ret = Parser::new(&allocator, source_text, source_type).parse();
-    let program = allocator.alloc(ret.program);
-    CodeGenerator::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
-        .build(program)
-        .source_text
-}
+use super::run;
@@ -0,0 +1,62 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{RemoveUnusedImports, TransformOptions, Transformer, TypeScriptOptions};
+
+use super::run;
+
+/// Strip TypeScript (keeping all value imports) and then remove unused imports.
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let (symbols, scopes) = SemanticBuilder::new(source_text)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    let options = TransformOptions {
+        typescript: TypeScriptOptions { only_remove_type_imports: true, ..Default::default() },
+        ..Default::default()
+    };
+    let _ = Transformer::new(
+        &allocator,
+        std::path::Path::new("test.ts"),
+        source_type,
+        source_text,
+        ret.trivias,
+        options,
+    )
+    .build_with_symbols_and_scopes(symbols, scopes, program);
+    RemoveUnusedImports::new().build(program);
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(program)
+        .source_text;
+    let expected = run(expected, SourceType::mjs());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+#[test]
+fn removes_import_unused_after_transform() {
+    test("import { a } from 'a'; export type T = typeof a;", "");
+    test(
+        "import a, { b, c as d } from 'a'; import * as e from 'e'; let x: typeof a = b; export type T = typeof e;",
+        "import { b } from 'a'; let x = b;",
+    );
+}
+
+#[test]
+fn keeps_side_effect_imports() {
+    test("import 'a'; import {} from 'b';", "import 'a'; import {} from 'b';");
+}
+
+#[test]
+fn keeps_referenced_imports() {
+    test(
+        "import a from 'a'; import { b } from 'b'; import { c } from 'c'; a(); export { b }; new c();",
+        "import a from 'a'; import { b } from 'b'; import { c } from 'c'; a(); export { b }; new c();",
+    );
+}
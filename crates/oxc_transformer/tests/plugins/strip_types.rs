@@ -0,0 +1,39 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::StripTypes;
+
+fn strip(source_text: &str) -> (String, usize) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    assert!(ret.errors.is_empty(), "for source {source_text}");
+    let ret = StripTypes::new(source_text).build(&ret.program);
+    (ret.source_text, ret.errors.len())
+}
+
+fn test(source_text: &str, expected: &str) {
+    let (result, errors) = strip(source_text);
+    assert_eq!(errors, 0, "for source {source_text}");
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+#[test]
+fn annotations() {
+    test("let x: T = y! as T;", "let x    = y      ;");
+}
+
+#[test]
+fn optional_parameters() {
+    test("function f(a: number, c?, {d}?: X) {}", "function f(a        , c , {d}    ) {}");
+}
+
+#[test]
+fn multi_line_arrow_return_type() {
+    test("const f = (a): {\n  b: T\n} => a;", "const f = (a    \n      \n) => a;");
+}
+
+#[test]
+fn runtime_syntax() {
+    let (_, errors) = strip("enum E { A }\nnamespace N {}");
+    assert_eq!(errors, 2);
+}
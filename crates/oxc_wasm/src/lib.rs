@@ -14,7 +14,7 @@ use oxc::{
     ast::{ast::Program, CommentKind, Trivias, Visit},
     codegen::{CodeGenerator, CodegenOptions},
     diagnostics::Error,
-    minifier::{CompressOptions, Minifier, MinifierOptions},
+    minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions},
     parser::{ParseOptions, Parser, ParserReturn},
     semantic::{
         dot::{DebugDot, DebugDotContext},
@@ -263,21 +263,17 @@ impl Oxc {
         {
             let compress_options = minifier_options.compress_options.unwrap_or_default();
             let options = MinifierOptions {
-                mangle: minifier_options.mangle.unwrap_or_default(),
-                compress: if minifier_options.compress.unwrap_or_default() {
-                    CompressOptions {
-                        booleans: compress_options.booleans,
-                        drop_console: compress_options.drop_console,
-                        drop_debugger: compress_options.drop_debugger,
-                        evaluate: compress_options.evaluate,
-                        join_vars: compress_options.join_vars,
-                        loops: compress_options.loops,
-                        typeofs: compress_options.typeofs,
-                        ..CompressOptions::default()
-                    }
-                } else {
-                    CompressOptions::all_false()
-                },
+                mangle: minifier_options.mangle.unwrap_or_default().then(MangleOptions::default),
+                compress: minifier_options.compress.unwrap_or_default().then(|| CompressOptions {
+                    booleans: compress_options.booleans,
+                    drop_console: compress_options.drop_console,
+                    drop_debugger: compress_options.drop_debugger,
+                    evaluate: compress_options.evaluate,
+                    join_vars: compress_options.join_vars,
+                    loops: compress_options.loops,
+                    typeofs: compress_options.typeofs,
+                    ..CompressOptions::default()
+                }),
             };
             Minifier::new(options).build(&allocator, &mut program).mangler
         } else {
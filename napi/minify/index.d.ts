@@ -1,3 +1,64 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
-export declare function minify(filename: string, sourceText: string): string;
+export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): string;
+
+/** Minify, and also return the original and mangled names of renamed bindings. */
+export declare function minifyWithNameMap(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult;
+
+export interface MinifyOptions {
+  /**
+   * Fold constants, remove dead code and apply other syntax compressions.
+   *
+   * When both this and {@link mangle} are `false`, only whitespace and
+   * redundant semicolons are removed, leaving names and syntax untouched.
+   *
+   * @default true
+   */
+  compress?: boolean
+  /**
+   * Mangle variable names.
+   *
+   * @default true
+   */
+  mangle?: boolean
+  /**
+   * Keep the original names of functions when mangling.
+   *
+   * @default false
+   */
+  keepFnames?: boolean
+  /**
+   * Keep quotes on object property keys, e.g. `{ "foo": 1 }`, when
+   * compressing. Otherwise quotes are removed from keys which are valid
+   * identifiers.
+   *
+   * @default false
+   */
+  keepQuotedProps?: boolean
+}
+
+export interface MinifyResult {
+  code: string
+  /**
+   * Bindings renamed by mangling, in source order. Empty if
+   * {@link MinifyOptions#mangle} is `false`.
+   */
+  nameMap: Array<RenamedBinding>
+}
+
+/**
+ * A binding renamed by mangling.
+ *
+ * Bindings with the same original name in different scopes have separate
+ * entries, which are told apart by their position in the source.
+ */
+export interface RenamedBinding {
+  /** Name in the source. */
+  original: string
+  /** Name in the minified code. */
+  mangled: string
+  /** Start of the binding in the source, as a UTF-8 byte offset. */
+  start: number
+  /** End of the binding in the source, as a UTF-8 byte offset. */
+  end: number
+}
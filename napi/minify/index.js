@@ -343,3 +343,4 @@ if (!nativeBinding) {
 }
 
 module.exports.minify = nativeBinding.minify;
+module.exports.minifyWithNameMap = nativeBinding.minifyWithNameMap;
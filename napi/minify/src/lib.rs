@@ -1,28 +1,129 @@
 use napi_derive::napi;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_codegen::{Codegen, CodegenOptions, CommentOptions};
+use oxc_minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
+#[napi(object)]
+#[derive(Default)]
+pub struct MinifyOptions {
+    /// Fold constants, remove dead code and apply other syntax compressions.
+    ///
+    /// When both this and {@link mangle} are `false`, only whitespace and
+    /// redundant semicolons are removed, leaving names and syntax untouched.
+    ///
+    /// @default true
+    pub compress: Option<bool>,
+
+    /// Mangle variable names.
+    ///
+    /// @default true
+    pub mangle: Option<bool>,
+
+    /// Keep the original names of functions when mangling.
+    ///
+    /// @default false
+    pub keep_fnames: Option<bool>,
+
+    /// Keep quotes on object property keys, e.g. `{ "foo": 1 }`, when
+    /// compressing. Otherwise quotes are removed from keys which are valid
+    /// identifiers.
+    ///
+    /// @default false
+    pub keep_quoted_props: Option<bool>,
+}
+
+#[napi(object)]
+pub struct MinifyResult {
+    pub code: String,
+
+    /// Bindings renamed by mangling, in source order. Empty if
+    /// {@link MinifyOptions#mangle} is `false`.
+    pub name_map: Vec<RenamedBinding>,
+}
+
+/// A binding renamed by mangling.
+///
+/// Bindings with the same original name in different scopes have separate
+/// entries, which are told apart by their position in the source.
+#[napi(object)]
+pub struct RenamedBinding {
+    /// Name in the source.
+    pub original: String,
+    /// Name in the minified code.
+    pub mangled: String,
+    /// Start of the binding in the source, as a UTF-8 byte offset.
+    pub start: u32,
+    /// End of the binding in the source, as a UTF-8 byte offset.
+    pub end: u32,
+}
+
 #[allow(clippy::needless_pass_by_value)]
 #[napi]
-pub fn minify(filename: String, source_text: String) -> String {
+pub fn minify(filename: String, source_text: String, options: Option<MinifyOptions>) -> String {
+    minify_impl(&filename, &source_text, options, false).code
+}
+
+/// Minify, and also return the original and mangled names of renamed bindings.
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn minify_with_name_map(
+    filename: String,
+    source_text: String,
+    options: Option<MinifyOptions>,
+) -> MinifyResult {
+    minify_impl(&filename, &source_text, options, true)
+}
+
+fn minify_impl(
+    filename: &str,
+    source_text: &str,
+    options: Option<MinifyOptions>,
+    with_name_map: bool,
+) -> MinifyResult {
+    let options = options.unwrap_or_default();
+    let compress = options.compress.unwrap_or(true).then(|| CompressOptions {
+        keep_quoted_props: options.keep_quoted_props.unwrap_or_default(),
+        ..CompressOptions::default()
+    });
+    let mangle = options.mangle.unwrap_or(true).then(|| MangleOptions {
+        keep_fnames: options.keep_fnames.unwrap_or_default(),
+        ..MangleOptions::default()
+    });
+
     let allocator = Allocator::default();
-    let source_type = SourceType::from_path(&filename).unwrap_or_default().with_typescript(true);
+    let source_type = SourceType::from_path(filename).unwrap_or_default().with_typescript(true);
 
-    let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
 
     let mangler =
-        Minifier::new(MinifierOptions { mangle: true, compress: CompressOptions::default() })
-            .build(&allocator, &mut program)
-            .mangler;
+        Minifier::new(MinifierOptions { mangle, compress }).build(&allocator, &mut program).mangler;
 
-    Codegen::new()
+    let name_map = match mangler.as_ref().filter(|_| with_name_map) {
+        Some(mangler) => mangler
+            .get_renamed_bindings(&program)
+            .into_iter()
+            .map(|binding| RenamedBinding {
+                original: binding.original.into_string(),
+                mangled: binding.mangled.into_string(),
+                start: binding.span.start,
+                end: binding.span.end,
+            })
+            .collect(),
+        None => vec![],
+    };
+
+    let code = Codegen::new()
         .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
         .with_mangler(mangler)
         .with_capacity(source_text.len())
+        // Only legal comments are printed in minify mode.
+        .enable_comment(source_text, ret.trivias, CommentOptions::default())
         .build(&program)
-        .source_text
+        .source_text;
+
+    MinifyResult { code, name_map }
 }
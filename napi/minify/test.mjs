@@ -0,0 +1,45 @@
+import assert from 'assert';
+import oxc from './index.js';
+
+console.log(`Testing on ${process.platform}-${process.arch}`);
+
+{
+  const code = `
+function foo ( bar ) {
+  const baz = bar + 1 + 2 ;
+  return baz ;
+}
+foo( 1 ) ;
+`;
+  const ret = oxc.minify('test.js', code, { compress: false, mangle: false });
+  assert(ret.length < code.length, ret);
+  assert(ret.includes('function foo(bar){'), ret);
+  assert(ret.includes('const baz=bar+1+2;'), ret);
+  assert(ret.includes('return baz}'), ret);
+  assert(!/\n|  /.test(ret.trim()), ret);
+}
+
+{
+  const code = `console.log({ "foo": 1, "foo-bar": 2, bar: 3 });`;
+  const ret = oxc.minify('test.js', code, { mangle: false });
+  assert(ret.includes('{foo:1,"foo-bar":2,bar:3}'), ret);
+
+  const quoted = oxc.minify('test.js', code, { mangle: false, keepQuotedProps: true });
+  assert(quoted.includes('{"foo":1,"foo-bar":2,bar:3}'), quoted);
+}
+
+{
+  // Shadowed bindings with the same name have separate entries
+  const code = 'export function f(x) { function g(x) { return x; } return g(x); }\n';
+  const ret = oxc.minifyWithNameMap('test.js', code, { compress: false });
+  assert.equal(ret.code, oxc.minify('test.js', code, { compress: false }));
+  assert.deepEqual(ret.nameMap.map(({ original }) => original), ['x', 'g', 'x']);
+  for (const { original, mangled, start, end } of ret.nameMap) {
+    assert.equal(code.slice(start, end), original);
+    assert(ret.code.includes(mangled), ret.code);
+  }
+
+  assert.deepEqual(oxc.minifyWithNameMap('test.js', code, { mangle: false }).nameMap, []);
+}
+
+console.log('Success.');
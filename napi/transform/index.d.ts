@@ -7,19 +7,275 @@ export interface ArrowFunctionsBindingOptions {
    * * Add a runtime check to ensure the functions are not instantiated.
    * * Add names to arrow functions.
    *
+   * Not supported yet. Setting it to `true` is reported as an error.
+   *
    * @default false
    */
   spec?: boolean
 }
 
+export interface BlockScopingBindingOptions {
+  /**
+   * Throw a `ReferenceError` when a binding is referenced before it is
+   * initialized. Uses `babelHelpers.tdz`, which must be available in the
+   * global scope.
+   *
+   * @default false
+   */
+  tdz?: boolean
+  /**
+   * Report reassignments of `const` bindings as errors, instead of throwing
+   * a `TypeError` at runtime with `babelHelpers.readOnlyError`.
+   *
+   * @default false
+   */
+  reportConstViolations?: boolean
+}
+
+export interface ClassPropertiesBindingOptions {
+  /**
+   * Initialize public class fields with assignments (`this.x = 1`) instead of
+   * `Object.defineProperty`.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
+export interface CompilerAssumptions {
+  /**
+   * Initialize public class fields with assignments (`this.x = 1`) instead of
+   * `Object.defineProperty`, when they are transformed by
+   * {@link ES2022BindingOptions#classProperties}. Same as its `loose` option.
+   *
+   * @default false
+   */
+  setPublicClassFields?: boolean
+  /**
+   * Assume `document.all` is not used, so nullish checks can be
+   * `x != null` instead of `x !== null && x !== void 0`.
+   *
+   * @default false
+   */
+  noDocumentAll?: boolean
+  /**
+   * Assume getters have no side effects, so objects of member expressions
+   * do not need to be cached in temporaries, e.g. `a.b ??= c` becomes
+   * `a.b ?? (a.b = c)` when transformed by
+   * {@link ES2021BindingOptions#logicalAssignmentOperators}.
+   *
+   * @default false
+   */
+  pureGetters?: boolean
+}
+
+export interface DecoratorBindingOptions {
+  /**
+   * Use the legacy decorators semantics, as TypeScript's
+   * `experimentalDecorators`. Decorators are called with the class, and
+   * their return value replaces it.
+   *
+   * Otherwise, the TC39 decorators semantics are used. Decorators are
+   * called with the class and a `context` object, and are applied with
+   * `babelHelpers.applyDecs2311`, which must be available in the global
+   * scope.
+   *
+   * @default false
+   */
+  experimentalDecorators?: boolean
+}
+
 export interface Es2015BindingOptions {
-  /** Transform arrow functions into function expressions. */
-  arrowFunction?: ArrowFunctionsBindingOptions
+  /**
+   * Transform arrow functions into function expressions.
+   *
+   * Enabled by default when {@link TransformOptions#target} is older than
+   * `'es2015'`.
+   * Set to `false` to keep arrow functions regardless of the target.
+   */
+  arrowFunction?: boolean | ArrowFunctionsBindingOptions
+  /**
+   * Transform shorthand properties (`{ a }`) and methods (`{ m() {} }`) into
+   * ordinary properties (`{ a: a }`, `{ m: function() {} }`).
+   *
+   * @default false
+   */
+  shorthandProperties?: boolean
+  /**
+   * Transform computed property keys (`{ [k]: v }`). Each key is evaluated
+   * exactly once, in order. Uses `babelHelpers.defineProperty`, which must be
+   * available in the global scope.
+   *
+   * @default false
+   */
+  computedProperties?: boolean
+  /**
+   * Transform template literals into string concatenation, and tagged
+   * templates into function calls. The strings array of each tagged template
+   * is created once by `babelHelpers.taggedTemplateLiteral`, which must be
+   * available in the global scope.
+   *
+   * @default false
+   */
+  templateLiterals?: boolean
+  /**
+   * Transform destructuring in variable declarations, assignments, function
+   * parameters, catch clauses and `for...in` / `for...of` heads. Uses
+   * `babelHelpers.slicedToArray` and other helpers, which must be available in
+   * the global scope.
+   *
+   * @default false
+   */
+  destructuring?: boolean
+  /**
+   * Transform `for...of` loops into `for` loops which use the iterator
+   * protocol directly, calling the iterator's `return()` method if the loop
+   * exits early. Uses `babelHelpers.createForOfIteratorHelper`, which must be
+   * available in the global scope.
+   */
+  forOf?: ForOfBindingOptions
+  /**
+   * Transform spread elements in array literals (`[...a]`), function calls
+   * (`f(...a)`) and `new` expressions (`new C(...a)`). Uses
+   * `babelHelpers.toConsumableArray` and `babelHelpers.construct`, which must
+   * be available in the global scope.
+   *
+   * Not enabled by {@link TransformOptions#target} yet, like most ES2015
+   * transforms, so it must be set explicitly when targeting `'es5'`.
+   *
+   * @default false
+   */
+  spread?: boolean
+  /**
+   * Transform generator functions (`function*`) to state machines run by
+   * the regenerator runtime. Uses `babelHelpers.regeneratorRuntime`, which
+   * must be available in the global scope.
+   *
+   * @default false
+   */
+  useRegenerator?: boolean
+  /**
+   * Transform `let` and `const` declarations into `var` declarations.
+   * Loop bodies which create closures over bindings declared in the loop
+   * are moved into functions, so each iteration gets its own bindings.
+   * Reassigning a `const` binding throws at runtime, using
+   * `babelHelpers.readOnlyError`, which must be available in the global scope.
+   */
+  blockScoping?: BlockScopingBindingOptions
+  /**
+   * Transform `typeof x` so it returns `"symbol"` for polyfilled symbols.
+   * Uses `babelHelpers.typeof`, which must be available in the global scope.
+   *
+   * @default false
+   */
+  typeofSymbol?: boolean
+  /**
+   * Transform `new.target` into expressions based on `this`.
+   *
+   * @default false
+   */
+  newTarget?: boolean
+  /**
+   * Transform regular expression literals with the sticky flag (`/x/y`)
+   * into `new RegExp("x", "y")` calls, so they do not cause syntax errors
+   * in engines which do not support the flag.
+   *
+   * @default false
+   */
+  stickyRegex?: boolean
+  /**
+   * Transform regular expression literals with the unicode flag (`/x/u`)
+   * into `new RegExp("x", "u")` calls, so they do not cause syntax errors
+   * in engines which do not support the flag. The pattern is not rewritten,
+   * so a `RegExp` polyfill is needed for the correct runtime behavior.
+   *
+   * @default false
+   */
+  unicodeRegex?: boolean
+}
+
+export interface Es2020BindingOptions {
+  /**
+   * Transform `export * as ns from "mod"` into an import of the module
+   * namespace object and a named export of it.
+   *
+   * @default false
+   */
+  exportNamespaceFrom?: boolean
+  /**
+   * Transform nullish coalescing operators (`a ?? b`) into conditional
+   * expressions.
+   *
+   * @default false
+   */
+  nullishCoalescingOperator?: boolean
+}
+
+export interface Es2021BindingOptions {
+  /**
+   * Transform logical assignment operators (`||=`, `&&=` and `??=`) into
+   * logical expressions. The assignment target is only evaluated once.
+   *
+   * @default false
+   */
+  logicalAssignmentOperators?: boolean
+  /**
+   * Remove numeric separators (`_`) from number and bigint literals, e.g.
+   * `1_000_000` becomes `1000000`.
+   *
+   * @default false
+   */
+  numericSeparator?: boolean
+}
+
+export interface Es2022BindingOptions {
+  /**
+   * Transform public class fields. Instance fields are initialized in the
+   * constructor after `super()`, and static fields after the class.
+   * Private class elements are transformed too.
+   */
+  classProperties?: ClassPropertiesBindingOptions
+  /**
+   * Transform private class fields, methods and accessors (`#x`), using
+   * `WeakMap`s and `WeakSet`s. Accesses to them are transformed into calls
+   * to `babelHelpers`, which must be available in the global scope.
+   *
+   * @default false
+   */
+  privateMethods?: boolean
+  /**
+   * Transform private brand checks (`#x in obj`).
+   *
+   * @default false
+   */
+  privateIn?: boolean
+}
+
+export interface Es2026BindingOptions {
+  /**
+   * Transform `using` and `await using` declarations into `const`
+   * declarations, with their resources disposed in reverse order in a
+   * `try` / `finally` block. `babelHelpers.usingCtx` must be available in
+   * the global scope.
+   *
+   * @default false
+   */
+  explicitResourceManagement?: boolean
 }
 
 /** TypeScript Isolated Declarations for Standalone DTS Emit */
 export declare function isolatedDeclaration(filename: string, sourceText: string, options?: IsolatedDeclarationsOptions | undefined | null): IsolatedDeclarationsResult
 
+export interface ForOfBindingOptions {
+  /**
+   * Assume the iterable is an array, and iterate over it by index instead of
+   * with the iterator protocol.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface IsolatedDeclarationsOptions {
   /**
    * Do not emit declarations for code that has an @internal annotation in its JSDoc comment.
@@ -30,6 +286,14 @@ export interface IsolatedDeclarationsOptions {
    * See <https://www.typescriptlang.org/tsconfig/#stripInternal>
    */
   stripInternal?: boolean
+  /**
+   * Use types from JSDoc comments (`@param`, `@returns` and `@type`) for
+   * functions and variables without type annotations, to emit declarations
+   * of JavaScript files.
+   *
+   * Default: `false`
+   */
+  jsdocTypes?: boolean
   sourcemap?: boolean
 }
 
@@ -88,6 +352,47 @@ export interface JsxOptions {
    * @default 'react'
    */
   importSource?: string
+  /**
+   * Check that the JSX runtime imported by the output, e.g.
+   * `react/jsx-runtime`, can be resolved from the directory of the file,
+   * following `exports` of `package.json`. The import is always emitted as
+   * the standard `<importSource>/jsx-runtime` subpath, and a warning is
+   * reported if it cannot be resolved.
+   *
+   * Only used for `automatic` {@link runtime}.
+   *
+   * @default false
+   */
+  resolveImportSource?: boolean
+  /**
+   * Hoist props objects passed to `jsx()` to module-level variables when
+   * all of their values are constant. Props objects containing a `ref` are
+   * never hoisted.
+   *
+   * Only used for `automatic` {@link runtime}.
+   *
+   * @default false
+   */
+  hoistConstantProps?: boolean
+  /**
+   * Emit a warning for JSX elements returned from `.map()` callbacks
+   * without a `key` prop.
+   *
+   * @default false
+   */
+  warnMissingKeys?: boolean
+  /**
+   * Arguments of the `jsx()` and `jsxs()` functions of the runtime.
+   *
+   * - `'react'`: `jsx(type, { ...props, children }, key)`
+   * - `'compat'`: `jsx(type, { ...props, key }, children)`, for runtimes
+   *   compatible with React which take children as the third argument.
+   *
+   * Only used for `automatic` {@link runtime}.
+   *
+   * @default 'react'
+   */
+  reactRuntime?: 'react' | 'compat'
   /**
    * Replace the function used when compiling JSX expressions. It should be a
    * qualified name (e.g. `React.createElement`) or an identifier (e.g.
@@ -138,12 +443,17 @@ export interface ReactRefreshOptions {
   /**
    * Specify the identifier of the refresh registration variable.
    *
+   * `[hash]` is replaced with a hash of the file path, so modules sharing a
+   * scope can use different registration functions, e.g. `$RefreshReg$__[hash]`.
+   *
    * @default `$RefreshReg$`.
    */
   refreshReg?: string
   /**
    * Specify the identifier of the refresh signature variable.
    *
+   * `[hash]` is replaced with a hash of the file path, as in {@link refreshReg}.
+   *
    * @default `$RefreshSig$`.
    */
   refreshSig?: string
@@ -159,6 +469,23 @@ export interface SourceMap {
   sourcesContent?: Array<string>
   version: number
   x_google_ignoreList?: Array<number>
+  /**
+   * Debug ID associating this source map with its generated file.
+   *
+   * @see {@link https://github.com/tc39/source-map/blob/main/proposals/debug-id.md}
+   */
+  debugId?: string
+  /**
+   * Decoded mappings, set instead of `mappings` if
+   * {@link TransformOptions#sourcemapSegments} is `true`.
+   *
+   * Each line of the generated code has an array of segments, in the same
+   * format as `@jridgewell/sourcemap-codec`: `[generatedColumn]`,
+   * `[generatedColumn, sourceIndex, originalLine, originalColumn]` or
+   * `[generatedColumn, sourceIndex, originalLine, originalColumn, nameIndex]`.
+   * All values are absolute and zero-based.
+   */
+  segments?: Array<Array<Array<number>>>
 }
 
 /**
@@ -166,6 +493,8 @@ export interface SourceMap {
  *
  * @param filename The name of the file being transformed. If this is a
  * relative path, consider setting the {@link TransformOptions#cwd} option..
+ * If omitted, `"unknown"` is used, and the language should be set with
+ * {@link TransformOptions#lang}.
  * @param sourceText the source code itself
  * @param options The options for the transformation. See {@link
  * TransformOptions} for more information.
@@ -173,7 +502,7 @@ export interface SourceMap {
  * @returns an object containing the transformed code, source maps, and any
  * errors that occurred during parsing or transformation.
  */
-export declare function transform(filename: string, sourceText: string, options?: TransformOptions | undefined | null): TransformResult
+export declare function transform(filename: string | undefined | null, sourceText: string, options?: TransformOptions | undefined | null): TransformResult
 
 /**
  * Options for transforming a JavaScript or TypeScript file.
@@ -182,6 +511,62 @@ export declare function transform(filename: string, sourceText: string, options?
  */
 export interface TransformOptions {
   sourceType?: 'script' | 'module' | 'unambiguous' | undefined
+  /**
+   * The ECMAScript version which the output should run in, e.g. `'es2015'`
+   * or `'esnext'`.
+   *
+   * Transforms are enabled for syntax which is newer than the target. Most
+   * ES2015 and ES2022 transforms are not enabled by the target yet, and must
+   * be enabled explicitly. Plugin options which are set explicitly take
+   * precedence, e.g. `arrowFunction: false` keeps arrow functions regardless
+   * of the target.
+   *
+   * Also used to warn about syntax which cannot be transformed or polyfilled
+   * for the target, such as BigInt literals before ES2020.
+   *
+   * Helpers are not injected into the output, but referenced from the
+   * `babelHelpers` global, so the host must provide helpers which run in the
+   * target.
+   *
+   * @default 'esnext'
+   */
+  target?: string
+  /**
+   * Warn about syntax in the output which is newer than {@link target},
+   * because the transform for it is not enabled, e.g. `a ?? b` when
+   * targeting `'es2015'` with {@link ES2020BindingOptions#nullishCoalescingOperator}
+   * set to `false`.
+   *
+   * @default false
+   */
+  warnUnsupportedSyntax?: boolean
+  /**
+   * Treat the source text as `js`, `jsx`, `ts` or `tsx`, instead of
+   * inferring the language from the file extension.
+   *
+   * Defaults to `js` if the file has no known extension.
+   */
+  lang?: 'js' | 'jsx' | 'ts' | 'tsx' | undefined
+  /**
+   * - `'transform'`: apply the transforms configured by other options.
+   * - `'strip'`: only erase TypeScript type syntax, replacing it with
+   *   whitespace, and leave all other code untouched. Lines and columns of
+   *   the remaining code are unchanged, so no source map is generated.
+   *   TypeScript syntax which has runtime semantics, such as enums and
+   *   namespaces, is reported as an error.
+   *
+   * @default 'transform'
+   */
+  mode?: 'transform' | 'strip'
+  /**
+   * Parse and check the output again, and report an error if it is not
+   * valid JavaScript, including early errors such as redeclared bindings.
+   * This catches bugs in the transformer and code generator, at the cost
+   * of parsing twice, so is intended for CI.
+   *
+   * @default false
+   */
+  verify?: boolean
   /**
    * The current working directory. Used to resolve relative paths in other
    * options.
@@ -191,50 +576,231 @@ export interface TransformOptions {
   typescript?: TypeScriptOptions
   /** Configure how TSX and JSX are transformed. */
   react?: JsxOptions
+  /** Transform class decorators. */
+  decorator?: DecoratorBindingOptions
   /** Enable ES2015 transformations. */
   es2015?: ES2015BindingOptions
+  /** Enable ES2020 transformations. */
+  es2020?: ES2020BindingOptions
+  /** Enable ES2021 transformations. */
+  es2021?: ES2021BindingOptions
+  /** Enable ES2022 transformations. */
+  es2022?: ES2022BindingOptions
+  /** Enable ES2026 transformations. */
+  es2026?: ES2026BindingOptions
+  /**
+   * Assumptions about the code being transformed, to produce smaller output.
+   *
+   * @see {@link https://babeljs.io/docs/assumptions}
+   */
+  assumptions?: CompilerAssumptions
+  /**
+   * Remove value imports whose bindings are no longer referenced after
+   * transformation, e.g. when their only use was in a type position.
+   *
+   * Side-effect imports (`import 'foo'`) are always kept.
+   *
+   * This is different from TypeScript's type-only import elision, which is
+   * controlled by {@link TypeScriptOptions#onlyRemoveTypeImports}.
+   *
+   * @default false
+   */
+  removeUnusedImports?: boolean
+  /**
+   * Configure how `import.meta` is handled.
+   *
+   * - `'preserve'`: `import.meta` is left as is.
+   * - `'commonjs'`: `import.meta.url` is replaced with
+   *   `require("url").pathToFileURL(__filename).href`, and other uses of
+   *   `import.meta` with an object containing `url`.
+   *
+   * @default 'preserve'
+   */
+  importMeta?: 'preserve' | 'commonjs'
+  /**
+   * Replace `this` at the top level of ES modules with `void 0`, for output
+   * which is converted to CommonJS by a later build step. Top-level `this`
+   * is `undefined` in ES modules, but `module.exports` in CommonJS.
+   *
+   * @default false
+   */
+  replaceTopLevelThis?: boolean
+  /**
+   * Keyword of import attributes in the output, for runtimes which only
+   * support one of them.
+   *
+   * - `'preserve'`: the keyword of the source is kept.
+   * - `'assert'`: `import json from "./a.json" assert { type: "json" }`
+   * - `'with'`: `import json from "./a.json" with { type: "json" }`
+   *
+   * @default 'preserve'
+   */
+  importAttributesKeyword?: 'assert' | 'with' | 'preserve'
+  /**
+   * The platform the output will run on. Transforms which depend on the
+   * environment use this to choose their output.
+   *
+   * When {@link importMeta} is `'commonjs'`, `import.meta.url` is replaced with:
+   * - `'node'`: `require("url").pathToFileURL(__filename).href`
+   * - `'browser'`: `document.currentScript && document.currentScript.src || document.baseURI`
+   * - `'neutral'`: a runtime check for `document`, which picks one of the above
+   *
+   * @default 'node'
+   */
+  platform?: 'node' | 'browser' | 'neutral'
+  /**
+   * Emit `@__PURE__` and `@__NO_SIDE_EFFECTS__` annotation comments.
+   *
+   * When `true`, annotations are kept whether or not {@link keepComments} is
+   * set. When `false`, no annotations are emitted, regardless of other options
+   * such as {@link JsxOptions#pure}. When not set, annotations are only
+   * emitted along with other comments, if {@link keepComments} is set.
+   */
+  annotations?: boolean
+  /**
+   * When to print semicolons after statements.
+   *
+   * - `'always'`: after every statement which can end with one.
+   * - `'asi'`: only where the next statement would otherwise continue the
+   *   previous one, e.g. `;[a, b] = [b, a]`. Other statements rely on
+   *   automatic semicolon insertion.
+   *
+   * @default 'always'
+   */
+  semicolons?: 'always' | 'asi'
+  /**
+   * Keep JSDoc comments in the output. Comments above statements which are
+   * removed, such as `import type` declarations, are moved to the following
+   * statement.
+   *
+   * @default false
+   */
+  keepComments?: boolean
+  /**
+   * Functions which have side effects, whose calls keep no `@__PURE__`
+   * annotation even if the source has one, e.g. `['track', 'console.log']`.
+   *
+   * Only used when annotations are emitted, see {@link annotations}.
+   */
+  impureFunctions?: Array<string>
+  /**
+   * Keep the hashbang (e.g. `#!/usr/bin/env node`) as the first line of
+   * the output. When `false`, the hashbang is removed.
+   *
+   * @default true
+   */
+  hashbang?: boolean
+  /**
+   * Transform the rest of a file which has a syntax error the parser
+   * cannot recover from. Lines containing such errors are skipped, and
+   * errors are still reported, with a warning for each skipped line, as
+   * other statements on the same line are missing from the output.
+   *
+   * When `false`, `code` is an empty string if parsing fails.
+   *
+   * @default false
+   */
+  recover?: boolean
   /**
    * Enable source map generation.
    *
    * When `true`, the `sourceMap` field of transform result objects will be populated.
    *
+   * Ignored when {@link mode} is `'strip'`, because lines and columns of the
+   * output are the same as the input.
+   *
    * @default false
    *
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * Append a `//# debugId=<id>` comment to the transformed code and set the
+   * matching `debugId` field on the source map.
+   *
+   * The ID is derived from the file name, the input and the transformed
+   * output, so the same input and options always produce the same ID.
+   *
+   * Only used when {@link sourcemap} is `true`.
+   *
+   * @default false
+   *
+   * @see {@link https://github.com/tc39/source-map/blob/main/proposals/debug-id.md}
+   */
+  sourcemapDebugIds?: boolean
+  /**
+   * Return the source map's mappings as decoded segments in
+   * {@link SourceMap#segments} instead of the VLQ-encoded `mappings` string,
+   * which is left empty. This avoids encoding the mappings of large files
+   * when the consumer decodes them anyway.
+   *
+   * Only used when {@link sourcemap} is `true`.
+   *
+   * @default false
+   */
+  sourcemapSegments?: boolean
+  /**
+   * Remove this prefix from each entry of the source map's `sources`, e.g.
+   * to turn absolute paths into paths relative to a repository root.
+   *
+   * Entries which do not start with the prefix are left unchanged.
+   *
+   * Only used when {@link sourcemap} is `true`.
+   */
+  stripSourceMapPrefix?: string
+  /**
+   * The `file` field of the source map.
+   *
+   * Defaults to the name of the output file, which is the name of the input
+   * file with TypeScript and JSX extensions changed to JavaScript ones, e.g.
+   * `foo.js` for `src/foo.ts` and `foo.mjs` for `src/foo.mts`.
+   *
+   * Only used when {@link sourcemap} is `true`.
+   */
+  sourcemapFile?: string
 }
 
 export interface TransformResult {
   /**
    * The transformed code.
    *
-   * If parsing failed, this will be an empty string.
+   * If parsing failed, or {@link TypeScriptOptions#emitDeclarationOnly} is
+   * set and declarations were generated, this will be an empty string.
    */
   code: string
   /**
    * The source map for the transformed code.
    *
-   * This will be set if {@link TransformOptions#sourcemap} is `true`.
+   * This will be set if {@link TransformOptions#sourcemap} is `true`, unless
+   * only declarations were emitted, or {@link TransformOptions#mode} is
+   * `'strip'`, which keeps lines and columns unchanged.
    */
   map?: SourceMap
   /**
    * The `.d.ts` declaration file for the transformed code. Declarations are
-   * only generated if `declaration` is set to `true` and a TypeScript file
-   * is provided.
+   * only generated if `declaration` is set and a TypeScript file is
+   * provided.
    *
    * If parsing failed and `declaration` is set, this will be an empty string.
    *
-   * @see {@link TypeScriptBindingOptions#declaration}
+   * @see {@link TypeScriptOptions#declaration}
    * @see [declaration tsconfig option](https://www.typescriptlang.org/tsconfig/#declaration)
    */
   declaration?: string
   /**
    * Declaration source map. Only generated if both
-   * {@link TypeScriptBindingOptions#declaration declaration} and
-   * {@link TransformOptions#sourcemap sourcemap} are set to `true`.
+   * {@link TypeScriptOptions#declaration declaration} and
+   * {@link TransformOptions#sourcemap sourcemap} are set.
    */
   declarationMap?: SourceMap
+  /**
+   * Whether the file was parsed as an ES module or a script.
+   *
+   * With {@link TransformOptions#sourceType} `'unambiguous'`, this is
+   * `'module'` if the file contains `import` or `export` statements, and
+   * `'script'` otherwise.
+   */
+  moduleType: 'module' | 'script'
   /**
    * Parse and transformation errors.
    *
@@ -246,11 +812,78 @@ export interface TransformResult {
 }
 
 export interface TypeScriptOptions {
+  /**
+   * Import which is retained when the file contains JSX, even if it looks
+   * unused. Ignored with the `automatic` {@link JsxOptions#runtime}, which
+   * does not reference it.
+   *
+   * @default 'React.createElement'
+   */
   jsxPragma?: string
+  /**
+   * Import which is retained when the file contains JSX fragments. Ignored
+   * with the `automatic` {@link JsxOptions#runtime}.
+   *
+   * @default 'React.Fragment'
+   */
   jsxPragmaFrag?: string
+  /**
+   * Alias of tsconfig's `jsxFactory`. Sets {@link JsxOptions#pragma} for
+   * the `classic` runtime, and {@link jsxPragma}, unless they are set.
+   *
+   * @see [jsxFactory](https://www.typescriptlang.org/tsconfig/#jsxFactory)
+   */
+  jsxFactory?: string
+  /**
+   * Alias of tsconfig's `jsxFragmentFactory`. Sets
+   * {@link JsxOptions#pragmaFrag} for the `classic` runtime, and
+   * {@link jsxPragmaFrag}, unless they are set.
+   *
+   * @see [jsxFragmentFactory](https://www.typescriptlang.org/tsconfig/#jsxFragmentFactory)
+   */
+  jsxFragmentFactory?: string
   onlyRemoveTypeImports?: boolean
+  /**
+   * Legacy TypeScript option controlling imports whose bindings are only
+   * used as types. Superseded by `verbatimModuleSyntax`.
+   *
+   * - `'remove'`: remove such imports.
+   * - `'preserve'`: keep such imports for their side effects, e.g.
+   *   `import { A } from 'mod'` becomes `import 'mod'`.
+   * - `'error'`: as `'preserve'`, but also report an error for such imports
+   *   which do not use `import type`.
+   *
+   * @default 'remove'
+   */
+  importsNotUsedAsValues?: 'remove' | 'preserve' | 'error'
   allowNamespaces?: boolean
   allowDeclareFields?: boolean
+  /**
+   * Transform `const enum`s whose members all have constant values to
+   * plain objects without reverse mappings, e.g. `const enum A { X }`
+   * becomes `var A = { X: 0 }`.
+   *
+   * @default false
+   */
+  optimizeConstEnums?: boolean
+  /**
+   * Inline accesses to members of `const enum`s declared in the same file,
+   * e.g. `A.X` becomes `0`, while still emitting the enum object.
+   *
+   * @see [preserveConstEnums](https://www.typescriptlang.org/tsconfig/#preserveConstEnums)
+   *
+   * @default false
+   */
+  preserveConstEnums?: boolean
+  /**
+   * Report an error for exported `const enum`s, as their members cannot be
+   * inlined by other modules which are compiled in isolation.
+   *
+   * @see [isolatedModules](https://www.typescriptlang.org/tsconfig/#isolatedModules)
+   *
+   * @default false
+   */
+  isolatedModules?: boolean
   /**
    * Also generate a `.d.ts` declaration file for TypeScript files.
    *
@@ -261,6 +894,15 @@ export interface TypeScriptOptions {
    * @default false
    */
   declaration?: IsolatedDeclarationsOptions
+  /**
+   * Only generate the `.d.ts` declaration file, without transforming the
+   * source file. `code` is empty and `map` is not set.
+   *
+   * Only used when {@link declaration} is set.
+   *
+   * @default false
+   */
+  emitDeclarationOnly?: boolean
   /**
    * Rewrite or remove TypeScript import/export declaration extensions.
    *
@@ -272,5 +914,14 @@ export interface TypeScriptOptions {
    * @default false
    */
   rewriteImportExtensions?: 'rewrite' | 'remove' | boolean
+  /**
+   * Append `.js` to relative import/export sources without an extension,
+   * e.g. `./a` becomes `./a.js`. This is needed for strict ESM resolution.
+   *
+   * Bare specifiers such as `lodash` are not changed.
+   *
+   * @default false
+   */
+  addMissingExtensions?: boolean
 }
 
@@ -6,10 +6,10 @@ use std::{
 
 use oxc_allocator::Allocator;
 use oxc_ast::{ast::Program, Trivias};
-use oxc_codegen::Codegen;
+use oxc_codegen::{Codegen, CodegenOptions, Semicolons};
 use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
 use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span};
 
 use crate::{IsolatedDeclarationsOptions, TransformOptions};
 
@@ -21,6 +21,16 @@ pub(crate) struct TransformContext<'a> {
 
     /// Generate source maps?
     source_map: bool,
+    /// Emit debug IDs in generated code and source maps?
+    source_map_debug_ids: bool,
+    /// Return source maps with decoded mapping segments?
+    source_map_segments: bool,
+    /// Prefix to remove from source map `sources`.
+    strip_source_map_prefix: Option<String>,
+    /// `file` of source maps, instead of the output file name.
+    source_map_file: Option<String>,
+    /// When to print semicolons after statements.
+    semicolons: Semicolons,
     /// Generate `.d.ts` files?
     ///
     /// Used by [`crate::transform`].
@@ -44,12 +54,37 @@ impl<'a> TransformContext<'a> {
         source_type: SourceType,
         options: Option<&TransformOptions>,
     ) -> Self {
-        let ParserReturn { errors, program, trivias, .. } =
-            Parser::new(allocator, source_text, source_type).parse();
+        let mut ret = Parser::new(allocator, source_text, source_type).parse();
+        if !ret.errors.is_empty() && Self::is_jsx_in_ts(allocator, source_text, source_type) {
+            let mut diagnostic = OxcDiagnostic::error("JSX is not allowed in `.ts` files.")
+                .with_help("Rename the file to `.tsx` to parse it as TypeScript with JSX.");
+            if let Some(label) = ret.errors[0].labels.as_ref().and_then(|labels| labels.first()) {
+                diagnostic = diagnostic.with_label(Span::sized(
+                    u32::try_from(label.offset()).unwrap_or_default(),
+                    u32::try_from(label.len()).unwrap_or_default(),
+                ));
+            }
+            ret.errors.insert(0, diagnostic);
+        }
+        if options.as_ref().and_then(|o| o.recover).unwrap_or_default() {
+            ret = Self::parse_with_recovery(allocator, source_text, source_type, ret);
+        }
+        let ParserReturn { errors, program, trivias, .. } = ret;
 
         // Options that are added by this napi crates and don't exist in
         // oxc_transformer.
         let source_map = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
+        let source_map_debug_ids =
+            options.as_ref().and_then(|o| o.sourcemap_debug_ids).unwrap_or_default();
+        let source_map_segments =
+            options.as_ref().and_then(|o| o.sourcemap_segments).unwrap_or_default();
+        let strip_source_map_prefix =
+            options.as_ref().and_then(|o| o.strip_source_map_prefix.clone());
+        let source_map_file = options.as_ref().and_then(|o| o.sourcemap_file.clone());
+        let semicolons = match options.as_ref().and_then(|o| o.semicolons.as_deref()) {
+            Some("asi") => Semicolons::Asi,
+            _ => Semicolons::Always,
+        };
         let declarations =
             options.as_ref().and_then(|o| o.typescript.as_ref()).and_then(|t| t.declaration);
 
@@ -59,6 +94,11 @@ impl<'a> TransformContext<'a> {
             trivias,
 
             source_map,
+            source_map_debug_ids,
+            source_map_segments,
+            strip_source_map_prefix,
+            source_map_file,
+            semicolons,
             declarations,
 
             filename,
@@ -68,6 +108,77 @@ impl<'a> TransformContext<'a> {
         }
     }
 
+    /// Whether a `.ts` file which failed to parse would parse as `.tsx`,
+    /// i.e. the parse errors are caused by JSX.
+    fn is_jsx_in_ts(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> bool {
+        source_type.is_typescript()
+            && !source_type.is_typescript_definition()
+            && !source_type.is_jsx()
+            && Parser::new(allocator, source_text, source_type.with_jsx(true))
+                .parse()
+                .errors
+                .is_empty()
+    }
+
+    /// Re-parse `source_text` with the lines containing unrecoverable syntax
+    /// errors blanked out, so the statements around them can still be
+    /// transformed. Offsets are preserved, so spans still refer to `source_text`.
+    ///
+    /// Each blanked line is reported with a warning after the syntax error.
+    fn parse_with_recovery(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        mut ret: ParserReturn<'a>,
+    ) -> ParserReturn<'a> {
+        const MAX_ATTEMPTS: usize = 16;
+
+        let mut text = source_text.to_string();
+        let mut fatal_errors = vec![];
+        for _ in 0..MAX_ATTEMPTS {
+            if !ret.panicked {
+                break;
+            }
+            // The error which stopped the parser is the last one reported.
+            let Some(error) = ret.errors.pop() else { break };
+            let Some(label) = error.labels.as_ref().and_then(|labels| labels.first()) else {
+                fatal_errors.push(error);
+                break;
+            };
+            let start = label.offset().min(text.len());
+            let end = (label.offset() + label.len()).min(text.len());
+            fatal_errors.push(error);
+
+            let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = text[end..].find('\n').map_or(text.len(), |i| end + i);
+            let line = &text[line_start..line_end];
+            if line.trim().is_empty() {
+                break;
+            }
+            // Replace with the same number of bytes, to keep offsets.
+            let blank = " ".repeat(line.len());
+            text.replace_range(line_start..line_end, &blank);
+            // Valid statements on the same line are skipped too, so report the whole range.
+            fatal_errors.push(
+                OxcDiagnostic::warn(
+                    "Line skipped because of a syntax error, and missing from the output.",
+                )
+                .with_label(Span::new(
+                    u32::try_from(line_start).unwrap_or_default(),
+                    u32::try_from(line_end).unwrap_or_default(),
+                )),
+            );
+
+            ret = Parser::new(allocator, allocator.alloc_str(&text), source_type).parse();
+        }
+        ret.errors.extend(fatal_errors);
+        ret
+    }
+
     #[inline]
     pub fn file_name(&self) -> &'a str {
         self.filename
@@ -88,6 +199,26 @@ impl<'a> TransformContext<'a> {
         self.declarations.as_ref()
     }
 
+    #[inline]
+    pub fn source_map_debug_ids(&self) -> bool {
+        self.source_map && self.source_map_debug_ids
+    }
+
+    #[inline]
+    pub fn source_map_segments(&self) -> bool {
+        self.source_map && self.source_map_segments
+    }
+
+    #[inline]
+    pub fn strip_source_map_prefix(&self) -> Option<&str> {
+        self.strip_source_map_prefix.as_deref()
+    }
+
+    #[inline]
+    pub fn source_map_file(&self) -> Option<&str> {
+        self.source_map_file.as_deref()
+    }
+
     #[inline]
     pub fn source_type(&self) -> SourceType {
         self.source_type
@@ -104,7 +235,10 @@ impl<'a> TransformContext<'a> {
     }
 
     pub fn codegen(&self) -> Codegen<'a> {
-        let codegen = Codegen::new();
+        let codegen = Codegen::new().with_options(CodegenOptions {
+            semicolons: self.semicolons,
+            ..CodegenOptions::default()
+        });
         if self.source_map {
             codegen.enable_source_map(self.file_name(), self.source_text())
         } else {
@@ -24,6 +24,13 @@ pub struct IsolatedDeclarationsOptions {
     /// See <https://www.typescriptlang.org/tsconfig/#stripInternal>
     pub strip_internal: Option<bool>,
 
+    /// Use types from JSDoc comments (`@param`, `@returns` and `@type`) for
+    /// functions and variables without type annotations, to emit declarations
+    /// of JavaScript files.
+    ///
+    /// Default: `false`
+    pub jsdoc_types: Option<bool>,
+
     pub sourcemap: Option<bool>,
 }
 
@@ -64,6 +71,7 @@ pub(crate) fn build_declarations(
         &ctx.trivias,
         oxc_isolated_declarations::IsolatedDeclarationsOptions {
             strip_internal: options.strip_internal.unwrap_or(false),
+            jsdoc_types: options.jsdoc_types.unwrap_or(false),
         },
     )
     .build(&ctx.program());
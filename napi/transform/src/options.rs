@@ -4,7 +4,11 @@ use std::path::PathBuf;
 
 use napi::Either;
 use napi_derive::napi;
-use oxc_transformer::{ArrowFunctionsOptions, ES2015Options, JsxRuntime, RewriteExtensionsMode};
+use oxc_transformer::{
+    ArrowFunctionsOptions, BlockScopingOptions, ClassPropertiesOptions, DecoratorOptions,
+    ES2015Options, ES2020Options, ES2021Options, ES2022Options, ES2026Options, ForOfOptions,
+    ImportsNotUsedAsValues, JsxRuntime, ReactRuntime, RegExpOptions, RewriteExtensionsMode,
+};
 
 use crate::IsolatedDeclarationsOptions;
 
@@ -12,11 +16,64 @@ use crate::IsolatedDeclarationsOptions;
 ///
 /// @see {@link transform}
 #[napi(object)]
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct TransformOptions {
     #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
     pub source_type: Option<String>,
 
+    /// The ECMAScript version which the output should run in, e.g. `'es2015'`
+    /// or `'esnext'`.
+    ///
+    /// Transforms are enabled for syntax which is newer than the target. Most
+    /// ES2015 and ES2022 transforms are not enabled by the target yet, and must
+    /// be enabled explicitly. Plugin options which are set explicitly take
+    /// precedence, e.g. `arrowFunction: false` keeps arrow functions regardless
+    /// of the target.
+    ///
+    /// Also used to warn about syntax which cannot be transformed or polyfilled
+    /// for the target, such as BigInt literals before ES2020.
+    ///
+    /// Helpers are not injected into the output, but referenced from the
+    /// `babelHelpers` global, so the host must provide helpers which run in the
+    /// target.
+    ///
+    /// @default 'esnext'
+    pub target: Option<String>,
+
+    /// Warn about syntax in the output which is newer than {@link target},
+    /// because the transform for it is not enabled, e.g. `a ?? b` when
+    /// targeting `'es2015'` with {@link ES2020BindingOptions#nullishCoalescingOperator}
+    /// set to `false`.
+    ///
+    /// @default false
+    pub warn_unsupported_syntax: Option<bool>,
+
+    /// Treat the source text as `js`, `jsx`, `ts` or `tsx`, instead of
+    /// inferring the language from the file extension.
+    ///
+    /// Defaults to `js` if the file has no known extension.
+    #[napi(ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | undefined")]
+    pub lang: Option<String>,
+
+    /// - `'transform'`: apply the transforms configured by other options.
+    /// - `'strip'`: only erase TypeScript type syntax, replacing it with
+    ///   whitespace, and leave all other code untouched. Lines and columns of
+    ///   the remaining code are unchanged, so no source map is generated.
+    ///   TypeScript syntax which has runtime semantics, such as enums and
+    ///   namespaces, is reported as an error.
+    ///
+    /// @default 'transform'
+    #[napi(ts_type = "'transform' | 'strip'")]
+    pub mode: Option<String>,
+
+    /// Parse and check the output again, and report an error if it is not
+    /// valid JavaScript, including early errors such as redeclared bindings.
+    /// This catches bugs in the transformer and code generator, at the cost
+    /// of parsing twice, so is intended for CI.
+    ///
+    /// @default false
+    pub verify: Option<bool>,
+
     /// The current working directory. Used to resolve relative paths in other
     /// options.
     pub cwd: Option<String>,
@@ -27,39 +84,289 @@ pub struct TransformOptions {
     /// Configure how TSX and JSX are transformed.
     pub react: Option<JsxOptions>,
 
+    /// Transform class decorators.
+    pub decorator: Option<DecoratorBindingOptions>,
+
     /// Enable ES2015 transformations.
     pub es2015: Option<ES2015BindingOptions>,
 
+    /// Enable ES2020 transformations.
+    pub es2020: Option<ES2020BindingOptions>,
+
+    /// Enable ES2021 transformations.
+    pub es2021: Option<ES2021BindingOptions>,
+
+    /// Enable ES2022 transformations.
+    pub es2022: Option<ES2022BindingOptions>,
+
+    /// Enable ES2026 transformations.
+    pub es2026: Option<ES2026BindingOptions>,
+
+    /// Assumptions about the code being transformed, to produce smaller output.
+    ///
+    /// @see {@link https://babeljs.io/docs/assumptions}
+    pub assumptions: Option<CompilerAssumptions>,
+
+    /// Remove value imports whose bindings are no longer referenced after
+    /// transformation, e.g. when their only use was in a type position.
+    ///
+    /// Side-effect imports (`import 'foo'`) are always kept.
+    ///
+    /// This is different from TypeScript's type-only import elision, which is
+    /// controlled by {@link TypeScriptOptions#onlyRemoveTypeImports}.
+    ///
+    /// @default false
+    pub remove_unused_imports: Option<bool>,
+
+    /// Configure how `import.meta` is handled.
+    ///
+    /// - `'preserve'`: `import.meta` is left as is.
+    /// - `'commonjs'`: `import.meta.url` is replaced with
+    ///   `require("url").pathToFileURL(__filename).href`, and other uses of
+    ///   `import.meta` with an object containing `url`.
+    ///
+    /// @default 'preserve'
+    #[napi(ts_type = "'preserve' | 'commonjs'")]
+    pub import_meta: Option<String>,
+
+    /// Replace `this` at the top level of ES modules with `void 0`, for output
+    /// which is converted to CommonJS by a later build step. Top-level `this`
+    /// is `undefined` in ES modules, but `module.exports` in CommonJS.
+    ///
+    /// @default false
+    pub replace_top_level_this: Option<bool>,
+
+    /// Keyword of import attributes in the output, for runtimes which only
+    /// support one of them.
+    ///
+    /// - `'preserve'`: the keyword of the source is kept.
+    /// - `'assert'`: `import json from "./a.json" assert { type: "json" }`
+    /// - `'with'`: `import json from "./a.json" with { type: "json" }`
+    ///
+    /// @default 'preserve'
+    #[napi(ts_type = "'assert' | 'with' | 'preserve'")]
+    pub import_attributes_keyword: Option<String>,
+
+    /// The platform the output will run on. Transforms which depend on the
+    /// environment use this to choose their output.
+    ///
+    /// When {@link importMeta} is `'commonjs'`, `import.meta.url` is replaced with:
+    /// - `'node'`: `require("url").pathToFileURL(__filename).href`
+    /// - `'browser'`: `document.currentScript && document.currentScript.src || document.baseURI`
+    /// - `'neutral'`: a runtime check for `document`, which picks one of the above
+    ///
+    /// @default 'node'
+    #[napi(ts_type = "'node' | 'browser' | 'neutral'")]
+    pub platform: Option<String>,
+
+    /// Emit `@__PURE__` and `@__NO_SIDE_EFFECTS__` annotation comments.
+    ///
+    /// When `true`, annotations are kept whether or not {@link keepComments} is
+    /// set. When `false`, no annotations are emitted, regardless of other options
+    /// such as {@link JsxOptions#pure}. When not set, annotations are only
+    /// emitted along with other comments, if {@link keepComments} is set.
+    pub annotations: Option<bool>,
+
+    /// When to print semicolons after statements.
+    ///
+    /// - `'always'`: after every statement which can end with one.
+    /// - `'asi'`: only where the next statement would otherwise continue the
+    ///   previous one, e.g. `;[a, b] = [b, a]`. Other statements rely on
+    ///   automatic semicolon insertion.
+    ///
+    /// @default 'always'
+    #[napi(ts_type = "'always' | 'asi'")]
+    pub semicolons: Option<String>,
+
+    /// Keep JSDoc comments in the output. Comments above statements which are
+    /// removed, such as `import type` declarations, are moved to the following
+    /// statement.
+    ///
+    /// @default false
+    pub keep_comments: Option<bool>,
+
+    /// Functions which have side effects, whose calls keep no `@__PURE__`
+    /// annotation even if the source has one, e.g. `['track', 'console.log']`.
+    ///
+    /// Only used when annotations are emitted, see {@link annotations}.
+    pub impure_functions: Option<Vec<String>>,
+
+    /// Keep the hashbang (e.g. `#!/usr/bin/env node`) as the first line of
+    /// the output. When `false`, the hashbang is removed.
+    ///
+    /// @default true
+    pub hashbang: Option<bool>,
+
+    /// Transform the rest of a file which has a syntax error the parser
+    /// cannot recover from. Lines containing such errors are skipped, and
+    /// errors are still reported, with a warning for each skipped line, as
+    /// other statements on the same line are missing from the output.
+    ///
+    /// When `false`, `code` is an empty string if parsing fails.
+    ///
+    /// @default false
+    pub recover: Option<bool>,
+
     /// Enable source map generation.
     ///
     /// When `true`, the `sourceMap` field of transform result objects will be populated.
     ///
+    /// Ignored when {@link mode} is `'strip'`, because lines and columns of the
+    /// output are the same as the input.
+    ///
     /// @default false
     ///
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
+
+    /// Append a `//# debugId=<id>` comment to the transformed code and set the
+    /// matching `debugId` field on the source map.
+    ///
+    /// The ID is derived from the file name, the input and the transformed
+    /// output, so the same input and options always produce the same ID.
+    ///
+    /// Only used when {@link sourcemap} is `true`.
+    ///
+    /// @default false
+    ///
+    /// @see {@link https://github.com/tc39/source-map/blob/main/proposals/debug-id.md}
+    pub sourcemap_debug_ids: Option<bool>,
+
+    /// Return the source map's mappings as decoded segments in
+    /// {@link SourceMap#segments} instead of the VLQ-encoded `mappings` string,
+    /// which is left empty. This avoids encoding the mappings of large files
+    /// when the consumer decodes them anyway.
+    ///
+    /// Only used when {@link sourcemap} is `true`.
+    ///
+    /// @default false
+    pub sourcemap_segments: Option<bool>,
+
+    /// Remove this prefix from each entry of the source map's `sources`, e.g.
+    /// to turn absolute paths into paths relative to a repository root.
+    ///
+    /// Entries which do not start with the prefix are left unchanged.
+    ///
+    /// Only used when {@link sourcemap} is `true`.
+    pub strip_source_map_prefix: Option<String>,
+
+    /// The `file` field of the source map.
+    ///
+    /// Defaults to the name of the output file, which is the name of the input
+    /// file with TypeScript and JSX extensions changed to JavaScript ones, e.g.
+    /// `foo.js` for `src/foo.ts` and `foo.mjs` for `src/foo.mts`.
+    ///
+    /// Only used when {@link sourcemap} is `true`.
+    pub sourcemap_file: Option<String>,
 }
 
-impl From<TransformOptions> for oxc_transformer::TransformOptions {
-    fn from(options: TransformOptions) -> Self {
-        Self {
-            cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
-            typescript: options.typescript.map(Into::into).unwrap_or_default(),
-            react: options.react.map(Into::into).unwrap_or_default(),
-            es2015: options.es2015.map(Into::into).unwrap_or_default(),
-            ..Self::default()
+impl TransformOptions {
+    /// Convert into transformer options, on top of `base`, which has the
+    /// plugins enabled by [`TransformOptions::target`]. Plugin options which
+    /// are set explicitly take precedence, so `false` disables a plugin
+    /// regardless of the target.
+    pub(crate) fn into_transform_options(
+        self,
+        mut base: oxc_transformer::TransformOptions,
+    ) -> oxc_transformer::TransformOptions {
+        let mut react: oxc_transformer::JsxOptions = self.react.map(Into::into).unwrap_or_default();
+        if self.annotations == Some(false) {
+            react.pure = false;
+        }
+        if let Some(typescript) = self.typescript.as_ref().filter(|_| react.runtime.is_classic()) {
+            if react.pragma.is_none() {
+                react.pragma.clone_from(&typescript.jsx_factory);
+            }
+            if react.pragma_frag.is_none() {
+                react.pragma_frag.clone_from(&typescript.jsx_fragment_factory);
+            }
+        }
+        if let Some(es2015) = self.es2015 {
+            es2015.apply_to(&mut base.es2015, &mut base.regexp);
+        }
+        if let Some(es2020) = self.es2020 {
+            es2020.apply_to(&mut base.es2020);
+        }
+        if let Some(es2021) = self.es2021 {
+            es2021.apply_to(&mut base.es2021);
+        }
+        if let Some(es2022) = self.es2022 {
+            es2022.apply_to(&mut base.es2022);
+        }
+        if let Some(es2026) = self.es2026 {
+            es2026.apply_to(&mut base.es2026);
+        }
+        oxc_transformer::TransformOptions {
+            cwd: self.cwd.map(PathBuf::from).unwrap_or_default(),
+            typescript: self.typescript.map(Into::into).unwrap_or_default(),
+            react,
+            decorator: self.decorator.map(Into::into),
+            assumptions: self.assumptions.map(Into::into).unwrap_or_default(),
+            ..base
         }
     }
 }
 
 #[napi(object)]
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct TypeScriptOptions {
+    /// Import which is retained when the file contains JSX, even if it looks
+    /// unused. Ignored with the `automatic` {@link JsxOptions#runtime}, which
+    /// does not reference it.
+    ///
+    /// @default 'React.createElement'
     pub jsx_pragma: Option<String>,
+    /// Import which is retained when the file contains JSX fragments. Ignored
+    /// with the `automatic` {@link JsxOptions#runtime}.
+    ///
+    /// @default 'React.Fragment'
     pub jsx_pragma_frag: Option<String>,
+    /// Alias of tsconfig's `jsxFactory`. Sets {@link JsxOptions#pragma} for
+    /// the `classic` runtime, and {@link jsxPragma}, unless they are set.
+    ///
+    /// @see [jsxFactory](https://www.typescriptlang.org/tsconfig/#jsxFactory)
+    pub jsx_factory: Option<String>,
+    /// Alias of tsconfig's `jsxFragmentFactory`. Sets
+    /// {@link JsxOptions#pragmaFrag} for the `classic` runtime, and
+    /// {@link jsxPragmaFrag}, unless they are set.
+    ///
+    /// @see [jsxFragmentFactory](https://www.typescriptlang.org/tsconfig/#jsxFragmentFactory)
+    pub jsx_fragment_factory: Option<String>,
     pub only_remove_type_imports: Option<bool>,
+    /// Legacy TypeScript option controlling imports whose bindings are only
+    /// used as types. Superseded by `verbatimModuleSyntax`.
+    ///
+    /// - `'remove'`: remove such imports.
+    /// - `'preserve'`: keep such imports for their side effects, e.g.
+    ///   `import { A } from 'mod'` becomes `import 'mod'`.
+    /// - `'error'`: as `'preserve'`, but also report an error for such imports
+    ///   which do not use `import type`.
+    ///
+    /// @default 'remove'
+    #[napi(ts_type = "'remove' | 'preserve' | 'error'")]
+    pub imports_not_used_as_values: Option<String>,
     pub allow_namespaces: Option<bool>,
     pub allow_declare_fields: Option<bool>,
+    /// Transform `const enum`s whose members all have constant values to
+    /// plain objects without reverse mappings, e.g. `const enum A { X }`
+    /// becomes `var A = { X: 0 }`.
+    ///
+    /// @default false
+    pub optimize_const_enums: Option<bool>,
+    /// Inline accesses to members of `const enum`s declared in the same file,
+    /// e.g. `A.X` becomes `0`, while still emitting the enum object.
+    ///
+    /// @see [preserveConstEnums](https://www.typescriptlang.org/tsconfig/#preserveConstEnums)
+    ///
+    /// @default false
+    pub preserve_const_enums: Option<bool>,
+    /// Report an error for exported `const enum`s, as their members cannot be
+    /// inlined by other modules which are compiled in isolation.
+    ///
+    /// @see [isolatedModules](https://www.typescriptlang.org/tsconfig/#isolatedModules)
+    ///
+    /// @default false
+    pub isolated_modules: Option<bool>,
     /// Also generate a `.d.ts` declaration file for TypeScript files.
     ///
     /// The source file must be compliant with all
@@ -68,6 +375,13 @@ pub struct TypeScriptOptions {
     ///
     /// @default false
     pub declaration: Option<IsolatedDeclarationsOptions>,
+    /// Only generate the `.d.ts` declaration file, without transforming the
+    /// source file. `code` is empty and `map` is not set.
+    ///
+    /// Only used when {@link declaration} is set.
+    ///
+    /// @default false
+    pub emit_declaration_only: Option<bool>,
     /// Rewrite or remove TypeScript import/export declaration extensions.
     ///
     /// - When set to `rewrite`, it will change `.ts`, `.mts`, `.cts` extensions to `.js`, `.mjs`, `.cjs` respectively.
@@ -78,20 +392,42 @@ pub struct TypeScriptOptions {
     /// @default false
     #[napi(ts_type = "'rewrite' | 'remove' | boolean")]
     pub rewrite_import_extensions: Option<Either<bool, String>>,
+    /// Append `.js` to relative import/export sources without an extension,
+    /// e.g. `./a` becomes `./a.js`. This is needed for strict ESM resolution.
+    ///
+    /// Bare specifiers such as `lodash` are not changed.
+    ///
+    /// @default false
+    pub add_missing_extensions: Option<bool>,
 }
 
 impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
     fn from(options: TypeScriptOptions) -> Self {
         let ops = oxc_transformer::TypeScriptOptions::default();
         oxc_transformer::TypeScriptOptions {
-            jsx_pragma: options.jsx_pragma.map(Into::into).unwrap_or(ops.jsx_pragma),
-            jsx_pragma_frag: options.jsx_pragma_frag.map(Into::into).unwrap_or(ops.jsx_pragma_frag),
+            jsx_pragma: options
+                .jsx_pragma
+                .or(options.jsx_factory)
+                .map(Into::into)
+                .unwrap_or(ops.jsx_pragma),
+            jsx_pragma_frag: options
+                .jsx_pragma_frag
+                .or(options.jsx_fragment_factory)
+                .map(Into::into)
+                .unwrap_or(ops.jsx_pragma_frag),
             only_remove_type_imports: options
                 .only_remove_type_imports
                 .unwrap_or(ops.only_remove_type_imports),
+            imports_not_used_as_values: match options.imports_not_used_as_values.as_deref() {
+                Some("preserve") => ImportsNotUsedAsValues::Preserve,
+                Some("error") => ImportsNotUsedAsValues::Error,
+                _ => ImportsNotUsedAsValues::Remove,
+            },
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
-            optimize_const_enums: false,
+            optimize_const_enums: options.optimize_const_enums.unwrap_or(ops.optimize_const_enums),
+            preserve_const_enums: options.preserve_const_enums.unwrap_or(ops.preserve_const_enums),
+            isolated_modules: options.isolated_modules.unwrap_or(ops.isolated_modules),
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
@@ -108,6 +444,9 @@ impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
                     },
                 }
             }),
+            add_missing_extensions: options
+                .add_missing_extensions
+                .unwrap_or(ops.add_missing_extensions),
         }
     }
 }
@@ -116,6 +455,7 @@ impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
 ///
 /// @see {@link https://babeljs.io/docs/babel-plugin-transform-react-jsx#options}
 #[napi(object)]
+#[derive(Debug)]
 pub struct JsxOptions {
     /// Decides which runtime to use.
     ///
@@ -156,6 +496,44 @@ pub struct JsxOptions {
     /// @default 'react'
     pub import_source: Option<String>,
 
+    /// Check that the JSX runtime imported by the output, e.g.
+    /// `react/jsx-runtime`, can be resolved from the directory of the file,
+    /// following `exports` of `package.json`. The import is always emitted as
+    /// the standard `<importSource>/jsx-runtime` subpath, and a warning is
+    /// reported if it cannot be resolved.
+    ///
+    /// Only used for `automatic` {@link runtime}.
+    ///
+    /// @default false
+    pub resolve_import_source: Option<bool>,
+
+    /// Hoist props objects passed to `jsx()` to module-level variables when
+    /// all of their values are constant. Props objects containing a `ref` are
+    /// never hoisted.
+    ///
+    /// Only used for `automatic` {@link runtime}.
+    ///
+    /// @default false
+    pub hoist_constant_props: Option<bool>,
+
+    /// Emit a warning for JSX elements returned from `.map()` callbacks
+    /// without a `key` prop.
+    ///
+    /// @default false
+    pub warn_missing_keys: Option<bool>,
+
+    /// Arguments of the `jsx()` and `jsxs()` functions of the runtime.
+    ///
+    /// - `'react'`: `jsx(type, { ...props, children }, key)`
+    /// - `'compat'`: `jsx(type, { ...props, key }, children)`, for runtimes
+    ///   compatible with React which take children as the third argument.
+    ///
+    /// Only used for `automatic` {@link runtime}.
+    ///
+    /// @default 'react'
+    #[napi(ts_type = "'react' | 'compat'")]
+    pub react_runtime: Option<String>,
+
     /// Replace the function used when compiling JSX expressions. It should be a
     /// qualified name (e.g. `React.createElement`) or an identifier (e.g.
     /// `createElement`).
@@ -208,6 +586,12 @@ impl From<JsxOptions> for oxc_transformer::JsxOptions {
             throw_if_namespace: options.throw_if_namespace.unwrap_or(ops.throw_if_namespace),
             pure: options.pure.unwrap_or(ops.pure),
             import_source: options.import_source,
+            hoist_constant_props: options.hoist_constant_props.unwrap_or(ops.hoist_constant_props),
+            warn_missing_keys: options.warn_missing_keys.unwrap_or(ops.warn_missing_keys),
+            react_runtime: match options.react_runtime.as_deref() {
+                Some("compat") => ReactRuntime::Compat,
+                _ => ReactRuntime::React,
+            },
             pragma: options.pragma,
             pragma_frag: options.pragma_frag,
             use_built_ins: options.use_built_ins,
@@ -222,14 +606,20 @@ impl From<JsxOptions> for oxc_transformer::JsxOptions {
 }
 
 #[napi(object)]
+#[derive(Debug)]
 pub struct ReactRefreshOptions {
     /// Specify the identifier of the refresh registration variable.
     ///
+    /// `[hash]` is replaced with a hash of the file path, so modules sharing a
+    /// scope can use different registration functions, e.g. `$RefreshReg$__[hash]`.
+    ///
     /// @default `$RefreshReg$`.
     pub refresh_reg: Option<String>,
 
     /// Specify the identifier of the refresh signature variable.
     ///
+    /// `[hash]` is replaced with a hash of the file path, as in {@link refreshReg}.
+    ///
     /// @default `$RefreshSig$`.
     pub refresh_sig: Option<String>,
 
@@ -248,12 +638,39 @@ impl From<ReactRefreshOptions> for oxc_transformer::ReactRefreshOptions {
 }
 
 #[napi(object)]
+#[derive(Debug)]
+pub struct DecoratorBindingOptions {
+    /// Use the legacy decorators semantics, as TypeScript's
+    /// `experimentalDecorators`. Decorators are called with the class, and
+    /// their return value replaces it.
+    ///
+    /// Otherwise, the TC39 decorators semantics are used. Decorators are
+    /// called with the class and a `context` object, and are applied with
+    /// `babelHelpers.applyDecs2311`, which must be available in the global
+    /// scope.
+    ///
+    /// @default false
+    pub experimental_decorators: Option<bool>,
+}
+
+impl From<DecoratorBindingOptions> for DecoratorOptions {
+    fn from(options: DecoratorBindingOptions) -> Self {
+        DecoratorOptions {
+            experimental_decorators: options.experimental_decorators.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
 pub struct ArrowFunctionsBindingOptions {
     /// This option enables the following:
     /// * Wrap the generated function in .bind(this) and keeps uses of this inside the function as-is, instead of using a renamed this.
     /// * Add a runtime check to ensure the functions are not instantiated.
     /// * Add names to arrow functions.
     ///
+    /// Not supported yet. Setting it to `true` is reported as an error.
+    ///
     /// @default false
     pub spec: Option<bool>,
 }
@@ -265,13 +682,325 @@ impl From<ArrowFunctionsBindingOptions> for ArrowFunctionsOptions {
 }
 
 #[napi(object)]
+#[derive(Debug)]
+pub struct ForOfBindingOptions {
+    /// Assume the iterable is an array, and iterate over it by index instead of
+    /// with the iterator protocol.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ForOfBindingOptions> for ForOfOptions {
+    fn from(options: ForOfBindingOptions) -> Self {
+        ForOfOptions { assume_array: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct BlockScopingBindingOptions {
+    /// Throw a `ReferenceError` when a binding is referenced before it is
+    /// initialized. Uses `babelHelpers.tdz`, which must be available in the
+    /// global scope.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+
+    /// Report reassignments of `const` bindings as errors, instead of throwing
+    /// a `TypeError` at runtime with `babelHelpers.readOnlyError`.
+    ///
+    /// @default false
+    pub report_const_violations: Option<bool>,
+}
+
+impl From<BlockScopingBindingOptions> for BlockScopingOptions {
+    fn from(options: BlockScopingBindingOptions) -> Self {
+        BlockScopingOptions {
+            tdz: options.tdz.unwrap_or_default(),
+            report_const_violations: options.report_const_violations.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
 pub struct ES2015BindingOptions {
     /// Transform arrow functions into function expressions.
-    pub arrow_function: Option<ArrowFunctionsBindingOptions>,
+    ///
+    /// Enabled by default when {@link TransformOptions#target} is older than
+    /// `'es2015'`.
+    /// Set to `false` to keep arrow functions regardless of the target.
+    pub arrow_function: Option<Either<bool, ArrowFunctionsBindingOptions>>,
+
+    /// Transform shorthand properties (`{ a }`) and methods (`{ m() {} }`) into
+    /// ordinary properties (`{ a: a }`, `{ m: function() {} }`).
+    ///
+    /// @default false
+    pub shorthand_properties: Option<bool>,
+
+    /// Transform computed property keys (`{ [k]: v }`). Each key is evaluated
+    /// exactly once, in order. Uses `babelHelpers.defineProperty`, which must be
+    /// available in the global scope.
+    ///
+    /// @default false
+    pub computed_properties: Option<bool>,
+
+    /// Transform template literals into string concatenation, and tagged
+    /// templates into function calls. The strings array of each tagged template
+    /// is created once by `babelHelpers.taggedTemplateLiteral`, which must be
+    /// available in the global scope.
+    ///
+    /// @default false
+    pub template_literals: Option<bool>,
+
+    /// Transform destructuring in variable declarations, assignments, function
+    /// parameters, catch clauses and `for...in` / `for...of` heads. Uses
+    /// `babelHelpers.slicedToArray` and other helpers, which must be available in
+    /// the global scope.
+    ///
+    /// @default false
+    pub destructuring: Option<bool>,
+
+    /// Transform `for...of` loops into `for` loops which use the iterator
+    /// protocol directly, calling the iterator's `return()` method if the loop
+    /// exits early. Uses `babelHelpers.createForOfIteratorHelper`, which must be
+    /// available in the global scope.
+    pub for_of: Option<ForOfBindingOptions>,
+
+    /// Transform spread elements in array literals (`[...a]`), function calls
+    /// (`f(...a)`) and `new` expressions (`new C(...a)`). Uses
+    /// `babelHelpers.toConsumableArray` and `babelHelpers.construct`, which must
+    /// be available in the global scope.
+    ///
+    /// Not enabled by {@link TransformOptions#target} yet, like most ES2015
+    /// transforms, so it must be set explicitly when targeting `'es5'`.
+    ///
+    /// @default false
+    pub spread: Option<bool>,
+
+    /// Transform generator functions (`function*`) to state machines run by
+    /// the regenerator runtime. Uses `babelHelpers.regeneratorRuntime`, which
+    /// must be available in the global scope.
+    ///
+    /// @default false
+    pub use_regenerator: Option<bool>,
+
+    /// Transform `let` and `const` declarations into `var` declarations.
+    /// Loop bodies which create closures over bindings declared in the loop
+    /// are moved into functions, so each iteration gets its own bindings.
+    /// Reassigning a `const` binding throws at runtime, using
+    /// `babelHelpers.readOnlyError`, which must be available in the global scope.
+    pub block_scoping: Option<BlockScopingBindingOptions>,
+
+    /// Transform `typeof x` so it returns `"symbol"` for polyfilled symbols.
+    /// Uses `babelHelpers.typeof`, which must be available in the global scope.
+    ///
+    /// @default false
+    pub typeof_symbol: Option<bool>,
+
+    /// Transform `new.target` into expressions based on `this`.
+    ///
+    /// @default false
+    pub new_target: Option<bool>,
+
+    /// Transform regular expression literals with the sticky flag (`/x/y`)
+    /// into `new RegExp("x", "y")` calls, so they do not cause syntax errors
+    /// in engines which do not support the flag.
+    ///
+    /// @default false
+    pub sticky_regex: Option<bool>,
+
+    /// Transform regular expression literals with the unicode flag (`/x/u`)
+    /// into `new RegExp("x", "u")` calls, so they do not cause syntax errors
+    /// in engines which do not support the flag. The pattern is not rewritten,
+    /// so a `RegExp` polyfill is needed for the correct runtime behavior.
+    ///
+    /// @default false
+    pub unicode_regex: Option<bool>,
+}
+
+impl ES2015BindingOptions {
+    /// Override the plugins which are set explicitly.
+    fn apply_to(self, es2015: &mut ES2015Options, regexp: &mut RegExpOptions) {
+        if let Some(arrow_function) = self.arrow_function {
+            es2015.arrow_function = match arrow_function {
+                Either::A(b) => b.then(ArrowFunctionsOptions::default),
+                Either::B(options) => Some(ArrowFunctionsOptions::from(options)),
+            };
+        }
+        set_if_some(&mut es2015.shorthand_properties, self.shorthand_properties);
+        set_if_some(&mut es2015.computed_properties, self.computed_properties);
+        set_if_some(&mut es2015.template_literals, self.template_literals);
+        set_if_some(&mut es2015.destructuring, self.destructuring);
+        if let Some(for_of) = self.for_of {
+            es2015.for_of = Some(for_of.into());
+        }
+        set_if_some(&mut es2015.spread, self.spread);
+        set_if_some(&mut es2015.use_regenerator, self.use_regenerator);
+        if let Some(block_scoping) = self.block_scoping {
+            es2015.block_scoping = Some(block_scoping.into());
+        }
+        set_if_some(&mut es2015.typeof_symbol, self.typeof_symbol);
+        set_if_some(&mut es2015.new_target, self.new_target);
+        set_if_some(&mut regexp.sticky_flag, self.sticky_regex);
+        set_if_some(&mut regexp.unicode_flag, self.unicode_regex);
+    }
+}
+
+fn set_if_some(option: &mut bool, value: Option<bool>) {
+    if let Some(value) = value {
+        *option = value;
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct ES2020BindingOptions {
+    /// Transform `export * as ns from "mod"` into an import of the module
+    /// namespace object and a named export of it.
+    ///
+    /// @default false
+    pub export_namespace_from: Option<bool>,
+
+    /// Transform nullish coalescing operators (`a ?? b`) into conditional
+    /// expressions.
+    ///
+    /// @default false
+    pub nullish_coalescing_operator: Option<bool>,
+}
+
+impl ES2020BindingOptions {
+    /// Override the plugins which are set explicitly.
+    fn apply_to(self, es2020: &mut ES2020Options) {
+        set_if_some(&mut es2020.export_namespace_from, self.export_namespace_from);
+        set_if_some(&mut es2020.nullish_coalescing_operator, self.nullish_coalescing_operator);
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct ES2021BindingOptions {
+    /// Transform logical assignment operators (`||=`, `&&=` and `??=`) into
+    /// logical expressions. The assignment target is only evaluated once.
+    ///
+    /// @default false
+    pub logical_assignment_operators: Option<bool>,
+
+    /// Remove numeric separators (`_`) from number and bigint literals, e.g.
+    /// `1_000_000` becomes `1000000`.
+    ///
+    /// @default false
+    pub numeric_separator: Option<bool>,
+}
+
+impl ES2021BindingOptions {
+    /// Override the plugins which are set explicitly.
+    fn apply_to(self, es2021: &mut ES2021Options) {
+        set_if_some(&mut es2021.logical_assignment_operators, self.logical_assignment_operators);
+        set_if_some(&mut es2021.numeric_separator, self.numeric_separator);
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Default)]
+pub struct CompilerAssumptions {
+    /// Initialize public class fields with assignments (`this.x = 1`) instead of
+    /// `Object.defineProperty`, when they are transformed by
+    /// {@link ES2022BindingOptions#classProperties}. Same as its `loose` option.
+    ///
+    /// @default false
+    pub set_public_class_fields: Option<bool>,
+
+    /// Assume `document.all` is not used, so nullish checks can be
+    /// `x != null` instead of `x !== null && x !== void 0`.
+    ///
+    /// @default false
+    pub no_document_all: Option<bool>,
+
+    /// Assume getters have no side effects, so objects of member expressions
+    /// do not need to be cached in temporaries, e.g. `a.b ??= c` becomes
+    /// `a.b ?? (a.b = c)` when transformed by
+    /// {@link ES2021BindingOptions#logicalAssignmentOperators}.
+    ///
+    /// @default false
+    pub pure_getters: Option<bool>,
+}
+
+impl From<CompilerAssumptions> for oxc_transformer::CompilerAssumptions {
+    fn from(assumptions: CompilerAssumptions) -> Self {
+        Self {
+            set_public_class_fields: assumptions.set_public_class_fields.unwrap_or_default(),
+            no_document_all: assumptions.no_document_all.unwrap_or_default(),
+            pure_getters: assumptions.pure_getters.unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct ClassPropertiesBindingOptions {
+    /// Initialize public class fields with assignments (`this.x = 1`) instead of
+    /// `Object.defineProperty`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassPropertiesBindingOptions> for ClassPropertiesOptions {
+    fn from(options: ClassPropertiesBindingOptions) -> Self {
+        ClassPropertiesOptions { set_public_class_fields: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct ES2022BindingOptions {
+    /// Transform public class fields. Instance fields are initialized in the
+    /// constructor after `super()`, and static fields after the class.
+    /// Private class elements are transformed too.
+    pub class_properties: Option<ClassPropertiesBindingOptions>,
+
+    /// Transform private class fields, methods and accessors (`#x`), using
+    /// `WeakMap`s and `WeakSet`s. Accesses to them are transformed into calls
+    /// to `babelHelpers`, which must be available in the global scope.
+    ///
+    /// @default false
+    pub private_methods: Option<bool>,
+
+    /// Transform private brand checks (`#x in obj`).
+    ///
+    /// @default false
+    pub private_in: Option<bool>,
+}
+
+impl ES2022BindingOptions {
+    /// Override the plugins which are set explicitly.
+    fn apply_to(self, es2022: &mut ES2022Options) {
+        if let Some(class_properties) = self.class_properties {
+            es2022.class_properties = Some(class_properties.into());
+        }
+        set_if_some(&mut es2022.private_methods, self.private_methods);
+        set_if_some(&mut es2022.private_in, self.private_in);
+    }
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct ES2026BindingOptions {
+    /// Transform `using` and `await using` declarations into `const`
+    /// declarations, with their resources disposed in reverse order in a
+    /// `try` / `finally` block. `babelHelpers.usingCtx` must be available in
+    /// the global scope.
+    ///
+    /// @default false
+    pub explicit_resource_management: Option<bool>,
 }
 
-impl From<ES2015BindingOptions> for ES2015Options {
-    fn from(options: ES2015BindingOptions) -> Self {
-        ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+impl ES2026BindingOptions {
+    /// Override the plugins which are set explicitly.
+    fn apply_to(self, es2026: &mut ES2026Options) {
+        set_if_some(&mut es2026.explicit_resource_management, self.explicit_resource_management);
     }
 }
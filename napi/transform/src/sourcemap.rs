@@ -1,4 +1,5 @@
 use napi_derive::napi;
+use sha1::{Digest, Sha1};
 
 // Aligned with Rollup's sourcemap input.
 //
@@ -27,6 +28,19 @@ pub struct SourceMap {
     pub version: u8,
     #[napi(js_name = "x_google_ignoreList")]
     pub x_google_ignorelist: Option<Vec<u32>>,
+    /// Debug ID associating this source map with its generated file.
+    ///
+    /// @see {@link https://github.com/tc39/source-map/blob/main/proposals/debug-id.md}
+    pub debug_id: Option<String>,
+    /// Decoded mappings, set instead of `mappings` if
+    /// {@link TransformOptions#sourcemapSegments} is `true`.
+    ///
+    /// Each line of the generated code has an array of segments, in the same
+    /// format as `@jridgewell/sourcemap-codec`: `[generatedColumn]`,
+    /// `[generatedColumn, sourceIndex, originalLine, originalColumn]` or
+    /// `[generatedColumn, sourceIndex, originalLine, originalColumn, nameIndex]`.
+    /// All values are absolute and zero-based.
+    pub segments: Option<Vec<Vec<Vec<u32>>>>,
 }
 
 impl From<oxc_sourcemap::SourceMap> for SourceMap {
@@ -43,6 +57,77 @@ impl From<oxc_sourcemap::SourceMap> for SourceMap {
             }),
             version: 3,
             x_google_ignorelist: None,
+            debug_id: None,
+            segments: None,
         }
     }
 }
+
+impl SourceMap {
+    /// Convert `source_map` with its mappings as decoded segments, without
+    /// encoding them to a `mappings` string.
+    pub(crate) fn from_segments(source_map: &oxc_sourcemap::SourceMap) -> Self {
+        let mut segments: Vec<Vec<Vec<u32>>> = vec![];
+        for token in source_map.get_tokens() {
+            let line = token.get_dst_line() as usize;
+            if segments.len() <= line {
+                segments.resize_with(line + 1, Vec::new);
+            }
+            let mut segment = vec![token.get_dst_col()];
+            if let Some(source_id) = token.get_source_id() {
+                segment.extend([source_id, token.get_src_line(), token.get_src_col()]);
+                segment.extend(token.get_name_id());
+            }
+            segments[line].push(segment);
+        }
+        Self {
+            file: source_map.get_file().map(ToString::to_string),
+            mappings: String::new(),
+            names: source_map.get_names().map(ToString::to_string).collect(),
+            source_root: source_map.get_source_root().map(ToString::to_string),
+            sources: source_map.get_sources().map(ToString::to_string).collect(),
+            sources_content: source_map
+                .get_source_contents()
+                .map(|contents| contents.map(ToString::to_string).collect()),
+            version: 3,
+            x_google_ignorelist: None,
+            debug_id: None,
+            segments: Some(segments),
+        }
+    }
+
+    /// Remove `prefix` from the start of each entry of `sources` which has it.
+    pub(crate) fn strip_sources_prefix(&mut self, prefix: &str) {
+        for source in &mut self.sources {
+            if let Some(stripped) = source.strip_prefix(prefix) {
+                *source = stripped.to_string();
+            }
+        }
+    }
+}
+
+/// Generate a debug ID in UUID format that is derived only from `inputs`, so
+/// that the same inputs always produce the same ID.
+///
+/// <https://github.com/tc39/source-map/blob/main/proposals/debug-id.md>
+pub(crate) fn generate_debug_id(inputs: &[&str]) -> String {
+    // SHA-1 of the length-prefixed inputs, so the ID is the same on every platform and version,
+    // and inputs cannot run into each other.
+    let mut hasher = Sha1::new();
+    for input in inputs {
+        hasher.update((input.len() as u64).to_le_bytes());
+        hasher.update(input.as_bytes());
+    }
+    let digest = hasher.finalize();
+    let hi = u64::from_be_bytes(digest[..8].try_into().unwrap());
+    let lo = u64::from_be_bytes(digest[8..16].try_into().unwrap());
+    // Set the version (4) and variant (RFC 4122) bits so the result is a well-formed UUID.
+    format!(
+        "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+        hi >> 32,
+        (hi >> 16) & 0xffff,
+        hi & 0x0fff,
+        ((lo >> 48) & 0x3fff) | 0x8000,
+        lo & 0xffff_ffff_ffff,
+    )
+}
@@ -1,11 +1,25 @@
+use std::{ffi::OsStr, path::Path};
+
+use napi::Either;
 use napi_derive::napi;
 use oxc_allocator::Allocator;
-use oxc_codegen::CodegenReturn;
+use oxc_ast::{ast::Statement, Trivias};
+use oxc_codegen::{CodegenReturn, CommentOptions};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_resolver::{ResolveOptions, Resolver};
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
-use oxc_transformer::Transformer;
+use oxc_transformer::{
+    ESTarget, EnvOptions, ImportAttributes, ImportAttributesKeyword, ImportMeta, ImportMetaMode,
+    ImpureFunctions, Platform, ReattachComments, RemoveUnusedImports, StripTypes, TopLevelThis,
+    Transformer, UnsupportedSyntax,
+};
 
-use crate::{context::TransformContext, isolated_declaration, SourceMap, TransformOptions};
+use crate::{
+    context::TransformContext, isolated_declaration, sourcemap::generate_debug_id, SourceMap,
+    TransformOptions,
+};
 
 // NOTE: Use JSDoc syntax for all doc comments, not rustdoc.
 // NOTE: Types must be aligned with [@types/babel__core](https://github.com/DefinitelyTyped/DefinitelyTyped/blob/master/types/babel__core/index.d.ts).
@@ -14,29 +28,40 @@ use crate::{context::TransformContext, isolated_declaration, SourceMap, Transfor
 pub struct TransformResult {
     /// The transformed code.
     ///
-    /// If parsing failed, this will be an empty string.
+    /// If parsing failed, or {@link TypeScriptOptions#emitDeclarationOnly} is
+    /// set and declarations were generated, this will be an empty string.
     pub code: String,
 
     /// The source map for the transformed code.
     ///
-    /// This will be set if {@link TransformOptions#sourcemap} is `true`.
+    /// This will be set if {@link TransformOptions#sourcemap} is `true`, unless
+    /// only declarations were emitted, or {@link TransformOptions#mode} is
+    /// `'strip'`, which keeps lines and columns unchanged.
     pub map: Option<SourceMap>,
 
     /// The `.d.ts` declaration file for the transformed code. Declarations are
-    /// only generated if `declaration` is set to `true` and a TypeScript file
-    /// is provided.
+    /// only generated if `declaration` is set and a TypeScript file is
+    /// provided.
     ///
     /// If parsing failed and `declaration` is set, this will be an empty string.
     ///
-    /// @see {@link TypeScriptBindingOptions#declaration}
+    /// @see {@link TypeScriptOptions#declaration}
     /// @see [declaration tsconfig option](https://www.typescriptlang.org/tsconfig/#declaration)
     pub declaration: Option<String>,
 
     /// Declaration source map. Only generated if both
-    /// {@link TypeScriptBindingOptions#declaration declaration} and
-    /// {@link TransformOptions#sourcemap sourcemap} are set to `true`.
+    /// {@link TypeScriptOptions#declaration declaration} and
+    /// {@link TransformOptions#sourcemap sourcemap} are set.
     pub declaration_map: Option<SourceMap>,
 
+    /// Whether the file was parsed as an ES module or a script.
+    ///
+    /// With {@link TransformOptions#sourceType} `'unambiguous'`, this is
+    /// `'module'` if the file contains `import` or `export` statements, and
+    /// `'script'` otherwise.
+    #[napi(ts_type = "'module' | 'script'")]
+    pub module_type: String,
+
     /// Parse and transformation errors.
     ///
     /// Oxc's parser recovers from common syntax errors, meaning that
@@ -49,6 +74,8 @@ pub struct TransformResult {
 ///
 /// @param filename The name of the file being transformed. If this is a
 /// relative path, consider setting the {@link TransformOptions#cwd} option..
+/// If omitted, `"unknown"` is used, and the language should be set with
+/// {@link TransformOptions#lang}.
 /// @param sourceText the source code itself
 /// @param options The options for the transformation. See {@link
 /// TransformOptions} for more information.
@@ -58,16 +85,34 @@ pub struct TransformResult {
 #[allow(clippy::needless_pass_by_value)]
 #[napi]
 pub fn transform(
-    filename: String,
+    filename: Option<String>,
     source_text: String,
     options: Option<TransformOptions>,
 ) -> TransformResult {
+    let filename = filename.unwrap_or_else(|| "unknown".to_string());
     let source_type = {
-        let mut source_type = SourceType::from_path(&filename).unwrap_or_default();
+        let mut source_type = match options.as_ref().and_then(|options| options.lang.as_deref()) {
+            Some("js") => SourceType::mjs(),
+            Some("jsx") => SourceType::jsx(),
+            Some("ts") => SourceType::ts(),
+            Some("tsx") => SourceType::tsx(),
+            _ => SourceType::from_path(&filename).unwrap_or_default(),
+        };
         // Force `script` or `module`
         match options.as_ref().and_then(|options| options.source_type.as_deref()) {
             Some("script") => source_type = source_type.with_script(true),
             Some("module") => source_type = source_type.with_module(true),
+            // `.mjs`, `.mts`, `.cjs` and `.cts` are unambiguous by Node's rules, so keep the
+            // module kind inferred from the extension.
+            Some("unambiguous") => {
+                let is_explicit = Path::new(&filename)
+                    .extension()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|ext| matches!(ext, "mjs" | "mts" | "cjs" | "cts"));
+                if !is_explicit {
+                    source_type = source_type.with_unambiguous(true);
+                }
+            }
             _ => {}
         }
         source_type
@@ -77,27 +122,122 @@ pub fn transform(
     let ctx =
         TransformContext::new(&allocator, &filename, &source_text, source_type, options.as_ref());
 
+    let module_type =
+        if ctx.program().source_type.is_module() { "module" } else { "script" }.to_string();
+
     let declarations_result = source_type
         .is_typescript()
         .then(|| ctx.declarations())
         .flatten()
         .map(|options| isolated_declaration::build_declarations(&ctx, *options));
 
-    let transpile_result = transpile(&ctx, options);
+    let emit_declaration_only = declarations_result.is_some()
+        && options
+            .as_ref()
+            .and_then(|o| o.typescript.as_ref())
+            .and_then(|t| t.emit_declaration_only)
+            .unwrap_or_default();
+    let verify = options.as_ref().and_then(|o| o.verify).unwrap_or_default();
+    let transpile_result = if emit_declaration_only {
+        CodegenReturn { source_text: String::new(), source_map: None }
+    } else {
+        transpile(&ctx, options)
+    };
+    if verify {
+        verify_output(&ctx, &transpile_result.source_text);
+    }
 
-    let (declaration, declaration_map) = declarations_result
-        .map_or((None, None), |d| (Some(d.source_text), d.source_map.map(Into::into)));
+    let to_source_map = |source_map: oxc_sourcemap::SourceMap| {
+        if ctx.source_map_segments() {
+            SourceMap::from_segments(&source_map)
+        } else {
+            SourceMap::from(source_map)
+        }
+    };
+    let (declaration, mut declaration_map) = declarations_result
+        .map_or((None, None), |d| (Some(d.source_text), d.source_map.map(to_source_map)));
+
+    let mut code = transpile_result.source_text;
+    let mut map = transpile_result.source_map.map(to_source_map);
+    if let Some(map) = &mut map {
+        map.file = Some(
+            ctx.source_map_file().map_or_else(|| output_file_name(&filename), ToString::to_string),
+        );
+    }
+    if let Some(prefix) = ctx.strip_source_map_prefix() {
+        for map in map.iter_mut().chain(declaration_map.iter_mut()) {
+            map.strip_sources_prefix(prefix);
+        }
+    }
+    if ctx.source_map_debug_ids() {
+        if let Some(map) = &mut map {
+            let debug_id = generate_debug_id(&[&filename, &source_text, &code]);
+            code.push_str("//# debugId=");
+            code.push_str(&debug_id);
+            code.push('\n');
+            map.debug_id = Some(debug_id);
+        }
+    }
 
     TransformResult {
-        code: transpile_result.source_text,
-        map: transpile_result.source_map.map(Into::into),
+        code,
+        map,
         declaration,
         declaration_map,
+        module_type,
         errors: ctx.take_and_render_reports(),
     }
 }
 
+/// Report an error if the transformed `code` cannot be parsed, which indicates a bug in the
+/// transformer or code generator.
+fn verify_output(ctx: &TransformContext<'_>, code: &str) {
+    let allocator = Allocator::default();
+    let source_type = ctx.program().source_type.with_javascript(true);
+    let ret = Parser::new(&allocator, code, source_type).parse();
+    let errors = if ret.errors.is_empty() {
+        // Early errors, e.g. redeclarations, are only reported by semantic analysis
+        SemanticBuilder::new(code).with_check_syntax_error(true).build(&ret.program).errors
+    } else {
+        ret.errors
+    };
+    // Spans of errors are in the output, not the source text, so only report the message
+    if let Some(error) = errors.first() {
+        ctx.add_diagnostics(vec![OxcDiagnostic::error(format!(
+            "Transformed output is not valid JavaScript: {}",
+            error.message
+        ))
+        .with_help("This is a bug. Please report it, with the input which caused it.")]);
+    }
+}
+
+/// Name of the file which `filename` is transformed to, e.g. `foo.js` for `src/foo.ts`.
+fn output_file_name(filename: &str) -> String {
+    let path = Path::new(filename);
+    let extension = match path.extension().and_then(OsStr::to_str) {
+        Some("mts") => "mjs",
+        Some("cts") => "cjs",
+        Some("ts" | "tsx" | "jsx") => "js",
+        Some(extension) => extension,
+        None => "",
+    };
+    path.with_extension(extension)
+        .file_name()
+        .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+}
+
 fn transpile(ctx: &TransformContext<'_>, options: Option<TransformOptions>) -> CodegenReturn {
+    // Declaration files only contain types, so output is always empty.
+    if ctx.source_type().is_typescript_definition() {
+        return CodegenReturn { source_text: String::new(), source_map: None };
+    }
+
+    if options.as_ref().and_then(|o| o.mode.as_deref()) == Some("strip") {
+        let ret = StripTypes::new(ctx.source_text()).build(&ctx.program());
+        ctx.add_diagnostics(ret.errors);
+        return CodegenReturn { source_text: ret.source_text, source_map: None };
+    }
+
     let semantic_ret = SemanticBuilder::new(ctx.source_text())
         // Estimate transformer will triple scopes, symbols, references
         .with_excess_capacity(2.0)
@@ -105,7 +245,78 @@ fn transpile(ctx: &TransformContext<'_>, options: Option<TransformOptions>) -> C
         .build(&ctx.program());
     ctx.add_diagnostics(semantic_ret.errors);
 
-    let options = options.map(oxc_transformer::TransformOptions::from).unwrap_or_default();
+    let target =
+        match options.as_ref().and_then(|o| o.target.as_deref()).map(str::parse::<ESTarget>) {
+            Some(Ok(target)) => Some(target),
+            Some(Err(error)) => {
+                ctx.add_diagnostics(vec![OxcDiagnostic::error(error)]);
+                None
+            }
+            None => None,
+        };
+    let warn_unsupported_syntax =
+        options.as_ref().and_then(|o| o.warn_unsupported_syntax).unwrap_or_default();
+    let arrow_function_spec = options
+        .as_ref()
+        .and_then(|o| o.es2015.as_ref())
+        .and_then(|es2015| match &es2015.arrow_function {
+            Some(Either::B(arrow_function)) => arrow_function.spec,
+            _ => None,
+        })
+        .unwrap_or_default();
+    if arrow_function_spec {
+        ctx.add_diagnostics(vec![OxcDiagnostic::error(
+            "The `spec` option of `arrowFunction` is not supported yet.",
+        )]);
+    }
+
+    let remove_unused_imports =
+        options.as_ref().and_then(|o| o.remove_unused_imports).unwrap_or_default();
+    let hashbang = options.as_ref().and_then(|o| o.hashbang).unwrap_or(true);
+    let annotations = options.as_ref().and_then(|o| o.annotations);
+    let reattach_comments = options
+        .as_ref()
+        .and_then(|o| o.keep_comments)
+        .unwrap_or_default()
+        .then(|| ReattachComments::new(&ctx.program()));
+    // `<importSource>/jsx-runtime` to resolve
+    let jsx_import_source = options
+        .as_ref()
+        .and_then(|o| o.react.as_ref())
+        .filter(|react| react.resolve_import_source.unwrap_or_default())
+        .map(|react| react.import_source.clone().unwrap_or_else(|| "react".to_string()));
+    let cwd = options.as_ref().and_then(|o| o.cwd.clone()).unwrap_or_default();
+    let impure_functions =
+        options.as_ref().and_then(|o| o.impure_functions.clone()).unwrap_or_default();
+    let import_meta = match options.as_ref().and_then(|o| o.import_meta.as_deref()) {
+        Some("commonjs") => ImportMetaMode::CommonJs,
+        _ => ImportMetaMode::Preserve,
+    };
+    let import_attributes_keyword =
+        match options.as_ref().and_then(|o| o.import_attributes_keyword.as_deref()) {
+            Some("assert") => ImportAttributesKeyword::Assert,
+            Some("with") => ImportAttributesKeyword::With,
+            _ => ImportAttributesKeyword::Preserve,
+        };
+    let replace_top_level_this =
+        options.as_ref().and_then(|o| o.replace_top_level_this).unwrap_or_default();
+    let platform = match options.as_ref().and_then(|o| o.platform.as_deref()) {
+        Some("browser") => Platform::Browser,
+        Some("neutral") => Platform::Neutral,
+        _ => Platform::Node,
+    };
+    // Plugins enabled by the target, which explicitly configured plugins override.
+    // ES version targets cannot fail to parse, unlike browserslist queries.
+    let base = target
+        .and_then(|target| {
+            oxc_transformer::TransformOptions::from_preset_env(&EnvOptions::from_target(target))
+                .ok()
+        })
+        .unwrap_or_default();
+    let options = match options {
+        Some(options) => options.into_transform_options(base),
+        None => base,
+    };
 
     let (symbols, scopes) = semantic_ret.semantic.into_symbol_table_and_scope_tree();
     let ret = Transformer::new(
@@ -120,5 +331,100 @@ fn transpile(ctx: &TransformContext<'_>, options: Option<TransformOptions>) -> C
 
     ctx.add_diagnostics(ret.errors);
 
-    ctx.codegen().build(&ctx.program())
+    if let Some(import_source) = jsx_import_source {
+        check_jsx_runtime(ctx, &import_source, Path::new(&cwd));
+    }
+
+    if remove_unused_imports {
+        RemoveUnusedImports::new().build(&mut ctx.program_mut());
+    }
+
+    ImportMeta::new(ctx.allocator, import_meta)
+        .with_platform(platform)
+        .build(&mut ctx.program_mut());
+
+    if replace_top_level_this {
+        TopLevelThis::new(ctx.allocator).build(&mut ctx.program_mut());
+    }
+
+    ImportAttributes::new(import_attributes_keyword).build(&mut ctx.program_mut());
+
+    // After transforms, so only syntax which remains in the output is reported
+    if let Some(target) = target {
+        let unsupported_syntax = UnsupportedSyntax::new(target)
+            .with_untransformed_syntax(warn_unsupported_syntax)
+            .build(&ctx.program());
+        ctx.add_diagnostics(unsupported_syntax);
+    }
+
+    if !hashbang {
+        ctx.program_mut().hashbang = None;
+    }
+
+    let mut codegen = ctx.codegen();
+    if reattach_comments.is_some() || annotations == Some(true) {
+        let trivias = match reattach_comments {
+            Some(reattach_comments) => reattach_comments.build(&ctx.program(), &ctx.trivias),
+            None => annotation_trivias(ctx.source_text(), &ctx.trivias),
+        };
+        let trivias = ImpureFunctions::new(&impure_functions).build(
+            &ctx.program(),
+            ctx.source_text(),
+            &trivias,
+        );
+        codegen = codegen.enable_comment(
+            ctx.source_text(),
+            trivias,
+            CommentOptions { preserve_annotate_comments: annotations.unwrap_or(true) },
+        );
+    }
+    codegen.build(&ctx.program())
+}
+
+/// Only the `@__PURE__` and `@__NO_SIDE_EFFECTS__` annotation comments of `trivias`,
+/// for printing annotations without other comments.
+fn annotation_trivias(source_text: &str, trivias: &Trivias) -> Trivias {
+    const ANNOTATIONS: [&str; 4] =
+        ["@__PURE__", "#__PURE__", "@__NO_SIDE_EFFECTS__", "#__NO_SIDE_EFFECTS__"];
+    let comments = trivias
+        .comments()
+        .filter(|comment| {
+            let text = comment.span.source_text(source_text);
+            ANNOTATIONS.iter().any(|annotation| text.contains(annotation))
+        })
+        .copied()
+        .collect::<Vec<_>>();
+    Trivias::new(comments.into_boxed_slice(), trivias.irregular_whitespaces().to_vec())
+}
+
+/// Warn if the JSX runtime of `import_source` is imported by the transformed program,
+/// but cannot be resolved from the directory of the file.
+fn check_jsx_runtime(ctx: &TransformContext<'_>, import_source: &str, cwd: &Path) {
+    let runtimes =
+        [format!("{import_source}/jsx-runtime"), format!("{import_source}/jsx-dev-runtime")];
+    let program = ctx.program();
+    let imported = program.body.iter().find_map(|stmt| match stmt {
+        Statement::ImportDeclaration(decl)
+            if runtimes.iter().any(|runtime| runtime == decl.source.value.as_str()) =>
+        {
+            Some(decl.source.value.as_str())
+        }
+        _ => None,
+    });
+    let Some(specifier) = imported else { return };
+
+    let file_path = cwd.join(ctx.file_path());
+    let directory = file_path.parent().unwrap_or(cwd);
+    let resolver = Resolver::new(ResolveOptions {
+        condition_names: vec!["import".into(), "node".into()],
+        ..ResolveOptions::default()
+    });
+    if let Err(error) = resolver.resolve(directory, specifier) {
+        ctx.add_diagnostics(vec![OxcDiagnostic::warn(format!(
+            "Cannot resolve the JSX runtime `{specifier}`: {error}"
+        ))
+        .with_help(format!(
+            "Install `{import_source}`, or set `importSource` to a package which exports `./jsx-runtime`."
+        ))]);
+    }
 }
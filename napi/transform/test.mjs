@@ -1,6 +1,128 @@
 import assert from 'assert';
+import fs from 'fs';
+import { createRequire } from 'module';
 import oxc from './index.js';
 
+const require = createRequire(import.meta.url);
+
+// Helpers referenced by transformed code in the runtime tests below, which compare the result of
+// running `run()` in the input and the output.
+const babelHelpers = {
+  assertClassBrand(e, t, n) {
+    if (typeof e === 'function' ? e === t : e.has(t)) return arguments.length < 3 ? t : n;
+    throw new TypeError('Private element is not present on this object');
+  },
+  checkInRHS(e) {
+    if (Object(e) !== e) throw new TypeError("right-hand side of 'in' should be an object");
+    return e;
+  },
+  classPrivateFieldInitSpec(e, t, a) {
+    if (t.has(e)) throw new TypeError('Cannot initialize the same private elements twice');
+    t.set(e, a);
+  },
+  classPrivateMethodInitSpec(e, a) {
+    if (a.has(e)) throw new TypeError('Cannot initialize the same private elements twice');
+    a.add(e);
+  },
+  classPrivateFieldGet2(s, a) {
+    return s.get(babelHelpers.assertClassBrand(s, a));
+  },
+  classPrivateFieldSet2(s, a, r) {
+    return s.set(babelHelpers.assertClassBrand(s, a), r), r;
+  },
+  classPrivateGetter(s, r, a) {
+    return a(babelHelpers.assertClassBrand(s, r));
+  },
+  classPrivateSetter(s, r, a, t) {
+    return r(babelHelpers.assertClassBrand(s, a), t), t;
+  },
+  construct(t, e) {
+    return Reflect.construct(t, e);
+  },
+  createForOfIteratorHelper(o) {
+    let it, normalCompletion = true, didErr = false, err;
+    return {
+      s() {
+        it = o[Symbol.iterator]();
+      },
+      n() {
+        const step = it.next();
+        normalCompletion = step.done;
+        return step;
+      },
+      e(e) {
+        didErr = true;
+        err = e;
+      },
+      f() {
+        try {
+          if (!normalCompletion && it.return != null) it.return();
+        } finally {
+          if (didErr) throw err;
+        }
+      },
+    };
+  },
+  defineProperty(e, r, t) {
+    return Object.defineProperty(e, r, { value: t, enumerable: true, configurable: true, writable: true });
+  },
+  objectDestructuringEmpty(t) {
+    if (t == null) throw new TypeError('Cannot destructure ' + t);
+  },
+  objectSpread2(e, ...r) {
+    for (const t of r) Object.defineProperties(e, Object.getOwnPropertyDescriptors(Object(t)));
+    return e;
+  },
+  objectWithoutProperties(e, t) {
+    if (e == null) return {};
+    const o = {};
+    for (const k of Reflect.ownKeys(e)) {
+      if (!t.includes(k) && Object.prototype.propertyIsEnumerable.call(e, k)) o[k] = e[k];
+    }
+    return o;
+  },
+  readOnlyError(r) {
+    throw new TypeError('"' + r + '" is read-only');
+  },
+  regeneratorRuntime() {
+    return require('regenerator-runtime');
+  },
+  slicedToArray(r, e) {
+    if (Array.isArray(r)) return r;
+    const a = [];
+    const it = r[Symbol.iterator]();
+    for (let s; a.length !== e && !(s = it.next()).done;) a.push(s.value);
+    if (a.length === e && typeof it.return === 'function') it.return();
+    return a;
+  },
+  taggedTemplateLiteral(e, t) {
+    return t || (t = e.slice(0)), Object.freeze(Object.defineProperties(e, { raw: { value: Object.freeze(t) } }));
+  },
+  tdz(name) {
+    throw new ReferenceError(name + ' is not defined - temporal dead zone');
+  },
+  toArray(r) {
+    return Array.isArray(r) ? r : Array.from(r);
+  },
+  toConsumableArray(r) {
+    return Array.from(r);
+  },
+  toPropertyKey(t) {
+    return typeof t === 'symbol' ? t : String(t);
+  },
+  typeof(obj) {
+    return typeof obj;
+  },
+  writeOnlyError(r) {
+    throw new TypeError('"' + r + '" is write-only');
+  },
+};
+
+// Define `code`, with `babelHelpers` in scope, and return the result of calling its `run()`.
+function runCode(code) {
+  return new Function('babelHelpers', `${code}\nreturn run();`)(babelHelpers);
+}
+
 console.log(`Testing on ${process.platform}-${process.arch}`);
 
 function test(ret, expected) {
@@ -43,6 +165,7 @@ test(oxc.isolatedDeclaration('test.ts', id, { sourcemap: true }), {
 test(oxc.transform('test.ts', 'class A<T> {}', { sourcemap: true }), {
   code: 'class A {}\n',
   map: {
+    file: 'test.js',
     mappings: 'AAAA,MAAM,EAAK,CAAE',
     names: [],
     sources: ['test.ts'],
@@ -83,4 +206,2084 @@ test(
   },
 );
 
+// Test source map debug ids are deterministic
+{
+  const options = { sourcemap: true, sourcemapDebugIds: true };
+  const a = oxc.transform('test.ts', 'const a: number = 1;', options);
+  const b = oxc.transform('test.ts', 'const a: number = 1;', options);
+  assert(a.errors.length == 0);
+  assert.match(a.map.debugId, /^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/);
+  assert.equal(a.map.debugId, b.map.debugId);
+  assert.equal(a.code, `const a = 1;\n//# debugId=${a.map.debugId}\n`);
+  assert.equal(a.code, b.code);
+
+  const c = oxc.transform('test.ts', 'const a: number = 2;', options);
+  assert.notEqual(a.map.debugId, c.map.debugId);
+
+  // Options which do not change the output do not change the ID
+  const d = oxc.transform('test.ts', 'const a: number = 1;', { ...options, target: 'esnext' });
+  assert.equal(a.map.debugId, d.map.debugId);
+
+  const e = oxc.transform('other.ts', 'const a: number = 1;', options);
+  assert.notEqual(a.map.debugId, e.map.debugId);
+}
+
+// Test `.cjs` and `.mjs` are not ambiguous under `sourceType: 'unambiguous'`
+{
+  // `with` statements are only allowed in scripts.
+  const code = 'with (a) {}';
+  assert.equal(oxc.transform('test.cjs', code, { sourceType: 'unambiguous' }).errors.length, 0);
+  assert.notEqual(oxc.transform('test.mjs', code, { sourceType: 'unambiguous' }).errors.length, 0);
+  // `import` declarations are only allowed in modules.
+  const esm = 'import a from "a";\nexport default a;';
+  assert.notEqual(oxc.transform('test.cjs', esm, { sourceType: 'unambiguous' }).errors.length, 0);
+  assert.equal(oxc.transform('test.mjs', esm, { sourceType: 'unambiguous' }).errors.length, 0);
+}
+
+// Test unused imports are removed after transformation
+test(
+  oxc.transform(
+    'test.ts',
+    `import { a, b } from 'a';\nimport 'c';\nexport type T = typeof a;\nconsole.log(b);`,
+    { typescript: { onlyRemoveTypeImports: true }, removeUnusedImports: true },
+  ),
+  { code: 'import { b } from "a";\nimport "c";\nconsole.log(b);\n' },
+);
+
+// Test logical assignment operators are lowered with the same semantics
+for (const operator of ['||=', '&&=', '??=']) {
+  for (const initial of ['0', '1', 'null']) {
+    const code = `
+function run() {
+  let a = ${initial};
+  const obj = { a: ${initial}, b: ${initial} };
+  let objCalls = 0, keyCalls = 0, rhsCalls = 0;
+  const getObj = () => (objCalls++, obj);
+  const getKey = () => (keyCalls++, 'b');
+  const rhs = () => (rhsCalls++, 2);
+  a ${operator} rhs();
+  getObj().a ${operator} rhs();
+  getObj()[getKey()] ${operator} rhs();
+  return [a, obj.a, obj.b, objCalls, keyCalls, rhsCalls];
+}`;
+    const ret = oxc.transform('test.js', code, { es2021: { logicalAssignmentOperators: true } });
+    assert(ret.errors.length == 0);
+    assert(!ret.code.includes(operator), ret.code);
+    const expected = runCode(code);
+    const actual = runCode(ret.code);
+    assert.deepEqual(actual, expected, `${operator} with ${initial}`);
+  }
+}
+
+// Test numeric separators are removed from every kind of numeric literal
+test(
+  oxc.transform(
+    'test.js',
+    'let a = [1_000_000, 1_0.0_1e1_0, 0b1010_0001, 0o7_7, 0xFF_FF, 10_0n, 0xF_Fn];',
+    { es2021: { numericSeparator: true } },
+  ),
+  { code: 'let a = [\n\t1000000,\n\t10.01e10,\n\t0b10100001,\n\t0o77,\n\t0xFFFF,\n\t100n,\n\t0xFFn\n];\n' },
+);
+
+// Test private class elements and brand checks are lowered with the same semantics
+{
+  const code = `
+function run() {
+  class Base { constructor(n) { this.n = n; } }
+  class A extends Base {
+    #x = 1;
+    #y;
+    static #count = 0;
+    constructor(n) {
+      super(n);
+      this.#y = this.#x + n;
+    }
+    #inc(by) { return this.#x += by; }
+    get #double() { return this.#x * 2; }
+    set #double(v) { this.#x = v / 2; }
+    static #create(n) { A.#count++; return new A(n); }
+    static make(n) { return A.#create(n); }
+    static count() { return A.#count; }
+    static has(obj) { return #x in obj && #inc in obj; }
+    run() {
+      const results = [this.#x, this.#y, this.#inc(2)];
+      this.#double = 10;
+      results.push(this.#double, this.#x++, ++this.#x, this.#x);
+      this.#y ||= 100;
+      this.#y ??= 200;
+      this.#y **= 2;
+      results.push(this.#y);
+      try { this.#inc = 1; } catch (e) { results.push(e instanceof TypeError); }
+      return results;
+    }
+  }
+  const B = class {
+    static #value = 10;
+    static #self = this;
+    static get() { return [B.#value, B.#self === B, #value in B, #value in {}]; }
+  };
+  const a = A.make(5);
+  return [a.run(), A.make(1).n, A.count(), A.has(a), A.has({}), B.get()];
+}`;
+  const expected = runCode(code);
+  for (const es2022 of [{ privateMethods: true, privateIn: true }, { privateIn: true }]) {
+    const ret = oxc.transform('test.js', code, { es2022 });
+    assert(ret.errors.length == 0);
+    if (es2022.privateMethods) {
+      // `readOnlyError("#inc")` keeps the name in a string, so only match `#` outside quotes.
+      assert(!/(?<!["'])#\w/.test(ret.code), ret.code);
+    } else {
+      assert(!ret.code.includes(' in obj'), ret.code);
+    }
+    const actual = runCode(ret.code);
+    assert.deepEqual(actual, expected, JSON.stringify(es2022));
+  }
+}
+
+// Test class fields are initialized in the same order, and computed keys are evaluated once
+{
+  const code = `
+function run() {
+  const log = [];
+  let i = 0;
+  const key = () => (log.push('key'), 'k' + i++);
+  class Base { constructor() { log.push('super'); } }
+  class A extends Base {
+    a = log.push('a');
+    #b = log.push('b');
+    static s = log.push('static s');
+    [key()] = log.push('computed');
+    c = this.a + this.#b;
+    constructor() {
+      log.push('before super');
+      super();
+      log.push('constructor');
+    }
+  }
+  log.push('defined');
+  const a = new A();
+  const b = new A();
+  const C = class { static self = this; x = 1; };
+  return [log, Object.keys(a), a.k0, a.c, b.k0, A.s, C.self === C, new C().x];
+}`;
+  const expected = runCode(code);
+  for (const classProperties of [{}, { loose: true }]) {
+    const ret = oxc.transform('test.js', code, { es2022: { classProperties } });
+    assert(ret.errors.length == 0);
+    assert(!ret.code.includes('#'), ret.code);
+    assert.equal(ret.code.includes('defineProperty'), !classProperties.loose, ret.code);
+    const actual = runCode(ret.code);
+    assert.deepEqual(actual, expected, JSON.stringify(classProperties));
+  }
+}
+
+// Test spec and `loose` class fields output in a subclass constructor
+{
+  const code = `class A extends B {
+  x = 1;
+  constructor(y) {
+    super(y);
+    this.y = y;
+  }
+}
+`;
+  test(oxc.transform('test.js', code, { es2022: { classProperties: {} } }), {
+    code: 'class A extends B {\n' +
+      '\tconstructor(y) {\n' +
+      '\t\tsuper(y);\n' +
+      '\t\tbabelHelpers.defineProperty(this, "x", 1);\n' +
+      '\t\tthis.y = y;\n' +
+      '\t}\n' +
+      '}\n',
+  });
+  test(oxc.transform('test.js', code, { es2022: { classProperties: { loose: true } } }), {
+    code: 'class A extends B {\n' +
+      '\tconstructor(y) {\n' +
+      '\t\tsuper(y);\n' +
+      '\t\tthis.x = 1;\n' +
+      '\t\tthis.y = y;\n' +
+      '\t}\n' +
+      '}\n',
+  });
+}
+
+// Test shorthand properties and methods are expanded
+{
+  const code = 'const o = { a, __proto__, m() { return 1; } };\n';
+  const ret = oxc.transform('test.js', code, { es2015: { shorthandProperties: true } });
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'const o = {\n' +
+      '\ta: a,\n' +
+      '\t["__proto__"]: __proto__,\n' +
+      '\tm: function() {\n' +
+      '\t\treturn 1;\n' +
+      '\t}\n' +
+      '};\n',
+  );
+}
+
+// Test computed keys are evaluated exactly once, in order
+{
+  const code = `
+function run() {
+  const log = [];
+  let i = 0;
+  const expr = () => (log.push('key ' + i), 'k' + i++);
+  const value = (v) => (log.push('value ' + v), v);
+  const a = 0;
+  const obj = { a, [expr()]: value(1), [expr()]: value(2), b: value(3), ...{ c: 4 }, get [expr()]() { return 5; }, m() { return 6; } };
+  return [log, Object.keys(obj), obj.k0, obj.k1, obj.k2, obj.m(), { __proto__: null }.__proto__];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, {
+    es2015: { shorthandProperties: true, computedProperties: true },
+  });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('[expr()]'), ret.code);
+  assert(ret.code.includes('{ a: a }'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(expected[0], ['key 0', 'value 1', 'key 1', 'value 2', 'value 3', 'key 2']);
+}
+
+// Test template literals, and tagged templates receive the same frozen strings array on each evaluation
+{
+  const code = `
+function run() {
+  const log = [];
+  const x = { toString() { log.push('x'); return 'X'; } };
+  const y = { toString() { log.push('y'); return 'Y'; } };
+  const untagged = \`a\${x}\${y}b\${1 + 1}\`;
+  const tag = (strings, ...values) => strings;
+  const f = (v) => tag\`one\\n\${v}two\`;
+  const first = f(1);
+  const second = f(2);
+  return [
+    untagged,
+    log,
+    first === second,
+    Object.isFrozen(first),
+    Object.isFrozen(first.raw),
+    [...first],
+    [...first.raw],
+    \`\`,
+  ];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { templateLiterals: true } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('`'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(expected.slice(0, 5), ['aXYb2', ['x', 'y'], true, true, true]);
+}
+
+// Test destructuring with defaults, holes, nested patterns, params and catch clauses
+{
+  const code = `
+function run() {
+  const log = [];
+  const d = (v) => (log.push('default ' + v), v);
+  var [a = d(1), , b = d(2), [c] = [d(3)]] = [undefined, 'hole', 'b'];
+  let { x, y: { z = d(4) } = {}, ['k' + 1]: k, ...rest } = { x: 'x', k1: 'k', r: 'r' };
+  const [first, ...others] = new Set([1, 2, 3]);
+  var p, q, o = {};
+  var assigned = ([p, q = d(5)] = [{ p: 'p' }]);
+  ({ p: o.p, q: o['q'] = d(6) } = p);
+  [p, q] = [q, p];
+  function f({ m, n = d(7) }, [i, j] = [8, 9], ...[...tail]) {
+    return [m, n, i, j, tail];
+  }
+  const g = ({ v }) => v;
+  const h = ([w]) => [w] = [w + 1];
+  let caught;
+  try {
+    throw { message: 'err', code: 1 };
+  } catch ({ message, ...props }) {
+    caught = [message, props];
+  }
+  let threw = false;
+  try {
+    ({} = null);
+  } catch {
+    threw = true;
+  }
+  return [
+    log, a, b, c, x, z, k, rest, first, others, assigned, o, p, q,
+    f({ m: 'm' }), f({ m: 1, n: 2 }, [3], 4, 5), g({ v: 'v' }), h([1]), caught, threw,
+  ];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { destructuring: true } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('[a ='), ret.code);
+  assert(!ret.code.includes('function f({'), ret.code);
+  assert(!ret.code.includes('catch ({'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(expected[0], ['default 1', 'default 3', 'default 4', 'default 5', 'default 6', 'default 7']);
+}
+
+// Test destructuring in `for...of` and `for...in` heads, with and without `for...of` transform
+{
+  const code = `
+function run() {
+  const out = [];
+  const fns = [];
+  for (const [k, { v = k }] of new Map([['a', {}], ['b', { v: 1 }]])) fns.push(() => [k, v]);
+  for (let { length } in { ab: 1, c: 2 }) {
+    let k = length;
+    out.push(k);
+  }
+  var p, q;
+  for ([p, q = 'q'] of [[1], [2, 3]]) out.push([p, q]);
+  for (var [x] of ['xy']) {}
+  return [out, fns.map((f) => f()), x];
+}`;
+  const expected = runCode(code);
+  for (const forOf of [undefined, {}]) {
+    const ret = oxc.transform('test.js', code, { es2015: { destructuring: true, forOf } });
+    assert(ret.errors.length == 0);
+    assert(!ret.code.includes('const [k'), ret.code);
+    assert(!ret.code.includes('for ([p'), ret.code);
+    assert.deepEqual(runCode(ret.code), expected);
+  }
+}
+
+// Test `for...of` calls `return()` when exiting loop early, and `loose` iterates arrays by index
+{
+  const code = `
+function run() {
+  const log = [];
+  const iterable = (name) => ({
+    [Symbol.iterator]() {
+      let i = 0;
+      return {
+        next: () => (log.push(name + ' next'), { done: i >= 3, value: i++ }),
+        return: () => (log.push(name + ' return'), {}),
+      };
+    },
+  });
+  for (const x of iterable('complete')) log.push('complete ' + x);
+  for (const x of iterable('break')) {
+    if (x === 1) break;
+  }
+  outer: for (const x of iterable('outer')) {
+    for (const y of iterable('inner')) {
+      if (y === 1) continue outer;
+    }
+  }
+  try {
+    for (const x of iterable('throw')) throw new Error('thrown ' + x);
+  } catch (e) {
+    log.push(e.message);
+  }
+  const returned = (() => {
+    for (const x of iterable('return')) if (x === 2) return x;
+  })();
+  let sum = 0, last;
+  for (last of [1, 2, 3]) sum += last;
+  if (sum) for (const [a, b] of [[1, 2]]) sum += a * b;
+  const fns = [];
+  for (const x of [1, 2]) fns.push(() => x);
+  return [log, returned, sum, last, fns.map((f) => f())];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { forOf: {} } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes(' of '), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert(expected[0].includes('break return'));
+  assert(expected[0].includes('inner return'));
+  assert(expected[0].includes('throw return'));
+  assert(expected[0].includes('return return'));
+  assert(!expected[0].includes('complete return'));
+
+  const arrayCode = `
+function run() {
+  const fns = [];
+  let sum = 0, last;
+  for (const x of [1, 2, 3]) {
+    if (x === 3) break;
+    fns.push(() => x);
+  }
+  for (last of [4, 5]) sum += last;
+  return [fns.map((f) => f()), sum, last];
+}`;
+  const arrayExpected = runCode(arrayCode);
+  const looseRet = oxc.transform('test.js', arrayCode, { es2015: { forOf: { loose: true } } });
+  assert(looseRet.errors.length == 0);
+  assert(!looseRet.code.includes(' of '), looseRet.code);
+  assert(!looseRet.code.includes('createForOfIteratorHelper'), looseRet.code);
+  assert(looseRet.code.includes('.length'), looseRet.code);
+  assert.deepEqual(runCode(looseRet.code), arrayExpected);
+}
+
+// Test labelled `break` and `continue` in lowered `for...of` loops
+{
+  const code = `
+function run() {
+  const log = [];
+  const iterable = (name, values) => ({
+    [Symbol.iterator]() {
+      let i = 0;
+      return {
+        next: () => (i < values.length ? { value: values[i++], done: false } : { done: true }),
+        return: () => (log.push(name + ' return'), { done: true }),
+      };
+    },
+  });
+  outer: for (const a of iterable('outer', [1, 2, 3])) {
+    for (const b of iterable('inner', [1, 2, 3])) {
+      if (b === 2) continue outer;
+      if (a === 3) break outer;
+      log.push(a + ':' + b);
+    }
+  }
+  const fns = [];
+  loop: for (const x of [1, 2, 3]) {
+    for (const y of [1, 2]) {
+      if (x === 3) break loop;
+      fns.push(() => x + y);
+      continue loop;
+    }
+  }
+  return [log, fns.map((f) => f())];
+}`;
+  const expected = runCode(code);
+  assert.deepEqual(expected, [
+    ['1:1', 'inner return', '2:1', 'inner return', 'inner return', 'outer return'],
+    [2, 3],
+  ]);
+  const ret = oxc.transform('test.js', code, { es2015: { forOf: {} } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes(' of '), ret.code);
+  assert(/outer: for \(/.test(ret.code), ret.code);
+  assert(/loop: for \(/.test(ret.code), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+
+  const arrayCode = `
+function run() {
+  const log = [];
+  outer: for (const a of [1, 2, 3]) {
+    for (const b of [1, 2, 3]) {
+      if (b === 2) continue outer;
+      if (a === 3) break outer;
+      log.push(a + ':' + b);
+    }
+  }
+  return log;
+}`;
+  const looseRet = oxc.transform('test.js', arrayCode, { es2015: { forOf: { loose: true } } });
+  assert(looseRet.errors.length == 0);
+  assert(!looseRet.code.includes(' of '), looseRet.code);
+  assert(/outer: for \(/.test(looseRet.code), looseRet.code);
+  assert.deepEqual(runCode(looseRet.code), ['1:1', '2:1']);
+}
+
+// Test spread in array literals, calls and `new` expressions
+{
+  const code = `
+function run() {
+  const log = [];
+  const a = [1, 2];
+  const set = new Set([3, 4]);
+  const f = function () { return [this === undefined || this === globalThis ? 'global' : this.name, Array.from(arguments)]; };
+  const obj = { name: 'obj', f };
+  const getObj = () => (log.push('getObj'), obj);
+  class C {
+    constructor() {
+      this.args = Array.from(arguments);
+    }
+  }
+  const copy = [...a];
+  return [
+    [...a, 0, ...set],
+    [0, ...a, , 5],
+    copy !== a,
+    f(...a),
+    f(0, ...set, 5),
+    obj.f(...a),
+    obj['f'](0, ...a),
+    getObj().f(...set),
+    new C(...a) instanceof C,
+    new C(0, ...set, 5).args,
+    log,
+  ];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { spread: true } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('(...'), ret.code);
+  assert(!ret.code.includes('[...'), ret.code);
+  assert(!ret.code.includes(', ...'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(expected[4], ['global', [0, 3, 4, 5]]);
+  assert.deepEqual(expected[10], ['getObj']);
+
+  // Not enabled by the target, so ES5 output keeps spread unless it is set
+  const es5 = oxc.transform('test.js', 'f(...[a, ...b]);\n', { target: 'es5' });
+  assert.equal(es5.code, 'f(...[a, ...b]);\n');
+}
+
+// Test generator functions are transformed to state machines
+{
+  const code = `
+function run() {
+  const log = [];
+  function* gen(n) {
+    try {
+      for (let i = 0; i < n; i++) {
+        log.push('before ' + i);
+        const received = yield i * 2;
+        log.push('received ' + received);
+      }
+      return 'done';
+    } finally {
+      log.push('finally');
+    }
+  }
+  const obj = {
+    name: 'obj',
+    data: { a: 1, b: 2 },
+    gen: function* () {
+      yield this.name;
+      yield arguments.length;
+    },
+    *keys() {
+      for (const key in this.data) {
+        yield key + this.data[key];
+      }
+    },
+  };
+  class C {
+    constructor() {
+      this.k = 'k';
+    }
+    *vals(x) {
+      yield this.k;
+      yield x;
+      yield arguments.length;
+    }
+  }
+  const results = [];
+  const it = gen(3);
+  results.push(it.next('ignored'), it.next('a'), it.next('b'), it.next('c'), it.next('d'));
+  const early = gen(5);
+  results.push(early.next(), early.return('early'), early.next());
+  results.push([...gen(2)], [...obj.gen(1, 2, 3)], [...obj.keys()], [...new C().vals(1, 2)]);
+  return [results, log];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { useRegenerator: true } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('function*'), ret.code);
+  assert(!ret.code.includes('*keys'), ret.code);
+  assert(!ret.code.includes('*vals'), ret.code);
+  assert(!ret.code.includes('yield'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(expected[0][5], { value: 0, done: false });
+  assert.deepEqual(expected[0][6], { value: 'early', done: true });
+  // `return()` of a suspended generator runs the `finally` block
+  assert.deepEqual(expected[1].slice(7, 9), ['before 0', 'finally']);
+}
+
+{
+  const code = `
+function run() {
+  const fns = [];
+  for (let i = 0; i < 3; i++) {
+    let j = i * 2;
+    fns.push(() => [i, j]);
+  }
+  let results = fns.map((fn) => fn());
+  {
+    let x = 'inner';
+    results.push(x);
+  }
+  let x = 'outer';
+  results.push(x);
+  return results;
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { blockScoping: {} } });
+  assert(ret.errors.length == 0);
+  assert(!/\b(let|const)\b/.test(ret.code), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(actual, [[0, 0], [1, 2], [2, 4], 'inner', 'outer']);
+
+  const violation = 'function run() { const a = 1; a = 2; }';
+  const thrown = oxc.transform('test.js', violation, { es2015: { blockScoping: {} } });
+  assert(thrown.errors.length == 0);
+  assert.throws(
+    () => runCode(thrown.code),
+    TypeError,
+  );
+  const reported = oxc.transform('test.js', violation, {
+    es2015: { blockScoping: { reportConstViolations: true } },
+  });
+  assert(reported.errors.length > 0);
+
+  const tdz = 'function run() { a; let a = 1; }';
+  const checked = oxc.transform('test.js', tdz, { es2015: { blockScoping: { tdz: true } } });
+  assert(checked.errors.length == 0);
+  assert.throws(
+    () => runCode(checked.code),
+    ReferenceError,
+  );
+}
+
+{
+  const typeofCalls = [];
+  const code = `
+function run() {
+  const sym = Symbol('s');
+  return [
+    typeof sym,
+    typeof sym === 'symbol',
+    typeof 1 === 'number',
+    typeof {},
+    typeof undeclaredVariable,
+  ];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { typeofSymbol: true } });
+  assert(ret.errors.length == 0);
+  assert(ret.code.includes('babelHelpers.typeof('), ret.code);
+  const typeofHelper = babelHelpers.typeof;
+  babelHelpers.typeof = (obj) => (typeofCalls.push(obj), typeofHelper(obj));
+  try {
+    assert.deepEqual(runCode(ret.code), expected);
+  } finally {
+    babelHelpers.typeof = typeofHelper;
+  }
+  assert.equal(typeofCalls.length, 3);
+}
+
+{
+  const code = `
+function run() {
+  function Named() {
+    return new.target;
+  }
+  const Anonymous = function () {
+    return (() => new.target)();
+  };
+  class Klass {
+    constructor() {
+      this.target = new.target;
+    }
+    method() {
+      return new.target;
+    }
+  }
+  class Sub extends Klass {}
+  return [
+    new Named() instanceof Named,
+    Named(),
+    new Anonymous() instanceof Anonymous,
+    Anonymous(),
+    new Klass().target === Klass,
+    new Sub().target === Sub,
+    new Klass().method(),
+  ];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, { es2015: { newTarget: true } });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('new.target'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+}
+
+{
+  const code = `
+function run() {
+  const sticky = /foo/y;
+  sticky.lastIndex = 3;
+  const unicode = /\\u{1F600}/u;
+  return [sticky.test('barfoo'), sticky.lastIndex, unicode.test('\\u{1F600}'), /plain/g.flags];
+}`;
+  const expected = runCode(code);
+  const ret = oxc.transform('test.js', code, {
+    es2015: { stickyRegex: true, unicodeRegex: true },
+  });
+  assert(ret.errors.length == 0);
+  assert(ret.code.includes('new RegExp("foo", "y")'), ret.code);
+  assert(ret.code.includes('new RegExp('), ret.code);
+  assert(!ret.code.includes('/y'), ret.code);
+  assert(!ret.code.includes('/u'), ret.code);
+  assert(ret.code.includes('/plain/g'), ret.code);
+  const actual = runCode(ret.code);
+  assert.deepEqual(actual, expected);
+  assert.deepEqual(expected, [true, 6, true, 'g']);
+}
+
+{
+  const code = `export * as ns from 'mod';
+export * as 'some name' from './other.js';
+export * from 'all';
+`;
+  const ret = oxc.transform('test.mjs', code, { es2020: { exportNamespaceFrom: true } });
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    `import * as _ns from "mod";
+export { _ns as ns };
+import * as _someName from "./other.js";
+export { _someName as "some name" };
+export * from "all";
+`,
+  );
+  const untransformed = oxc.transform('test.mjs', code);
+  assert(untransformed.code.includes('export * as ns from "mod"'), untransformed.code);
+}
+
+{
+  const code = 'export const url = import.meta.url;\nexport const meta = import.meta;\n';
+  const commonjs = oxc.transform('test.mjs', code, { importMeta: 'commonjs' });
+  assert(commonjs.errors.length == 0);
+  assert.equal(
+    commonjs.code,
+    'export const url = require("url").pathToFileURL(__filename).href;\n' +
+      'export const meta = { url: require("url").pathToFileURL(__filename).href };\n',
+  );
+  const preserve = oxc.transform('test.mjs', code, { importMeta: 'preserve' });
+  assert(preserve.errors.length == 0);
+  assert.equal(preserve.code, code);
+  assert.equal(oxc.transform('test.mjs', code).code, code);
+}
+
+{
+  const code = 'export const url = import.meta.url;\n';
+  const node = oxc.transform('test.mjs', code, { importMeta: 'commonjs', platform: 'node' });
+  assert(node.errors.length == 0);
+  assert.equal(node.code, 'export const url = require("url").pathToFileURL(__filename).href;\n');
+  const browser = oxc.transform('test.mjs', code, { importMeta: 'commonjs', platform: 'browser' });
+  assert(browser.errors.length == 0);
+  assert.equal(
+    browser.code,
+    'export const url = document.currentScript && document.currentScript.src || document.baseURI;\n',
+  );
+}
+
+{
+  const code = 'const el = <div />;\nconst x = /* @__PURE__ */ foo();\n';
+  const ret = oxc.transform('test.jsx', code, { react: { pure: true }, annotations: false });
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('__PURE__'), ret.code);
+  assert(!ret.code.includes('__NO_SIDE_EFFECTS__'), ret.code);
+}
+
+{
+  // `annotations: true` keeps annotations without `keepComments`, which controls other comments.
+  // By default, annotations are only kept along with other comments.
+  const code = '/** doc */\nconst a = /* @__PURE__ */ f();\n';
+  assert.equal(oxc.transform('test.js', code).code, 'const a = f();\n');
+  assert.equal(
+    oxc.transform('test.js', code, { annotations: true }).code,
+    'const a = /* @__PURE__ */ f();\n',
+  );
+  assert.equal(oxc.transform('test.js', code, { keepComments: true }).code, code);
+  assert.equal(
+    oxc.transform('test.js', code, { keepComments: true, annotations: false }).code,
+    '/** doc */\nconst a = f();\n',
+  );
+}
+
+{
+  const code = '@dec\nclass A {}\n';
+  const legacy = oxc.transform('test.mjs', code, {
+    decorator: { experimentalDecorators: true },
+  });
+  assert(legacy.errors.length == 0);
+  assert.equal(legacy.code, 'var _class;\nlet A = dec(_class = class A {}) || _class;\n');
+  const tc39 = oxc.transform('test.mjs', code, { decorator: {} });
+  assert(tc39.errors.length == 0);
+  assert.equal(
+    tc39.code,
+    `let _classDecs = [dec], _A, _initClass;
+class A {}
+[_A, _initClass] = babelHelpers.applyDecs2311(A, _classDecs, []).c;
+A = _A;
+_initClass();
+`,
+  );
+}
+
+{
+  // Legacy decorators on members and parameters are reported, and removed from the output
+  const code = '@dec\nclass A {\n  @prop x = 1;\n  @method m(@param a) {}\n}\n';
+  const ret = oxc.transform('test.mjs', code, { decorator: { experimentalDecorators: true } });
+  assert.equal(ret.errors.length, 1);
+  assert(ret.errors[0].includes('Decorators on class members and parameters are not supported yet.'));
+  assert.equal(
+    ret.code,
+    'var _class;\nlet A = dec(_class = class A {\n\tx = 1;\n\tm(a) {}\n}) || _class;\n',
+  );
+}
+
+{
+  const code = 'class A {\n  accessor x = 1;\n  static accessor y;\n}\n';
+  const ret = oxc.transform('test.js', code, { decorator: {} });
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'class A {\n' +
+      '\t#A = 1;\n' +
+      '\tget x() {\n\t\treturn this.#A;\n\t}\n' +
+      '\tset x(v) {\n\t\tthis.#A = v;\n\t}\n' +
+      '\tstatic #B;\n' +
+      '\tstatic get y() {\n\t\treturn this.#B;\n\t}\n' +
+      '\tstatic set y(v) {\n\t\tthis.#B = v;\n\t}\n' +
+      '}\n',
+  );
+  const A = new Function(`${ret.code}\nreturn A;`)();
+  const a = new A();
+  a.x = 2;
+  A.y = 3;
+  assert.deepEqual([a.x, A.y, Object.keys(a)], [2, 3, []]);
+}
+
+{
+  const options = { es2026: { explicitResourceManagement: true } };
+  const catchFinally = (indent, dispose) =>
+    `${indent}} catch (_) {\n${indent}\t_usingCtx.e = _;\n${indent}} finally {\n` +
+    `${indent}\t${dispose};\n${indent}}\n`;
+
+  let ret = oxc.transform('test.js', '{\n  using a = getA();\n  use(a);\n}\n', options);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    '{\n' +
+      '\ttry {\n' +
+      '\t\tvar _usingCtx = babelHelpers.usingCtx();\n' +
+      '\t\tconst a = _usingCtx.u(getA());\n' +
+      '\t\tuse(a);\n' +
+      catchFinally('\t', '_usingCtx.d()') +
+      '}\n',
+  );
+
+  ret = oxc.transform(
+    'test.js',
+    'function f() {\n  using a = getA(), b = getB();\n  using c = getC();\n  return a + b + c;\n}\n',
+    options,
+  );
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'function f() {\n' +
+      '\ttry {\n' +
+      '\t\tvar _usingCtx = babelHelpers.usingCtx();\n' +
+      '\t\tconst a = _usingCtx.u(getA()), b = _usingCtx.u(getB());\n' +
+      '\t\tconst c = _usingCtx.u(getC());\n' +
+      '\t\treturn a + b + c;\n' +
+      catchFinally('\t', '_usingCtx.d()') +
+      '}\n',
+  );
+
+  ret = oxc.transform(
+    'test.js',
+    'async function f() {\n  await using a = getA();\n  using b = getB();\n  use(a, b);\n}\n',
+    options,
+  );
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'async function f() {\n' +
+      '\ttry {\n' +
+      '\t\tvar _usingCtx = babelHelpers.usingCtx();\n' +
+      '\t\tconst a = _usingCtx.a(getA());\n' +
+      '\t\tconst b = _usingCtx.u(getB());\n' +
+      '\t\tuse(a, b);\n' +
+      catchFinally('\t', 'await _usingCtx.d()') +
+      '}\n',
+  );
+}
+
+{
+  const code = '#!/usr/bin/env node\n"use strict";\nconst el = <div />;\n';
+  let ret = oxc.transform('test.jsx', code, { sourceType: 'module' });
+  assert(ret.errors.length == 0);
+  assert(
+    ret.code.startsWith(
+      '#!/usr/bin/env node\n"use strict";\nimport { jsx as _jsx } from "react/jsx-runtime";\n',
+    ),
+    ret.code,
+  );
+  ret = oxc.transform('test.jsx', code, { sourceType: 'module', hashbang: false });
+  assert(ret.errors.length == 0);
+  assert(ret.code.startsWith('"use strict";\nimport { jsx as _jsx }'), ret.code);
+}
+
+{
+  const ret = oxc.transform('/home/user/repo/packages/a/src/index.ts', 'class A<T> {}', {
+    sourcemap: true,
+    stripSourceMapPrefix: '/home/user/repo/',
+  });
+  assert(ret.errors.length == 0);
+  assert.deepEqual(ret.map.sources, ['packages/a/src/index.ts']);
+}
+
+{
+  const code = 'const el = <div />;\n';
+  // Development output starts with `var _jsxFileName = ...`, so only check the import is present
+  const hasImport = (react, line) => {
+    const ret = oxc.transform('test.jsx', code, { sourceType: 'module', react });
+    assert(ret.errors.length == 0);
+    assert(ret.code.split('\n').includes(line), ret.code);
+  };
+  hasImport(
+    { importSource: 'preact', development: true },
+    'import { jsxDEV as _jsxDEV } from "preact/jsx-dev-runtime";',
+  );
+  hasImport({ importSource: 'preact' }, 'import { jsx as _jsx } from "preact/jsx-runtime";');
+  hasImport(
+    { importSource: '@emotion/react', development: true },
+    'import { jsxDEV as _jsxDEV } from "@emotion/react/jsx-dev-runtime";',
+  );
+}
+
+{
+  const code = `function f<const T>(x: T): T {
+  return x;
+}
+class A<in T> {}
+class B<out T> {}
+class C<in out T> {}
+type D<in out T> = (x: T) => T;
+const g = <const T,>(x: T) => x;
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'function f(x) {\n' +
+      '\treturn x;\n' +
+      '}\n' +
+      'class A {}\n' +
+      'class B {}\n' +
+      'class C {}\n' +
+      'const g = (x) => x;\n',
+  );
+}
+
+{
+  const code = `const b = (a as const).b;
+foo satisfies Bar;
+const c = a as T as U;
+const d = (a + b as number) * 2;
+const e = () => ({} satisfies object);
+(obj as any).x = 1;
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'const b = a.b;\n' +
+      'foo;\n' +
+      'const c = a;\n' +
+      'const d = (a + b) * 2;\n' +
+      'const e = () => ({});\n' +
+      'obj.x = 1;\n',
+  );
+}
+
+{
+  const code = `a!.b;
+arr![0];
+fn!();
+a!.b!.c;
+(a as T)!;
+a?.b!.c;
+x!.y = 1;
+count!++;
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'a.b;\n' +
+      'arr[0];\n' +
+      'fn();\n' +
+      'a.b.c;\n' +
+      'a;\n' +
+      'a?.b.c;\n' +
+      'x.y = 1;\n' +
+      'count++;\n',
+  );
+}
+
+{
+  const code = 'class A {\n  x!: number;\n  y = 1;\n}\nlet y!: T;\n';
+  let ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, 'class A {\n\tx;\n\ty = 1;\n}\nlet y;\n');
+  ret = oxc.transform('test.ts', code, { typescript: { allowDeclareFields: false } });
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, 'class A {\n\ty = 1;\n}\nlet y;\n');
+}
+
+{
+  const code = "import { A } from './a';\nimport type { B } from './b';\nlet x: A;\n";
+  const transform = (importsNotUsedAsValues) =>
+    oxc.transform('test.ts', code, { typescript: { importsNotUsedAsValues } });
+  let ret = transform('remove');
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, 'let x;\nexport {};\n');
+  ret = transform('preserve');
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, 'import "./a";\nlet x;\n');
+  ret = transform('error');
+  assert.equal(ret.errors.length, 1);
+  assert(ret.errors[0].includes("'importsNotUsedAsValues' is set to 'error'"), ret.errors[0]);
+  assert.equal(ret.code, 'import "./a";\nlet x;\n');
+}
+
+{
+  const code = `class A extends B {
+  constructor(public a: number, b: string, private readonly c = 1, protected d?: boolean) {
+    console.log('before');
+    super(b);
+    this.e = a + c;
+  }
+}
+class C {
+  constructor(readonly x: number) {}
+}
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'class A extends B {\n' +
+      '\tconstructor(a, b, c = 1, d) {\n' +
+      '\t\tconsole.log("before");\n' +
+      '\t\tsuper(b);\n' +
+      '\t\tthis.a = a;\n' +
+      '\t\tthis.c = c;\n' +
+      '\t\tthis.d = d;\n' +
+      '\t\tthis.e = a + c;\n' +
+      '\t}\n' +
+      '}\n' +
+      'class C {\n' +
+      '\tconstructor(x) {\n' +
+      '\t\tthis.x = x;\n' +
+      '\t}\n' +
+      '}\n',
+  );
+}
+
+{
+  const code = `class A extends B {
+  override method() {}
+  override get x() {
+    return 1;
+  }
+  override set x(v) {}
+  override y = 1;
+  static override z = 2;
+  constructor(override w: number) {
+    super();
+  }
+}
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes('override'), ret.code);
+  assert.equal(
+    ret.code,
+    'class A extends B {\n' +
+      '\tmethod() {}\n' +
+      '\tget x() {\n\t\treturn 1;\n\t}\n' +
+      '\tset x(v) {}\n' +
+      '\ty = 1;\n' +
+      '\tstatic z = 2;\n' +
+      '\tconstructor(w) {\n\t\tsuper();\n\t\tthis.w = w;\n\t}\n' +
+      '}\n',
+  );
+}
+
+{
+  const code = `function f(this: Foo, x: number, y?: string) {
+  return [this, x, y];
+}
+const g = function (this: void) {};
+class A {
+  m(this: A, x: number) {}
+}
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert(ret.code.startsWith('function f(x, y) {\n'), ret.code);
+  assert(ret.code.includes('const g = function() {};\n'), ret.code);
+  assert(ret.code.includes('\tm(x) {}\n'), ret.code);
+  const f = new Function(`${ret.code}\nreturn f;`)();
+  const self = {};
+  assert.deepEqual(f.call(self, 1, 'a'), [self, 1, 'a']);
+}
+
+{
+  const code = `export abstract class Shape {
+  abstract name: string;
+  abstract area(): number;
+  protected abstract get sides(): number;
+  describe() {
+    return this.name;
+  }
+}
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'export class Shape {\n\tdescribe() {\n\t\treturn this.name;\n\t}\n}\n',
+  );
+}
+
+{
+  const code = `class A {
+  [key: string]: unknown;
+  static [key: number]: string;
+  get(key: string): string;
+  get(key: number): number;
+  get(key: string | number) {
+    return key;
+  }
+  constructor(a: string);
+  constructor(a: number);
+  constructor(a: any) {}
+}
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'class A {\n' +
+      '\tget(key) {\n\t\treturn key;\n\t}\n' +
+      '\tconstructor(a) {}\n' +
+      '}\n',
+  );
+}
+
+{
+  let code = `declare const a: number;
+declare function b(): void;
+declare module 'm' {
+  export const c: number;
+}
+declare global {
+  interface Window {
+    d: string;
+  }
+}
+declare namespace E {
+  const e: number;
+}
+const keep = 1;
+export { keep };
+`;
+  let ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, 'const keep = 1;\nexport { keep };\n');
+
+  code = `const keep = 1;
+namespace N {
+  declare const x: number;
+  declare function f(): void;
+  export const y = keep + 1;
+}
+`;
+  ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert(!/declare|\bx\b|\bf\b/.test(ret.code), ret.code);
+  const N = new Function(`${ret.code}\nreturn N;`)();
+  assert.deepEqual(N, { y: 2 });
+}
+
+{
+  const code = 'declare const a: number;\nexport interface A {\n  a: string;\n}\n';
+  let ret = oxc.transform('types.d.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, '');
+  ret = oxc.transform('types.d.ts', code, { typescript: { declaration: {} } });
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, '');
+  assert(ret.declaration.includes('export interface A'), ret.declaration);
+}
+
+{
+  const code = "export const enum E {\n  A,\n  B = 'b',\n  C = 5,\n  'c-d' = 6,\n}\n" +
+    'const enum F {\n  X = E.C + 1,\n}\n';
+  const objects = 'export let E = {\n\tA: 0,\n\tB: "b",\n\tC: 5,\n\t"c-d": 6\n};\n' +
+    'var F = { X: 6 };\n';
+  const transform = (typescript) => oxc.transform('test.ts', code, { typescript });
+
+  let ret = transform({});
+  assert(ret.errors.length == 0);
+  assert(ret.code.includes('E[E["A"] = 0] = "A";'), ret.code);
+
+  ret = transform({ optimizeConstEnums: true });
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, objects);
+
+  ret = transform({ isolatedModules: true });
+  assert.equal(ret.errors.length, 1);
+  assert(ret.errors[0].includes("Exported const enums are not supported with 'isolatedModules'"));
+  assert(ret.code.includes('E[E["A"] = 0] = "A";'), ret.code);
+
+  ret = transform({ optimizeConstEnums: true, isolatedModules: true });
+  assert.equal(ret.errors.length, 1);
+  assert.equal(ret.code, objects);
+}
+
+{
+  const code = 'import foo = require("foo");\nimport bar = foo.bar;\nexport = { foo, bar };\n';
+  const expected = 'const foo = require("foo");\n' +
+    'var bar = foo.bar;\n' +
+    'module.exports = {\n\tfoo,\n\tbar\n};\n';
+  let ret = oxc.transform('test.ts', code, { sourceType: 'script' });
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, expected);
+  // Only supported when compiling to CommonJS, so ES modules get a warning for each.
+  ret = oxc.transform('test.ts', code, { sourceType: 'module' });
+  assert.equal(ret.errors.length, 2);
+  assert.equal(ret.code, expected);
+}
+
+{
+  const code = `import { type A, B } from 'a';
+import { C as D } from 'c';
+import type * as NS from 'ns';
+import { E } from 'e';
+import { F } from 'f';
+import * as G from 'g';
+let a: A = B;
+let d: D;
+let ns: NS.T;
+let e: typeof E;
+let f: typeof F.x;
+let g: G.T = G.value;
+`;
+  const ret = oxc.transform('test.ts', code);
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'import { B } from "a";\n' +
+      'import * as G from "g";\n' +
+      'let a = B;\n' +
+      'let d;\n' +
+      'let ns;\n' +
+      'let e;\n' +
+      'let f;\n' +
+      'let g = G.value;\n',
+  );
+}
+
+for (const code of ['', '\n\n', '   \t\n', '// only a comment\n', '/* block */\n']) {
+  for (const filename of ['test.js', 'test.ts']) {
+    const ret = oxc.transform(filename, code, { sourcemap: true });
+    assert.deepEqual(ret.errors, [], JSON.stringify(code));
+    assert.equal(ret.code, '', JSON.stringify(code));
+    assert.equal(ret.map.version, 3);
+    assert.equal(ret.map.mappings, '');
+    assert.deepEqual(ret.map.names, []);
+    assert(Array.isArray(ret.map.sources));
+  }
+}
+
+{
+  const code = 'export const a: number = 1;\n';
+  const ret = oxc.transform('test.ts', code, {
+    sourcemap: true,
+    typescript: { declaration: {}, emitDeclarationOnly: true },
+  });
+  assert(ret.errors.length == 0);
+  assert.equal(ret.code, '');
+  assert.equal(ret.map, undefined);
+  assert.equal(ret.declaration, 'export declare const a: number;\n');
+  assert.deepEqual(ret.declarationMap.sources, ['test.ts']);
+  assert.notEqual(ret.declarationMap.mappings, '');
+}
+
+{
+  // Without a filename
+  const ret = oxc.transform(undefined, 'const a = 1;', { sourcemap: true });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.code, 'const a = 1;\n');
+  assert.deepEqual(ret.map.sources, ['unknown']);
+
+  // Language set explicitly
+  const ts = oxc.transform(null, 'const a: number = 1;', { lang: 'ts' });
+  assert.deepEqual(ts.errors, []);
+  assert.equal(ts.code, 'const a = 1;\n');
+}
+
+{
+  // `TransformResult` type declarations match the returned object.
+  const dts = fs.readFileSync(new URL('./index.d.ts', import.meta.url), 'utf8');
+  const body = dts.match(/export interface TransformResult \{([\s\S]*?)\n\}/)[1];
+  const fields = Object.fromEntries(
+    [...body.matchAll(/^  (\w+)(\??): (.+)$/gm)].map(([, name, optional, type]) => [
+      name,
+      `${optional}${type}`,
+    ]),
+  );
+  assert.deepEqual(fields, {
+    code: 'string',
+    map: '?SourceMap',
+    declaration: '?string',
+    declarationMap: '?SourceMap',
+    moduleType: "'module' | 'script'",
+    errors: 'Array<string>',
+  });
+
+  const ret = oxc.transform('test.ts', 'export const a: number = 1;', {
+    sourcemap: true,
+    typescript: { declaration: {} },
+  });
+  assert.deepEqual(Object.keys(ret).sort(), Object.keys(fields).sort());
+  const js = oxc.transform('test.js', 'const a = 1;');
+  assert.equal(js.map, undefined);
+  assert.equal(js.declaration, undefined);
+  assert.equal(js.declarationMap, undefined);
+}
+
+{
+  const code = 'const list = items.map((x) => <li>{x}</li>);';
+  let ret = oxc.transform('test.jsx', code, { react: { warnMissingKeys: true } });
+  assert.equal(ret.errors.length, 1);
+  assert(ret.errors[0].includes('Missing "key" prop for element in iterator.'), ret.errors[0]);
+  // Labelled at the `<li>` element
+  assert(ret.errors[0].includes('test.jsx:1:31'), ret.errors[0]);
+
+  ret = oxc.transform('test.jsx', 'items.map((x) => <li key={x}>{x}</li>);', {
+    react: { warnMissingKeys: true },
+  });
+  assert.deepEqual(ret.errors, []);
+
+  // Opt-in
+  ret = oxc.transform('test.jsx', code);
+  assert.deepEqual(ret.errors, []);
+}
+
+{
+  // Helpers and imports are injected in a deterministic order.
+  const code = `
+class A {
+  #x = 1;
+  static #y = 2;
+  get() { return [this.#x, A.#y]; }
+  set(v) { this.#x = v; }
+}
+export const el = <><div {...props} /><span /></>;
+`;
+  const options = { es2022: { classProperties: {} }, react: { runtime: 'automatic' } };
+  const first = oxc.transform('test.jsx', code, options);
+  assert.deepEqual(first.errors, []);
+  const helpers = ['classPrivateFieldInitSpec', 'classPrivateFieldGet2', 'classPrivateFieldSet2'];
+  for (const helper of helpers) {
+    assert(first.code.includes(`babelHelpers.${helper}`), first.code);
+  }
+  assert(first.code.includes('from "react/jsx-runtime";'), first.code);
+  const second = oxc.transform('test.jsx', code, options);
+  assert.equal(second.code, first.code);
+}
+
+{
+  // React Fast Refresh registers each exported component
+  const code = `
+export function Foo() {
+  return <div />;
+}
+export const Bar = () => <span />;
+`;
+  const ret = oxc.transform('test.jsx', code, { react: { refresh: {} } });
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('}\n_c = Foo;\n'), ret.code);
+  assert(ret.code.includes('export const Bar = () => _jsx("span", {});\n_c2 = Bar;\n'), ret.code);
+  assert(
+    ret.code.endsWith('var _c, _c2;\n$RefreshReg$(_c, "Foo");\n$RefreshReg$(_c2, "Bar");\n'),
+    ret.code,
+  );
+}
+
+{
+  // Re-exports as and from `default` are kept in ES module output
+  const code = 'const x: number = 1;\nexport { x as default };\nexport { default as y } from "y";\n';
+  const ret = oxc.transform('test.ts', code);
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    'const x = 1;\nexport { x as default };\nexport { default as y } from "y";\n',
+  );
+}
+
+{
+  // tsconfig-style `jsxFactory` / `jsxFragmentFactory`
+  const code = 'import { h, Fragment } from "preact";\nexport const a = <><div /></>;\n';
+  const ret = oxc.transform('test.tsx', code, {
+    typescript: { jsxFactory: 'h', jsxFragmentFactory: 'Fragment' },
+    react: { runtime: 'classic' },
+  });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    'import { h, Fragment } from "preact";\nexport const a = h(Fragment, null, h("div", null));\n',
+  );
+}
+
+{
+  const code = 'const enum E { A, B = "b" }\nconsole.log(E.A, E["B"]);\n';
+  const enumObject = 'var E = function(E) {\n' +
+    '\tE[E["A"] = 0] = "A";\n' +
+    '\tE["B"] = "b";\n' +
+    '\treturn E;\n' +
+    '}(E || {});\n';
+  let ret = oxc.transform('test.ts', code);
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.code, enumObject + 'console.log(E.A, E["B"]);\n');
+
+  ret = oxc.transform('test.ts', code, { typescript: { preserveConstEnums: true } });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.code, enumObject + 'console.log(0, "b");\n');
+}
+
+{
+  const code = 'const a: number = 1;\nconst b = ;\nconst c: number = 3;\n';
+  let ret = oxc.transform('test.ts', code);
+  assert.equal(ret.errors.length, 1);
+  assert.equal(ret.code, '');
+
+  // Statements around the broken one are still transformed
+  ret = oxc.transform('test.ts', code, { recover: true });
+  assert.equal(ret.errors.length, 2);
+  assert(ret.errors[0].includes('test.ts:2:'), ret.errors[0]);
+  assert(ret.errors[1].includes('Line skipped because of a syntax error'), ret.errors[1]);
+  assert.equal(ret.code, 'const a = 1;\nconst c = 3;\n');
+
+  // Valid statements on a skipped line are reported as missing
+  ret = oxc.transform('test.ts', 'const a = 1; const b = ; const c = 3;\nconst d = 4;\n', { recover: true });
+  assert.equal(ret.errors.length, 2);
+  assert(ret.errors[0].includes('test.ts:1:'), ret.errors[0]);
+  assert(ret.errors[1].includes('Line skipped because of a syntax error'), ret.errors[1]);
+  assert(ret.errors[1].includes('const a = 1; const b = ; const c = 3;'), ret.errors[1]);
+  assert.equal(ret.code, 'const d = 4;\n');
+}
+
+{
+  // Spread children
+  const code = 'const a = <C>{...items}</C>;\nconst b = <C><a />{...items}</C>;\n';
+  let ret = oxc.transform('test.jsx', code);
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    'import { jsx as _jsx } from "react/jsx-runtime";\n' +
+      'const a = _jsx(C, { children: [...items] });\n' +
+      'const b = _jsx(C, { children: [_jsx("a", {}), ...items] });\n',
+  );
+
+  ret = oxc.transform('test.jsx', code, { react: { runtime: 'classic' } });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    'const a = React.createElement(C, null, ...items);\n' +
+      'const b = React.createElement(C, null, React.createElement("a", null), ...items);\n',
+  );
+}
+
+{
+  // `jsx` for single or dynamic children, `jsxs` for multiple static children
+  const code = 'const a = <C><a /></C>;\nconst b = <C><a /><b /></C>;\nconst c = <C>{...items}</C>;\n';
+  const ret = oxc.transform('test.jsx', code);
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    'import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";\n' +
+      'const a = _jsx(C, { children: _jsx("a", {}) });\n' +
+      'const b = _jsxs(C, { children: [_jsx("a", {}), _jsx("b", {})] });\n' +
+      'const c = _jsx(C, { children: [...items] });\n',
+  );
+}
+
+{
+  // `key` is passed as the third argument of `jsx`, not in props
+  const ret = oxc.transform('test.jsx', 'const a = <C key={k} a={1} />;\n');
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    'import { jsx as _jsx } from "react/jsx-runtime";\nconst a = _jsx(C, { a: 1 }, k);\n',
+  );
+}
+
+{
+  // Development `__source.fileName` is relative to `cwd`
+  const options = { cwd: '/project', react: { development: true } };
+  let ret = oxc.transform('/project/src/App.jsx', '<div />;\n', options);
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.startsWith('var _jsxFileName = "src/App.jsx";\n'), ret.code);
+
+  // Files outside of `cwd` keep their path
+  ret = oxc.transform('/other/App.jsx', '<div />;\n', options);
+  assert(ret.code.startsWith('var _jsxFileName = "/other/App.jsx";\n'), ret.code);
+}
+
+{
+  // JSX in a `.ts` file
+  let ret = oxc.transform('test.ts', 'const a = <div>hi</div>;\n');
+  assert(ret.errors.length > 0);
+  assert(ret.errors[0].includes('JSX is not allowed in `.ts` files.'), ret.errors[0]);
+  assert(ret.errors[0].includes('Rename the file to `.tsx`'), ret.errors[0]);
+
+  // Type assertions are not reported as JSX
+  ret = oxc.transform('test.ts', 'const a = <number>b;\nconst c = <div>;\n');
+  assert(ret.errors.length > 0);
+  assert(ret.errors.every((error) => !error.includes('JSX is not allowed')), ret.errors[0]);
+}
+
+{
+  // BigInt literals cannot be transformed for targets before ES2020
+  let ret = oxc.transform('test.js', 'const a = 10n;\n', { target: 'es2015' });
+  assert.equal(ret.errors.length, 1);
+  assert(ret.errors[0].includes('BigInt literals are not supported'), ret.errors[0]);
+  assert.equal(ret.code, 'const a = 10n;\n');
+
+  ret = oxc.transform('test.js', 'const a = 10n;\n', { target: 'esnext' });
+  assert.deepEqual(ret.errors, []);
+}
+
+{
+  // Comments above removed statements are kept on the following statement
+  const code = 'const a = 0;\n/** Comment */\nimport type { A } from "a";\nconst b = 1;\n';
+  let ret = oxc.transform('test.ts', code, { keepComments: true });
+  assert.deepEqual(ret.errors, []);
+  // `export {}` keeps the output an ES module after the only import is removed
+  assert.equal(ret.code, 'const a = 0;\n/** Comment */\nconst b = 1;\nexport {};\n');
+
+  ret = oxc.transform('test.ts', '/** A */\ninterface A {}\n/** B */\ntype B = A;\nlet c;\n', {
+    keepComments: true,
+  });
+  assert.equal(ret.code, '/** A */\n/** B */\nlet c;\n');
+
+  ret = oxc.transform('test.ts', code);
+  assert.equal(ret.code, 'const a = 0;\nconst b = 1;\nexport {};\n');
+}
+
+{
+  // `mode: 'strip'` only erases types, keeping the position of all other code
+  const code = [
+    "import type { A } from './a';",
+    "import { b, type C } from './b';",
+    'interface D { x: number }',
+    'export function f<T>(a: T, c?: C): T {',
+    '  return (a as T)!;',
+    '}',
+    'abstract class E implements D {',
+    '  private x: number = 1;',
+    '  abstract g(): void;',
+    '}',
+    '',
+  ].join('\n');
+  let ret = oxc.transform('test.ts', code, { mode: 'strip', sourcemap: true });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.map, undefined);
+  assert.equal(ret.code.length, code.length);
+  assert.deepEqual(
+    ret.code.split('\n').map((line) => line.trim().replace(/\s+/g, ' ')),
+    [
+      '',
+      "import { b, } from './b';",
+      '',
+      'export function f (a , c ) {',
+      'return (a ) ;',
+      '}',
+      'class E {',
+      'x = 1;',
+      '',
+      '}',
+      '',
+    ],
+  );
+  assert.equal(ret.code.indexOf('return'), code.indexOf('return'));
+
+  // A line break is not allowed before `=>`, so `)` is moved after a multi-line return type
+  ret = oxc.transform('test.ts', 'const f = (a): Array<\n  string\n> => [a];\n', { mode: 'strip' });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.code, 'const f = (a         \n        \n) => [a];\n');
+  ret = oxc.transform('test.ts', 'const g = (a): string => a;\n', { mode: 'strip' });
+  assert.equal(ret.code, 'const g = (a)         => a;\n');
+
+  ret = oxc.transform('test.ts', 'enum A { B }\n', { mode: 'strip' });
+  assert.equal(ret.errors.length, 1);
+  assert(ret.errors[0].includes('Enum is not supported when only stripping types.'), ret.errors[0]);
+}
+
+{
+  // Declarations of JavaScript files from JSDoc types
+  const code = [
+    '/**',
+    ' * Add two numbers.',
+    ' * @param {number} a',
+    ' * @param {number} [b]',
+    ' * @returns {number}',
+    ' */',
+    'export function add(a, b) {',
+    '  return a + (b ?? 0);',
+    '}',
+    '/** @type {string[]} */',
+    'export const names = [];',
+    '',
+  ].join('\n');
+  const ret = oxc.isolatedDeclaration('test.js', code, { jsdocTypes: true });
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('export declare function add(a: number, b?: number): number;'), ret.code);
+  assert(ret.code.includes('export declare const names: string[];'), ret.code);
+}
+
+{
+  // `setPublicClassFields` assumption
+  const code = 'class A {\n  x = 1;\n  static y = 2;\n}\n';
+  const options = { es2022: { classProperties: {} } };
+  test(oxc.transform('test.js', code, options), {
+    code: 'class A {\n' +
+      '\tconstructor() {\n' +
+      '\t\tbabelHelpers.defineProperty(this, "x", 1);\n' +
+      '\t}\n' +
+      '}\n' +
+      'babelHelpers.defineProperty(A, "y", 2);\n',
+  });
+  test(oxc.transform('test.js', code, { ...options, assumptions: { setPublicClassFields: true } }), {
+    code: 'class A {\n' +
+      '\tconstructor() {\n' +
+      '\t\tthis.x = 1;\n' +
+      '\t}\n' +
+      '}\n' +
+      'A.y = 2;\n',
+  });
+}
+
+{
+  // `noDocumentAll` assumption
+  const code = 'const a = b ?? c;\n';
+  const options = { es2020: { nullishCoalescingOperator: true } };
+  let ret = oxc.transform('test.js', code, options);
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('(_b = b) !== null && _b !== void 0 ? _b : c'), ret.code);
+
+  ret = oxc.transform('test.js', code, { ...options, assumptions: { noDocumentAll: true } });
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('(_b = b) != null ? _b : c'), ret.code);
+}
+
+{
+  // `pureGetters` assumption
+  const code = 'a.b ??= c;\n';
+  const options = { es2021: { logicalAssignmentOperators: true } };
+  let ret = oxc.transform('test.js', code, options);
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('(_a = a).b ?? (_a.b = c)'), ret.code);
+
+  ret = oxc.transform('test.js', code, { ...options, assumptions: { pureGetters: true } });
+  assert.deepEqual(ret.errors, []);
+  assert(!ret.code.includes('_a'), ret.code);
+  assert(ret.code.includes('a.b ?? (a.b = c)'), ret.code);
+}
+
+{
+  // `sourcemapSegments` returns the decoded form of `mappings`
+  function decodeMappings(mappings) {
+    const chars = 'ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/';
+    const state = [0, 0, 0, 0, 0];
+    return mappings.split(';').map((line) => {
+      state[0] = 0;
+      return line.split(',').filter(Boolean).map((encoded) => {
+        const values = [];
+        let value = 0;
+        let shift = 0;
+        for (const char of encoded) {
+          const digit = chars.indexOf(char);
+          value += (digit & 31) << shift;
+          shift += 5;
+          if (!(digit & 32)) {
+            values.push(value & 1 ? -(value >>> 1) : value >>> 1);
+            value = 0;
+            shift = 0;
+          }
+        }
+        return values.map((delta, i) => (state[i] += delta));
+      });
+    });
+  }
+
+  const code = 'const a: number = 1;\nfunction foo(b: string) {\n  return b + a;\n}\n';
+  const encoded = oxc.transform('test.ts', code, { sourcemap: true });
+  const decoded = oxc.transform('test.ts', code, { sourcemap: true, sourcemapSegments: true });
+  assert.deepEqual(decoded.errors, []);
+  assert.equal(decoded.code, encoded.code);
+  assert.equal(decoded.map.mappings, '');
+  assert.deepEqual(decoded.map.sources, encoded.map.sources);
+  assert.deepEqual(decoded.map.names, encoded.map.names);
+  const expected = decodeMappings(encoded.map.mappings);
+  // Trailing lines without segments are not encoded
+  while (expected.length > decoded.map.segments.length) {
+    assert.deepEqual(expected.pop(), []);
+  }
+  assert.deepEqual(decoded.map.segments, expected);
+}
+
+{
+  // `importAttributesKeyword`, with `a` used so its import is not removed
+  const withCode = 'import a from "./a.json" with { type: "json" };\n' +
+    'export * from "./b.json" with { type: "json" };\nconsole.log(a);\n';
+  const assertCode = 'import a from "./a.json" assert { type: "json" };\n' +
+    'export * from "./b.json" assert { type: "json" };\nconsole.log(a);\n';
+  const keywords = (code) => code.match(/\b(with|assert) \{/g);
+
+  let ret = oxc.transform('test.js', assertCode, { importAttributesKeyword: 'with' });
+  assert.deepEqual(ret.errors, []);
+  assert.deepEqual(keywords(ret.code), ['with {', 'with {'], ret.code);
+
+  ret = oxc.transform('test.js', withCode, { importAttributesKeyword: 'assert' });
+  assert.deepEqual(ret.errors, []);
+  assert.deepEqual(keywords(ret.code), ['assert {', 'assert {'], ret.code);
+
+  for (const [code, keyword] of [[withCode, 'with {'], [assertCode, 'assert {']]) {
+    for (const options of [{ importAttributesKeyword: 'preserve' }, {}]) {
+      ret = oxc.transform('test.js', code, options);
+      assert.deepEqual(ret.errors, []);
+      assert.deepEqual(keywords(ret.code), [keyword, keyword], ret.code);
+    }
+  }
+}
+
+{
+  // `moduleType` reflects the source type resolved by `sourceType: 'unambiguous'`
+  const options = { sourceType: 'unambiguous' };
+  let ret = oxc.transform('test.js', 'import a from "a";\nconsole.log(a);\n', options);
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.moduleType, 'module');
+
+  ret = oxc.transform('test.js', 'const a = require("a");\nmodule.exports = a;\n', options);
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.moduleType, 'script');
+
+  assert.equal(oxc.transform('test.cjs', 'module.exports = 1;\n').moduleType, 'script');
+  assert.equal(oxc.transform('test.mjs', 'export default 1;\n').moduleType, 'module');
+}
+
+{
+  // `[hash]` in React Refresh registration names is derived from the filename
+  const code = 'export const App = () => <div />;\n';
+  const options = { react: { refresh: { refreshReg: '$RefreshReg$__[hash]', refreshSig: '$RefreshSig$__[hash]' } } };
+  const a = oxc.transform('a.jsx', code, options);
+  assert.deepEqual(a.errors, []);
+  const [, hash] = a.code.match(/\$RefreshReg\$__([0-9a-f]{8})\(/) ?? [];
+  assert(hash, a.code);
+  assert(!a.code.includes('[hash]'), a.code);
+
+  const b = oxc.transform('b.jsx', code, options);
+  assert(b.code.includes('$RefreshReg$__'), b.code);
+  assert(!b.code.includes(`$RefreshReg$__${hash}`), b.code);
+  assert.equal(oxc.transform('a.jsx', code, options).code, a.code);
+}
+
+{
+  // `impureFunctions` removes `@__PURE__` annotations of listed calls
+  const code = 'const a = /* @__PURE__ */ track();\n' +
+    'const b = /* @__PURE__ */ console.log("b");\n' +
+    'const c = /* @__PURE__ */ create();\n';
+  let ret = oxc.transform('test.js', code, { keepComments: true });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(ret.code.match(/@__PURE__/g).length, 3, ret.code);
+
+  ret = oxc.transform('test.js', code, {
+    keepComments: true,
+    impureFunctions: ['track', 'console.log'],
+  });
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('const a = track();'), ret.code);
+  assert(ret.code.includes('const b = console.log("b");'), ret.code);
+  assert(ret.code.includes('const c = /* @__PURE__ */ create();'), ret.code);
+
+  // Also without `keepComments`
+  ret = oxc.transform('test.js', code, { annotations: true, impureFunctions: ['track'] });
+  assert.deepEqual(ret.errors, []);
+  assert(ret.code.includes('const a = track();'), ret.code);
+  assert(ret.code.includes('const c = /* @__PURE__ */ create();'), ret.code);
+}
+
+{
+  // `warnUnsupportedSyntax` reports syntax newer than the target which was not transformed
+  const code = 'var a = b ?? c;\n';
+  const es2020 = { nullishCoalescingOperator: false };
+  let ret = oxc.transform('test.js', code, { target: 'es2015', warnUnsupportedSyntax: true, es2020 });
+  assert.equal(ret.errors.length, 1, ret.errors);
+  assert(ret.errors[0].includes('The nullish coalescing operator (`??`) is not supported'), ret.errors[0]);
+  assert(ret.errors[0].includes('ES2020'), ret.errors[0]);
+  assert.equal(ret.code, code);
+
+  // Not reported when transformed, when not opted in, or when the target supports it.
+  // The target enables the transform, unless it is disabled explicitly.
+  ret = oxc.transform('test.js', code, { target: 'es2015', warnUnsupportedSyntax: true });
+  assert.deepEqual(ret.errors, []);
+  assert(!ret.code.includes('??'), ret.code);
+  assert.deepEqual(oxc.transform('test.js', code, { target: 'es2015', es2020 }).errors, []);
+  ret = oxc.transform('test.js', code, { target: 'es2020', warnUnsupportedSyntax: true });
+  assert.deepEqual(ret.errors, []);
+}
+
+{
+  // `resolveImportSource` warns when the JSX runtime cannot be resolved
+  const code = 'const el = <div />;\n';
+  const react = { importSource: 'oxc-missing-jsx-source' };
+  let ret = oxc.transform('test.jsx', code, { react: { ...react, resolveImportSource: true } });
+  assert(ret.code.includes('from "oxc-missing-jsx-source/jsx-runtime"'), ret.code);
+  assert.equal(ret.errors.length, 1, ret.errors);
+  assert(
+    ret.errors[0].includes('Cannot resolve the JSX runtime `oxc-missing-jsx-source/jsx-runtime`'),
+    ret.errors[0],
+  );
+
+  ret = oxc.transform('test.jsx', code, { react });
+  assert(ret.code.includes('from "oxc-missing-jsx-source/jsx-runtime"'), ret.code);
+  assert.deepEqual(ret.errors, []);
+
+  // Files without JSX do not import the runtime
+  ret = oxc.transform('test.jsx', 'const a = 1;\n', { react: { ...react, resolveImportSource: true } });
+  assert.deepEqual(ret.errors, []);
+}
+
+{
+  // Top-level `this` of ES modules is `undefined`, so replace it when converting to CommonJS
+  const code = [
+    'export const a = this;',
+    'const b = () => this;',
+    'function c() {',
+    '\treturn this;',
+    '}',
+    'class D {',
+    '\t[this.key] = this;',
+    '}',
+    '',
+  ].join('\n');
+  const ret = oxc.transform('test.mjs', code, { replaceTopLevelThis: true });
+  assert.deepEqual(ret.errors, []);
+  assert.equal(
+    ret.code,
+    [
+      'export const a = void 0;',
+      'const b = () => void 0;',
+      'function c() {',
+      '\treturn this;',
+      '}',
+      'class D {',
+      '\t[(void 0).key] = this;',
+      '}',
+      '',
+    ].join('\n'),
+  );
+
+  // Kept by default
+  assert.equal(oxc.transform('test.mjs', code).code, code);
+  // Not replaced by `importMeta`
+  assert.equal(oxc.transform('test.mjs', code, { importMeta: 'commonjs' }).code, code);
+  // Only ES modules, top-level `this` of scripts is kept
+  assert.equal(oxc.transform('test.cjs', 'const a = this;\n', { replaceTopLevelThis: true }).code, 'const a = this;\n');
+}
+
+{
+  // Semicolons are only kept where the next statement would continue the previous one
+  const code = 'const a = b;\n[a, b] = [b, a];\nf();\n(g || h)();\n';
+  const ret = oxc.transform('test.js', code, { semicolons: 'asi' });
+  assert.equal(ret.code, 'const a = b\n;[a, b] = [b, a]\nf()\n;(g || h)()\n');
+  assert.equal(oxc.transform('test.js', code).code, code);
+}
+
+{
+  // Methods are decorated before fields. Initializers added by method decorators run before
+  // the first field, and those added by field decorators run before the next field.
+  const code = `class A {
+  @field a = 1;
+  @log('m') m() {}
+  b = 2;
+  @field c;
+}
+`;
+  const ret = oxc.transform('test.js', code, { decorator: {} });
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    `let _dec = log("m"), _init_a, _init_extra_a, _init_c, _init_extra_c, _initProto;
+class A {
+	constructor() {
+		_init_extra_c(this);
+	}
+	static {
+		[_init_a, _init_extra_a, _init_c, _init_extra_c, _initProto] = babelHelpers.applyDecs2311(this, [], [
+			[
+				_dec,
+				2,
+				"m"
+			],
+			[
+				field,
+				0,
+				"a"
+			],
+			[
+				field,
+				0,
+				"c"
+			]
+		]).e;
+	}
+	a = (_initProto(this), _init_a(this, 1));
+	m() {}
+	b = (_init_extra_a(this), 2);
+	c = _init_c(this);
+}
+`,
+  );
+}
+
+{
+  const code = '<div key="a">foo<br /></div>;\n';
+  const react = oxc.transform('test.jsx', code);
+  assert.equal(
+    react.code,
+    'import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";\n' +
+      '_jsxs("div", { children: ["foo", _jsx("br", {})] }, "a");\n',
+  );
+  const compat = oxc.transform('test.jsx', code, { react: { reactRuntime: 'compat' } });
+  assert.equal(
+    compat.code,
+    'import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";\n' +
+      '_jsxs("div", { key: "a" }, ["foo", _jsx("br", {})]);\n',
+  );
+}
+
+{
+  // `file` of source maps is the name of the output file
+  const code = 'const a: number = 1;\n';
+  const ts = oxc.transform('src/foo.ts', code, { sourcemap: true });
+  assert.equal(ts.map.file, 'foo.js');
+  const mts = oxc.transform('src/foo.mts', code, { sourcemap: true });
+  assert.equal(mts.map.file, 'foo.mjs');
+  const js = oxc.transform('src/foo.js', 'const a = 1;\n', { sourcemap: true });
+  assert.equal(js.map.file, 'foo.js');
+  const file = oxc.transform('src/foo.ts', code, { sourcemap: true, sourcemapFile: 'bundle.js' });
+  assert.equal(file.map.file, 'bundle.js');
+}
+
+// Test `jsxDEV` receives `this` as the sixth (`self`) argument in development mode
+{
+  const code = `
+class C {
+  render() {
+    return <div />;
+  }
+}
+export const args = new C().render();
+`;
+  const ret = oxc.transform('test.jsx', code, { sourceType: 'module', react: { development: true } });
+  assert(ret.errors.length == 0);
+  assert(/_jsxDEV\("div", \{\}, void 0, false, \{[^]*\}, this\)/.test(ret.code), ret.code);
+  const body = ret.code
+    .split('\n')
+    .filter((line) => !line.startsWith('import '))
+    .join('\n')
+    .replace('export const args', 'const args');
+  const args = new Function('_jsxDEV', `${body}\nreturn args;`)((...args) => args);
+  assert.equal(args.length, 6);
+  assert.equal(args[0], 'div');
+  assert.equal(args[2], undefined);
+  assert.equal(args[3], false);
+  assert.equal(typeof args[4].fileName, 'string');
+  assert.equal(args[4].lineNumber, 4);
+  assert.equal(args[5].constructor.name, 'C');
+}
+
+// Test arrow functions are lowered for `target: 'es5'` unless `arrowFunction` is set
+{
+  const code = 'const f = (a) => a + 1;\n';
+  const es5 = oxc.transform('test.js', code, { target: 'es5' });
+  assert(es5.errors.length == 0);
+  assert(!es5.code.includes('=>'), es5.code);
+  assert(es5.code.includes('function'), es5.code);
+
+  const es2020 = oxc.transform('test.js', code, { target: 'es2020' });
+  assert(es2020.errors.length == 0);
+  assert.equal(es2020.code, 'const f = (a) => a + 1;\n');
+
+  const disabled = oxc.transform('test.js', code, { target: 'es5', es2015: { arrowFunction: false } });
+  assert.equal(disabled.code, 'const f = (a) => a + 1;\n');
+
+  const enabled = oxc.transform('test.js', code, { target: 'es2020', es2015: { arrowFunction: true } });
+  assert(!enabled.code.includes('=>'), enabled.code);
+
+  const spec = oxc.transform('test.js', 'const f = () => this;\n', {
+    target: 'es5',
+    es2015: { arrowFunction: { spec: true } },
+  });
+  assert.equal(spec.errors.length, 1);
+  assert(spec.errors[0].includes('`spec` option'), spec.errors[0]);
+}
+
+// Test `jsxPragma` imports are not retained with the automatic runtime
+{
+  const code = "import React from 'react';\nconst el = <><div /></>;\n";
+  const typescript = { jsxPragma: 'React.createElement', jsxPragmaFrag: 'React.Fragment' };
+  const automatic = oxc.transform('test.tsx', code, { typescript, react: { runtime: 'automatic' } });
+  assert(automatic.errors.length == 0);
+  assert(!automatic.code.includes('import React'), automatic.code);
+  assert(automatic.code.includes('react/jsx-runtime'), automatic.code);
+
+  const classic = oxc.transform('test.tsx', code, { typescript, react: { runtime: 'classic' } });
+  assert(classic.errors.length == 0);
+  assert(classic.code.includes('import React from "react";'), classic.code);
+
+  const pragma = oxc.transform('test.tsx', `/** @jsxRuntime classic */\n${code}`, {
+    typescript,
+    react: { runtime: 'automatic' },
+  });
+  assert(pragma.code.includes('import React from "react";'), pragma.code);
+}
+
+// Test `verify` parses and checks the output again, and reports output which is not valid JavaScript
+{
+  const inputs = [
+    ['test.js', 'const f = (a) => a ?? 1;\n', {}],
+    ['test.ts', 'enum E { A }\nnamespace N { export const x = 1; }\nclass C { constructor(private a: number) {} }\n', {}],
+    ['test.tsx', 'export default function App() { return <><div key="a" /></>; }\n', {}],
+    ['test.jsx', 'const el = <div {...props} />;\n', { react: { runtime: 'classic' } }],
+    ['test.js', 'label: for (const x of xs) { if (x) continue label; }\n', { target: 'es5', es2015: { forOf: {} } }],
+    ['test.ts', 'let x = <number> y;\nlet z = a!;\n', { semicolons: 'asi' }],
+  ];
+  for (const [filename, code, options] of inputs) {
+    const ret = oxc.transform(filename, code, { ...options, verify: true });
+    assert.deepEqual(ret.errors, [], `${filename}: ${code}\n${ret.code}`);
+  }
+
+  // Strip mode leaves enums in place, which is not valid JavaScript
+  const ret = oxc.transform('test.ts', 'enum E { A }\n', { mode: 'strip', verify: true });
+  assert(ret.errors.some((e) => e.includes('Transformed output is not valid JavaScript')), ret.errors.join('\n'));
+  const unverified = oxc.transform('test.ts', 'enum E { A }\n', { mode: 'strip' });
+  assert(!unverified.errors.some((e) => e.includes('Transformed output is not valid JavaScript')));
+
+  // Early errors are only reported by semantic analysis, after the output parses
+  const duplicate = oxc.transform('test.ts', 'let x: number;\nfunction x() {}\n', { mode: 'strip', verify: true });
+  assert.equal(duplicate.code, 'let x        ;\nfunction x() {}\n');
+  assert(
+    duplicate.errors.some((e) => e.includes('Transformed output is not valid JavaScript: Identifier `x` has already')),
+    duplicate.errors.join('\n'),
+  );
+}
+
 console.log('Success.');
@@ -24,7 +24,7 @@ fn bench_isolated_declarations(criterion: &mut Criterion) {
                 &allocator,
                 source_text,
                 &trivias,
-                IsolatedDeclarationsOptions { strip_internal: true },
+                IsolatedDeclarationsOptions { strip_internal: true, jsdoc_types: false },
             )
             .build(&program);
         });
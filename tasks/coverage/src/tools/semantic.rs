@@ -17,7 +17,7 @@ use crate::{
 fn get_default_transformer_options() -> TransformOptions {
     TransformOptions {
         typescript: TypeScriptOptions::default(),
-        es2015: ES2015Options { arrow_function: None },
+        es2015: ES2015Options { arrow_function: None, ..Default::default() },
         react: JsxOptions {
             jsx_plugin: true,
             jsx_self_plugin: true,
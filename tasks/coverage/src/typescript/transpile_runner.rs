@@ -182,7 +182,7 @@ fn transpile(path: &Path, source_text: &str) -> (String, Vec<OxcDiagnostic>) {
         &allocator,
         source_text,
         &ret.trivias,
-        IsolatedDeclarationsOptions { strip_internal: true },
+        IsolatedDeclarationsOptions { strip_internal: true, jsdoc_types: false },
     )
     .build(&ret.program);
     let printed = CodeGenerator::new().build(&ret.program).source_text;
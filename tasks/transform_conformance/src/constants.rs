@@ -2,17 +2,17 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-preset-env",
     // // ES2024
     // "babel-plugin-transform-unicode-sets-regex",
-    // // ES2022
-    // "babel-plugin-transform-class-properties",
+    // ES2022
+    "babel-plugin-transform-class-properties",
     // "babel-plugin-transform-class-static-block",
-    // "babel-plugin-transform-private-methods",
-    // "babel-plugin-transform-private-property-in-object",
+    "babel-plugin-transform-private-methods",
+    "babel-plugin-transform-private-property-in-object",
     // // [Syntax] "babel-plugin-transform-syntax-top-level-await",
     // ES2021
     "babel-plugin-transform-logical-assignment-operators",
-    // "babel-plugin-transform-numeric-separator",
+    "babel-plugin-transform-numeric-separator",
     // ES2020
-    // "babel-plugin-transform-export-namespace-from",
+    "babel-plugin-transform-export-namespace-from",
     // "babel-plugin-transform-dynamic-import",
     "babel-plugin-transform-nullish-coalescing-operator",
     // "babel-plugin-transform-optional-chaining",
@@ -34,14 +34,21 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-regenerator",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-typeof-symbol",
     // "babel-plugin-transform-function-name",
-    // "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-shorthand-properties",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-template-literals",
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
-    // "babel-plugin-transform-new-target",
+    "babel-plugin-transform-new-target",
     // // ES3
     // "babel-plugin-transform-property-literals",
     // TypeScript
@@ -55,21 +62,17 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
     // // Proposal
-    // "babel-plugin-proposal-decorators",
-
+    "babel-plugin-proposal-decorators",
+    "babel-plugin-proposal-explicit-resource-management",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
 ];
 
 pub(crate) const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
-    "transform-class-properties",
     "transform-classes",
-    "transform-destructuring",
     "transform-modules-commonjs",
     "transform-optional-chaining",
     "transform-parameters",
-    "transform-private-methods",
     "transform-property-literals",
     "transform-react-constant-elements",
 ];
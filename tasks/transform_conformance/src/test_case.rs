@@ -184,6 +184,16 @@ pub trait TestCase {
     }
 }
 
+/// Whether the options enable `__source` in JSX, which contains the path of the file.
+fn emits_jsx_source(options: &BabelOptions) -> bool {
+    options.has_plugin("transform-react-jsx-source")
+        || options.has_plugin("transform-react-jsx-development")
+        || options
+            .get_preset("react")
+            .flatten()
+            .is_some_and(|react| react["development"].as_bool() == Some(true))
+}
+
 #[derive(Debug)]
 pub struct ConformanceTestCase {
     path: PathBuf,
@@ -297,6 +307,13 @@ impl TestCase for ConformanceTestCase {
             output = output_path.and_then(|path| fs::read_to_string(path).ok()).map_or_else(
                 String::default,
                 |output| {
+                    // Babel's `__source` fixtures replace the CWD in the absolute path of the file
+                    // with `<CWD>`, whereas our `fileName` is relative to the CWD.
+                    let output = if emits_jsx_source(babel_options) {
+                        output.cow_replace("<CWD>/", "")
+                    } else {
+                        output.into()
+                    };
                     // Get expected code by parsing the source text, so we can get the same code generated result.
                     let ret = Parser::new(&allocator, &output, source_type).parse();
                     CodeGenerator::new()
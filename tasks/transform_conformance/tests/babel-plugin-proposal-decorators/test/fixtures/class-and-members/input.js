@@ -0,0 +1,8 @@
+@dec
+class A {
+  @method m() {}
+  @getter get g() {
+    return 1;
+  }
+  @field static s = 1;
+}
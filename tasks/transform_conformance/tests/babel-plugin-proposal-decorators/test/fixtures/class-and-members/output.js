@@ -0,0 +1,22 @@
+let _classDecs = [dec], _init_s, _init_extra_s, _initProto, _A, _initClass;
+class A {
+  constructor() {
+    _initProto(this);
+  }
+  static {
+    ({
+      e: [_init_s, _init_extra_s, _initProto],
+      c: [_A, _initClass]
+    } = babelHelpers.applyDecs2311(this, _classDecs, [[method, 2, "m"], [getter, 3, "g"], [field, 8, "s"]]));
+  }
+  m() {}
+  get g() {
+    return 1;
+  }
+  static s = _init_s(this, 1);
+  static {
+    _init_extra_s(this);
+  }
+}
+A = _A;
+_initClass();
@@ -0,0 +1,2 @@
+var _class;
+let A = dec(_class = class A {}) || _class;
@@ -0,0 +1,6 @@
+class A {
+  @field a = 1;
+  @log("m") m() {}
+  b = 2;
+  @field c;
+}
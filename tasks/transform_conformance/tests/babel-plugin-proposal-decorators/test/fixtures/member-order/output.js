@@ -0,0 +1,13 @@
+let _dec = log("m"), _init_a, _init_extra_a, _init_c, _init_extra_c, _initProto;
+class A {
+  constructor() {
+    _init_extra_c(this);
+  }
+  static {
+    [_init_a, _init_extra_a, _init_c, _init_extra_c, _initProto] = babelHelpers.applyDecs2311(this, [], [[_dec, 2, "m"], [field, 0, "a"], [field, 0, "c"]]).e;
+  }
+  a = (_initProto(this), _init_a(this, 1));
+  m() {}
+  b = (_init_extra_a(this), 2);
+  c = _init_c(this);
+}
@@ -0,0 +1,11 @@
+for (using x of xs) use(x);
+
+for (using x of xs) {
+  let x = 1;
+}
+
+async function f() {
+  for (await using x of xs) {
+    use(x);
+  }
+}
@@ -0,0 +1,38 @@
+for (const _x of xs) {
+	try {
+		var _usingCtx = babelHelpers.usingCtx();
+		const x = _usingCtx.u(_x);
+		use(x);
+	} catch (_) {
+		_usingCtx.e = _;
+	} finally {
+		_usingCtx.d();
+	}
+}
+for (const _x2 of xs) {
+	try {
+		var _usingCtx2 = babelHelpers.usingCtx();
+		const x = _usingCtx2.u(_x2);
+		{
+			let x = 1;
+		}
+	} catch (_2) {
+		_usingCtx2.e = _2;
+	} finally {
+		_usingCtx2.d();
+	}
+}
+async function f() {
+	for (const _x3 of xs) {
+		try {
+			var _usingCtx3 = babelHelpers.usingCtx();
+			const x = _usingCtx3.a(_x3);
+			use(x);
+		} catch (_3) {
+			_usingCtx3.e = _3;
+		} finally {
+			await _usingCtx3.d();
+		}
+	}
+}
+
@@ -0,0 +1,3 @@
+for (using x = getX(), y = () => x; ; ) {
+  use(x, y);
+}
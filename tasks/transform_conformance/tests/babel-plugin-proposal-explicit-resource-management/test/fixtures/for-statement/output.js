@@ -0,0 +1,14 @@
+{
+	try {
+		var _usingCtx = babelHelpers.usingCtx();
+		const x = _usingCtx.u(getX()), y = _usingCtx.u(() => x);
+		for (;;) {
+			use(x, y);
+		}
+	} catch (_) {
+		_usingCtx.e = _;
+	} finally {
+		_usingCtx.d();
+	}
+}
+
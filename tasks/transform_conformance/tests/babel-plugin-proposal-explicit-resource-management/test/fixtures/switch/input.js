@@ -0,0 +1,7 @@
+switch (k) {
+  case 1:
+    using a = getA();
+    break;
+  case 2:
+    use(a);
+}
@@ -0,0 +1,14 @@
+try {
+	var _usingCtx = babelHelpers.usingCtx();
+	switch (k) {
+		case 1:
+			const a = _usingCtx.u(getA());
+			break;
+		case 2: use(a);
+	}
+} catch (_) {
+	_usingCtx.e = _;
+} finally {
+	_usingCtx.d();
+}
+
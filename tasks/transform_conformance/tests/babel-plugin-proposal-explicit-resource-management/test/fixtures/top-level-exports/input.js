@@ -0,0 +1,9 @@
+import { x } from "x";
+using r = getR();
+export const a = 1, b = x;
+export class C {}
+export default class D {}
+export function f() {}
+export * from "y";
+let c = 3;
+export { c };
@@ -0,0 +1,20 @@
+import { x } from "x";
+export { a, b };
+export { C };
+export { D as default };
+export function f() {}
+export * from "y";
+export { c };
+try {
+	var _usingCtx = babelHelpers.usingCtx();
+	const r = _usingCtx.u(getR());
+	var a = 1, b = x;
+	var C = class {};
+	var D = class {};
+	var c = 3;
+} catch (_) {
+	_usingCtx.e = _;
+} finally {
+	_usingCtx.d();
+}
+
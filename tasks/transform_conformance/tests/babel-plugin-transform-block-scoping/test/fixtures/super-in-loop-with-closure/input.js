@@ -0,0 +1,8 @@
+class A extends B {
+  m(fns) {
+    for (let i = 0; i < 3; i++) {
+      fns.push(() => i);
+      super.m(i);
+    }
+  }
+}
@@ -0,0 +1,9 @@
+function f() {
+  x;
+  let x = 1;
+  return () => x;
+}
+const g = () => {
+  y;
+  const y = 2;
+};
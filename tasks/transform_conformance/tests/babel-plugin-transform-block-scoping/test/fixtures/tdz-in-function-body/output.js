@@ -0,0 +1,9 @@
+function f() {
+  babelHelpers.tdz("x");
+  var x = 1;
+  return () => x;
+}
+var g = () => {
+  babelHelpers.tdz("y");
+  var y = 2;
+};
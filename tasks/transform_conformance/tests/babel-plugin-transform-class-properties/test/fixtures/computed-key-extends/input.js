@@ -0,0 +1,8 @@
+class A extends B() {
+  [k()] = 1;
+  static [s()] = 2;
+}
+
+const C = class extends D() {
+  [k()] = 1;
+};
@@ -0,0 +1,17 @@
+var _k, _s, _B, _k2, _D;
+_B = B();
+_k = k();
+_s = s();
+class A extends _B {
+  constructor(..._args) {
+    super(..._args);
+    babelHelpers.defineProperty(this, _k, 1);
+  }
+}
+babelHelpers.defineProperty(A, _s, 2);
+const C = (_D = D(), _k2 = k(), class extends _D {
+  constructor(..._args2) {
+    super(..._args2);
+    babelHelpers.defineProperty(this, _k2, 1);
+  }
+});
@@ -0,0 +1,9 @@
+class A {
+  #x;
+  static #y;
+  #z() {}
+
+  constructor(arr) {
+    [this.#x, A.#y = 2, ...this.#z] = arr;
+  }
+}
@@ -0,0 +1,10 @@
+var _A_brand = new WeakSet(), _x = new WeakMap();
+class A {
+  constructor(arr) {
+    babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+    babelHelpers.classPrivateFieldInitSpec(this, _x, void 0);
+    [babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._, babelHelpers.assertClassBrand(A, A, _y)._ = 2, ...(this, babelHelpers.toSetter(babelHelpers.readOnlyError, ["#z"]))._] = arr;
+  }
+}
+var _y = { _: void 0 };
+function _z() {}
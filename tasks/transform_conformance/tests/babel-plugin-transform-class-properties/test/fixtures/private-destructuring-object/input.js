@@ -0,0 +1,8 @@
+class A {
+  #x;
+  set #y(v) {}
+
+  constructor(obj) {
+    ({ a: this.#x, b: this.#y = 1, ...this.#x } = obj);
+  }
+}
@@ -0,0 +1,9 @@
+var _A_brand = new WeakSet(), _x = new WeakMap();
+class A {
+  constructor(obj) {
+    babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+    babelHelpers.classPrivateFieldInitSpec(this, _x, void 0);
+    ({a: babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._, b: babelHelpers.toSetter(babelHelpers.classPrivateSetter, [_A_brand, _set_y, this])._ = 1, ...babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._} = obj);
+  }
+}
+function _set_y(_this, v) {}
@@ -0,0 +1,11 @@
+class A {
+  #x = 1;
+
+  getX() {
+    return this.#x;
+  }
+
+  setX(x) {
+    this.#x = x;
+  }
+}
@@ -0,0 +1,12 @@
+var _x = new WeakMap();
+class A {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _x, 1);
+  }
+  getX() {
+    return babelHelpers.classPrivateFieldGet2(_x, this);
+  }
+  setX(x) {
+    babelHelpers.classPrivateFieldSet2(_x, this, x);
+  }
+}
@@ -0,0 +1,8 @@
+class A {
+  #x;
+
+  run(arr, obj) {
+    for (this.#x of arr) {}
+    for (this.#x in obj) {}
+  }
+}
@@ -0,0 +1,10 @@
+var _x = new WeakMap();
+class A {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _x, void 0);
+  }
+  run(arr, obj) {
+    for (babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._ of arr) {}
+    for (babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._ in obj) {}
+  }
+}
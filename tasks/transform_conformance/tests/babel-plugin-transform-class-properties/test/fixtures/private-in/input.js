@@ -0,0 +1,7 @@
+class A {
+  #x = 1;
+
+  static has(obj) {
+    return #x in obj;
+  }
+}
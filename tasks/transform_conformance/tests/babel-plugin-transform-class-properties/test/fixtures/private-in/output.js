@@ -0,0 +1,9 @@
+var _x = new WeakMap();
+class A {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _x, 1);
+  }
+  static has(obj) {
+    return _x.has(babelHelpers.checkInRHS(obj));
+  }
+}
@@ -0,0 +1,9 @@
+class A {
+  #m() {
+    return 1;
+  }
+
+  run() {
+    return this.#m();
+  }
+}
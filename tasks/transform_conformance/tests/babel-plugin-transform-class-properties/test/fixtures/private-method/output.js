@@ -0,0 +1,12 @@
+var _A_brand = new WeakSet();
+class A {
+  constructor() {
+    babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+  }
+  run() {
+    return babelHelpers.assertClassBrand(_A_brand, this, _m).call(this);
+  }
+}
+function _m() {
+  return 1;
+}
@@ -0,0 +1,13 @@
+class A {
+  #x;
+  #m() {}
+
+  run(o) {
+    o?.#x;
+    o?.a.#x;
+    o.a?.#x.b;
+    o?.#m();
+    o.#m?.();
+    o?.a.#m?.();
+  }
+}
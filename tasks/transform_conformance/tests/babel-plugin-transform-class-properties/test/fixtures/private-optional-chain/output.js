@@ -0,0 +1,17 @@
+var _A_brand = new WeakSet(), _x = new WeakMap();
+class A {
+  constructor() {
+    babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+    babelHelpers.classPrivateFieldInitSpec(this, _x, void 0);
+  }
+  run(o) {
+    var _o$a, _o$m, _o$a2, _o$a2$m;
+    o === null || o === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, o);
+    o === null || o === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, o.a);
+    (_o$a = o.a) === null || _o$a === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, _o$a).b;
+    o === null || o === void 0 ? void 0 : babelHelpers.assertClassBrand(_A_brand, o, _m).call(o);
+    (_o$m = babelHelpers.assertClassBrand(_A_brand, o, _m)) === null || _o$m === void 0 ? void 0 : _o$m.call(o);
+    o === null || o === void 0 || (_o$a2$m = babelHelpers.assertClassBrand(_A_brand, _o$a2 = o.a, _m)) === null || _o$a2$m === void 0 ? void 0 : _o$a2$m.call(_o$a2);
+  }
+}
+function _m() {}
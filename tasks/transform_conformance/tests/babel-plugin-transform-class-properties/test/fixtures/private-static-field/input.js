@@ -0,0 +1,7 @@
+class A {
+  static #x = 1;
+
+  static getX() {
+    return A.#x;
+  }
+}
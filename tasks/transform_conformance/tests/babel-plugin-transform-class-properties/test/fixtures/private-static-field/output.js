@@ -0,0 +1,6 @@
+class A {
+  static getX() {
+    return babelHelpers.assertClassBrand(A, A, _x)._;
+  }
+}
+var _x = { _: 1 };
@@ -0,0 +1,10 @@
+class A extends B {
+  x = 1;
+  constructor(a) {
+    if (a) {
+      super(a);
+    } else {
+      super();
+    }
+  }
+}
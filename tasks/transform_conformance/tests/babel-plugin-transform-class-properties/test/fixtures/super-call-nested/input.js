@@ -0,0 +1,8 @@
+class A extends B {
+  x = 1;
+  constructor(a) {
+    if (a) {
+      super(a);
+    }
+  }
+}
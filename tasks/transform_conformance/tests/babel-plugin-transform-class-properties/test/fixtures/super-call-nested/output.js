@@ -0,0 +1,8 @@
+class A extends B {
+  constructor(a) {
+    var _super = (..._args) => (super(..._args), babelHelpers.defineProperty(this, "x", 1), this);
+    if (a) {
+      _super(a);
+    }
+  }
+}
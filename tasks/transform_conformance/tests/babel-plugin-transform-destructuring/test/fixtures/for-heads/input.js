@@ -0,0 +1,7 @@
+const m = new Map();
+for (const [k, v] of m) console.log(k, v);
+for (let { a, b: [c] = [] } in o) { let a = 1; f(a, c); }
+for (var [x, y] of m) {}
+for ([p, q] of m) g(() => p);
+for ({ r } of m) {}
+label: for (const [k] of m) { continue label; }
@@ -0,0 +1,29 @@
+var _ref8;
+const m = new Map();
+for (const _ref of m) {
+  const _ref2 = babelHelpers.slicedToArray(_ref, 2), k = _ref2[0], v = _ref2[1];
+  console.log(k, v);
+}
+for (let _ref3 in o) {
+  let a = _ref3.a, _ref3$b = _ref3.b, _ref4 = babelHelpers.slicedToArray(_ref3$b === void 0 ? [] : _ref3$b, 1), c = _ref4[0];
+  {
+    let a = 1;
+    f(a, c);
+  }
+}
+for (var _ref5 of m) {
+  var _ref6 = babelHelpers.slicedToArray(_ref5, 2), x = _ref6[0], y = _ref6[1];
+}
+for (var _ref7 of m) {
+  _ref8 = babelHelpers.slicedToArray(_ref7, 2);
+  p = _ref8[0];
+  q = _ref8[1];
+  g(() => p);
+}
+for (var _ref9 of m) {
+  r = _ref9.r;
+}
+label: for (const _ref10 of m) {
+  const _ref11 = babelHelpers.slicedToArray(_ref10, 1), k = _ref11[0];
+  continue label;
+}
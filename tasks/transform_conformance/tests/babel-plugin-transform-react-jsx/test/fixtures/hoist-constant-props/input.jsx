@@ -0,0 +1,14 @@
+function App({ cls, r, props }) {
+  return (
+    <>
+      <div className="static" id="a" />
+      <div className="static">text</div>
+      <div className={cls} />
+      <div key="k" className="static" />
+      <div ref="r" className="static" />
+      <div ref={r} className="static" />
+      <div {...props} />
+      <div />
+    </>
+  );
+}
@@ -0,0 +1,22 @@
+import { jsx as _jsx, Fragment as _Fragment, jsxs as _jsxs } from "react/jsx-runtime";
+var _props = {
+  className: "static",
+  id: "a"
+}, _props2 = {
+  className: "static",
+  children: "text"
+}, _props3 = {
+  className: "static"
+};
+function App({ cls, r, props }) {
+  return _jsxs(_Fragment, { children: [
+    _jsx("div", _props),
+    _jsx("div", _props2),
+    _jsx("div", { className: cls }),
+    _jsx("div", _props3, "k"),
+    _jsx("div", { ref: "r", className: "static" }),
+    _jsx("div", { ref: r, className: "static" }),
+    _jsx("div", { ...props }),
+    _jsx("div", {})
+  ] });
+}
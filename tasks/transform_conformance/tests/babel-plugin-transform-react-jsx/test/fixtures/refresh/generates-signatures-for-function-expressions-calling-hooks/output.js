@@ -5,7 +5,7 @@ export const A = _s(React.memo(_c2 = _s(React.forwardRef(_c = _s((props, ref) =>
 	const [foo, setFoo] = useState(0);
 	React.useEffect(() => {});
 	return _jsx("h1", {
-		ref,
+		ref: ref,
 		children: foo
 	});
 }, "useState{[foo, setFoo](0)}\\nuseEffect{}")), "useState{[foo, setFoo](0)}\\nuseEffect{}")), "useState{[foo, setFoo](0)}\\nuseEffect{}");
@@ -15,7 +15,7 @@ export const B = _s2(React.memo(_c5 = _s2(React.forwardRef(_c4 = _s2(function(pr
 	const [foo, setFoo] = useState(0);
 	React.useEffect(() => {});
 	return _jsx("h1", {
-		ref,
+		ref: ref,
 		children: foo
 	});
 }, "useState{[foo, setFoo](0)}\\nuseEffect{}")), "useState{[foo, setFoo](0)}\\nuseEffect{}")), "useState{[foo, setFoo](0)}\\nuseEffect{}");
@@ -27,7 +27,7 @@ function hoc() {
 		const [foo, setFoo] = useState(0);
 		React.useEffect(() => {});
 		return _jsx("h1", {
-			ref,
+			ref: ref,
 			children: foo
 		});
 	}, "useState{[foo, setFoo](0)}\\nuseEffect{}");
@@ -0,0 +1,9 @@
+class A {
+  *gen(x) {
+    yield this.a;
+    yield x;
+  }
+  static *[Symbol.iterator]() {
+    yield arguments.length;
+  }
+}
@@ -0,0 +1,9 @@
+const obj = {
+  *gen(x) {
+    yield this.a;
+    yield x;
+  },
+  *[key]() {
+    yield arguments.length;
+  },
+};
@@ -0,0 +1,30 @@
+const obj = {
+  gen: babelHelpers.regeneratorRuntime().mark(function _callee(x) {
+    return babelHelpers.regeneratorRuntime().wrap(function (_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 1;
+          return this.a;
+        case 1:
+          _context.next = 2;
+          return x;
+        case 2:
+        case "end":
+          return _context.stop();
+      }
+    }, _callee, this);
+  }),
+  [key]: babelHelpers.regeneratorRuntime().mark(function _callee2() {
+    var _args = arguments;
+    return babelHelpers.regeneratorRuntime().wrap(function (_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 1;
+          return _args.length;
+        case 1:
+        case "end":
+          return _context2.stop();
+      }
+    }, _callee2);
+  })
+};
@@ -0,0 +1,9 @@
+function* gen() {
+  for (let i = 0; i < 2; i++) {
+    try {
+      yield i;
+    } catch (e) {
+      log(e);
+    }
+  }
+}
@@ -0,0 +1,34 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var i, e;
+  return babelHelpers.regeneratorRuntime().wrap(function (_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        i = 0;
+      case 1:
+        if (!(i < 2)) {
+          _context.next = 10;
+          break;
+        }
+        _context.prev = 2;
+        _context.next = 4;
+        return i;
+      case 4:
+        _context.next = 8;
+        break;
+      case 5:
+        _context.prev = 5;
+        e = _context["catch"](2);
+        {
+          log(e);
+        }
+      case 8:
+        i++;
+        _context.next = 1;
+        break;
+      case 10:
+      case "end":
+        return _context.stop();
+    }
+  }, _marked, null, [[2, 5]]);
+}
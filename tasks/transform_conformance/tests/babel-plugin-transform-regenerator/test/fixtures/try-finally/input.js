@@ -0,0 +1,7 @@
+function* gen() {
+  try {
+    yield 0;
+  } finally {
+    log("finally");
+  }
+}
@@ -0,0 +1,20 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  return babelHelpers.regeneratorRuntime().wrap(function (_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 2;
+        return 0;
+      case 2:
+        _context.prev = 2;
+        {
+          log("finally");
+        }
+        return _context.finish(2);
+      case 5:
+      case "end":
+        return _context.stop();
+    }
+  }, _marked, null, [[0,, 2, 5]]);
+}
@@ -0,0 +1,6 @@
+function test(f, a, args) {
+  f?.(...args);
+  a?.b(...args);
+  a.b?.(...args);
+  a?.b(...args).c;
+}
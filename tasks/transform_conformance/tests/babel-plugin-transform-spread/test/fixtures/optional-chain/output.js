@@ -0,0 +1,6 @@
+function test(f, a, args) {
+  f?.apply(void 0, babelHelpers.toConsumableArray(args));
+  a?.b.apply(a, babelHelpers.toConsumableArray(args));
+  a.b?.apply(a, babelHelpers.toConsumableArray(args));
+  a?.b.apply(a, babelHelpers.toConsumableArray(args)).c;
+}
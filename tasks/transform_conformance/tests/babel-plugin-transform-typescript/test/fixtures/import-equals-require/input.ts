@@ -0,0 +1,3 @@
+import foo = require("foo");
+import bar = foo.bar;
+foo(bar);
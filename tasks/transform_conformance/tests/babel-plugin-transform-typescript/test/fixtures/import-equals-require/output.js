@@ -0,0 +1,3 @@
+const foo = require("foo");
+var bar = foo.bar;
+foo(bar);
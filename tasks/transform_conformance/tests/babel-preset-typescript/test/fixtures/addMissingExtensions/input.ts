@@ -0,0 +1,13 @@
+import "./a";
+import "../dir/b";
+import "./a.ts";
+import "./a.mjs";
+export * from "./c";
+export { d } from "./d";
+// Directories are not changed.
+import ".";
+import "./dir/";
+// Bare specifiers are not changed.
+import "lodash";
+import "a-package/file";
+import "@scope/package";
@@ -0,0 +1,13 @@
+import "./a.js";
+import "../dir/b.js";
+import "./a.js";
+import "./a.mjs";
+export * from "./c.js";
+export { d } from "./d.js";
+// Directories are not changed.
+import ".";
+import "./dir/";
+// Bare specifiers are not changed.
+import "lodash";
+import "a-package/file";
+import "@scope/package";